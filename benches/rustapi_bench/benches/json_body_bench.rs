@@ -0,0 +1,74 @@
+//! JSON body parsing allocation benchmarks
+//!
+//! Compares copying a request body before simd-json parsing (the old
+//! `json::from_slice` behavior) against reusing the body's own buffer via
+//! `Bytes::try_into_mut` (the `json::from_slice_owned` behavior), on a
+//! typical JSON echo path payload.
+
+#![allow(dead_code)]
+
+use bytes::Bytes;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EchoBody {
+    name: String,
+    email: String,
+    age: u32,
+    tags: Vec<String>,
+}
+
+fn sample_payload() -> Bytes {
+    let body = EchoBody {
+        name: "John Doe".to_string(),
+        email: "john@example.com".to_string(),
+        age: 30,
+        tags: vec!["rust".to_string(), "api".to_string(), "web".to_string()],
+    };
+    Bytes::from(serde_json::to_vec(&body).unwrap())
+}
+
+/// The old behavior: always copy the buffer before letting simd-json parse
+/// it in place.
+fn parse_via_copy(bytes: &Bytes) -> EchoBody {
+    let mut copy = bytes.to_vec();
+    simd_json::from_slice(&mut copy).unwrap()
+}
+
+/// The new behavior: reuse the buffer in place when it's uniquely owned,
+/// only falling back to a copy if it's still shared.
+fn parse_via_try_into_mut(bytes: Bytes) -> EchoBody {
+    match bytes.try_into_mut() {
+        Ok(mut owned) => simd_json::from_slice(&mut owned).unwrap(),
+        Err(shared) => {
+            let mut copy = shared.to_vec();
+            simd_json::from_slice(&mut copy).unwrap()
+        }
+    }
+}
+
+/// Benchmark parsing a JSON echo-path body, copy vs. zero-copy.
+fn bench_json_body_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("json_body_parsing");
+
+    group.bench_function("copy_before_parse", |b| {
+        let payload = sample_payload();
+        b.iter(|| parse_via_copy(black_box(&payload)))
+    });
+
+    group.bench_function("try_into_mut_before_parse", |b| {
+        // Uniquely owned each iteration, mirroring `Request::take_body`
+        // handing an extractor sole ownership of the buffer.
+        b.iter_batched(
+            sample_payload,
+            |payload| parse_via_try_into_mut(black_box(payload)),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_json_body_parsing);
+criterion_main!(benches);
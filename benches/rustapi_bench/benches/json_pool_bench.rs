@@ -0,0 +1,92 @@
+//! JSON response buffer pooling allocation benchmarks
+//!
+//! Compares allocating a fresh `Vec` per call (the old `Json<T>` response
+//! path) against drawing a scratch buffer from a thread-local pool (the
+//! `json::to_bytes_pooled` behavior), on a typical small JSON response
+//! payload.
+
+#![allow(dead_code)]
+
+use bytes::Bytes;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde::Serialize;
+use std::cell::RefCell;
+
+#[derive(Debug, Serialize)]
+struct UserResponse {
+    id: u64,
+    name: String,
+    email: String,
+    active: bool,
+}
+
+fn sample_response() -> UserResponse {
+    UserResponse {
+        id: 42,
+        name: "Jane Doe".to_string(),
+        email: "jane@example.com".to_string(),
+        active: true,
+    }
+}
+
+const POOLED_JSON_THRESHOLD: usize = 8 * 1024;
+const POOL_CAPACITY: usize = 32;
+
+thread_local! {
+    static BUFFER_POOL: RefCell<Vec<Vec<u8>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_scratch_buffer() -> Vec<u8> {
+    BUFFER_POOL
+        .with(|pool| pool.borrow_mut().pop())
+        .unwrap_or_else(|| Vec::with_capacity(POOLED_JSON_THRESHOLD))
+}
+
+fn return_scratch_buffer(mut buf: Vec<u8>) {
+    if buf.capacity() > POOLED_JSON_THRESHOLD {
+        return;
+    }
+    buf.clear();
+    BUFFER_POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        if pool.len() < POOL_CAPACITY {
+            pool.push(buf);
+        }
+    });
+}
+
+/// The old behavior: allocate a fresh `Vec` for every response.
+fn serialize_fresh_vec(value: &UserResponse) -> Bytes {
+    let mut buf = Vec::with_capacity(256);
+    serde_json::to_writer(&mut buf, value).unwrap();
+    Bytes::from(buf)
+}
+
+/// The new behavior: reuse a thread-local scratch buffer, copying out the
+/// finished bytes so the buffer can be returned to the pool immediately.
+fn serialize_pooled(value: &UserResponse) -> Bytes {
+    let mut buf = take_scratch_buffer();
+    serde_json::to_writer(&mut buf, value).unwrap();
+    let bytes = Bytes::copy_from_slice(&buf);
+    return_scratch_buffer(buf);
+    bytes
+}
+
+/// Benchmark serializing a small JSON response, fresh allocation vs. pooled.
+fn bench_json_response_serialization(c: &mut Criterion) {
+    let mut group = c.benchmark_group("json_response_serialization");
+    let payload = sample_response();
+
+    group.bench_function("fresh_vec_per_response", |b| {
+        b.iter(|| serialize_fresh_vec(black_box(&payload)))
+    });
+
+    group.bench_function("pooled_scratch_buffer", |b| {
+        b.iter(|| serialize_pooled(black_box(&payload)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_json_response_serialization);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+//! Middleware `LayerStack` dispatch benchmarks
+//!
+//! Exercises the real `rustapi_core::middleware::LayerStack` (via `RustApi`
+//! and `TestClient`) across increasing layer counts, to confirm per-request
+//! dispatch overhead stays roughly proportional to the number of layers
+//! instead of growing from the deep-cloning a naive per-request rebuild of
+//! the chain would add.
+
+#![allow(dead_code)]
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rustapi_core::middleware::{BoxedNext, MiddlewareLayer};
+use rustapi_core::{get, RustApi, TestClient};
+use std::future::Future;
+use std::pin::Pin;
+
+/// A middleware that does no real work beyond forwarding to `next`, isolating
+/// dispatch overhead from any single layer's own logic.
+#[derive(Clone)]
+struct PassthroughLayer;
+
+impl MiddlewareLayer for PassthroughLayer {
+    fn call(
+        &self,
+        req: rustapi_core::Request,
+        next: BoxedNext,
+    ) -> Pin<Box<dyn Future<Output = rustapi_core::Response> + Send + 'static>> {
+        next(req)
+    }
+
+    fn clone_box(&self) -> Box<dyn MiddlewareLayer> {
+        Box::new(self.clone())
+    }
+}
+
+async fn handler() -> &'static str {
+    "ok"
+}
+
+fn build_app(layer_count: usize) -> RustApi {
+    let mut app = RustApi::new();
+    for _ in 0..layer_count {
+        app = app.layer(PassthroughLayer);
+    }
+    app.route("/", get(handler))
+}
+
+/// Benchmark a single request through stacks of increasing depth.
+fn bench_layer_stack_dispatch(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("layer_stack_dispatch");
+
+    for layer_count in [0, 1, 5, 10].iter() {
+        let client = TestClient::new(build_app(*layer_count));
+
+        group.bench_with_input(
+            BenchmarkId::new("layers", layer_count),
+            &client,
+            |b, client| b.iter(|| rt.block_on(client.get("/"))),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_layer_stack_dispatch);
+criterion_main!(benches);
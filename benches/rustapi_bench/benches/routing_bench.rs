@@ -0,0 +1,67 @@
+//! Route matching benchmarks
+//!
+//! Compares the radix-tree matcher (`matchit`) RustAPI's router is built on
+//! against a naive linear scan over registered routes, across route table
+//! sizes, to confirm lookup stays roughly flat as the route count grows
+//! instead of degrading linearly.
+
+#![allow(dead_code)]
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use matchit::Router as MatchitRouter;
+
+/// Build `count` distinct routes of the form `/resourceN/:id`.
+fn sample_routes(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|i| format!("/resource{}/:id", i))
+        .collect()
+}
+
+fn build_matchit_router(routes: &[String]) -> MatchitRouter<usize> {
+    let mut router = MatchitRouter::new();
+    for (i, route) in routes.iter().enumerate() {
+        router.insert(route, i).unwrap();
+    }
+    router
+}
+
+/// Linear scan matcher: the naive alternative matchit replaces, checking
+/// each registered route's static prefix and trailing `:id` segment in turn.
+fn linear_scan_match(routes: &[String], path: &str) -> bool {
+    routes.iter().any(|route| {
+        route
+            .strip_suffix(":id")
+            .is_some_and(|prefix| path.starts_with(prefix))
+    })
+}
+
+/// Benchmark matching the last-registered route, the worst case for a
+/// linear scan, across increasing route table sizes.
+fn bench_route_matching(c: &mut Criterion) {
+    let mut group = c.benchmark_group("route_matching");
+
+    for route_count in [10, 100, 1_000, 10_000].iter() {
+        let routes = sample_routes(*route_count);
+        let target_path = format!("/resource{}/42", route_count - 1);
+
+        group.bench_with_input(
+            BenchmarkId::new("matchit_radix_tree", route_count),
+            &routes,
+            |b, routes| {
+                let router = build_matchit_router(routes);
+                b.iter(|| router.at(black_box(&target_path)).unwrap().value)
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("linear_scan", route_count),
+            &routes,
+            |b, routes| b.iter(|| linear_scan_match(routes, black_box(&target_path))),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_route_matching);
+criterion_main!(benches);
@@ -1,6 +1,9 @@
 //! CLI argument parsing
 
-use crate::commands::{self, AddArgs, DoctorArgs, GenerateArgs, NewArgs, RunArgs, WatchArgs};
+use crate::commands::{
+    self, AddArgs, DevArgs, DoctorArgs, GenerateArgs, NewArgs, OpenapiArgs, RoutesArgs, RunArgs,
+    WatchArgs,
+};
 use clap::{Parser, Subcommand};
 
 /// RustAPI CLI - Project scaffolding and development utilities
@@ -24,6 +27,9 @@ enum Commands {
     /// Watch for changes and auto-reload (dedicated)
     Watch(WatchArgs),
 
+    /// Run the dev server with watch mode and a live-reload hint (shorthand for `run --watch`)
+    Dev(DevArgs),
+
     /// Add a feature or dependency
     Add(AddArgs),
 
@@ -40,6 +46,13 @@ enum Commands {
         #[arg(short, long, default_value = "8080")]
         port: u16,
     },
+
+    /// List the running app's routes (methods, paths, summaries, tags)
+    Routes(RoutesArgs),
+
+    /// Work with the running app's OpenAPI spec
+    #[command(subcommand)]
+    Openapi(OpenapiArgs),
 }
 
 impl Cli {
@@ -49,10 +62,13 @@ impl Cli {
             Commands::New(args) => commands::new_project(args).await,
             Commands::Run(args) => commands::run_dev(args).await,
             Commands::Watch(args) => commands::watch(args).await,
+            Commands::Dev(args) => commands::dev(args).await,
             Commands::Add(args) => commands::add(args).await,
             Commands::Doctor(args) => commands::doctor(args).await,
             Commands::Generate(args) => commands::generate(args).await,
             Commands::Docs { port } => commands::open_docs(port).await,
+            Commands::Routes(args) => commands::list_routes(args).await,
+            Commands::Openapi(args) => commands::openapi(args).await,
         }
     }
 }
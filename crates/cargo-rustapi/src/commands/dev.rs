@@ -0,0 +1,39 @@
+//! Dev command - shorthand for `run --watch` with a live-reload hint
+
+use anyhow::Result;
+use clap::Args;
+use console::style;
+
+use super::run::{run_dev, RunArgs};
+
+/// Arguments for the `dev` command
+#[derive(Args, Debug)]
+pub struct DevArgs {
+    /// Port to run on
+    #[arg(short, long, default_value = "8080")]
+    pub port: u16,
+
+    /// Additional features to enable
+    #[arg(short, long, value_delimiter = ',')]
+    pub features: Option<Vec<String>>,
+}
+
+/// Watch the source tree, rebuild, and restart the server on change
+///
+/// Shorthand for `cargo rustapi run --watch`. Add `.dev_reload()` to your
+/// `RustApi` builder to get a browser-visible "reloading" banner instead of a
+/// silently stale page while the rebuild is in progress.
+pub async fn dev(args: DevArgs) -> Result<()> {
+    println!(
+        "{}",
+        style("Add `.dev_reload()` to your RustApi builder for a live-reload banner").dim()
+    );
+
+    run_dev(RunArgs {
+        port: args.port,
+        features: args.features,
+        release: false,
+        watch: true,
+    })
+    .await
+}
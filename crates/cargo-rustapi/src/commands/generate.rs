@@ -26,6 +26,12 @@ pub enum GenerateArgs {
         /// Resource name (e.g., "users", "products")
         name: String,
     },
+
+    /// Generate a middleware layer
+    Middleware {
+        /// Middleware name (e.g., "logging", "auth_check")
+        name: String,
+    },
 }
 
 /// Execute code generation
@@ -34,6 +40,7 @@ pub async fn generate(args: GenerateArgs) -> Result<()> {
         GenerateArgs::Handler { name } => generate_handler(&name).await,
         GenerateArgs::Model { name } => generate_model(&name).await,
         GenerateArgs::Crud { name } => generate_crud(&name).await,
+        GenerateArgs::Middleware { name } => generate_middleware(&name).await,
     }
 }
 
@@ -261,6 +268,92 @@ async fn generate_crud(name: &str) -> Result<()> {
     Ok(())
 }
 
+async fn generate_middleware(name: &str) -> Result<()> {
+    let middleware_dir = Path::new("src/middleware");
+
+    // Create middleware directory if it doesn't exist
+    if !middleware_dir.exists() {
+        fs::create_dir_all(middleware_dir).await?;
+
+        // Create mod.rs
+        let mod_content = format!("pub mod {};\n", name);
+        fs::write(middleware_dir.join("mod.rs"), mod_content).await?;
+    } else {
+        // Append to existing mod.rs
+        let mod_path = middleware_dir.join("mod.rs");
+        if mod_path.exists() {
+            let mut content = fs::read_to_string(&mod_path).await?;
+            if !content.contains(&format!("mod {};", name)) {
+                content.push_str(&format!("pub mod {};\n", name));
+                fs::write(&mod_path, content).await?;
+            }
+        }
+    }
+
+    // Generate middleware file
+    let type_name = to_pascal_case(name);
+    let middleware_content = format!(
+        r#"//! {name} middleware
+
+use rustapi_rs::middleware::{{BoxedNext, MiddlewareLayer}};
+use rustapi_rs::prelude::*;
+use std::future::Future;
+use std::pin::Pin;
+
+/// TODO: describe what {name} does
+#[derive(Clone, Default)]
+pub struct {type_name}Layer;
+
+impl {type_name}Layer {{
+    pub fn new() -> Self {{
+        Self
+    }}
+}}
+
+impl MiddlewareLayer for {type_name}Layer {{
+    fn call(
+        &self,
+        req: Request,
+        next: BoxedNext,
+    ) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> {{
+        Box::pin(async move {{
+            // TODO: inspect/modify the request before it reaches the handler
+
+            let response = next(req).await;
+
+            // TODO: inspect/modify the response before it goes out
+
+            response
+        }})
+    }}
+
+    fn clone_box(&self) -> Box<dyn MiddlewareLayer> {{
+        Box::new(self.clone())
+    }}
+}}
+"#,
+        name = name,
+        type_name = type_name,
+    );
+
+    let middleware_path = middleware_dir.join(format!("{}.rs", name));
+    fs::write(&middleware_path, middleware_content).await?;
+
+    println!(
+        "{} Generated middleware: {}",
+        style("✓").green(),
+        middleware_path.display()
+    );
+    println!();
+    println!("Don't forget to register the layer in main.rs:");
+    println!(
+        "  {}",
+        style(format!(".layer(middleware::{}::{}Layer::new())", name, type_name)).cyan()
+    );
+
+    Ok(())
+}
+
 // Helper functions
 fn capitalize(s: &str) -> String {
     let mut chars = s.chars();
@@ -1,17 +1,23 @@
 //! CLI commands
 
 mod add;
+mod dev;
 mod docs;
 mod doctor;
 mod generate;
 mod new;
+mod openapi;
+mod routes;
 mod run;
 mod watch;
 
 pub use add::{add, AddArgs};
+pub use dev::{dev, DevArgs};
 pub use docs::open_docs;
 pub use doctor::{doctor, DoctorArgs};
 pub use generate::{generate, GenerateArgs};
 pub use new::{new_project, NewArgs};
+pub use openapi::{openapi, OpenapiArgs};
+pub use routes::{list_routes, RoutesArgs};
 pub use run::{run_dev, RunArgs};
 pub use watch::{watch, WatchArgs};
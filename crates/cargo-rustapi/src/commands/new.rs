@@ -68,6 +68,8 @@ pub async fn new_project(mut args: NewArgs) -> Result<()> {
             "api - REST API with CRUD",
             "web - Web app with templates",
             "full - Full-featured app",
+            "auth - Register/login with JWT-protected routes",
+            "htmx - Server-rendered fragments swapped in by htmx",
         ];
         let selection = Select::with_theme(&theme)
             .with_prompt("Select a template")
@@ -80,6 +82,8 @@ pub async fn new_project(mut args: NewArgs) -> Result<()> {
             1 => ProjectTemplate::Api,
             2 => ProjectTemplate::Web,
             3 => ProjectTemplate::Full,
+            4 => ProjectTemplate::Auth,
+            5 => ProjectTemplate::Htmx,
             _ => ProjectTemplate::Minimal,
         }
     };
@@ -93,7 +97,10 @@ pub async fn new_project(mut args: NewArgs) -> Result<()> {
         let available = ["jwt", "cors", "rate-limit", "config", "toon", "ws", "view"];
         let defaults = match template {
             ProjectTemplate::Full => vec![true, true, true, true, false, false, false],
-            ProjectTemplate::Web => vec![false, false, false, false, false, false, true],
+            ProjectTemplate::Web | ProjectTemplate::Htmx => {
+                vec![false, false, false, false, false, false, true]
+            }
+            ProjectTemplate::Auth => vec![true, false, false, false, false, false, false],
             _ => vec![false; available.len()],
         };
 
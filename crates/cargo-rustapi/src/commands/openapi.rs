@@ -0,0 +1,80 @@
+//! OpenAPI spec export command
+
+use anyhow::{Context, Result};
+use clap::{Subcommand, ValueEnum};
+use console::style;
+use serde_json::Value;
+use std::path::PathBuf;
+use tokio::fs;
+
+/// Output format for `rustapi openapi export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SpecFormat {
+    Json,
+    Yaml,
+}
+
+/// Arguments for the `openapi` command
+#[derive(Subcommand, Debug)]
+pub enum OpenapiArgs {
+    /// Export the running app's OpenAPI spec to a file
+    Export {
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "json")]
+        format: SpecFormat,
+
+        /// File to write the spec to (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Port the dev server is running on
+        #[arg(short, long, default_value = "8080")]
+        port: u16,
+
+        /// Path the OpenAPI docs were mounted at (see `.docs(path)` in your app)
+        #[arg(long, default_value = "/docs")]
+        docs_path: String,
+    },
+}
+
+/// Execute an `openapi` subcommand
+pub async fn openapi(args: OpenapiArgs) -> Result<()> {
+    match args {
+        OpenapiArgs::Export {
+            format,
+            output,
+            port,
+            docs_path,
+        } => export(format, output, port, &docs_path).await,
+    }
+}
+
+async fn export(format: SpecFormat, output: Option<PathBuf>, port: u16, docs_path: &str) -> Result<()> {
+    let url = format!("http://localhost:{}{}/openapi.json", port, docs_path);
+
+    let spec: Value = reqwest::get(&url)
+        .await
+        .with_context(|| format!("Failed to reach {}", url))?
+        .json()
+        .await
+        .context("Response was not valid OpenAPI JSON")?;
+
+    let rendered = match format {
+        SpecFormat::Json => serde_json::to_string_pretty(&spec)?,
+        SpecFormat::Yaml => serde_yaml::to_string(&spec)?,
+    };
+
+    match output {
+        Some(path) => {
+            fs::write(&path, rendered).await?;
+            println!(
+                "{} Exported OpenAPI spec to {}",
+                style("✓").green(),
+                path.display()
+            );
+        }
+        None => println!("{}", rendered),
+    }
+
+    Ok(())
+}
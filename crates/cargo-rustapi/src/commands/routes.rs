@@ -0,0 +1,93 @@
+//! Route listing command
+
+use anyhow::{Context, Result};
+use clap::Args;
+use console::style;
+use serde_json::Value;
+
+/// Arguments for the `routes` command
+#[derive(Args, Debug)]
+pub struct RoutesArgs {
+    /// Port the dev server is running on
+    #[arg(short, long, default_value = "8080")]
+    pub port: u16,
+
+    /// Path the OpenAPI docs were mounted at (see `.docs(path)` in your app)
+    #[arg(long, default_value = "/docs")]
+    pub docs_path: String,
+}
+
+/// Print the route table by reading the running app's OpenAPI spec
+pub async fn list_routes(args: RoutesArgs) -> Result<()> {
+    let url = format!(
+        "http://localhost:{}{}/openapi.json",
+        args.port, args.docs_path
+    );
+
+    let spec: Value = reqwest::get(&url)
+        .await
+        .with_context(|| format!("Failed to reach {}", url))?
+        .json()
+        .await
+        .context("Response was not valid OpenAPI JSON")?;
+
+    let paths = spec
+        .get("paths")
+        .and_then(Value::as_object)
+        .context("OpenAPI spec has no `paths` object")?;
+
+    let mut rows: Vec<(String, String, String, String)> = Vec::new();
+    for (path, methods) in paths {
+        let Some(methods) = methods.as_object() else {
+            continue;
+        };
+        for method in ["get", "post", "put", "patch", "delete"] {
+            let Some(operation) = methods.get(method) else {
+                continue;
+            };
+            let summary = operation
+                .get("summary")
+                .and_then(Value::as_str)
+                .unwrap_or("-")
+                .to_string();
+            let tags = operation
+                .get("tags")
+                .and_then(Value::as_array)
+                .map(|tags| {
+                    tags.iter()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+            rows.push((method.to_uppercase(), path.clone(), summary, tags));
+        }
+    }
+
+    rows.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+
+    if rows.is_empty() {
+        println!("{}", style("No routes found in the OpenAPI spec").yellow());
+        return Ok(());
+    }
+
+    let method_width = rows.iter().map(|r| r.0.len()).max().unwrap_or(6).max(6);
+    let path_width = rows.iter().map(|r| r.1.len()).max().unwrap_or(4).max(4);
+    let summary_width = rows.iter().map(|r| r.2.len()).max().unwrap_or(7).max(7);
+
+    println!(
+        "{:<method_width$}  {:<path_width$}  {:<summary_width$}  {}",
+        style("METHOD").bold(),
+        style("PATH").bold(),
+        style("SUMMARY").bold(),
+        style("TAGS").bold(),
+    );
+    for (method, path, summary, tags) in &rows {
+        println!(
+            "{:<method_width$}  {:<path_width$}  {:<summary_width$}  {}",
+            method, path, summary, tags
+        );
+    }
+
+    Ok(())
+}
@@ -56,9 +56,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
     let addr = format!("127.0.0.1:{}", port);
 
-    tracing::info!("🚀 Server running at http://{}", addr);
-    tracing::info!("📚 API docs at http://{}/docs", addr);
-
     RustApi::new()
         .state(state)
         // Health check
@@ -71,6 +68,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         .mount(handlers::items::delete)
         // Documentation
         .docs("/docs")
+        .print_startup_info(true)
         .run(&addr)
         .await
 }
@@ -0,0 +1,279 @@
+//! Auth project template - register/login/JWT-protected routes
+
+use super::common;
+use anyhow::Result;
+use tokio::fs;
+
+pub async fn generate(name: &str, features: &[String]) -> Result<()> {
+    // Auth needs the jwt feature
+    let mut all_features = features.to_vec();
+    if !all_features.contains(&"jwt".to_string()) {
+        all_features.push("jwt".to_string());
+    }
+
+    // Cargo.toml
+    let cargo_toml = format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+rustapi-rs = {{ version = "0.1"{features} }}
+tokio = {{ version = "1", features = ["full"] }}
+serde = {{ version = "1", features = ["derive"] }}
+tracing = "0.1"
+tracing-subscriber = {{ version = "0.3", features = ["env-filter"] }}
+uuid = {{ version = "1", features = ["v4"] }}
+"#,
+        name = name,
+        features = common::features_to_cargo(&all_features),
+    );
+    fs::write(format!("{name}/Cargo.toml"), cargo_toml).await?;
+
+    // Create directories
+    fs::create_dir_all(format!("{name}/src/handlers")).await?;
+    fs::create_dir_all(format!("{name}/src/models")).await?;
+
+    // main.rs
+    let main_rs = r#"mod handlers;
+mod models;
+
+use rustapi_rs::prelude::*;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub type AppState = Arc<RwLock<models::Store>>;
+
+#[rustapi::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // Initialize tracing
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::from_default_env()
+                .add_directive("info".parse().unwrap()),
+        )
+        .init();
+
+    // Create shared state
+    let state: AppState = Arc::new(RwLock::new(models::Store::new()));
+
+    let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
+    let addr = format!("127.0.0.1:{}", port);
+
+    RustApi::new()
+        .state(state)
+        // Health check
+        .route("/health", get(handlers::health))
+        // Auth endpoints
+        .route("/auth/register", post(handlers::auth::register))
+        .route("/auth/login", post(handlers::auth::login))
+        .route("/auth/me", get(handlers::auth::me))
+        // Documentation
+        .docs("/docs")
+        .print_startup_info(true)
+        .run(&addr)
+        .await
+}
+"#;
+    fs::write(format!("{name}/src/main.rs"), main_rs).await?;
+
+    // handlers/mod.rs
+    let handlers_mod = r#"//! Request handlers
+
+pub mod auth;
+
+use rustapi_rs::prelude::*;
+use serde::Serialize;
+
+#[derive(Serialize, Schema)]
+pub struct HealthResponse {
+    pub status: String,
+    pub version: String,
+}
+
+pub async fn health() -> Json<HealthResponse> {
+    Json(HealthResponse {
+        status: "ok".to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    })
+}
+"#;
+    fs::write(format!("{name}/src/handlers/mod.rs"), handlers_mod).await?;
+
+    // handlers/auth.rs
+    let handlers_auth = r#"//! Registration, login, and current-user handlers
+
+use crate::models::{User, UserClaims};
+use crate::AppState;
+use rustapi_rs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Schema)]
+pub struct RegisterRequest {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize, Schema)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize, Schema)]
+pub struct LoginResponse {
+    pub token: String,
+    pub token_type: String,
+}
+
+/// Register a new user
+#[rustapi::post("/auth/register")]
+#[rustapi::tag("Authentication")]
+#[rustapi::summary("Register a new account")]
+pub async fn register(
+    State(state): State<AppState>,
+    Json(body): Json<RegisterRequest>,
+) -> Result<Created<Json<LoginResponse>>> {
+    let mut store = state.write().await;
+
+    if store.users.contains_key(&body.username) {
+        return Err(ApiError::bad_request("Username already taken"));
+    }
+
+    // TODO: Hash the password before storing it - this is plaintext for demo purposes only
+    let user = User::new(body.username.clone(), body.password);
+    store.users.insert(body.username, user.clone());
+
+    let token = issue_token(&user)?;
+
+    Ok(Created(Json(LoginResponse {
+        token,
+        token_type: "Bearer".to_string(),
+    })))
+}
+
+/// Login and get a JWT token
+#[rustapi::post("/auth/login")]
+#[rustapi::tag("Authentication")]
+#[rustapi::summary("Login with username and password")]
+pub async fn login(
+    State(state): State<AppState>,
+    Json(body): Json<LoginRequest>,
+) -> Result<Json<LoginResponse>> {
+    let store = state.read().await;
+
+    let user = store
+        .users
+        .get(&body.username)
+        .filter(|user| user.password == body.password)
+        .ok_or_else(|| ApiError::unauthorized("Invalid credentials"))?;
+
+    let token = issue_token(user)?;
+
+    Ok(Json(LoginResponse {
+        token,
+        token_type: "Bearer".to_string(),
+    }))
+}
+
+/// Get current user info
+#[rustapi::get("/auth/me")]
+#[rustapi::tag("Authentication")]
+#[rustapi::summary("Get current authenticated user")]
+pub async fn me(auth: AuthUser<UserClaims>) -> Json<UserClaims> {
+    Json(auth.claims)
+}
+
+fn issue_token(user: &User) -> Result<String> {
+    let jwt_secret = std::env::var("JWT_SECRET")
+        .unwrap_or_else(|_| "dev-secret-change-in-production".to_string());
+
+    let claims = UserClaims {
+        sub: user.id.clone(),
+        username: user.username.clone(),
+        exp: (chrono_now() + 86400) as usize, // 24 hours
+    };
+
+    create_token(&claims, &jwt_secret).map_err(|_| ApiError::internal("Failed to issue token"))
+}
+
+fn chrono_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+"#;
+    fs::write(format!("{name}/src/handlers/auth.rs"), handlers_auth).await?;
+
+    // models/mod.rs
+    let models_mod = r#"//! Data models
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// In-memory data store
+pub struct Store {
+    pub users: HashMap<String, User>,
+}
+
+impl Store {
+    pub fn new() -> Self {
+        Self {
+            users: HashMap::new(),
+        }
+    }
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A registered user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    pub id: String,
+    pub username: String,
+    pub password: String,
+}
+
+impl User {
+    pub fn new(username: String, password: String) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            username,
+            password,
+        }
+    }
+}
+
+/// Claims embedded in the JWT issued at login
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserClaims {
+    pub sub: String,
+    pub username: String,
+    pub exp: usize,
+}
+"#;
+    fs::write(format!("{name}/src/models/mod.rs"), models_mod).await?;
+
+    // .env.example with JWT secret
+    let env_example = r#"# Server configuration
+PORT=8080
+
+# JWT Secret (CHANGE THIS IN PRODUCTION!)
+JWT_SECRET=your-super-secret-key-change-in-production
+
+# Logging
+RUST_LOG=info
+"#;
+    fs::write(format!("{name}/.env.example"), env_example).await?;
+
+    // .gitignore
+    common::generate_gitignore(name).await?;
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+//! Htmx project template - server-rendered HTML fragments swapped in by htmx
+
+use super::common;
+use anyhow::Result;
+use tokio::fs;
+
+pub async fn generate(name: &str, features: &[String]) -> Result<()> {
+    // Add view feature
+    let mut all_features = features.to_vec();
+    if !all_features.contains(&"view".to_string()) {
+        all_features.push("view".to_string());
+    }
+
+    // Cargo.toml
+    let cargo_toml = format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+rustapi-rs = {{ version = "0.1"{features} }}
+rustapi-view = "0.1"
+tokio = {{ version = "1", features = ["full"] }}
+serde = {{ version = "1", features = ["derive"] }}
+tracing = "0.1"
+tracing-subscriber = {{ version = "0.3", features = ["env-filter"] }}
+"#,
+        name = name,
+        features = common::features_to_cargo(&all_features),
+    );
+    fs::write(format!("{name}/Cargo.toml"), cargo_toml).await?;
+
+    // Create directories
+    fs::create_dir_all(format!("{name}/src/handlers")).await?;
+    fs::create_dir_all(format!("{name}/templates")).await?;
+    fs::create_dir_all(format!("{name}/static")).await?;
+
+    // main.rs
+    let main_rs = r#"mod handlers;
+
+use rustapi_rs::prelude::*;
+use rustapi_view::Templates;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[derive(Clone)]
+pub struct AppState {
+    pub templates: Templates,
+    pub counter: Arc<Mutex<i64>>,
+}
+
+#[rustapi::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // Initialize tracing
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::from_default_env()
+                .add_directive("info".parse().unwrap()),
+        )
+        .init();
+
+    // Initialize templates
+    let state = AppState {
+        templates: Templates::new("templates/**/*.html")?,
+        counter: Arc::new(Mutex::new(0i64)),
+    };
+
+    let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
+    let addr = format!("127.0.0.1:{}", port);
+
+    RustApi::new()
+        .state(state)
+        // Page
+        .route("/", get(handlers::home))
+        // Htmx fragment endpoints
+        .route("/counter/increment", post(handlers::increment))
+        .route("/counter/decrement", post(handlers::decrement))
+        // Static files
+        .serve_static("/static", "./static")
+        .print_startup_info(true)
+        .run(&addr)
+        .await
+}
+"#;
+    fs::write(format!("{name}/src/main.rs"), main_rs).await?;
+
+    // handlers/mod.rs
+    let handlers_mod = r#"//! Page and htmx fragment handlers
+
+use crate::AppState;
+use rustapi_rs::prelude::*;
+use rustapi_view::View;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct HomeContext {
+    pub title: String,
+    pub count: i64,
+}
+
+#[derive(Serialize)]
+pub struct CounterContext {
+    pub count: i64,
+}
+
+/// Home page - renders the counter at its current value
+pub async fn home(State(state): State<AppState>) -> View<HomeContext> {
+    let count = *state.counter.lock().await;
+    View::render(&state.templates, "index.html", HomeContext {
+        title: "Home".to_string(),
+        count,
+    }).await
+}
+
+/// Increment the counter and return just the updated fragment for htmx to swap in
+pub async fn increment(State(state): State<AppState>) -> View<CounterContext> {
+    let mut count = state.counter.lock().await;
+    *count += 1;
+    View::render(&state.templates, "_counter.html", CounterContext { count: *count }).await
+}
+
+/// Decrement the counter and return just the updated fragment for htmx to swap in
+pub async fn decrement(State(state): State<AppState>) -> View<CounterContext> {
+    let mut count = state.counter.lock().await;
+    *count -= 1;
+    View::render(&state.templates, "_counter.html", CounterContext { count: *count }).await
+}
+"#;
+    fs::write(format!("{name}/src/handlers/mod.rs"), handlers_mod).await?;
+
+    // templates/base.html
+    let base_html = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{% block title %}{{ title }}{% endblock %} - RustAPI</title>
+    <link rel="stylesheet" href="/static/style.css">
+    <script src="https://unpkg.com/htmx.org@1.9.12"></script>
+</head>
+<body>
+    <main>
+        {% block content %}{% endblock %}
+    </main>
+
+    <footer>
+        <p>Built with RustAPI + htmx</p>
+    </footer>
+</body>
+</html>
+"#;
+    fs::write(format!("{name}/templates/base.html"), base_html).await?;
+
+    // templates/index.html
+    let index_html = r#"{% extends "base.html" %}
+
+{% block content %}
+<h1>{{ title }}</h1>
+<p>Click the buttons below - htmx swaps in the new count without a page reload.</p>
+
+<div id="counter">
+    {% include "_counter.html" %}
+</div>
+{% endblock %}
+"#;
+    fs::write(format!("{name}/templates/index.html"), index_html).await?;
+
+    // templates/_counter.html - the fragment swapped in by htmx
+    let counter_html = r##"<div id="counter">
+    <p>Count: <strong>{{ count }}</strong></p>
+    <button hx-post="/counter/increment" hx-target="#counter" hx-swap="outerHTML">+1</button>
+    <button hx-post="/counter/decrement" hx-target="#counter" hx-swap="outerHTML">-1</button>
+</div>
+"##;
+    fs::write(format!("{name}/templates/_counter.html"), counter_html).await?;
+
+    // static/style.css
+    let style_css = r#"* {
+    box-sizing: border-box;
+    margin: 0;
+    padding: 0;
+}
+
+body {
+    font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, Oxygen, Ubuntu, sans-serif;
+    line-height: 1.6;
+    color: #333;
+    max-width: 800px;
+    margin: 0 auto;
+    padding: 20px;
+}
+
+button {
+    margin-right: 0.5rem;
+    padding: 0.5rem 1rem;
+    cursor: pointer;
+}
+
+footer {
+    margin-top: 3rem;
+    padding-top: 1rem;
+    border-top: 1px solid #eee;
+    color: #666;
+    font-size: 0.9rem;
+}
+"#;
+    fs::write(format!("{name}/static/style.css"), style_css).await?;
+
+    // .gitignore and .env.example
+    common::generate_gitignore(name).await?;
+    common::generate_env_example(name).await?;
+
+    Ok(())
+}
@@ -44,11 +44,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
     let addr = format!("127.0.0.1:{}", port);
 
-    println!("🚀 Server running at http://{}", addr);
-
     RustApi::new()
         .route("/", get(hello))
         .docs("/docs")
+        .print_startup_info(true)
         .run(&addr)
         .await
 }
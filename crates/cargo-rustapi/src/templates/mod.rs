@@ -1,7 +1,9 @@
 //! Project templates
 
 mod api;
+mod auth;
 mod full;
+mod htmx;
 mod minimal;
 mod web;
 
@@ -19,6 +21,10 @@ pub enum ProjectTemplate {
     Web,
     /// Full-featured template with all batteries
     Full,
+    /// Auth template with register/login/JWT-protected routes
+    Auth,
+    /// Htmx template with server-rendered fragments
+    Htmx,
 }
 
 /// Generate a project from a template
@@ -32,6 +38,8 @@ pub async fn generate_project(
         ProjectTemplate::Api => api::generate(name, features).await,
         ProjectTemplate::Web => web::generate(name, features).await,
         ProjectTemplate::Full => full::generate(name, features).await,
+        ProjectTemplate::Auth => auth::generate(name, features).await,
+        ProjectTemplate::Htmx => htmx::generate(name, features).await,
     }
 }
 
@@ -58,8 +58,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
     let addr = format!("127.0.0.1:{}", port);
 
-    tracing::info!("🚀 Server running at http://{}", addr);
-
     RustApi::new()
         .state(templates)
         // Pages
@@ -67,6 +65,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         .route("/about", get(handlers::about))
         // Static files
         .serve_static("/static", "./static")
+        .print_startup_info(true)
         .run(&addr)
         .await
 }
@@ -0,0 +1,207 @@
+//! Circuit breaker for outbound requests
+//!
+//! Mirrors `rustapi_extras::circuit_breaker`'s state names, config field
+//! names and defaults (closed -> open after `failure_threshold` failures,
+//! open fails fast until `timeout` elapses, half-open closes again after
+//! `success_threshold` successes) so the two are easy to reason about
+//! together, even though this one guards outbound `reqwest` calls instead
+//! of wrapping `rustapi_core::{Request, Response}` as `CircuitBreakerLayer`
+//! does.
+
+use crate::error::{ClientError, Result};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// Circuit breaker state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests pass through normally.
+    Closed,
+    /// Requests fail fast without hitting the network.
+    Open,
+    /// A limited number of requests are allowed through to test recovery.
+    HalfOpen,
+}
+
+/// Configuration for [`Client::circuit_breaker`](crate::Client::circuit_breaker).
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Number of consecutive failures before opening the circuit.
+    pub failure_threshold: usize,
+    /// Duration to wait before transitioning from `Open` to `HalfOpen`.
+    pub timeout: Duration,
+    /// Number of successful requests in `HalfOpen` before closing again.
+    pub success_threshold: usize,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            timeout: Duration::from_secs(60),
+            success_threshold: 2,
+        }
+    }
+}
+
+struct State {
+    circuit: CircuitState,
+    failure_count: usize,
+    success_count: usize,
+    last_failure_time: Option<Instant>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            circuit: CircuitState::Closed,
+            failure_count: 0,
+            success_count: 0,
+            last_failure_time: None,
+        }
+    }
+}
+
+/// Circuit breaker guarding calls made through a [`crate::Client`].
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Arc<RwLock<State>>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Arc::new(RwLock::new(State::default())),
+        }
+    }
+
+    /// The circuit's current state.
+    pub async fn state(&self) -> CircuitState {
+        self.state.read().await.circuit
+    }
+
+    /// Run `call`, failing fast with [`ClientError::CircuitOpen`] instead of
+    /// invoking it while the circuit is open, and updating the circuit's
+    /// state based on the outcome.
+    pub(crate) async fn call<F, Fut>(&self, call: F) -> Result<reqwest::Response>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<reqwest::Response>>,
+    {
+        {
+            let mut state = self.state.write().await;
+            if state.circuit == CircuitState::Open {
+                let elapsed = state
+                    .last_failure_time
+                    .map(|t| t.elapsed() >= self.config.timeout)
+                    .unwrap_or(false);
+                if elapsed {
+                    tracing::info!("circuit breaker transitioning to HalfOpen");
+                    state.circuit = CircuitState::HalfOpen;
+                    state.success_count = 0;
+                } else {
+                    return Err(ClientError::CircuitOpen);
+                }
+            }
+        }
+
+        let result = call().await;
+        self.record(&result).await;
+        result
+    }
+
+    async fn record(&self, result: &Result<reqwest::Response>) {
+        let succeeded = matches!(result, Ok(response) if response.status().is_success());
+        let mut state = self.state.write().await;
+
+        if succeeded {
+            match state.circuit {
+                CircuitState::HalfOpen => {
+                    state.success_count += 1;
+                    if state.success_count >= self.config.success_threshold {
+                        tracing::info!("circuit breaker transitioning to Closed");
+                        state.circuit = CircuitState::Closed;
+                        state.failure_count = 0;
+                        state.success_count = 0;
+                    }
+                }
+                CircuitState::Closed => {
+                    state.failure_count = 0;
+                }
+                CircuitState::Open => {}
+            }
+        } else {
+            state.failure_count += 1;
+            state.last_failure_time = Some(Instant::now());
+            if state.circuit != CircuitState::Open && state.failure_count >= self.config.failure_threshold {
+                tracing::warn!("circuit breaker transitioning to Open");
+                state.circuit = CircuitState::Open;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn ok_response() -> Result<reqwest::Response> {
+        Ok(http::Response::builder()
+            .status(200)
+            .body(reqwest::Body::from("ok"))
+            .unwrap()
+            .into())
+    }
+
+    #[tokio::test]
+    async fn opens_after_failure_threshold() {
+        let cb = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            ..CircuitBreakerConfig::default()
+        });
+
+        cb.call(|| async { Err(ClientError::NotRetryable) }).await.ok();
+        assert_eq!(cb.state().await, CircuitState::Closed);
+
+        cb.call(|| async { Err(ClientError::NotRetryable) }).await.ok();
+        assert_eq!(cb.state().await, CircuitState::Open);
+    }
+
+    #[tokio::test]
+    async fn fails_fast_while_open() {
+        let cb = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            timeout: Duration::from_secs(60),
+            ..CircuitBreakerConfig::default()
+        });
+
+        cb.call(|| async { Err(ClientError::NotRetryable) }).await.ok();
+        assert_eq!(cb.state().await, CircuitState::Open);
+
+        let result = cb.call(ok_response).await;
+        assert!(matches!(result, Err(ClientError::CircuitOpen)));
+    }
+
+    #[tokio::test]
+    async fn half_open_closes_after_success_threshold() {
+        let cb = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            timeout: Duration::from_millis(0),
+            success_threshold: 2,
+        });
+
+        cb.call(|| async { Err(ClientError::NotRetryable) }).await.ok();
+        assert_eq!(cb.state().await, CircuitState::Open);
+
+        // timeout has already elapsed (0ms), so the next call moves to HalfOpen
+        cb.call(ok_response).await.ok();
+        assert_eq!(cb.state().await, CircuitState::HalfOpen);
+
+        cb.call(ok_response).await.ok();
+        assert_eq!(cb.state().await, CircuitState::Closed);
+    }
+}
@@ -0,0 +1,23 @@
+//! Error type returned by [`crate::Client`] requests.
+
+/// Result type alias used throughout this crate.
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+/// Errors that can occur while sending a request through [`crate::Client`].
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The underlying `reqwest` call failed (connection error, timeout,
+    /// non-UTF8 headers, etc).
+    #[error("request failed: {0}")]
+    Request(#[source] reqwest::Error),
+
+    /// The request body couldn't be cloned for a retry attempt (e.g. a
+    /// streaming body), so no further attempts were made.
+    #[error("request body is not cloneable, cannot retry")]
+    NotRetryable,
+
+    /// The circuit breaker is open and is failing requests fast until its
+    /// timeout elapses.
+    #[error("circuit breaker is open")]
+    CircuitOpen,
+}
@@ -0,0 +1,203 @@
+//! # RustAPI Client
+//!
+//! An outbound HTTP client for calling other services from inside a
+//! RustAPI handler, so a fan-out call to a downstream service gets the
+//! same correlation, retry, and circuit-breaking behavior the framework
+//! already gives inbound requests.
+//!
+//! - Propagates `x-request-id` / `traceparent` from the current
+//!   [`RequestContext`](rustapi_core::middleware::RequestContext) onto every
+//!   outbound request, so logs on both sides of the call line up.
+//! - [`RetryConfig`] and [`CircuitBreaker`] mirror the field names, defaults
+//!   and backoff/state-machine semantics of `rustapi_extras::retry` and
+//!   `rustapi_extras::circuit_breaker` -- they're independent types (this
+//!   crate has no dependency on `rustapi-extras`), since those layers are
+//!   Tower-style middleware for *inbound* `rustapi_core::{Request, Response}`
+//!   and can't be invoked for outbound `reqwest` calls directly.
+//! - [`Client::new`] takes an arbitrary base URL, so pointing it at
+//!   `rustapi_testing::MockServer::start().await.base_url()` in tests needs
+//!   no special mock mode.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use rustapi_client::Client;
+//!
+//! # async fn run() -> rustapi_client::Result<()> {
+//! let client = Client::new("https://payments.internal");
+//! let res = client.get("/accounts/42").send().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use rustapi_core::middleware::RequestContext;
+
+mod circuit_breaker;
+mod error;
+mod retry;
+
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+pub use error::{ClientError, Result};
+pub use retry::{RetryConfig, RetryStrategy};
+
+/// Header carrying the originating request's id, matching
+/// `rustapi_core::middleware::request_id`'s response header.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// Header carrying the W3C trace context, matching
+/// `rustapi_extras::otel::propagation::TRACEPARENT_HEADER`.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// An HTTP client for calling a single downstream service.
+///
+/// Cheap to clone -- the underlying `reqwest::Client` and circuit breaker
+/// state are reference-counted, so a `Client` is usually stored once in
+/// application state and cloned into handlers.
+#[derive(Clone)]
+pub struct Client {
+    base_url: String,
+    http: reqwest::Client,
+    retry: RetryConfig,
+    circuit_breaker: Option<CircuitBreaker>,
+}
+
+impl Client {
+    /// Create a client that resolves relative paths against `base_url`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            http: reqwest::Client::new(),
+            retry: RetryConfig::default(),
+            circuit_breaker: None,
+        }
+    }
+
+    /// Use a pre-configured `reqwest::Client` instead of the default one,
+    /// e.g. to set a custom timeout or TLS trust root.
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Override the retry policy applied to every request (default: up to 3
+    /// retries after the first attempt, exponential backoff -- see
+    /// [`RetryConfig::default`]).
+    pub fn retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Wrap outbound calls in a [`CircuitBreaker`] with the given config.
+    /// Off by default.
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(CircuitBreaker::new(config));
+        self
+    }
+
+    /// Current circuit breaker state, if one is configured.
+    pub async fn circuit_state(&self) -> Option<CircuitState> {
+        match &self.circuit_breaker {
+            Some(cb) => Some(cb.state().await),
+            None => None,
+        }
+    }
+
+    fn url_for(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    /// Start building a request. `path` is resolved relative to the
+    /// client's base URL.
+    pub fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+        RequestBuilder {
+            client: self.clone(),
+            request: self.http.request(method, self.url_for(path)),
+        }
+    }
+
+    /// Start a `GET` request.
+    pub fn get(&self, path: &str) -> RequestBuilder {
+        self.request(reqwest::Method::GET, path)
+    }
+
+    /// Start a `POST` request.
+    pub fn post(&self, path: &str) -> RequestBuilder {
+        self.request(reqwest::Method::POST, path)
+    }
+
+    /// Start a `PUT` request.
+    pub fn put(&self, path: &str) -> RequestBuilder {
+        self.request(reqwest::Method::PUT, path)
+    }
+
+    /// Start a `PATCH` request.
+    pub fn patch(&self, path: &str) -> RequestBuilder {
+        self.request(reqwest::Method::PATCH, path)
+    }
+
+    /// Start a `DELETE` request.
+    pub fn delete(&self, path: &str) -> RequestBuilder {
+        self.request(reqwest::Method::DELETE, path)
+    }
+}
+
+/// A request in progress, mirroring `reqwest::RequestBuilder`'s API for the
+/// handful of methods this crate needs.
+pub struct RequestBuilder {
+    client: Client,
+    request: reqwest::RequestBuilder,
+}
+
+impl RequestBuilder {
+    /// Add a header to the request.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.request = self.request.header(key, value);
+        self
+    }
+
+    /// Set a JSON body, mirroring `reqwest::RequestBuilder::json`.
+    pub fn json<T: serde::Serialize + ?Sized>(mut self, body: &T) -> Self {
+        self.request = self.request.json(body);
+        self
+    }
+
+    /// Send the request, applying request-context propagation, retries and
+    /// the circuit breaker (if configured).
+    pub async fn send(self) -> Result<reqwest::Response> {
+        let Self { client, request } = self;
+        let request = propagate_context(request);
+
+        let attempt = || async {
+            let request = request
+                .try_clone()
+                .ok_or(ClientError::NotRetryable)?
+                .build()
+                .map_err(ClientError::Request)?;
+            client.http.execute(request).await.map_err(ClientError::Request)
+        };
+
+        let retry = client.retry.clone();
+        let call = || retry::with_retries(&retry, attempt);
+
+        match &client.circuit_breaker {
+            Some(cb) => cb.call(call).await,
+            None => call().await,
+        }
+    }
+}
+
+fn propagate_context(request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    match RequestContext::current() {
+        Some(ctx) => {
+            let request = request.header(REQUEST_ID_HEADER, ctx.request_id());
+            match ctx.trace_id() {
+                Some(trace_id) => request.header(TRACEPARENT_HEADER, trace_id),
+                None => request,
+            }
+        }
+        None => request,
+    }
+}
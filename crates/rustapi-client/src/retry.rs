@@ -0,0 +1,172 @@
+//! Retry policy for outbound requests
+//!
+//! Mirrors `rustapi_extras::retry::{RetryStrategy, RetryConfig}` field-for-field
+//! -- same names, same defaults, same backoff formulas -- so a team that
+//! already tunes retries on the inbound side doesn't have to relearn a
+//! second vocabulary for outbound calls. It's an independent type rather
+//! than a re-export because `RetryLayer` retries `rustapi_core::Request`
+//! values through a `BoxedNext`, and there's no equivalent hook on the
+//! outbound side to plug into.
+
+use crate::error::{ClientError, Result};
+use std::future::Future;
+use std::time::Duration;
+
+/// Backoff strategy between retry attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryStrategy {
+    /// Fixed delay between retries.
+    Fixed,
+    /// Exponential backoff (delay doubles each attempt).
+    Exponential,
+    /// Linear backoff (delay increases by one base unit each attempt).
+    Linear,
+}
+
+/// Configuration for [`Client::retry_config`](crate::Client::retry_config).
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of retry attempts (excluding the initial attempt).
+    pub max_attempts: u32,
+    /// Initial backoff duration.
+    pub initial_backoff: Duration,
+    /// Maximum backoff duration (cap for exponential/linear growth).
+    pub max_backoff: Duration,
+    /// Backoff strategy to use.
+    pub strategy: RetryStrategy,
+    /// Which HTTP status codes are considered retryable.
+    pub retryable_statuses: Vec<u16>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(30),
+            strategy: RetryStrategy::Exponential,
+            retryable_statuses: vec![429, 500, 502, 503, 504],
+        }
+    }
+}
+
+impl RetryConfig {
+    fn backoff_for(&self, attempt: u32) -> Duration {
+        let base = self.initial_backoff;
+        let calculated = match self.strategy {
+            RetryStrategy::Fixed => base,
+            RetryStrategy::Exponential => base * 2_u32.saturating_pow(attempt),
+            RetryStrategy::Linear => base * (attempt + 1),
+        };
+        calculated.min(self.max_backoff)
+    }
+}
+
+/// Run `attempt` up to `config.max_attempts + 1` times, retrying on
+/// transport errors and on responses whose status is in
+/// `config.retryable_statuses`, sleeping for the configured backoff between
+/// attempts.
+pub(crate) async fn with_retries<F, Fut>(config: &RetryConfig, attempt: F) -> Result<reqwest::Response>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<reqwest::Response>>,
+{
+    for retry_attempt in 0..=config.max_attempts {
+        let result = attempt().await;
+
+        let should_retry = match &result {
+            Ok(response) => config.retryable_statuses.contains(&response.status().as_u16()),
+            Err(ClientError::Request(err)) => !err.is_builder(),
+            Err(_) => false,
+        };
+
+        if retry_attempt < config.max_attempts && should_retry {
+            let backoff = config.backoff_for(retry_attempt);
+            tracing::warn!(
+                attempt = retry_attempt + 1,
+                max_attempts = config.max_attempts,
+                backoff_ms = backoff.as_millis(),
+                "request failed, retrying"
+            );
+            tokio::time::sleep(backoff).await;
+            continue;
+        }
+
+        if retry_attempt > 0 && result.is_ok() {
+            tracing::info!(attempt = retry_attempt + 1, "request succeeded after retry");
+        }
+        return result;
+    }
+
+    unreachable!("retry loop finished without returning a result")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_backoff_does_not_grow() {
+        let config = RetryConfig {
+            strategy: RetryStrategy::Fixed,
+            initial_backoff: Duration::from_millis(50),
+            ..RetryConfig::default()
+        };
+        assert_eq!(config.backoff_for(0), Duration::from_millis(50));
+        assert_eq!(config.backoff_for(3), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn exponential_backoff_doubles_each_attempt() {
+        let config = RetryConfig {
+            strategy: RetryStrategy::Exponential,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(30),
+            ..RetryConfig::default()
+        };
+        assert_eq!(config.backoff_for(0), Duration::from_millis(100));
+        assert_eq!(config.backoff_for(1), Duration::from_millis(200));
+        assert_eq!(config.backoff_for(2), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn linear_backoff_increases_by_one_base_unit() {
+        let config = RetryConfig {
+            strategy: RetryStrategy::Linear,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(30),
+            ..RetryConfig::default()
+        };
+        assert_eq!(config.backoff_for(0), Duration::from_millis(100));
+        assert_eq!(config.backoff_for(1), Duration::from_millis(200));
+        assert_eq!(config.backoff_for(2), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn backoff_is_capped_at_max_backoff() {
+        let config = RetryConfig {
+            strategy: RetryStrategy::Exponential,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            ..RetryConfig::default()
+        };
+        assert_eq!(config.backoff_for(10), Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_non_retryable_error() {
+        let config = RetryConfig {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            ..RetryConfig::default()
+        };
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let result = with_retries(&config, || async {
+            calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Err(ClientError::NotRetryable)
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+}
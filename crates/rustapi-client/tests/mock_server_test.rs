@@ -0,0 +1,37 @@
+use http::{Method, StatusCode};
+use rustapi_client::{Client, RetryConfig, RetryStrategy};
+use rustapi_testing::{MockResponse, MockServer, RequestMatcher};
+
+#[tokio::test]
+async fn get_against_mock_server_by_base_url_injection() {
+    let server = MockServer::start().await;
+    server
+        .expect(RequestMatcher::new().method(Method::GET).path("/hello"))
+        .respond_with(MockResponse::new().body("Hello World"));
+
+    let client = Client::new(server.base_url());
+    let response = client.get("/hello").send().await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().await.unwrap(), "Hello World");
+}
+
+#[tokio::test]
+async fn retries_on_retryable_status_then_succeeds() {
+    let server = MockServer::start().await;
+    server.expect(RequestMatcher::new().path("/flaky")).respond_with_sequence([
+        MockResponse::new().status(StatusCode::SERVICE_UNAVAILABLE),
+        MockResponse::new().status(StatusCode::OK).body("recovered"),
+    ]);
+
+    let client = Client::new(server.base_url()).retry_config(RetryConfig {
+        max_attempts: 2,
+        initial_backoff: std::time::Duration::from_millis(1),
+        strategy: RetryStrategy::Fixed,
+        ..RetryConfig::default()
+    });
+    let response = client.get("/flaky").send().await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().await.unwrap(), "recovered");
+}
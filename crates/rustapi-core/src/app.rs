@@ -32,6 +32,12 @@ pub struct RustApi {
     layers: LayerStack,
     body_limit: Option<usize>,
     interceptors: InterceptorChain,
+    export_openapi_path: Option<String>,
+    #[cfg(feature = "codegen")]
+    export_typescript_client_path: Option<String>,
+    docs_mount_path: Option<String>,
+    print_startup_info: bool,
+    reuse_port_acceptors: Option<usize>,
 }
 
 impl RustApi {
@@ -57,6 +63,12 @@ impl RustApi {
             layers: LayerStack::new(),
             body_limit: Some(DEFAULT_BODY_LIMIT), // Default 1MB limit
             interceptors: InterceptorChain::new(),
+            export_openapi_path: None,
+            #[cfg(feature = "codegen")]
+            export_typescript_client_path: None,
+            docs_mount_path: None,
+            print_startup_info: false,
+            reuse_port_acceptors: None,
         }
     }
 
@@ -161,6 +173,31 @@ impl RustApi {
         self
     }
 
+    /// Spawn `workers` independent acceptor tasks bound to the same address
+    /// via `SO_REUSEPORT`, instead of a single accept loop handing
+    /// connections off to a shared worker pool.
+    ///
+    /// Under very high connection churn a single acceptor can become a
+    /// bottleneck; with `SO_REUSEPORT` the kernel load-balances incoming
+    /// connections across the `workers` sockets directly. Only takes effect
+    /// on platforms that support `SO_REUSEPORT` (Linux, macOS, and other
+    /// BSDs); on unsupported platforms [`RustApi::run`] logs a warning and
+    /// falls back to a single acceptor.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// RustApi::new()
+    ///     .reuse_port_acceptors(num_cpus::get())
+    ///     .route("/", get(hello))
+    ///     .run("0.0.0.0:8080")
+    ///     .await
+    /// ```
+    pub fn reuse_port_acceptors(mut self, workers: usize) -> Self {
+        self.reuse_port_acceptors = Some(workers.max(1));
+        self
+    }
+
     /// Add a middleware layer to the application
     ///
     /// Layers are executed in the order they are added (outermost first).
@@ -308,6 +345,27 @@ impl RustApi {
         self
     }
 
+    /// Register an OpenAPI schema under an explicit component name.
+    ///
+    /// Generic wrapper types (e.g. `Paginated<User>`) are automatically named
+    /// `Paginated_User` by [`RustApi::register_schema`] to avoid collisions
+    /// between instantiations; use this instead when that name isn't the one
+    /// you want.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// RustApi::new()
+    ///     .register_schema_as::<Paginated<User>>("PaginatedUsers")
+    /// ```
+    pub fn register_schema_as<T: for<'a> rustapi_openapi::Schema<'a>>(
+        mut self,
+        name: impl Into<String>,
+    ) -> Self {
+        self.openapi_spec = self.openapi_spec.register_as::<T>(name);
+        self
+    }
+
     /// Configure OpenAPI info (title, version, description)
     pub fn openapi_info(mut self, title: &str, version: &str, description: Option<&str>) -> Self {
         // NOTE: Do not reset the spec here; doing so would drop collected paths/schemas.
@@ -598,6 +656,64 @@ impl RustApi {
         self.route(&catch_all_path, method_router)
     }
 
+    /// Reverse-proxy requests under `prefix` to `upstream`, streaming request
+    /// bodies through unbuffered.
+    ///
+    /// Rewrites the `Host` header to the upstream's authority (unless
+    /// [`ProxyConfig::preserve_host`] is used) and adds `X-Forwarded-For`,
+    /// `X-Forwarded-Host`, and `X-Forwarded-Proto`. Call `.proxy()` multiple
+    /// times with different prefixes to route to different upstreams --
+    /// useful for peeling routes off a legacy service one at a time.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use rustapi_rs::prelude::*;
+    ///
+    /// RustApi::new()
+    ///     .proxy("/legacy", "http://old-service:8000")
+    ///     .route("/", get(hello))
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    pub fn proxy(self, prefix: &str, upstream: impl AsRef<str>) -> Self {
+        self.proxy_with_config(crate::proxy::ProxyConfig::new(prefix, upstream))
+    }
+
+    /// Reverse-proxy with a custom [`ProxyConfig`](crate::ProxyConfig).
+    pub fn proxy_with_config(self, config: crate::proxy::ProxyConfig) -> Self {
+        use crate::router::MethodRouter;
+        use std::collections::HashMap;
+
+        let prefix = config.prefix.clone();
+        let catch_all_path = format!("{}/*path", prefix.trim_end_matches('/'));
+        let proxy = std::sync::Arc::new(crate::proxy::Proxy::new(config));
+
+        let methods = [
+            http::Method::GET,
+            http::Method::POST,
+            http::Method::PUT,
+            http::Method::PATCH,
+            http::Method::DELETE,
+            http::Method::HEAD,
+            http::Method::OPTIONS,
+        ];
+
+        let mut handlers = HashMap::new();
+        for method in methods {
+            let proxy = proxy.clone();
+            let handler: crate::handler::BoxedHandler = std::sync::Arc::new(move |req| {
+                let proxy = proxy.clone();
+                Box::pin(async move { proxy.handle(req).await })
+                    as std::pin::Pin<Box<dyn std::future::Future<Output = crate::Response> + Send>>
+            });
+            handlers.insert(method, handler);
+        }
+        let method_router = MethodRouter::from_boxed(handlers);
+
+        self.route(&catch_all_path, method_router)
+    }
+
     /// Enable response compression
     ///
     /// Adds gzip/deflate compression for response bodies. The compression
@@ -639,6 +755,170 @@ impl RustApi {
         self.layer(crate::middleware::CompressionLayer::with_config(config))
     }
 
+    /// Enable the dev-mode reload banner
+    ///
+    /// Stamps HTML responses with a small script that polls the server and
+    /// reloads the tab when it detects a restart (e.g. after `cargo watch`
+    /// rebuilds your app), so you get a visible signal instead of a silently
+    /// stale page. This does not preserve in-memory state across restarts -
+    /// it's still a clean process respawn. Development only; don't call this
+    /// when building for production.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// RustApi::new()
+    ///     .dev_reload()
+    ///     .route("/", get(handler))
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    pub fn dev_reload(self) -> Self {
+        self.layer(crate::middleware::DevReloadLayer::new())
+    }
+
+    /// Mount `/healthz` (liveness) and `/readyz` (readiness) endpoints
+    ///
+    /// `/healthz` returns 200 OK unless shutdown has been signaled via the
+    /// check's [`crate::health::ShutdownHandle`], in which case it starts
+    /// failing too so orchestrators eventually recycle the instance.
+    /// `/readyz` runs the registered checks (honoring their configured
+    /// timeout and cache) and returns 503 while any of them fail or while
+    /// shutting down, so traffic stops being routed here before the process
+    /// actually exits.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use rustapi_rs::prelude::*;
+    /// use rustapi_core::health::{HealthCheckBuilder, HealthStatus};
+    ///
+    /// let health = HealthCheckBuilder::new(false)
+    ///     .add_check("database", || async { HealthStatus::healthy() })
+    ///     .build();
+    ///
+    /// RustApi::new()
+    ///     .health_routes(health)
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    pub fn health_routes(self, health: crate::health::HealthCheck) -> Self {
+        self.health_routes_at("/healthz", "/readyz", health)
+    }
+
+    /// Like [`RustApi::health_routes`], but mounted at custom paths
+    pub fn health_routes_at(
+        self,
+        liveness_path: &str,
+        readiness_path: &str,
+        health: crate::health::HealthCheck,
+    ) -> Self {
+        use crate::router::get;
+        use http::StatusCode;
+
+        let liveness_check = health.clone();
+        let liveness_handler = move || {
+            let health = liveness_check.clone();
+            async move {
+                let (status, body) = if health.is_shutting_down() {
+                    (StatusCode::SERVICE_UNAVAILABLE, r#"{"status":"shutting_down"}"#)
+                } else {
+                    (StatusCode::OK, r#"{"status":"ok"}"#)
+                };
+                http::Response::builder()
+                    .status(status)
+                    .header(http::header::CONTENT_TYPE, "application/json")
+                    .body(http_body_util::Full::new(bytes::Bytes::from_static(
+                        body.as_bytes(),
+                    )))
+                    .unwrap()
+            }
+        };
+
+        let readiness_handler = move || {
+            let health = health.clone();
+            async move {
+                let result = health.execute().await;
+                let status = if result.status.is_healthy() {
+                    StatusCode::OK
+                } else {
+                    StatusCode::SERVICE_UNAVAILABLE
+                };
+                let body = serde_json::to_string(&result).unwrap_or_default();
+                http::Response::builder()
+                    .status(status)
+                    .header(http::header::CONTENT_TYPE, "application/json")
+                    .body(http_body_util::Full::new(bytes::Bytes::from(body)))
+                    .unwrap()
+            }
+        };
+
+        self.route(liveness_path, get(liveness_handler))
+            .route(readiness_path, get(readiness_handler))
+    }
+
+    /// Log a startup report when [`RustApi::run`] binds the server
+    ///
+    /// Prints the bound address, enabled middleware layers, and a formatted
+    /// table of every registered route (method, path, summary), plus the
+    /// docs URL if `.docs()` or one of its variants was called. Disabled by
+    /// default; intended to replace the hand-written `println!` banner most
+    /// examples write themselves.
+    pub fn print_startup_info(mut self, enabled: bool) -> Self {
+        self.print_startup_info = enabled;
+        self
+    }
+
+    /// Render the startup report built by [`RustApi::print_startup_info`]
+    fn log_startup_info(&self, addr: &str) {
+        tracing::info!("RustAPI listening on http://{addr}");
+
+        if let Some(docs_path) = &self.docs_mount_path {
+            tracing::info!("API docs available at http://{addr}{docs_path}");
+        }
+
+        let layer_names = self.layers.names();
+        if layer_names.is_empty() {
+            tracing::info!("No middleware layers configured");
+        } else {
+            tracing::info!("Middleware layers: {}", layer_names.join(" -> "));
+        }
+
+        let mut rows: Vec<(String, String, String)> = Vec::new();
+        for (path, item) in &self.openapi_spec.paths {
+            for (method, operation) in [
+                ("GET", &item.get),
+                ("POST", &item.post),
+                ("PUT", &item.put),
+                ("PATCH", &item.patch),
+                ("DELETE", &item.delete),
+            ] {
+                if let Some(operation) = operation {
+                    let summary = operation.summary.clone().unwrap_or_default();
+                    rows.push((method.to_string(), path.clone(), summary));
+                }
+            }
+        }
+        rows.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+
+        if rows.is_empty() {
+            tracing::info!("No routes registered");
+            return;
+        }
+
+        let method_width = rows.iter().map(|r| r.0.len()).max().unwrap_or(6).max(6);
+        let path_width = rows.iter().map(|r| r.1.len()).max().unwrap_or(4).max(4);
+
+        tracing::info!(
+            "{:<method_width$}  {:<path_width$}  SUMMARY",
+            "METHOD",
+            "PATH"
+        );
+        for (method, path, summary) in &rows {
+            tracing::info!("{:<method_width$}  {:<path_width$}  {}", method, path, summary);
+        }
+    }
+
     /// Enable Swagger UI documentation
     ///
     /// This adds two endpoints:
@@ -688,6 +968,7 @@ impl RustApi {
         description: Option<&str>,
     ) -> Self {
         use crate::router::get;
+        self.docs_mount_path = Some(path.trim_end_matches('/').to_string());
         // Update spec info
         self.openapi_spec.info.title = title.to_string();
         self.openapi_spec.info.version = version.to_string();
@@ -697,34 +978,361 @@ impl RustApi {
 
         let path = path.trim_end_matches('/');
         let openapi_path = format!("{}/openapi.json", path);
+        let openapi_yaml_path = format!("{}/openapi.yaml", path);
 
-        // Clone values for closures
-        let spec_json =
-            serde_json::to_string_pretty(&self.openapi_spec.to_json()).unwrap_or_default();
+        // Serialize the spec once; endpoints below just clone the resulting
+        // `Bytes` (a cheap refcount bump) per request.
+        let payload = SpecPayload::new(&self.openapi_spec);
         let openapi_url = openapi_path.clone();
 
         // Add OpenAPI JSON endpoint
-        let spec_handler = move || {
-            let json = spec_json.clone();
+        let json = payload.json;
+        let json_etag = payload.json_etag;
+        let spec_handler = move |crate::extract::Headers(headers): crate::extract::Headers| {
+            let json = json.clone();
+            let json_etag = json_etag.clone();
             async move {
-                http::Response::builder()
-                    .status(http::StatusCode::OK)
-                    .header(http::header::CONTENT_TYPE, "application/json")
-                    .body(http_body_util::Full::new(bytes::Bytes::from(json)))
-                    .unwrap()
+                spec_response(
+                    &json,
+                    &json_etag,
+                    "application/json",
+                    headers.get(http::header::IF_NONE_MATCH),
+                )
+            }
+        };
+
+        // Add OpenAPI YAML endpoint
+        let yaml = payload.yaml;
+        let yaml_etag = payload.yaml_etag;
+        let spec_yaml_handler = move |crate::extract::Headers(headers): crate::extract::Headers| {
+            let yaml = yaml.clone();
+            let yaml_etag = yaml_etag.clone();
+            async move {
+                spec_response(
+                    &yaml,
+                    &yaml_etag,
+                    "application/yaml",
+                    headers.get(http::header::IF_NONE_MATCH),
+                )
+            }
+        };
+
+        // Add Swagger UI endpoint. Rendered once here rather than per
+        // request, since `Embedded` assets can run into the hundreds of KB.
+        let docs_payload = DocsHtmlPayload::new(rustapi_openapi::SwaggerUiAsset::new(
+            &openapi_url,
+            rustapi_openapi::SwaggerAssets::Embedded,
+        ));
+        let docs_handler = move |crate::extract::Headers(headers): crate::extract::Headers| {
+            let payload = docs_payload.clone();
+            async move {
+                docs_html_response(
+                    &payload,
+                    headers.get(http::header::ACCEPT_ENCODING),
+                    headers.get(http::header::IF_NONE_MATCH),
+                )
+            }
+        };
+
+        self.route(&openapi_path, get(spec_handler))
+            .route(&openapi_yaml_path, get(spec_yaml_handler))
+            .route(path, get(docs_handler))
+    }
+
+    /// Enable Swagger UI documentation, choosing between embedded (offline) and
+    /// CDN-hosted assets.
+    ///
+    /// Embedded is the default used by [`RustApi::docs`] and works fully
+    /// air-gapped; CDN produces a smaller binary but requires the browser to
+    /// reach jsDelivr when `/docs` is opened.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// RustApi::new()
+    ///     .route("/users", get(list_users))
+    ///     .docs_with_assets("/docs", rustapi_openapi::SwaggerAssets::Cdn)
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    #[cfg(feature = "swagger-ui")]
+    pub fn docs_with_assets(mut self, path: &str, assets: rustapi_openapi::SwaggerAssets) -> Self {
+        use crate::router::get;
+
+        let path = path.trim_end_matches('/');
+        self.docs_mount_path = Some(path.to_string());
+        let openapi_path = format!("{}/openapi.json", path);
+
+        let payload = SpecPayload::new(&self.openapi_spec);
+        let openapi_url = openapi_path.clone();
+
+        let json = payload.json;
+        let json_etag = payload.json_etag;
+        let spec_handler = move |crate::extract::Headers(headers): crate::extract::Headers| {
+            let json = json.clone();
+            let json_etag = json_etag.clone();
+            async move {
+                spec_response(
+                    &json,
+                    &json_etag,
+                    "application/json",
+                    headers.get(http::header::IF_NONE_MATCH),
+                )
+            }
+        };
+
+        let docs_payload =
+            DocsHtmlPayload::new(rustapi_openapi::SwaggerUiAsset::new(&openapi_url, assets));
+        let docs_handler = move |crate::extract::Headers(headers): crate::extract::Headers| {
+            let payload = docs_payload.clone();
+            async move {
+                docs_html_response(
+                    &payload,
+                    headers.get(http::header::ACCEPT_ENCODING),
+                    headers.get(http::header::IF_NONE_MATCH),
+                )
+            }
+        };
+
+        self.route(&openapi_path, get(spec_handler))
+            .route(path, get(docs_handler))
+    }
+
+    /// Serve ReDoc as an alternative documentation UI, backed by the same OpenAPI spec.
+    ///
+    /// Adds two endpoints:
+    /// - `{path}` - ReDoc interface
+    /// - `{path}/openapi.json` - OpenAPI JSON specification
+    ///
+    /// Like [`RustApi::docs`], call this after registering all routes so the
+    /// captured spec is complete.
+    ///
+    /// # Example
+    ///
+    /// ```text
+    /// RustApi::new()
+    ///     .route("/users", get(list_users))
+    ///     .docs_redoc("/redoc")
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    #[cfg(feature = "swagger-ui")]
+    pub fn docs_redoc(mut self, path: &str) -> Self {
+        use crate::router::get;
+
+        let path = path.trim_end_matches('/');
+        self.docs_mount_path = Some(path.to_string());
+        let openapi_path = format!("{}/openapi.json", path);
+
+        let payload = SpecPayload::new(&self.openapi_spec);
+        let openapi_url = openapi_path.clone();
+
+        let json = payload.json;
+        let json_etag = payload.json_etag;
+        let spec_handler = move |crate::extract::Headers(headers): crate::extract::Headers| {
+            let json = json.clone();
+            let json_etag = json_etag.clone();
+            async move {
+                spec_response(
+                    &json,
+                    &json_etag,
+                    "application/json",
+                    headers.get(http::header::IF_NONE_MATCH),
+                )
             }
         };
 
-        // Add Swagger UI endpoint
         let docs_handler = move || {
             let url = openapi_url.clone();
-            async move { rustapi_openapi::swagger_ui_html(&url) }
+            async move { rustapi_openapi::redoc_html(&url) }
         };
 
         self.route(&openapi_path, get(spec_handler))
             .route(path, get(docs_handler))
     }
 
+    /// Serve Scalar as an alternative documentation UI, backed by the same OpenAPI spec.
+    ///
+    /// Adds two endpoints:
+    /// - `{path}` - Scalar interface
+    /// - `{path}/openapi.json` - OpenAPI JSON specification
+    ///
+    /// Like [`RustApi::docs`], call this after registering all routes so the
+    /// captured spec is complete.
+    ///
+    /// # Example
+    ///
+    /// ```text
+    /// RustApi::new()
+    ///     .route("/users", get(list_users))
+    ///     .docs_scalar("/reference")
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    #[cfg(feature = "swagger-ui")]
+    pub fn docs_scalar(mut self, path: &str) -> Self {
+        use crate::router::get;
+
+        let path = path.trim_end_matches('/');
+        self.docs_mount_path = Some(path.to_string());
+        let openapi_path = format!("{}/openapi.json", path);
+
+        let payload = SpecPayload::new(&self.openapi_spec);
+        let openapi_url = openapi_path.clone();
+
+        let json = payload.json;
+        let json_etag = payload.json_etag;
+        let spec_handler = move |crate::extract::Headers(headers): crate::extract::Headers| {
+            let json = json.clone();
+            let json_etag = json_etag.clone();
+            async move {
+                spec_response(
+                    &json,
+                    &json_etag,
+                    "application/json",
+                    headers.get(http::header::IF_NONE_MATCH),
+                )
+            }
+        };
+
+        let docs_handler = move || {
+            let url = openapi_url.clone();
+            async move { rustapi_openapi::scalar_html(&url) }
+        };
+
+        self.route(&openapi_path, get(spec_handler))
+            .route(path, get(docs_handler))
+    }
+
+    /// Enable Swagger UI documentation restricted to routes in `group` (see
+    /// [`Route::doc_group`]), plus any routes with no group assigned.
+    ///
+    /// Useful for serving a "public" spec alongside a more detailed "internal" one
+    /// from the same route table:
+    ///
+    /// ```text
+    /// RustApi::new()
+    ///     .route("/users", get(list_users))
+    ///     .route("/admin/users", get(list_users_admin).doc_group("internal"))
+    ///     .docs_group("/docs", "public")        // omits /admin/users
+    ///     .docs_group("/internal/docs", "internal")
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    #[cfg(feature = "swagger-ui")]
+    pub fn docs_group(mut self, path: &str, group: &str) -> Self {
+        use crate::router::get;
+
+        let path = path.trim_end_matches('/');
+        self.docs_mount_path = Some(path.to_string());
+        let openapi_path = format!("{}/openapi.json", path);
+        let filtered_spec = self.openapi_spec.filter_by_group(group);
+
+        let payload = SpecPayload::new(&filtered_spec);
+        let openapi_url = openapi_path.clone();
+
+        let json = payload.json;
+        let json_etag = payload.json_etag;
+        let spec_handler = move |crate::extract::Headers(headers): crate::extract::Headers| {
+            let json = json.clone();
+            let json_etag = json_etag.clone();
+            async move {
+                spec_response(
+                    &json,
+                    &json_etag,
+                    "application/json",
+                    headers.get(http::header::IF_NONE_MATCH),
+                )
+            }
+        };
+
+        let docs_payload = DocsHtmlPayload::new(rustapi_openapi::SwaggerUiAsset::new(
+            &openapi_url,
+            rustapi_openapi::SwaggerAssets::Embedded,
+        ));
+        let docs_handler = move |crate::extract::Headers(headers): crate::extract::Headers| {
+            let payload = docs_payload.clone();
+            async move {
+                docs_html_response(
+                    &payload,
+                    headers.get(http::header::ACCEPT_ENCODING),
+                    headers.get(http::header::IF_NONE_MATCH),
+                )
+            }
+        };
+
+        self.route(&openapi_path, get(spec_handler))
+            .route(path, get(docs_handler))
+    }
+
+    /// Merge security scheme definitions, global security requirements, and
+    /// tag metadata (descriptions, ordering, external docs) from an
+    /// [`OpenApiConfig`](rustapi_openapi::OpenApiConfig) into the generated spec.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use rustapi_openapi::OpenApiConfig;
+    ///
+    /// RustApi::new()
+    ///     .security(OpenApiConfig::default().bearer_auth())
+    ///     .route("/users", get(list_users))
+    ///     .docs("/docs")
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    pub fn security(mut self, config: rustapi_openapi::OpenApiConfig) -> Self {
+        self.openapi_spec = self.openapi_spec.apply_security_config(&config);
+        self
+    }
+
+    /// Write the OpenAPI spec to `path` at startup, if the `RUSTAPI_EXPORT_OPENAPI`
+    /// environment variable is set.
+    ///
+    /// The format (JSON or YAML) is inferred from the file extension, defaulting
+    /// to JSON. This is meant for CI jobs that want to commit or diff the spec
+    /// without running the app manually:
+    ///
+    /// ```text
+    /// RustApi::new()
+    ///     .route("/users", get(list_users))
+    ///     .export_openapi("openapi.yaml")
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    ///
+    /// ```text
+    /// RUSTAPI_EXPORT_OPENAPI=1 cargo run
+    /// ```
+    pub fn export_openapi(mut self, path: impl Into<String>) -> Self {
+        self.export_openapi_path = Some(path.into());
+        self
+    }
+
+    /// Export a typed TypeScript `fetch` client generated from the in-memory
+    /// OpenAPI spec to `path` on startup, gated by the `RUSTAPI_EXPORT_TS_CLIENT`
+    /// environment variable (see [`RustApi::export_openapi`]). Requires the
+    /// `codegen` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// RustApi::new()
+    ///     .route("/users", get(list_users))
+    ///     .export_typescript_client("client.ts")
+    ///     .run("127.0.0.1:8080")
+    ///     .await
+    /// ```
+    ///
+    /// ```text
+    /// RUSTAPI_EXPORT_TS_CLIENT=1 cargo run
+    /// ```
+    #[cfg(feature = "codegen")]
+    pub fn export_typescript_client(mut self, path: impl Into<String>) -> Self {
+        self.export_typescript_client_path = Some(path.into());
+        self
+    }
+
     /// Enable Swagger UI documentation with Basic Auth protection
     ///
     /// When username and password are provided, the docs endpoint will require
@@ -785,6 +1393,9 @@ impl RustApi {
         use base64::{engine::general_purpose::STANDARD, Engine};
         use std::collections::HashMap;
 
+        let path = path.trim_end_matches('/');
+        self.docs_mount_path = Some(path.to_string());
+
         // Update spec info
         self.openapi_spec.info.title = title.to_string();
         self.openapi_spec.info.version = version.to_string();
@@ -792,7 +1403,6 @@ impl RustApi {
             self.openapi_spec.info.description = Some(desc.to_string());
         }
 
-        let path = path.trim_end_matches('/');
         let openapi_path = format!("{}/openapi.json", path);
 
         // Create expected auth header value
@@ -801,26 +1411,33 @@ impl RustApi {
         let expected_auth = format!("Basic {}", encoded);
 
         // Clone values for closures
-        let spec_json =
-            serde_json::to_string_pretty(&self.openapi_spec.to_json()).unwrap_or_default();
+        let payload = SpecPayload::new(&self.openapi_spec);
         let openapi_url = openapi_path.clone();
+        let docs_payload = DocsHtmlPayload::new(rustapi_openapi::SwaggerUiAsset::new(
+            &openapi_url,
+            rustapi_openapi::SwaggerAssets::Embedded,
+        ));
         let expected_auth_spec = expected_auth.clone();
         let expected_auth_docs = expected_auth;
 
         // Create spec handler with auth check
+        let json = payload.json;
+        let json_etag = payload.json_etag;
         let spec_handler: crate::handler::BoxedHandler =
             std::sync::Arc::new(move |req: crate::Request| {
-                let json = spec_json.clone();
+                let json = json.clone();
+                let json_etag = json_etag.clone();
                 let expected = expected_auth_spec.clone();
                 Box::pin(async move {
                     if !check_basic_auth(&req, &expected) {
                         return unauthorized_response();
                     }
-                    http::Response::builder()
-                        .status(http::StatusCode::OK)
-                        .header(http::header::CONTENT_TYPE, "application/json")
-                        .body(http_body_util::Full::new(bytes::Bytes::from(json)))
-                        .unwrap()
+                    spec_response(
+                        &json,
+                        &json_etag,
+                        "application/json",
+                        req.headers().get(http::header::IF_NONE_MATCH),
+                    )
                 })
                     as std::pin::Pin<Box<dyn std::future::Future<Output = crate::Response> + Send>>
             });
@@ -828,13 +1445,17 @@ impl RustApi {
         // Create docs handler with auth check
         let docs_handler: crate::handler::BoxedHandler =
             std::sync::Arc::new(move |req: crate::Request| {
-                let url = openapi_url.clone();
+                let payload = docs_payload.clone();
                 let expected = expected_auth_docs.clone();
                 Box::pin(async move {
                     if !check_basic_auth(&req, &expected) {
                         return unauthorized_response();
                     }
-                    rustapi_openapi::swagger_ui_html(&url)
+                    docs_html_response(
+                        &payload,
+                        req.headers().get(http::header::ACCEPT_ENCODING),
+                        req.headers().get(http::header::IF_NONE_MATCH),
+                    )
                 })
                     as std::pin::Pin<Box<dyn std::future::Future<Output = crate::Response> + Send>>
             });
@@ -863,14 +1484,41 @@ impl RustApi {
     ///     .await
     /// ```
     pub async fn run(mut self, addr: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(path) = &self.export_openapi_path {
+            if std::env::var("RUSTAPI_EXPORT_OPENAPI").is_ok() {
+                let format = if path.ends_with(".yaml") || path.ends_with(".yml") {
+                    rustapi_openapi::SpecFormat::Yaml
+                } else {
+                    rustapi_openapi::SpecFormat::Json
+                };
+                if let Err(err) = self.openapi_spec.write_to_file(path, format) {
+                    tracing::warn!("failed to export OpenAPI spec to {path}: {err}");
+                }
+            }
+        }
+
+        #[cfg(feature = "codegen")]
+        if let Some(path) = &self.export_typescript_client_path {
+            if std::env::var("RUSTAPI_EXPORT_TS_CLIENT").is_ok() {
+                let ts = rustapi_openapi::codegen::generate_typescript_client(&self.openapi_spec);
+                if let Err(err) = std::fs::write(path, ts) {
+                    tracing::warn!("failed to export TypeScript client to {path}: {err}");
+                }
+            }
+        }
+
         // Apply body limit layer if configured (should be first in the chain)
         if let Some(limit) = self.body_limit {
             // Prepend body limit layer so it's the first to process requests
             self.layers.prepend(Box::new(BodyLimitLayer::new(limit)));
         }
 
+        if self.print_startup_info {
+            self.log_startup_info(addr);
+        }
+
         let server = Server::new(self.router, self.layers, self.interceptors);
-        server.run(addr).await
+        server.run(addr, self.reuse_port_acceptors.unwrap_or(1)).await
     }
 
     /// Get the inner router (for testing or advanced usage)
@@ -1037,6 +1685,198 @@ mod tests {
     use http::Method;
     use proptest::prelude::*;
 
+    #[test]
+    fn print_startup_info_defaults_to_disabled() {
+        let app = RustApi::new();
+        assert!(!app.print_startup_info);
+    }
+
+    #[test]
+    fn print_startup_info_can_be_enabled() {
+        let app = RustApi::new().print_startup_info(true);
+        assert!(app.print_startup_info);
+    }
+
+    #[test]
+    fn reuse_port_acceptors_defaults_to_none() {
+        let app = RustApi::new();
+        assert_eq!(app.reuse_port_acceptors, None);
+    }
+
+    #[test]
+    fn reuse_port_acceptors_sets_worker_count() {
+        let app = RustApi::new().reuse_port_acceptors(4);
+        assert_eq!(app.reuse_port_acceptors, Some(4));
+    }
+
+    #[test]
+    fn reuse_port_acceptors_clamps_zero_to_one() {
+        let app = RustApi::new().reuse_port_acceptors(0);
+        assert_eq!(app.reuse_port_acceptors, Some(1));
+    }
+
+    #[cfg(feature = "swagger-ui")]
+    #[test]
+    fn docs_records_mount_path_for_startup_report() {
+        let app = RustApi::new().docs("/api-docs/");
+        assert_eq!(app.docs_mount_path.as_deref(), Some("/api-docs"));
+    }
+
+    #[cfg(feature = "swagger-ui")]
+    #[tokio::test]
+    async fn openapi_json_endpoint_returns_strong_etag() {
+        use crate::test_client::TestClient;
+
+        let app = RustApi::new().docs("/docs");
+        let client = TestClient::new(app);
+
+        let response = client.get("/docs/openapi.json").await;
+        response.assert_status(http::StatusCode::OK);
+        let etag = response
+            .headers()
+            .get(http::header::ETAG)
+            .expect("response should carry an ETag");
+        assert!(etag.to_str().unwrap().starts_with('"'));
+    }
+
+    #[cfg(feature = "swagger-ui")]
+    #[tokio::test]
+    async fn openapi_json_endpoint_returns_304_for_matching_if_none_match() {
+        use crate::test_client::{TestClient, TestRequest};
+
+        let app = RustApi::new().docs("/docs");
+        let client = TestClient::new(app);
+
+        let first = client.get("/docs/openapi.json").await;
+        first.assert_status(http::StatusCode::OK);
+        let etag = first
+            .headers()
+            .get(http::header::ETAG)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let second = client
+            .request(TestRequest::get("/docs/openapi.json").header("if-none-match", &etag))
+            .await;
+        second.assert_status(http::StatusCode::NOT_MODIFIED);
+        assert!(second.body().is_empty());
+    }
+
+    #[cfg(feature = "swagger-ui")]
+    #[tokio::test]
+    async fn openapi_json_and_yaml_endpoints_return_stable_etags_across_requests() {
+        use crate::test_client::TestClient;
+
+        let app = RustApi::new().docs("/docs");
+        let client = TestClient::new(app);
+
+        let json_a = client.get("/docs/openapi.json").await;
+        let json_b = client.get("/docs/openapi.json").await;
+        assert_eq!(
+            json_a.headers().get(http::header::ETAG),
+            json_b.headers().get(http::header::ETAG)
+        );
+
+        let yaml_a = client.get("/docs/openapi.yaml").await;
+        let yaml_b = client.get("/docs/openapi.yaml").await;
+        assert_eq!(
+            yaml_a.headers().get(http::header::ETAG),
+            yaml_b.headers().get(http::header::ETAG)
+        );
+        assert_ne!(
+            json_a.headers().get(http::header::ETAG),
+            yaml_a.headers().get(http::header::ETAG)
+        );
+    }
+
+    #[cfg(feature = "swagger-ui")]
+    #[tokio::test]
+    async fn docs_html_endpoint_returns_strong_etag_and_cache_control() {
+        use crate::test_client::TestClient;
+
+        let app = RustApi::new().docs("/docs");
+        let client = TestClient::new(app);
+
+        let response = client.get("/docs").await;
+        response.assert_status(http::StatusCode::OK);
+        let etag = response
+            .headers()
+            .get(http::header::ETAG)
+            .expect("response should carry an ETag");
+        assert!(etag.to_str().unwrap().starts_with('"'));
+        assert_eq!(
+            response
+                .headers()
+                .get(http::header::CACHE_CONTROL)
+                .and_then(|v| v.to_str().ok()),
+            Some("public, max-age=31536000, immutable")
+        );
+    }
+
+    #[cfg(feature = "swagger-ui")]
+    #[tokio::test]
+    async fn docs_html_endpoint_returns_304_for_matching_if_none_match() {
+        use crate::test_client::{TestClient, TestRequest};
+
+        let app = RustApi::new().docs("/docs");
+        let client = TestClient::new(app);
+
+        let first = client.get("/docs").await;
+        first.assert_status(http::StatusCode::OK);
+        let etag = first
+            .headers()
+            .get(http::header::ETAG)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let second = client
+            .request(TestRequest::get("/docs").header("if-none-match", &etag))
+            .await;
+        second.assert_status(http::StatusCode::NOT_MODIFIED);
+        assert!(second.body().is_empty());
+    }
+
+    #[cfg(all(feature = "swagger-ui", feature = "compression"))]
+    #[tokio::test]
+    async fn docs_html_endpoint_serves_precompressed_gzip_when_accepted() {
+        use crate::test_client::{TestClient, TestRequest};
+
+        let app = RustApi::new().docs("/docs");
+        let client = TestClient::new(app);
+
+        let response = client
+            .request(TestRequest::get("/docs").header("accept-encoding", "gzip"))
+            .await;
+        response.assert_status(http::StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get(http::header::CONTENT_ENCODING)
+                .and_then(|v| v.to_str().ok()),
+            Some("gzip")
+        );
+    }
+
+    #[cfg(all(feature = "swagger-ui", feature = "compression"))]
+    #[tokio::test]
+    async fn docs_html_endpoint_omits_content_encoding_without_accept_encoding() {
+        use crate::test_client::TestClient;
+
+        let app = RustApi::new().docs("/docs");
+        let client = TestClient::new(app);
+
+        let response = client.get("/docs").await;
+        response.assert_status(http::StatusCode::OK);
+        assert!(response
+            .headers()
+            .get(http::header::CONTENT_ENCODING)
+            .is_none());
+    }
+
     #[test]
     fn state_is_available_via_extractor() {
         let app = RustApi::new().state(123u32);
@@ -1059,6 +1899,44 @@ mod tests {
         assert_eq!(value, 123u32);
     }
 
+    #[test]
+    fn nested_router_state_is_available_via_extractor_without_parent_state() {
+        #[derive(Clone)]
+        struct DbPool(&'static str);
+
+        async fn handler() -> &'static str {
+            "handler"
+        }
+
+        let users = Router::new()
+            .route("/users", get(handler))
+            .state(DbPool("users-db"));
+        let router = Router::new().nest("/api", users);
+
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri("/api/users")
+            .body(())
+            .unwrap();
+        let (parts, _) = req.into_parts();
+
+        let local_state = match router.match_route("/api/users", &Method::GET) {
+            crate::router::RouteMatch::Found { local_state, .. } => local_state.cloned(),
+            _ => panic!("route should be found"),
+        };
+
+        let request = Request::new(
+            parts,
+            crate::request::BodyVariant::Buffered(Bytes::new()),
+            router.state_ref(),
+            PathParams::new(),
+        )
+        .with_local_state(local_state);
+
+        let State(pool) = State::<DbPool>::from_request_parts(&request).unwrap();
+        assert_eq!(pool.0, "users-db");
+    }
+
     #[test]
     fn test_path_param_type_inference_integer() {
         use super::infer_path_param_schema;
@@ -1770,6 +2648,207 @@ fn unauthorized_response() -> crate::Response {
         .unwrap()
 }
 
+/// Pre-serialized `/openapi.json` and `/openapi.yaml` bodies for a `docs_*`
+/// mount, each with a strong ETag derived from their content.
+///
+/// The spec is finalized when a `docs_*` method is called and never changes
+/// afterward (`RustApi` is a consuming builder), so serializing once here
+/// and cloning the cheap [`bytes::Bytes`] handles per request avoids
+/// re-serializing a potentially large spec on every docs request.
+#[cfg(feature = "swagger-ui")]
+struct SpecPayload {
+    json: bytes::Bytes,
+    json_etag: String,
+    yaml: bytes::Bytes,
+    yaml_etag: String,
+}
+
+#[cfg(feature = "swagger-ui")]
+impl SpecPayload {
+    fn new(spec: &rustapi_openapi::OpenApiSpec) -> Self {
+        let json = bytes::Bytes::from(
+            serde_json::to_string_pretty(&spec.to_json()).unwrap_or_default(),
+        );
+        let yaml = bytes::Bytes::from(spec.to_yaml());
+        let json_etag = strong_etag(&json);
+        let yaml_etag = strong_etag(&yaml);
+        Self {
+            json,
+            json_etag,
+            yaml,
+            yaml_etag,
+        }
+    }
+}
+
+/// A strong ETag (RFC 7232) derived from `bytes`' content, so it only
+/// changes when the bytes it identifies do.
+#[cfg(feature = "swagger-ui")]
+fn strong_etag(bytes: &bytes::Bytes) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    format!("\"{:x}\"", hasher.finish())
+}
+
+/// Build a response for a cached spec body, replying `304 Not Modified`
+/// when `if_none_match` already names the current ETag instead of resending
+/// the (possibly large) body.
+#[cfg(feature = "swagger-ui")]
+fn spec_response(
+    body: &bytes::Bytes,
+    etag: &str,
+    content_type: &'static str,
+    if_none_match: Option<&http::HeaderValue>,
+) -> crate::Response {
+    if if_none_match.and_then(|v| v.to_str().ok()) == Some(etag) {
+        return http::Response::builder()
+            .status(http::StatusCode::NOT_MODIFIED)
+            .header(http::header::ETAG, etag)
+            .body(http_body_util::Full::new(bytes::Bytes::new()))
+            .unwrap();
+    }
+
+    http::Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, content_type)
+        .header(http::header::ETAG, etag)
+        .body(http_body_util::Full::new(body.clone()))
+        .unwrap()
+}
+
+/// A rendered Swagger UI page cached the same way [`SpecPayload`] caches the
+/// spec body: rendered once, at `.docs*()` call time, and served from cached
+/// `Bytes` per request afterward. With `Embedded` assets the HTML can run
+/// into the hundreds of KB, so when the `compression` feature is enabled a
+/// gzip variant is precomputed alongside it (and a brotli one too, with
+/// `compression-brotli`), so a compressed response never pays the
+/// compression cost inline.
+#[cfg(feature = "swagger-ui")]
+#[derive(Clone)]
+struct DocsHtmlPayload {
+    html: bytes::Bytes,
+    etag: String,
+    #[cfg(feature = "compression")]
+    gzip: bytes::Bytes,
+    #[cfg(feature = "compression-brotli")]
+    brotli: bytes::Bytes,
+}
+
+#[cfg(feature = "swagger-ui")]
+impl DocsHtmlPayload {
+    fn new(asset: rustapi_openapi::SwaggerUiAsset) -> Self {
+        #[cfg(feature = "compression")]
+        let compressor = crate::middleware::CompressionLayer::new().level(9);
+
+        Self {
+            #[cfg(feature = "compression")]
+            gzip: compressor
+                .compress(&asset.html, crate::middleware::CompressionAlgorithm::Gzip)
+                .map(bytes::Bytes::from)
+                .unwrap_or_else(|_| asset.html.clone()),
+            #[cfg(feature = "compression-brotli")]
+            brotli: compressor
+                .compress(&asset.html, crate::middleware::CompressionAlgorithm::Brotli)
+                .map(bytes::Bytes::from)
+                .unwrap_or_else(|_| asset.html.clone()),
+            etag: asset.etag,
+            html: asset.html,
+        }
+    }
+}
+
+/// Cache lifetime advertised for precomputed docs UI assets: a year, since
+/// the body only ever changes when the app is rebuilt and restarted with a
+/// different route table or embedded asset bundle, and the ETag still
+/// catches that case for clients that revalidate anyway.
+#[cfg(feature = "swagger-ui")]
+const DOCS_ASSET_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Build a response for a cached docs HTML body, replying `304 Not Modified`
+/// when `if_none_match` already names the current ETag, and otherwise
+/// serving whichever precomputed encoding `accept_encoding` allows for.
+#[cfg(all(feature = "swagger-ui", feature = "compression"))]
+fn docs_html_response(
+    payload: &DocsHtmlPayload,
+    accept_encoding: Option<&http::HeaderValue>,
+    if_none_match: Option<&http::HeaderValue>,
+) -> crate::Response {
+    if if_none_match.and_then(|v| v.to_str().ok()) == Some(payload.etag.as_str()) {
+        return http::Response::builder()
+            .status(http::StatusCode::NOT_MODIFIED)
+            .header(http::header::ETAG, &payload.etag)
+            .header(http::header::CACHE_CONTROL, DOCS_ASSET_CACHE_CONTROL)
+            .body(http_body_util::Full::new(bytes::Bytes::new()))
+            .unwrap();
+    }
+
+    let algorithm = accept_encoding
+        .and_then(|v| v.to_str().ok())
+        .map(crate::middleware::CompressionAlgorithm::from_accept_encoding)
+        .unwrap_or(crate::middleware::CompressionAlgorithm::Identity);
+
+    #[cfg(feature = "compression-brotli")]
+    if algorithm == crate::middleware::CompressionAlgorithm::Brotli {
+        return http::Response::builder()
+            .status(http::StatusCode::OK)
+            .header(http::header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .header(http::header::CONTENT_ENCODING, "br")
+            .header(http::header::ETAG, &payload.etag)
+            .header(http::header::CACHE_CONTROL, DOCS_ASSET_CACHE_CONTROL)
+            .body(http_body_util::Full::new(payload.brotli.clone()))
+            .unwrap();
+    }
+
+    if algorithm == crate::middleware::CompressionAlgorithm::Gzip {
+        return http::Response::builder()
+            .status(http::StatusCode::OK)
+            .header(http::header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .header(http::header::CONTENT_ENCODING, "gzip")
+            .header(http::header::ETAG, &payload.etag)
+            .header(http::header::CACHE_CONTROL, DOCS_ASSET_CACHE_CONTROL)
+            .body(http_body_util::Full::new(payload.gzip.clone()))
+            .unwrap();
+    }
+
+    http::Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, "text/html; charset=utf-8")
+        .header(http::header::ETAG, &payload.etag)
+        .header(http::header::CACHE_CONTROL, DOCS_ASSET_CACHE_CONTROL)
+        .body(http_body_util::Full::new(payload.html.clone()))
+        .unwrap()
+}
+
+/// Same as the `compression`-enabled [`docs_html_response`], minus encoding
+/// negotiation: no compressed variant was precomputed, so this always serves
+/// `payload.html` as-is.
+#[cfg(all(feature = "swagger-ui", not(feature = "compression")))]
+fn docs_html_response(
+    payload: &DocsHtmlPayload,
+    _accept_encoding: Option<&http::HeaderValue>,
+    if_none_match: Option<&http::HeaderValue>,
+) -> crate::Response {
+    if if_none_match.and_then(|v| v.to_str().ok()) == Some(payload.etag.as_str()) {
+        return http::Response::builder()
+            .status(http::StatusCode::NOT_MODIFIED)
+            .header(http::header::ETAG, &payload.etag)
+            .header(http::header::CACHE_CONTROL, DOCS_ASSET_CACHE_CONTROL)
+            .body(http_body_util::Full::new(bytes::Bytes::new()))
+            .unwrap();
+    }
+
+    http::Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, "text/html; charset=utf-8")
+        .header(http::header::ETAG, &payload.etag)
+        .header(http::header::CACHE_CONTROL, DOCS_ASSET_CACHE_CONTROL)
+        .body(http_body_util::Full::new(payload.html.clone()))
+        .unwrap()
+}
+
 /// Configuration builder for RustAPI with auto-routes
 pub struct RustApiConfig {
     docs_path: Option<String>,
@@ -1840,6 +2919,11 @@ impl RustApiConfig {
         self
     }
 
+    /// Enable the dev-mode reload banner - see [`RustApi::dev_reload`]
+    pub fn dev_reload(self) -> Self {
+        self.layer(crate::middleware::DevReloadLayer::new())
+    }
+
     /// Build the RustApi instance
     pub fn build(self) -> RustApi {
         let mut app = RustApi::new().mount_auto_routes_grouped();
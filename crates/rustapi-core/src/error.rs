@@ -279,6 +279,15 @@ impl ApiError {
         Self::new(StatusCode::CONFLICT, "conflict", message)
     }
 
+    /// Create a 415 Unsupported Media Type error
+    pub fn unsupported_media_type(message: impl Into<String>) -> Self {
+        Self::new(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "unsupported_media_type",
+            message,
+        )
+    }
+
     /// Create a 500 Internal Server Error
     pub fn internal(message: impl Into<String>) -> Self {
         Self::new(StatusCode::INTERNAL_SERVER_ERROR, "internal_error", message)
@@ -408,6 +417,32 @@ impl From<serde_json::Error> for ApiError {
 
 impl From<crate::json::JsonError> for ApiError {
     fn from(err: crate::json::JsonError) -> Self {
+        // A missing required field is a validation problem, not a malformed
+        // payload -- report it the same way field-level validation errors
+        // are reported, so clients can handle both the same way.
+        if err.is_missing_field() {
+            // serde stops descending before the missing field, so the field
+            // name lives in the message ("missing field `price`") rather
+            // than the path; the path locates the struct that was missing it.
+            let field_name = err
+                .to_string()
+                .split('`')
+                .nth(1)
+                .unwrap_or("<unknown>")
+                .to_string();
+            let parent_path = err.path().map(|p| p.to_string()).filter(|p| p != ".");
+            let field = match parent_path {
+                Some(parent) => format!("{parent}.{field_name}"),
+                None => field_name,
+            };
+
+            return ApiError::validation(vec![FieldError {
+                field,
+                code: "required".to_string(),
+                message: "field is required".to_string(),
+            }]);
+        }
+
         ApiError::bad_request(format!("Invalid JSON: {}", err))
     }
 }
@@ -440,6 +475,24 @@ impl From<rustapi_validate::ValidationError> for ApiError {
     }
 }
 
+impl From<rustapi_validate::v2::ValidationErrors> for ApiError {
+    fn from(err: rustapi_validate::v2::ValidationErrors) -> Self {
+        let fields = err
+            .fields
+            .into_iter()
+            .flat_map(|(field, errors)| {
+                errors.into_iter().map(move |e| FieldError {
+                    field: field.clone(),
+                    code: e.code.clone(),
+                    message: e.interpolate_message(),
+                })
+            })
+            .collect();
+
+        ApiError::validation(fields)
+    }
+}
+
 impl ApiError {
     /// Create a validation error from a ValidationError
     pub fn from_validation_error(err: rustapi_validate::ValidationError) -> Self {
@@ -454,6 +507,11 @@ impl ApiError {
             message,
         )
     }
+
+    /// Create a 502 Bad Gateway error
+    pub fn bad_gateway(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_GATEWAY, "bad_gateway", message)
+    }
 }
 
 // SQLx error conversion (feature-gated)
@@ -1067,4 +1125,53 @@ mod tests {
         let dev_fields = dev_response.error.fields.unwrap();
         assert_eq!(dev_fields.len(), 2);
     }
+
+    #[test]
+    fn test_json_type_error_includes_field_path() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Item {
+            #[allow(dead_code)]
+            price: f64,
+        }
+        #[derive(Debug, serde::Deserialize)]
+        struct Payload {
+            #[allow(dead_code)]
+            items: Vec<Item>,
+        }
+
+        let json_err = crate::json::from_slice::<Payload>(
+            br#"{"items": [{"price": 1.0}, {"price": "not a number"}]}"#,
+        )
+        .unwrap_err();
+        let api_error: ApiError = json_err.into();
+
+        assert_eq!(api_error.error_type, "bad_request");
+        assert!(
+            api_error.message.contains("items[1].price"),
+            "message should include the offending field path: {}",
+            api_error.message
+        );
+    }
+
+    #[test]
+    fn test_json_missing_field_maps_to_validation_error() {
+        #[derive(Debug, serde::Deserialize)]
+        struct CreateUser {
+            #[allow(dead_code)]
+            name: String,
+            #[allow(dead_code)]
+            email: String,
+        }
+
+        let json_err =
+            crate::json::from_slice::<CreateUser>(br#"{"name": "Ada"}"#).unwrap_err();
+        let api_error: ApiError = json_err.into();
+
+        assert_eq!(api_error.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(api_error.error_type, "validation_error");
+        let fields = api_error.fields.expect("missing field error should carry field details");
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].field, "email");
+        assert_eq!(fields[0].code, "required");
+    }
 }
@@ -96,6 +96,12 @@ impl<T: FromRequestParts> FromRequest for T {
 /// Parses the request body as JSON and deserializes into type `T`.
 /// Also works as a response type when T: Serialize.
 ///
+/// Before parsing, the request's `Content-Type` is checked against
+/// `application/json` (a `charset` parameter is allowed); a missing or
+/// mismatched header returns a 415 Unsupported Media Type error rather than
+/// a confusing deserialization failure. Register a [`JsonConfig`] via
+/// `.state(...)` to relax the "header must be present" requirement.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -114,17 +120,79 @@ pub struct Json<T>(pub T);
 
 impl<T: DeserializeOwned + Send> FromRequest for Json<T> {
     async fn from_request(req: &mut Request) -> Result<Self> {
+        check_json_content_type(req)?;
+
         req.load_body().await?;
         let body = req
             .take_body()
             .ok_or_else(|| ApiError::internal("Body already consumed"))?;
 
-        // Use simd-json accelerated parsing when available (2-4x faster)
-        let value: T = json::from_slice(&body)?;
+        // Use simd-json accelerated parsing when available (2-4x faster),
+        // reusing `body`'s buffer in place rather than copying it.
+        let value: T = json::from_slice_owned(body)?;
         Ok(Json(value))
     }
 }
 
+/// Configuration for [`Json<T>`]/[`ValidatedJson<T>`] Content-Type enforcement
+///
+/// Register a custom instance via `.state(...)` to change strictness; by
+/// default a `Content-Type` header is required. Either way, a header that
+/// is present but not `application/json` is always rejected with a 415.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_core::JsonConfig;
+///
+/// let app = RustApi::new().state(JsonConfig { require_content_type: false });
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonConfig {
+    /// Whether a `Content-Type` header must be present at all.
+    ///
+    /// When `false`, a missing header is treated as `application/json`.
+    pub require_content_type: bool,
+}
+
+impl Default for JsonConfig {
+    fn default() -> Self {
+        Self {
+            require_content_type: true,
+        }
+    }
+}
+
+/// Reject requests whose `Content-Type` isn't `application/json` (ignoring a
+/// trailing `charset`/other parameters) with a 415, per the app's [`JsonConfig`].
+fn check_json_content_type(req: &Request) -> Result<()> {
+    let config = req.state().get::<JsonConfig>().cloned().unwrap_or_default();
+
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok());
+
+    let Some(content_type) = content_type else {
+        return if config.require_content_type {
+            Err(ApiError::unsupported_media_type(
+                "Missing Content-Type header, expected application/json",
+            ))
+        } else {
+            Ok(())
+        };
+    };
+
+    let mime = content_type.split(';').next().unwrap_or("").trim();
+    if mime.eq_ignore_ascii_case("application/json") {
+        Ok(())
+    } else {
+        Err(ApiError::unsupported_media_type(format!(
+            "Expected Content-Type application/json, got: {mime}"
+        )))
+    }
+}
+
 impl<T> Deref for Json<T> {
     type Target = T;
 
@@ -145,19 +213,16 @@ impl<T> From<T> for Json<T> {
     }
 }
 
-/// Default pre-allocation size for JSON response buffers (256 bytes)
-/// This covers most small to medium JSON responses without reallocation.
-const JSON_RESPONSE_INITIAL_CAPACITY: usize = 256;
-
 // IntoResponse for Json - allows using Json<T> as a return type
 impl<T: Serialize> IntoResponse for Json<T> {
     fn into_response(self) -> crate::response::Response {
-        // Use pre-allocated buffer to reduce allocations
-        match json::to_vec_with_capacity(&self.0, JSON_RESPONSE_INITIAL_CAPACITY) {
+        // Serialize through a pooled scratch buffer so repeated small
+        // responses (the common case) don't allocate a fresh Vec each time.
+        match json::to_bytes_pooled(&self.0) {
             Ok(body) => http::Response::builder()
                 .status(StatusCode::OK)
                 .header(header::CONTENT_TYPE, "application/json")
-                .body(Full::new(Bytes::from(body)))
+                .body(Full::new(body))
                 .unwrap(),
             Err(err) => {
                 ApiError::internal(format!("Failed to serialize response: {}", err)).into_response()
@@ -169,14 +234,20 @@ impl<T: Serialize> IntoResponse for Json<T> {
 /// Validated JSON body extractor
 ///
 /// Parses the request body as JSON, deserializes into type `T`, and validates
-/// using the `Validate` trait. Returns a 422 Unprocessable Entity error with
-/// detailed field-level validation errors if validation fails.
+/// using the `Validate`/`AsyncValidate` traits generated by `#[derive(Validate)]`.
+/// Returns a 422 Unprocessable Entity error with detailed field-level validation
+/// errors if validation fails.
+///
+/// Async rules (`async_unique`, `async_exists`, `async_api`, `custom_async`) run
+/// against a [`rustapi_validate::v2::ValidationContext`] registered as app state
+/// via `.state(...)`. If no context is registered, async rules run against an
+/// empty context and fail with a "not configured" error - structs with only
+/// sync rules are unaffected.
 ///
 /// # Example
 ///
 /// ```rust,ignore
 /// use rustapi_rs::prelude::*;
-/// use validator::Validate;
 ///
 /// #[derive(Deserialize, Validate)]
 /// struct CreateUser {
@@ -184,11 +255,12 @@ impl<T: Serialize> IntoResponse for Json<T> {
 ///     email: String,
 ///     #[validate(length(min = 8))]
 ///     password: String,
+///     #[validate(custom_async = "is_unique_email")]
+///     unique_email: String,
 /// }
 ///
 /// async fn register(ValidatedJson(body): ValidatedJson<CreateUser>) -> impl IntoResponse {
-///     // body is already validated!
-///     // If email is invalid or password too short, a 422 error is returned automatically
+///     // body is already validated, including the async uniqueness check!
 /// }
 /// ```
 #[derive(Debug, Clone, Copy, Default)]
@@ -206,18 +278,51 @@ impl<T> ValidatedJson<T> {
     }
 }
 
-impl<T: DeserializeOwned + rustapi_validate::Validate + Send> FromRequest for ValidatedJson<T> {
+impl<T: DeserializeOwned + rustapi_validate::v2::AsyncValidate + rustapi_validate::v2::Transform + Send>
+    FromRequest for ValidatedJson<T>
+{
     async fn from_request(req: &mut Request) -> Result<Self> {
+        check_json_content_type(req)?;
+
         req.load_body().await?;
         // First, deserialize the JSON body using simd-json when available
         let body = req
             .take_body()
             .ok_or_else(|| ApiError::internal("Body already consumed"))?;
 
-        let value: T = json::from_slice(&body)?;
+        let mut value: T = json::from_slice_owned(body)?;
+
+        // Normalize before validating, so e.g. a trimmed/lowercased email is
+        // what gets checked (and what the handler receives). Structs without
+        // `#[transform(...)]` attributes see a no-op `transform()`.
+        value.transform();
+
+        // Then, run sync and async validation. Structs without async rules see
+        // a no-op `validate_async`, so the (possibly empty) context is harmless.
+        let ctx = req
+            .state()
+            .get::<rustapi_validate::v2::ValidationContext>()
+            .cloned()
+            .unwrap_or_default();
+
+        if let Err(validation_error) =
+            rustapi_validate::v2::AsyncValidate::validate_full(&value, &ctx).await
+        {
+            // If a message catalog is registered, translate the error messages
+            // into the locale negotiated from `Accept-Language` before they're
+            // baked into the response by the `From` impl below.
+            let validation_error = match req.state().get::<rustapi_validate::v2::MessageCatalog>() {
+                Some(catalog) => {
+                    let accept_language = req
+                        .headers()
+                        .get(header::ACCEPT_LANGUAGE)
+                        .and_then(|v| v.to_str().ok());
+                    let locale = catalog.negotiate(accept_language);
+                    catalog.localize(&validation_error, &locale)
+                }
+                None => validation_error,
+            };
 
-        // Then, validate it
-        if let Err(validation_error) = rustapi_validate::Validate::validate(&value) {
             // Convert validation error to API error with 422 status
             return Err(validation_error.into());
         }
@@ -254,7 +359,11 @@ impl<T: Serialize> IntoResponse for ValidatedJson<T> {
 
 /// Query string extractor
 ///
-/// Parses the query string into type `T`.
+/// Parses the query string into type `T`. With the `query-extended` feature
+/// enabled, this also understands bracket/nested syntax (`?tags[]=a&tags[]=b`,
+/// `?filter[status]=done`) via `serde_qs`; without it, only flat keys
+/// (`?page=1&limit=20`) are supported. See [`QueryConfig`] to opt a
+/// particular app back into the plain flat parser at runtime.
 ///
 /// # Example
 ///
@@ -269,12 +378,53 @@ impl<T: Serialize> IntoResponse for ValidatedJson<T> {
 ///     // params.page, params.limit
 /// }
 /// ```
+///
+/// Fields can also use `uuid::Uuid`, `chrono::DateTime<Utc>` (`chrono`
+/// feature), or `rust_decimal::Decimal` (`rust_decimal` feature) — each
+/// parses via its own `serde::Deserialize` impl, so a malformed value
+/// becomes a 400 with that type's own error message. `time::OffsetDateTime`
+/// (`time` feature) needs an explicit
+/// `#[serde(with = "time::serde::rfc3339")]` on the field, since its bare
+/// `Deserialize` impl doesn't accept RFC 3339 strings.
 #[derive(Debug, Clone)]
 pub struct Query<T>(pub T);
 
+/// Configuration for [`Query<T>`] parsing (only meaningful with the
+/// `query-extended` feature enabled).
+///
+/// By default, extended (bracket/nested) query parsing is on wherever the
+/// feature is compiled in. Register a custom instance via `.state(...)` to
+/// opt a particular app back into the plain flat parser, e.g. because it
+/// relies on literal `[]` characters in a key.
+#[cfg(feature = "query-extended")]
+#[derive(Debug, Clone)]
+pub struct QueryConfig {
+    /// Whether to parse bracket/nested syntax via `serde_qs` instead of the
+    /// plain flat `serde_urlencoded` parser.
+    pub extended: bool,
+}
+
+#[cfg(feature = "query-extended")]
+impl Default for QueryConfig {
+    fn default() -> Self {
+        Self { extended: true }
+    }
+}
+
 impl<T: DeserializeOwned> FromRequestParts for Query<T> {
     fn from_request_parts(req: &Request) -> Result<Self> {
         let query = req.query_string().unwrap_or("");
+
+        #[cfg(feature = "query-extended")]
+        {
+            let config = req.state().get::<QueryConfig>().cloned().unwrap_or_default();
+            if config.extended {
+                let value: T = serde_qs::from_str(query)
+                    .map_err(|e| ApiError::bad_request(format!("Invalid query string: {}", e)))?;
+                return Ok(Query(value));
+            }
+        }
+
         let value: T = serde_urlencoded::from_str(query)
             .map_err(|e| ApiError::bad_request(format!("Invalid query string: {}", e)))?;
         Ok(Query(value))
@@ -310,6 +460,16 @@ impl<T> Deref for Query<T> {
 ///     // Both params extracted
 /// }
 /// ```
+///
+/// Any `T: FromStr` works, including `uuid::Uuid`, `chrono::DateTime<Utc>`
+/// (with the `chrono` feature), and `rust_decimal::Decimal` (with the
+/// `rust_decimal` feature). A value that fails to parse becomes a 400 with
+/// `T::Err`'s message, e.g. `Invalid path parameter: invalid character:
+/// expected an optional prefix...`.
+///
+/// `time::OffsetDateTime` (the `time` feature) doesn't implement `FromStr`
+/// upstream, so it only works as a [`Query`]/`Schema` field, not as a path
+/// parameter; use `chrono::DateTime<Utc>` for a date-time path parameter.
 #[derive(Debug, Clone)]
 pub struct Path<T>(pub T);
 
@@ -344,6 +504,11 @@ impl<T> Deref for Path<T> {
 ///
 /// Extracts shared application state.
 ///
+/// If the matched route was mounted with [`Router::nest`](crate::Router::nest)
+/// under a router that set its own `.state(...)`, that nested state is checked
+/// first, falling back to the app-wide state so a feature module's router can
+/// carry its own `State<T>` without the whole app needing to know about it.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -361,12 +526,17 @@ pub struct State<T>(pub T);
 
 impl<T: Clone + Send + Sync + 'static> FromRequestParts for State<T> {
     fn from_request_parts(req: &Request) -> Result<Self> {
-        req.state().get::<T>().cloned().map(State).ok_or_else(|| {
-            ApiError::internal(format!(
-                "State of type `{}` not found. Did you forget to call .state()?",
-                std::any::type_name::<T>()
-            ))
-        })
+        req.local_state()
+            .and_then(|ext| ext.get::<T>())
+            .or_else(|| req.state().get::<T>())
+            .cloned()
+            .map(State)
+            .ok_or_else(|| {
+                ApiError::internal(format!(
+                    "State of type `{}` not found. Did you forget to call .state()?",
+                    std::any::type_name::<T>()
+                ))
+            })
     }
 }
 
@@ -510,6 +680,11 @@ impl FromRequestParts for Headers {
     }
 }
 
+// Headers - No documented parameters, since it captures whatever the caller sent
+impl OperationModifier for Headers {
+    fn update_operation(_op: &mut Operation) {}
+}
+
 impl Deref for Headers {
     type Target = http::HeaderMap;
 
@@ -622,6 +797,59 @@ impl<T> DerefMut for Extension<T> {
     }
 }
 
+/// Local extractor
+///
+/// Retrieves a request-scoped typed value set by middleware via
+/// [`Request::set_local`]. This formalizes the ad-hoc
+/// `req.extensions_mut().insert(...)` pattern middleware like `JwtLayer` uses
+/// to hand a typed value to handlers for the current request only, as
+/// distinct from app-wide values reachable via `State<T>`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_core::extract::Local;
+///
+/// // Middleware calls req.set_local(CurrentUser { id: 1 })
+/// #[derive(Clone)]
+/// struct CurrentUser { id: i64 }
+///
+/// async fn handler(Local(user): Local<CurrentUser>) -> impl IntoResponse {
+///     format!("User ID: {}", user.id)
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Local<T>(pub T);
+
+impl<T: Clone + Send + Sync + 'static> FromRequestParts for Local<T> {
+    fn from_request_parts(req: &Request) -> Result<Self> {
+        req.extensions()
+            .get::<T>()
+            .cloned()
+            .map(Local)
+            .ok_or_else(|| {
+                ApiError::internal(format!(
+                    "Local value of type `{}` not found. Did middleware call req.set_local()?",
+                    std::any::type_name::<T>()
+                ))
+            })
+    }
+}
+
+impl<T> Deref for Local<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Local<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 /// Client IP address extractor
 ///
 /// Extracts the client IP address from the request. When `trust_proxy` is enabled,
@@ -717,23 +945,28 @@ impl Cookies {
 #[cfg(feature = "cookies")]
 impl FromRequestParts for Cookies {
     fn from_request_parts(req: &Request) -> Result<Self> {
-        let mut jar = cookie::CookieJar::new();
-
-        if let Some(cookie_header) = req.headers().get(header::COOKIE) {
-            if let Ok(cookie_str) = cookie_header.to_str() {
-                // Parse each cookie from the header
-                for cookie_part in cookie_str.split(';') {
-                    let trimmed = cookie_part.trim();
-                    if !trimmed.is_empty() {
-                        if let Ok(cookie) = cookie::Cookie::parse(trimmed.to_string()) {
-                            jar.add_original(cookie.into_owned());
+        // Memoized: middleware (e.g. a CSRF guard) and the handler often both
+        // extract Cookies, and re-walking the `Cookie` header each time is
+        // wasted work. See `Request::cached_extract`.
+        req.cached_extract(|| {
+            let mut jar = cookie::CookieJar::new();
+
+            if let Some(cookie_header) = req.headers().get(header::COOKIE) {
+                if let Ok(cookie_str) = cookie_header.to_str() {
+                    // Parse each cookie from the header
+                    for cookie_part in cookie_str.split(';') {
+                        let trimmed = cookie_part.trim();
+                        if !trimmed.is_empty() {
+                            if let Ok(cookie) = cookie::Cookie::parse(trimmed.to_string()) {
+                                jar.add_original(cookie.into_owned());
+                            }
                         }
                     }
                 }
             }
-        }
 
-        Ok(Cookies(jar))
+            Ok(Cookies(jar))
+        })
     }
 }
 
@@ -812,6 +1045,8 @@ impl<T: for<'a> Schema<'a>> OperationModifier for ValidatedJson<T> {
                 },
             },
         );
+
+        add_unsupported_media_type_response(op);
     }
 }
 
@@ -834,9 +1069,34 @@ impl<T: for<'a> Schema<'a>> OperationModifier for Json<T> {
             required: true,
             content,
         });
+
+        add_unsupported_media_type_response(op);
     }
 }
 
+// Document the 415 Unsupported Media Type response that `check_json_content_type`
+// can return, shared by both `Json<T>` and `ValidatedJson<T>`.
+fn add_unsupported_media_type_response(op: &mut Operation) {
+    op.responses.insert(
+        "415".to_string(),
+        ResponseSpec {
+            description: "Unsupported Media Type".to_string(),
+            content: {
+                let mut map = HashMap::new();
+                map.insert(
+                    "application/json".to_string(),
+                    MediaType {
+                        schema: SchemaRef::Ref {
+                            reference: "#/components/schemas/ErrorSchema".to_string(),
+                        },
+                    },
+                );
+                Some(map)
+            },
+        },
+    );
+}
+
 // Path - Path parameters are automatically extracted from route patterns
 // The add_path_params_to_operation function in app.rs handles OpenAPI documentation
 // based on the {param} syntax in route paths (e.g., "/users/{id}")
@@ -973,7 +1233,7 @@ mod tests {
     use super::*;
     use crate::path_params::PathParams;
     use bytes::Bytes;
-    use http::{Extensions, Method};
+    use http::{Extensions, Method, StatusCode};
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseError;
     use std::sync::Arc;
@@ -1023,6 +1283,71 @@ mod tests {
         )
     }
 
+    /// Create a test request with the given headers and app state
+    fn create_test_request_with_state(
+        method: Method,
+        path: &str,
+        headers: Vec<(&str, &str)>,
+        state: Extensions,
+    ) -> Request {
+        let uri: http::Uri = path.parse().unwrap();
+        let mut builder = http::Request::builder().method(method).uri(uri);
+
+        for (name, value) in headers {
+            builder = builder.header(name, value);
+        }
+
+        let req = builder.body(()).unwrap();
+        let (parts, _) = req.into_parts();
+
+        Request::new(
+            parts,
+            crate::request::BodyVariant::Buffered(Bytes::new()),
+            Arc::new(state),
+            PathParams::new(),
+        )
+    }
+
+    #[test]
+    fn test_json_content_type_missing_header_is_rejected_by_default() {
+        let request = create_test_request_with_headers(Method::POST, "/test", vec![]);
+        let result = check_json_content_type(&request);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().status,
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+
+    #[test]
+    fn test_json_content_type_wrong_header_is_always_rejected() {
+        let request = create_test_request_with_headers(
+            Method::POST,
+            "/test",
+            vec![("content-type", "text/plain")],
+        );
+        let result = check_json_content_type(&request);
+
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().status,
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+
+    #[test]
+    fn test_json_content_type_missing_header_is_allowed_when_not_required() {
+        let mut state = Extensions::new();
+        state.insert(JsonConfig {
+            require_content_type: false,
+        });
+        let request =
+            create_test_request_with_state(Method::POST, "/test", vec![], state);
+
+        assert!(check_json_content_type(&request).is_ok());
+    }
+
     // **Feature: phase3-batteries-included, Property 14: Headers extractor completeness**
     //
     // For any request with headers H, the `Headers` extractor SHALL return a map
@@ -1389,6 +1714,188 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_local_extractor_present() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct CurrentUser(String);
+
+        let mut request = create_test_request_with_headers(Method::GET, "/test", vec![]);
+        request.set_local(CurrentUser("alice".to_string()));
+
+        let result = Local::<CurrentUser>::from_request_parts(&request);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().0, CurrentUser("alice".to_string()));
+    }
+
+    #[test]
+    fn test_local_extractor_missing() {
+        #[derive(Clone, Debug)]
+        #[allow(dead_code)]
+        struct CurrentUser(String);
+
+        let request = create_test_request_with_headers(Method::GET, "/test", vec![]);
+
+        let result = Local::<CurrentUser>::from_request_parts(&request);
+        assert!(result.is_err());
+    }
+
+    // Query tests (extended parsing is feature-gated)
+    #[cfg(feature = "query-extended")]
+    mod query_tests {
+        use super::*;
+        use serde::Deserialize;
+
+        #[derive(Debug, Deserialize)]
+        struct TagsQuery {
+            tags: Vec<String>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct FilterQuery {
+            filter: std::collections::HashMap<String, String>,
+        }
+
+        #[test]
+        fn test_query_extended_parses_bracket_arrays() {
+            let request = create_test_request_with_headers(
+                Method::GET,
+                "/list?tags[]=a&tags[]=b",
+                vec![],
+            );
+
+            let Query(parsed) = Query::<TagsQuery>::from_request_parts(&request).unwrap();
+            assert_eq!(parsed.tags, vec!["a".to_string(), "b".to_string()]);
+        }
+
+        #[test]
+        fn test_query_extended_parses_nested_maps() {
+            let request = create_test_request_with_headers(
+                Method::GET,
+                "/list?filter[status]=done",
+                vec![],
+            );
+
+            let Query(parsed) = Query::<FilterQuery>::from_request_parts(&request).unwrap();
+            assert_eq!(parsed.filter.get("status"), Some(&"done".to_string()));
+        }
+
+        #[test]
+        fn test_query_config_can_disable_extended_parsing() {
+            let mut extensions = Extensions::new();
+            extensions.insert(QueryConfig { extended: false });
+            let request = Request::new(
+                http::Request::builder()
+                    .method(Method::GET)
+                    .uri("/list?tags[]=a&tags[]=b".parse::<http::Uri>().unwrap())
+                    .body(())
+                    .unwrap()
+                    .into_parts()
+                    .0,
+                crate::request::BodyVariant::Buffered(Bytes::new()),
+                Arc::new(extensions),
+                PathParams::new(),
+            );
+
+            // With extended parsing disabled, `tags[]` is a literal (unknown)
+            // key, so the plain flat parser can't fill `tags: Vec<String>`.
+            let result = Query::<TagsQuery>::from_request_parts(&request);
+            assert!(result.is_err());
+        }
+    }
+
+    // Extended scalar types for Path/Query (uuid is always available; the
+    // others are feature-gated).
+    mod extended_scalar_tests {
+        use super::*;
+
+        fn request_with_path_param(name: &str, value: &str) -> Request {
+            let mut params = PathParams::new();
+            params.insert(name.to_string(), value.to_string());
+
+            Request::new(
+                http::Request::builder()
+                    .method(Method::GET)
+                    .uri("/item/{}".parse::<http::Uri>().unwrap())
+                    .body(())
+                    .unwrap()
+                    .into_parts()
+                    .0,
+                crate::request::BodyVariant::Buffered(Bytes::new()),
+                Arc::new(Extensions::new()),
+                params,
+            )
+        }
+
+        #[test]
+        fn test_path_parses_uuid() {
+            let id = uuid::Uuid::new_v4();
+            let request = request_with_path_param("id", &id.to_string());
+
+            let Path(parsed) = Path::<uuid::Uuid>::from_request_parts(&request).unwrap();
+            assert_eq!(parsed, id);
+        }
+
+        #[test]
+        fn test_path_invalid_uuid_is_bad_request_with_message() {
+            let request = request_with_path_param("id", "not-a-uuid");
+
+            let err = Path::<uuid::Uuid>::from_request_parts(&request).unwrap_err();
+            assert_eq!(err.status, http::StatusCode::BAD_REQUEST);
+            assert!(err.message.starts_with("Invalid path parameter:"));
+        }
+
+        #[cfg(feature = "chrono")]
+        #[test]
+        fn test_query_parses_chrono_datetime() {
+            use serde::Deserialize;
+
+            #[derive(Debug, Deserialize)]
+            struct Event {
+                at: chrono::DateTime<chrono::Utc>,
+            }
+
+            let request = create_test_request_with_headers(
+                Method::GET,
+                "/events?at=2024-01-15T10:30:00Z",
+                vec![],
+            );
+
+            let Query(parsed) = Query::<Event>::from_request_parts(&request).unwrap();
+            assert_eq!(parsed.at.to_rfc3339(), "2024-01-15T10:30:00+00:00");
+        }
+
+        #[cfg(feature = "time")]
+        #[test]
+        fn test_query_parses_time_offset_date_time() {
+            use serde::Deserialize;
+
+            #[derive(Debug, Deserialize)]
+            struct Event {
+                #[serde(with = "time::serde::rfc3339")]
+                at: time::OffsetDateTime,
+            }
+
+            let request = create_test_request_with_headers(
+                Method::GET,
+                "/events?at=2024-01-15T10:30:00Z",
+                vec![],
+            );
+
+            let Query(parsed) = Query::<Event>::from_request_parts(&request).unwrap();
+            assert_eq!(parsed.at.year(), 2024);
+        }
+
+        #[cfg(feature = "rust_decimal")]
+        #[test]
+        fn test_path_parses_decimal() {
+            let request = request_with_path_param("amount", "19.99");
+
+            let Path(parsed) =
+                Path::<rust_decimal::Decimal>::from_request_parts(&request).unwrap();
+            assert_eq!(parsed, "19.99".parse::<rust_decimal::Decimal>().unwrap());
+        }
+    }
+
     // Cookies tests (feature-gated)
     #[cfg(feature = "cookies")]
     mod cookies_tests {
@@ -1509,5 +2016,24 @@ mod tests {
             assert_eq!(cookies.iter().count(), 1);
             assert_eq!(cookies.get("token").unwrap().value(), "xyz789");
         }
+
+        #[test]
+        fn test_cookies_extractor_is_memoized_across_calls() {
+            let request = create_test_request_with_headers(
+                Method::GET,
+                "/test",
+                vec![("cookie", "session=abc123")],
+            );
+
+            // Simulates middleware and the handler both extracting Cookies
+            // for the same request.
+            let first = Cookies::from_request_parts(&request).unwrap();
+            let second = Cookies::from_request_parts(&request).unwrap();
+
+            assert_eq!(
+                first.get("session").unwrap().value(),
+                second.get("session").unwrap().value()
+            );
+        }
     }
 }
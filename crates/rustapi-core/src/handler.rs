@@ -57,7 +57,11 @@
 use crate::extract::FromRequest;
 use crate::request::Request;
 use crate::response::{IntoResponse, Response};
-use rustapi_openapi::{Operation, OperationModifier, ResponseModifier};
+use rustapi_openapi::{
+    MediaType, Operation, OperationModifier, RequestBody, ResponseModifier, ResponseSpec, Schema,
+    SchemaRef,
+};
+use std::collections::HashMap;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
@@ -381,6 +385,63 @@ impl Route {
         self
     }
 
+    /// Assign this route to a documentation group (e.g. "public", "internal"),
+    /// so it only appears in specs built with a matching group via
+    /// [`RustApi::docs_group`](crate::RustApi::docs_group).
+    pub fn doc_group(mut self, group: impl Into<String>) -> Self {
+        self.operation = self.operation.doc_group(group);
+        self
+    }
+
+    /// Exclude this route from the generated OpenAPI document (e.g. health
+    /// checks, metrics, debug endpoints) without disabling docs entirely -
+    /// the route keeps serving requests as normal.
+    pub fn hidden(mut self) -> Self {
+        self.operation = self.operation.hidden();
+        self
+    }
+
+    /// Document the request body as `T`, for handlers that take a raw
+    /// [`Request`] (and so have no [`OperationModifier`] extractor to infer
+    /// it from).
+    pub fn request_body<T: for<'a> Schema<'a>>(mut self) -> Self {
+        let (name, _) = T::schema();
+        let schema_ref = SchemaRef::Ref {
+            reference: format!("#/components/schemas/{}", name),
+        };
+
+        let mut content = HashMap::new();
+        content.insert("application/json".to_string(), MediaType { schema: schema_ref });
+
+        self.operation.request_body = Some(RequestBody {
+            required: true,
+            content,
+        });
+        self
+    }
+
+    /// Document the response at `status` as `T`, for handlers that return a
+    /// raw [`Response`] (and so have no [`ResponseModifier`] return type to
+    /// infer it from).
+    pub fn response<T: for<'a> Schema<'a>>(mut self, status: impl Into<http::StatusCode>) -> Self {
+        let (name, _) = T::schema();
+        let schema_ref = SchemaRef::Ref {
+            reference: format!("#/components/schemas/{}", name),
+        };
+
+        let mut content = HashMap::new();
+        content.insert("application/json".to_string(), MediaType { schema: schema_ref });
+
+        self.operation.responses.insert(
+            status.into().as_u16().to_string(),
+            ResponseSpec {
+                description: "Successful response".to_string(),
+                content: Some(content),
+            },
+        );
+        self
+    }
+
     /// Get the route path
     pub fn path(&self) -> &str {
         self.path
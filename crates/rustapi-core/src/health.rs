@@ -29,7 +29,12 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Default per-check timeout applied by [`HealthCheck::execute`]
+pub const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Health status of a component
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -82,8 +87,30 @@ impl HealthStatus {
     }
 }
 
+/// Handle to signal that an app is beginning graceful shutdown
+///
+/// Cloned out of a [`HealthCheck`] via [`HealthCheck::shutdown_handle`] and
+/// handed to whatever drives shutdown (a signal handler, an admin endpoint).
+/// Once flipped, both the liveness and readiness endpoints mounted by
+/// `RustApi::health_routes` start reporting failure, so orchestrators stop
+/// routing traffic and eventually recycle the instance.
+#[derive(Clone)]
+pub struct ShutdownHandle(Arc<AtomicBool>);
+
+impl ShutdownHandle {
+    /// Mark the app as shutting down
+    pub fn begin_shutdown(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether shutdown has been signaled
+    pub fn is_shutting_down(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
 /// Overall health check result
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthCheckResult {
     /// Overall status
     pub status: HealthStatus,
@@ -105,26 +132,56 @@ pub type HealthCheckFn =
 pub struct HealthCheck {
     checks: HashMap<String, HealthCheckFn>,
     version: Option<String>,
+    check_timeout: Duration,
+    cache_ttl: Option<Duration>,
+    cache: Arc<Mutex<Option<(Instant, HealthCheckResult)>>>,
+    shutting_down: Arc<AtomicBool>,
 }
 
 impl HealthCheck {
     /// Execute all health checks
+    ///
+    /// Each check is bounded by the configured `check_timeout` (a check that
+    /// doesn't resolve in time counts as unhealthy). If `cache_ttl` is set and
+    /// a result younger than it exists, that cached result is returned
+    /// without re-running the checks.
     pub async fn execute(&self) -> HealthCheckResult {
+        if self.shutting_down.load(Ordering::Relaxed) {
+            return HealthCheckResult {
+                status: HealthStatus::unhealthy("shutting down"),
+                checks: HashMap::new(),
+                version: self.version.clone(),
+                timestamp: current_timestamp(),
+            };
+        }
+
+        if let Some(ttl) = self.cache_ttl {
+            if let Some((cached_at, result)) = self.cache.lock().unwrap().clone() {
+                if cached_at.elapsed() < ttl {
+                    return result;
+                }
+            }
+        }
+
         let mut results = HashMap::new();
         let mut overall_status = HealthStatus::Healthy;
 
         for (name, check) in &self.checks {
-            let status = check().await;
+            let status = match tokio::time::timeout(self.check_timeout, check()).await {
+                Ok(status) => status,
+                Err(_) => HealthStatus::unhealthy(format!(
+                    "check timed out after {:?}",
+                    self.check_timeout
+                )),
+            };
 
             // Determine overall status
             match &status {
                 HealthStatus::Unhealthy { .. } => {
                     overall_status = HealthStatus::unhealthy("one or more checks failed");
                 }
-                HealthStatus::Degraded { .. } => {
-                    if overall_status.is_healthy() {
-                        overall_status = HealthStatus::degraded("one or more checks degraded");
-                    }
+                HealthStatus::Degraded { .. } if overall_status.is_healthy() => {
+                    overall_status = HealthStatus::degraded("one or more checks degraded");
                 }
                 _ => {}
             }
@@ -132,29 +189,49 @@ impl HealthCheck {
             results.insert(name.clone(), status);
         }
 
-        // Use UTC timestamp formatted as ISO 8601
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|d| {
-                let secs = d.as_secs();
-                let nanos = d.subsec_nanos();
-                format!("{}.{:09}Z", secs, nanos)
-            })
-            .unwrap_or_else(|_| "unknown".to_string());
-
-        HealthCheckResult {
+        let result = HealthCheckResult {
             status: overall_status,
             checks: results,
             version: self.version.clone(),
-            timestamp,
+            timestamp: current_timestamp(),
+        };
+
+        if self.cache_ttl.is_some() {
+            *self.cache.lock().unwrap() = Some((Instant::now(), result.clone()));
         }
+
+        result
+    }
+
+    /// Whether shutdown has been signaled via a [`ShutdownHandle`] taken from this check
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::Relaxed)
+    }
+
+    /// Get a handle to signal graceful shutdown for this health check
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle(self.shutting_down.clone())
     }
 }
 
+/// Current UTC timestamp formatted as ISO 8601
+fn current_timestamp() -> String {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| {
+            let secs = d.as_secs();
+            let nanos = d.subsec_nanos();
+            format!("{}.{:09}Z", secs, nanos)
+        })
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
 /// Builder for health check configuration
 pub struct HealthCheckBuilder {
     checks: HashMap<String, HealthCheckFn>,
     version: Option<String>,
+    check_timeout: Duration,
+    cache_ttl: Option<Duration>,
 }
 
 impl HealthCheckBuilder {
@@ -174,6 +251,8 @@ impl HealthCheckBuilder {
         Self {
             checks,
             version: None,
+            check_timeout: DEFAULT_CHECK_TIMEOUT,
+            cache_ttl: None,
         }
     }
 
@@ -209,11 +288,27 @@ impl HealthCheckBuilder {
         self
     }
 
+    /// Set the timeout applied to each individual check (default 5s)
+    pub fn check_timeout(mut self, timeout: Duration) -> Self {
+        self.check_timeout = timeout;
+        self
+    }
+
+    /// Cache the aggregate result for `ttl`, instead of re-running every check on each request
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
     /// Build the health check
     pub fn build(self) -> HealthCheck {
         HealthCheck {
             checks: self.checks,
             version: self.version,
+            check_timeout: self.check_timeout,
+            cache_ttl: self.cache_ttl,
+            cache: Arc::new(Mutex::new(None)),
+            shutting_down: Arc::new(AtomicBool::new(false)),
         }
     }
 }
@@ -281,4 +376,57 @@ mod tests {
         assert_eq!(result.checks.len(), 1);
         assert!(result.checks.contains_key("self"));
     }
+
+    #[tokio::test]
+    async fn health_check_shutdown_handle_fails_execute() {
+        let health = HealthCheckBuilder::new(false)
+            .add_check("db", || async { HealthStatus::healthy() })
+            .build();
+        let shutdown = health.shutdown_handle();
+
+        assert!(!health.is_shutting_down());
+        shutdown.begin_shutdown();
+        assert!(health.is_shutting_down());
+
+        let result = health.execute().await;
+        assert!(result.status.is_unhealthy());
+        assert!(result.checks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn health_check_slow_check_times_out() {
+        let health = HealthCheckBuilder::new(false)
+            .add_check("slow", || async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                HealthStatus::healthy()
+            })
+            .check_timeout(Duration::from_millis(10))
+            .build();
+
+        let result = health.execute().await;
+
+        assert!(result.status.is_unhealthy());
+        assert!(result.checks["slow"].is_unhealthy());
+    }
+
+    #[tokio::test]
+    async fn health_check_cache_ttl_reuses_result() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = calls.clone();
+        let health = HealthCheckBuilder::new(false)
+            .add_check("db", move || {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                    HealthStatus::healthy()
+                }
+            })
+            .cache_ttl(Duration::from_secs(60))
+            .build();
+
+        health.execute().await;
+        health.execute().await;
+
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
 }
@@ -39,9 +39,14 @@ pub fn from_slice<T: DeserializeOwned>(slice: &[u8]) -> Result<T, JsonError> {
 /// Deserialize JSON from a byte slice.
 ///
 /// Standard `serde_json` implementation when `simd-json` feature is disabled.
+///
+/// Deserialization errors carry the path to the offending field (e.g.
+/// `items[3].price`), via `serde_path_to_error`, so callers can report
+/// exactly where a payload went wrong instead of a bare line/column.
 #[cfg(not(feature = "simd-json"))]
 pub fn from_slice<T: DeserializeOwned>(slice: &[u8]) -> Result<T, JsonError> {
-    serde_json::from_slice(slice).map_err(JsonError::SerdeJson)
+    let mut de = serde_json::Deserializer::from_slice(slice);
+    serde_path_to_error::deserialize(&mut de).map_err(JsonError::SerdeJsonPath)
 }
 
 /// Deserialize JSON from a mutable byte slice (zero-copy with simd-json).
@@ -55,10 +60,36 @@ pub fn from_slice_mut<T: DeserializeOwned>(slice: &mut [u8]) -> Result<T, JsonEr
 
 /// Deserialize JSON from a mutable byte slice.
 ///
-/// Falls back to standard implementation when simd-json is disabled.
+/// Falls back to standard implementation when simd-json is disabled. See
+/// [`from_slice`] for the path-tracking behavior on deserialization errors.
 #[cfg(not(feature = "simd-json"))]
 pub fn from_slice_mut<T: DeserializeOwned>(slice: &mut [u8]) -> Result<T, JsonError> {
-    serde_json::from_slice(slice).map_err(JsonError::SerdeJson)
+    let mut de = serde_json::Deserializer::from_slice(slice);
+    serde_path_to_error::deserialize(&mut de).map_err(JsonError::SerdeJsonPath)
+}
+
+/// Deserialize JSON from an owned buffer, parsing in-place with no extra
+/// allocation when possible.
+///
+/// When the `simd-json` feature is enabled and `bytes` isn't shared with any
+/// other clone (the common case for a body just taken via
+/// [`Request::take_body`](crate::request::Request::take_body)), this uses
+/// [`Bytes::try_into_mut`] to reuse the buffer as a `BytesMut` and parses via
+/// [`from_slice_mut`], avoiding the copy [`from_slice`] would otherwise make.
+/// Falls back to [`from_slice`] if the buffer is still shared, and always
+/// falls back to it when `simd-json` is disabled.
+pub fn from_slice_owned<T: DeserializeOwned>(bytes: bytes::Bytes) -> Result<T, JsonError> {
+    #[cfg(feature = "simd-json")]
+    {
+        match bytes.try_into_mut() {
+            Ok(mut owned) => from_slice_mut(&mut owned),
+            Err(shared) => from_slice(&shared),
+        }
+    }
+    #[cfg(not(feature = "simd-json"))]
+    {
+        from_slice(&bytes)
+    }
 }
 
 /// Serialize a value to a JSON byte vector.
@@ -86,18 +117,97 @@ pub fn to_vec_pretty<T: Serialize>(value: &T) -> Result<Vec<u8>, JsonError> {
     serde_json::to_vec_pretty(value).map_err(JsonError::SerdeJson)
 }
 
+/// Scratch buffers up to this size are recycled by [`to_bytes_pooled`]
+/// instead of being reallocated on every call; buffers that grow past it
+/// during serialization are dropped normally rather than pooled, so the
+/// pool never retains an oversized buffer.
+const POOLED_JSON_THRESHOLD: usize = 8 * 1024;
+
+/// Maximum number of scratch buffers retained per thread.
+const POOL_CAPACITY: usize = 32;
+
+thread_local! {
+    static BUFFER_POOL: std::cell::RefCell<Vec<Vec<u8>>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+fn take_scratch_buffer() -> Vec<u8> {
+    BUFFER_POOL
+        .with(|pool| pool.borrow_mut().pop())
+        .unwrap_or_else(|| Vec::with_capacity(POOLED_JSON_THRESHOLD))
+}
+
+fn return_scratch_buffer(mut buf: Vec<u8>) {
+    if buf.capacity() > POOLED_JSON_THRESHOLD {
+        return;
+    }
+    buf.clear();
+    BUFFER_POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        if pool.len() < POOL_CAPACITY {
+            pool.push(buf);
+        }
+    });
+}
+
+/// Serialize a value to JSON `Bytes`, drawing the scratch buffer used for
+/// serialization from a per-thread pool instead of allocating a fresh `Vec`
+/// on every call.
+///
+/// Intended for hot paths returning small JSON payloads (e.g. `Json<T>`
+/// responses): the scratch buffer is reused across calls as long as it
+/// stays at or under [`POOLED_JSON_THRESHOLD`] bytes, so repeated small
+/// responses stop paying for a fresh allocation (and its eventual growth
+/// reallocations) each time. Larger payloads still serialize correctly;
+/// their buffer is simply not retained afterward.
+pub fn to_bytes_pooled<T: Serialize>(value: &T) -> Result<bytes::Bytes, JsonError> {
+    let mut buf = take_scratch_buffer();
+    let result = serde_json::to_writer(&mut buf, value).map_err(JsonError::SerdeJson);
+    let bytes = result.map(|()| bytes::Bytes::copy_from_slice(&buf));
+    return_scratch_buffer(buf);
+    bytes
+}
+
 /// JSON error type that wraps both serde_json and simd-json errors.
 #[derive(Debug)]
 pub enum JsonError {
     SerdeJson(serde_json::Error),
+    /// A `serde_json` deserialization error, tagged with the path (e.g.
+    /// `items[3].price`) at which it occurred.
+    SerdeJsonPath(serde_path_to_error::Error<serde_json::Error>),
     #[cfg(feature = "simd-json")]
     SimdJson(simd_json::Error),
 }
 
+impl JsonError {
+    /// The path to the field that caused the error, if known.
+    ///
+    /// Only populated for [`from_slice`]/[`from_slice_mut`] errors, since
+    /// path tracking requires driving the `Deserializer` manually.
+    pub fn path(&self) -> Option<&serde_path_to_error::Path> {
+        match self {
+            JsonError::SerdeJsonPath(e) => Some(e.path()),
+            _ => None,
+        }
+    }
+
+    /// Whether the underlying error is a missing required field.
+    pub fn is_missing_field(&self) -> bool {
+        let inner = match self {
+            JsonError::SerdeJsonPath(e) => e.inner(),
+            JsonError::SerdeJson(e) => e,
+            #[cfg(feature = "simd-json")]
+            JsonError::SimdJson(_) => return false,
+        };
+        inner.to_string().starts_with("missing field")
+    }
+}
+
 impl std::fmt::Display for JsonError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             JsonError::SerdeJson(e) => write!(f, "{}", e),
+            JsonError::SerdeJsonPath(e) => write!(f, "{}", e),
             #[cfg(feature = "simd-json")]
             JsonError::SimdJson(e) => write!(f, "{}", e),
         }
@@ -108,6 +218,7 @@ impl std::error::Error for JsonError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             JsonError::SerdeJson(e) => Some(e),
+            JsonError::SerdeJsonPath(e) => Some(e),
             #[cfg(feature = "simd-json")]
             JsonError::SimdJson(e) => Some(e),
         }
@@ -120,9 +231,83 @@ impl From<serde_json::Error> for JsonError {
     }
 }
 
+impl From<serde_path_to_error::Error<serde_json::Error>> for JsonError {
+    fn from(e: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        JsonError::SerdeJsonPath(e)
+    }
+}
+
 #[cfg(feature = "simd-json")]
 impl From<simd_json::Error> for JsonError {
     fn from(e: simd_json::Error) -> Self {
         JsonError::SimdJson(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn from_slice_owned_parses_uniquely_owned_bytes() {
+        let bytes = bytes::Bytes::from(r#"{"x":1,"y":2}"#);
+        let value: Point = from_slice_owned(bytes).unwrap();
+        assert_eq!(value, Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    fn from_slice_owned_parses_shared_bytes() {
+        let bytes = bytes::Bytes::from(r#"{"x":3,"y":4}"#);
+        let _clone = bytes.clone(); // keep the buffer shared
+        let value: Point = from_slice_owned(bytes).unwrap();
+        assert_eq!(value, Point { x: 3, y: 4 });
+    }
+
+    #[test]
+    fn from_slice_owned_rejects_invalid_json() {
+        let bytes = bytes::Bytes::from(r#"{"x":1"#);
+        let result: Result<Point, JsonError> = from_slice_owned(bytes);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn to_bytes_pooled_serializes_correctly() {
+        let point = Point { x: 5, y: -3 };
+        let bytes = to_bytes_pooled(&point).unwrap();
+        assert_eq!(&bytes[..], br#"{"x":5,"y":-3}"#);
+    }
+
+    #[test]
+    fn to_bytes_pooled_reuses_scratch_buffer_across_calls() {
+        // Drain any buffer left behind by other tests in this thread so the
+        // first call below is guaranteed to start from an empty pool.
+        BUFFER_POOL.with(|pool| pool.borrow_mut().clear());
+
+        let _ = to_bytes_pooled(&Point { x: 1, y: 2 }).unwrap();
+        let pooled_after_first_call = BUFFER_POOL.with(|pool| pool.borrow().len());
+        assert_eq!(pooled_after_first_call, 1);
+
+        let _ = to_bytes_pooled(&Point { x: 3, y: 4 }).unwrap();
+        let pooled_after_second_call = BUFFER_POOL.with(|pool| pool.borrow().len());
+        assert_eq!(pooled_after_second_call, 1, "the same buffer should be reused, not accumulated");
+    }
+
+    #[test]
+    fn to_bytes_pooled_does_not_retain_oversized_buffers() {
+        BUFFER_POOL.with(|pool| pool.borrow_mut().clear());
+
+        let large = vec!['a'; POOLED_JSON_THRESHOLD + 1024]
+            .into_iter()
+            .collect::<String>();
+        let _ = to_bytes_pooled(&large).unwrap();
+
+        assert!(BUFFER_POOL.with(|pool| pool.borrow().is_empty()));
+    }
+}
@@ -40,6 +40,11 @@
 //!
 //! - `metrics` - Enable Prometheus metrics middleware
 //! - `cookies` - Enable cookie parsing extractor
+//! - `query-extended` - Parse bracket/nested query strings (`tags[]=a`, `filter[status]=done`) in [`Query`]
+//! - `uuid` - Emit the OpenAPI `uuid` format for `Uuid` fields in [`Query`]/`Schema` (`Path<Uuid>` always works)
+//! - `chrono` - `Path`/`Query`/`Schema` support for `chrono::DateTime`, with the OpenAPI `date-time` format
+//! - `time` - `Query`/`Schema` support for `time::OffsetDateTime`, with the OpenAPI `date-time` format
+//! - `rust_decimal` - `Path`/`Query`/`Schema` support for `rust_decimal::Decimal`
 //! - `test-utils` - Enable testing utilities like `TestClient`
 //! - `swagger-ui` - Enable Swagger UI documentation endpoint
 //!
@@ -63,6 +68,7 @@ pub mod middleware;
 pub mod multipart;
 pub mod path_params;
 pub mod path_validation;
+mod proxy;
 mod request;
 mod response;
 mod router;
@@ -83,6 +89,7 @@ mod test_client;
 pub mod __private {
     pub use crate::auto_route::AUTO_ROUTES;
     pub use crate::auto_schema::AUTO_SCHEMAS;
+    pub use http;
     pub use linkme;
     pub use rustapi_openapi;
 }
@@ -94,22 +101,33 @@ pub use error::{get_environment, ApiError, Environment, FieldError, Result};
 pub use extract::Cookies;
 pub use extract::{
     Body, BodyStream, ClientIp, Extension, FromRequest, FromRequestParts, HeaderValue, Headers,
-    Json, Path, Query, State, ValidatedJson,
+    Json, JsonConfig, Local, Path, Query, State, ValidatedJson,
 };
+#[cfg(feature = "query-extended")]
+pub use extract::QueryConfig;
 pub use handler::{
     delete_route, get_route, patch_route, post_route, put_route, Handler, HandlerService, Route,
     RouteHandler,
 };
-pub use health::{HealthCheck, HealthCheckBuilder, HealthCheckResult, HealthStatus};
+pub use health::{
+    HealthCheck, HealthCheckBuilder, HealthCheckResult, HealthStatus, ShutdownHandle,
+    DEFAULT_CHECK_TIMEOUT,
+};
 pub use interceptor::{InterceptorChain, RequestInterceptor, ResponseInterceptor};
 #[cfg(feature = "compression")]
 pub use middleware::CompressionLayer;
-pub use middleware::{BodyLimitLayer, RequestId, RequestIdLayer, TracingLayer, DEFAULT_BODY_LIMIT};
+pub use middleware::{
+    BodyLimitLayer, DevReloadLayer, RequestContext, RequestContextLayer, RequestId,
+    RequestIdLayer, TracingLayer, DEFAULT_BODY_LIMIT, DEV_RELOAD_PING_PATH,
+};
 #[cfg(feature = "metrics")]
 pub use middleware::{MetricsLayer, MetricsResponse};
 pub use multipart::{Multipart, MultipartConfig, MultipartField, UploadedFile};
+pub use proxy::ProxyConfig;
 pub use request::Request;
-pub use response::{Created, Html, IntoResponse, NoContent, Redirect, Response, WithStatus};
+pub use response::{
+    Created, Html, IntoResponse, NoContent, Preload, PreloadHint, Redirect, Response, WithStatus,
+};
 pub use router::{delete, get, patch, post, put, MethodRouter, Router};
 pub use sse::{sse_response, KeepAlive, Sse, SseEvent};
 pub use static_files::{serve_dir, StaticFile, StaticFileConfig};
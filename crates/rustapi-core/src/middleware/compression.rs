@@ -217,7 +217,7 @@ impl CompressionLayer {
     }
 
     /// Compress bytes using the specified algorithm
-    fn compress(
+    pub(crate) fn compress(
         &self,
         data: &[u8],
         algorithm: CompressionAlgorithm,
@@ -0,0 +1,239 @@
+//! Dev-mode reload banner middleware
+//!
+//! Injects a small polling script into HTML responses so the browser tab
+//! notices when the server has restarted (e.g. after `cargo watch` rebuilds
+//! it) and reloads itself, instead of the developer staring at a stale page.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rustapi_rs::prelude::*;
+//!
+//! RustApi::new()
+//!     .dev_reload()
+//!     .route("/", get(handler))
+//!     .run("127.0.0.1:8080")
+//!     .await
+//! ```
+
+use super::layer::{BoxedNext, MiddlewareLayer};
+use crate::request::Request;
+use crate::response::Response;
+use bytes::Bytes;
+use http::header;
+use http_body_util::{BodyExt, Full};
+use std::future::Future;
+use std::pin::Pin;
+
+/// Path the dev-reload script polls to detect a server restart
+pub const DEV_RELOAD_PING_PATH: &str = "/__rustapi_dev/boot";
+
+/// Middleware that stamps HTML responses with a live-reload script
+///
+/// Each process start gets a fresh boot id from [`DevReloadLayer::new`]. The
+/// injected script polls [`DEV_RELOAD_PING_PATH`]; when the id it gets back
+/// differs from the one baked into the page (because the server restarted),
+/// it shows a "reloading" banner and reloads the tab. This doesn't preserve
+/// in-memory application state across the restart - the process is still a
+/// clean `cargo watch` respawn - it just gives you a visible signal instead
+/// of a silently stale page. Intended for development only.
+#[derive(Clone)]
+pub struct DevReloadLayer {
+    boot_id: String,
+}
+
+impl DevReloadLayer {
+    /// Create a new layer with a freshly generated boot id
+    pub fn new() -> Self {
+        Self {
+            boot_id: generate_boot_id(),
+        }
+    }
+
+    /// The boot id served at [`DEV_RELOAD_PING_PATH`]
+    pub fn boot_id(&self) -> &str {
+        &self.boot_id
+    }
+}
+
+impl Default for DevReloadLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MiddlewareLayer for DevReloadLayer {
+    fn call(
+        &self,
+        req: Request,
+        next: BoxedNext,
+    ) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> {
+        let boot_id = self.boot_id.clone();
+
+        Box::pin(async move {
+            if req.uri().path() == DEV_RELOAD_PING_PATH {
+                return http::Response::builder()
+                    .status(http::StatusCode::OK)
+                    .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+                    .body(Full::new(Bytes::from(boot_id)))
+                    .unwrap();
+            }
+
+            let response = next(req).await;
+            inject_reload_script(response, &boot_id).await
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn MiddlewareLayer> {
+        Box::new(self.clone())
+    }
+}
+
+/// Inject the reload-polling script into an HTML response, just before `</body>`
+async fn inject_reload_script(response: Response, boot_id: &str) -> Response {
+    let is_html = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("text/html"));
+
+    if !is_html {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let body_bytes = match body.collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(_) => return http::Response::from_parts(parts, Full::new(Bytes::new())),
+    };
+
+    let mut html = String::from_utf8_lossy(&body_bytes).into_owned();
+    let script = reload_script(boot_id);
+    match html.rfind("</body>") {
+        Some(pos) => html.insert_str(pos, &script),
+        None => html.push_str(&script),
+    }
+
+    parts.headers.remove(header::CONTENT_LENGTH);
+    http::Response::from_parts(parts, Full::new(Bytes::from(html)))
+}
+
+fn reload_script(boot_id: &str) -> String {
+    format!(
+        r#"<script>
+(function() {{
+    var bootId = "{boot_id}";
+    setInterval(function() {{
+        fetch("{ping_path}").then(function(res) {{ return res.text(); }}).then(function(id) {{
+            if (id !== bootId) {{
+                var banner = document.createElement("div");
+                banner.textContent = "🔄 Server restarted, reloading...";
+                banner.style.cssText = "position:fixed;top:0;left:0;right:0;z-index:99999;padding:8px;text-align:center;background:#222;color:#fff;font-family:sans-serif;font-size:14px";
+                document.body.appendChild(banner);
+                setTimeout(function() {{ location.reload(); }}, 300);
+            }}
+        }}).catch(function() {{ /* server is restarting, keep polling */ }});
+    }}, 1000);
+}})();
+</script>
+"#,
+        boot_id = boot_id,
+        ping_path = DEV_RELOAD_PING_PATH,
+    )
+}
+
+fn generate_boot_id() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    format!("{:x}", nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::middleware::layer::LayerStack;
+    use crate::path_params::PathParams;
+    use http::{Extensions, Method};
+    use std::sync::Arc;
+
+    fn create_test_request(method: Method, path: &str) -> Request {
+        let uri: http::Uri = path.parse().unwrap();
+        let builder = http::Request::builder().method(method).uri(uri);
+        let req = builder.body(()).unwrap();
+        let (parts, _) = req.into_parts();
+
+        Request::new(
+            parts,
+            crate::request::BodyVariant::Buffered(Bytes::new()),
+            Arc::new(Extensions::new()),
+            PathParams::new(),
+        )
+    }
+
+    fn html_handler(body: &'static str) -> BoxedNext {
+        Arc::new(move |_req: Request| {
+            Box::pin(async move {
+                http::Response::builder()
+                    .status(http::StatusCode::OK)
+                    .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+                    .body(Full::new(Bytes::from(body)))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        })
+    }
+
+    #[tokio::test]
+    async fn test_dev_reload_serves_boot_id_at_ping_path() {
+        let mut stack = LayerStack::new();
+        let layer = DevReloadLayer::new();
+        let boot_id = layer.boot_id().to_string();
+        stack.push(Box::new(layer));
+
+        let handler = html_handler("<html><body></body></html>");
+        let request = create_test_request(Method::GET, DEV_RELOAD_PING_PATH);
+        let response = stack.execute(request, handler).await;
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(String::from_utf8(body.to_vec()).unwrap(), boot_id);
+    }
+
+    #[tokio::test]
+    async fn test_dev_reload_injects_script_into_html_body() {
+        let mut stack = LayerStack::new();
+        stack.push(Box::new(DevReloadLayer::new()));
+
+        let handler = html_handler("<html><body><h1>hi</h1></body></html>");
+        let request = create_test_request(Method::GET, "/");
+        let response = stack.execute(request, handler).await;
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains(DEV_RELOAD_PING_PATH));
+        assert!(html.ends_with("</body></html>"));
+    }
+
+    #[tokio::test]
+    async fn test_dev_reload_leaves_non_html_responses_untouched() {
+        let mut stack = LayerStack::new();
+        stack.push(Box::new(DevReloadLayer::new()));
+
+        let handler: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(http::StatusCode::OK)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Full::new(Bytes::from(r#"{"ok":true}"#)))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let request = create_test_request(Method::GET, "/api");
+        let response = stack.execute(request, handler).await;
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(String::from_utf8(body.to_vec()).unwrap(), r#"{"ok":true}"#);
+    }
+}
@@ -23,6 +23,10 @@ pub type BoxedMiddleware = Arc<
 pub type BoxedNext =
     Arc<dyn Fn(Request) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> + Send + Sync>;
 
+/// A compiled, sharable ordering of layers, reused across requests by
+/// [`LayerStack::execute`] instead of being rebuilt from the layer `Vec` each time.
+type CompiledLayers = Arc<[Arc<dyn MiddlewareLayer>]>;
+
 /// Trait for middleware that can be applied to RustAPI
 ///
 /// This trait allows both Tower layers and custom middleware to be used
@@ -37,6 +41,12 @@ pub trait MiddlewareLayer: Send + Sync + 'static {
 
     /// Clone this middleware into a boxed trait object
     fn clone_box(&self) -> Box<dyn MiddlewareLayer>;
+
+    /// A human-readable name for this layer, used by startup diagnostics like
+    /// `RustApi::print_startup_info`. Defaults to the layer's type name.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 
 impl Clone for Box<dyn MiddlewareLayer> {
@@ -46,29 +56,48 @@ impl Clone for Box<dyn MiddlewareLayer> {
 }
 
 /// A stack of middleware layers
+///
+/// Layers are stored behind `Arc` rather than `Box` so that dispatching a
+/// request only ever bumps a refcount, never deep-clones a layer's state
+/// (see [`LayerStack::execute`]).
 #[derive(Clone, Default)]
 pub struct LayerStack {
-    layers: Vec<Box<dyn MiddlewareLayer>>,
+    layers: Vec<Arc<dyn MiddlewareLayer>>,
+    /// The composed dispatcher for `layers`, built lazily on first use and
+    /// reused for every subsequent request instead of re-walking `layers`
+    /// and re-boxing a fresh chain each time. Invalidated by `push`/`prepend`.
+    compiled: Arc<std::sync::OnceLock<CompiledLayers>>,
 }
 
 impl LayerStack {
     /// Create a new empty layer stack
     pub fn new() -> Self {
-        Self { layers: Vec::new() }
+        Self {
+            layers: Vec::new(),
+            compiled: Arc::new(std::sync::OnceLock::new()),
+        }
     }
 
     /// Add a middleware layer to the stack
     ///
     /// Layers are executed in the order they are added (outermost first).
     pub fn push(&mut self, layer: Box<dyn MiddlewareLayer>) {
-        self.layers.push(layer);
+        self.layers.push(Arc::from(layer));
+        self.invalidate();
     }
 
     /// Add a middleware layer to the beginning of the stack
     ///
     /// This layer will be executed first (outermost).
     pub fn prepend(&mut self, layer: Box<dyn MiddlewareLayer>) {
-        self.layers.insert(0, layer);
+        self.layers.insert(0, Arc::from(layer));
+        self.invalidate();
+    }
+
+    /// Drop the cached dispatcher so the next `execute` rebuilds it from the
+    /// current `layers`.
+    fn invalidate(&mut self) {
+        self.compiled = Arc::new(std::sync::OnceLock::new());
     }
 
     /// Check if the stack is empty
@@ -81,7 +110,19 @@ impl LayerStack {
         self.layers.len()
     }
 
+    /// Names of every layer in the stack, outermost first
+    pub fn names(&self) -> Vec<&'static str> {
+        self.layers.iter().map(|layer| layer.name()).collect()
+    }
+
     /// Execute the middleware stack with a final handler
+    ///
+    /// The outermost-first ordering of `layers` is compiled into an
+    /// index-based dispatcher once per `LayerStack` (cached in `compiled`)
+    /// rather than re-walking `layers` and re-boxing a fresh chain of
+    /// closures on every call. Each layer is reached through a cheap `Arc`
+    /// clone instead of the deep `clone_box` copy the naive per-request
+    /// rebuild would require.
     pub fn execute(
         &self,
         req: Request,
@@ -91,27 +132,37 @@ impl LayerStack {
             return handler(req);
         }
 
-        // Build the chain from inside out
-        // The last layer added should be the outermost (first to execute)
-        let mut next = handler;
-
-        for layer in self.layers.iter().rev() {
-            let layer = layer.clone_box();
-            let current_next = next;
-            next = Arc::new(move |req: Request| {
-                let layer = layer.clone_box();
-                let next = current_next.clone();
-                Box::pin(async move { layer.call(req, next).await })
-                    as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        let layers = self
+            .compiled
+            .get_or_init(|| Arc::from(self.layers.clone()))
+            .clone();
+
+        dispatch(layers, 0, req, handler)
+    }
+}
+
+/// Invoke `layers[index]`, wiring its `next` continuation to dispatch the
+/// following index so the outermost layer (index 0) runs first.
+fn dispatch(
+    layers: CompiledLayers,
+    index: usize,
+    req: Request,
+    handler: BoxedNext,
+) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> {
+    match layers.get(index) {
+        Some(layer) => {
+            let layer = layer.clone();
+            let next: BoxedNext = Arc::new(move |req: Request| {
+                dispatch(layers.clone(), index + 1, req, handler.clone())
             });
+            layer.call(req, next)
         }
-
-        next(req)
+        None => handler(req),
     }
 }
 
 impl IntoIterator for LayerStack {
-    type Item = Box<dyn MiddlewareLayer>;
+    type Item = Arc<dyn MiddlewareLayer>;
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -119,9 +170,10 @@ impl IntoIterator for LayerStack {
     }
 }
 
-impl Extend<Box<dyn MiddlewareLayer>> for LayerStack {
-    fn extend<T: IntoIterator<Item = Box<dyn MiddlewareLayer>>>(&mut self, iter: T) {
+impl Extend<Arc<dyn MiddlewareLayer>> for LayerStack {
+    fn extend<T: IntoIterator<Item = Arc<dyn MiddlewareLayer>>>(&mut self, iter: T) {
         self.layers.extend(iter);
+        self.invalidate();
     }
 }
 
@@ -344,6 +396,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_layer_stack_names_defaults_to_type_name() {
+        let mut stack = LayerStack::new();
+        assert!(stack.names().is_empty());
+
+        stack.push(Box::new(OrderTrackingMiddleware::new(
+            0,
+            Arc::new(std::sync::Mutex::new(Vec::new())),
+        )));
+
+        let names = stack.names();
+        assert_eq!(names.len(), 1);
+        assert!(names[0].ends_with("OrderTrackingMiddleware"));
+    }
+
     #[test]
     fn test_empty_layer_stack_calls_handler_directly() {
         let rt = tokio::runtime::Runtime::new().unwrap();
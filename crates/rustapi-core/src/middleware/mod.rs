@@ -19,17 +19,23 @@
 mod body_limit;
 #[cfg(feature = "compression")]
 mod compression;
+mod dev_reload;
 mod layer;
 #[cfg(feature = "metrics")]
 mod metrics;
+pub mod predicate;
+mod request_context;
 mod request_id;
 mod tracing_layer;
 
 pub use body_limit::{BodyLimitLayer, DEFAULT_BODY_LIMIT};
 #[cfg(feature = "compression")]
 pub use compression::{CompressionAlgorithm, CompressionConfig, CompressionLayer};
+pub use dev_reload::{DevReloadLayer, DEV_RELOAD_PING_PATH};
 pub use layer::{BoxedNext, LayerStack, MiddlewareLayer};
+pub use predicate::{LayerWhen, RequestPredicate};
 #[cfg(feature = "metrics")]
 pub use metrics::{MetricsLayer, MetricsResponse};
+pub use request_context::{RequestContext, RequestContextLayer};
 pub use request_id::{RequestId, RequestIdLayer};
 pub use tracing_layer::TracingLayer;
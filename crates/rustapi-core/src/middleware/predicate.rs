@@ -0,0 +1,194 @@
+//! Conditional middleware execution
+//!
+//! [`LayerWhen`] runs an inner [`MiddlewareLayer`] only when a
+//! [`RequestPredicate`] matches, and falls through to `next` untouched
+//! otherwise. This replaces the bespoke "skip these paths" checks that used
+//! to be hand-rolled inside individual layers (see `JwtLayer::skip_paths` /
+//! `LoggingConfig::skip_paths` in `rustapi-extras`) with one composable
+//! mechanism any layer can opt into from the outside.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rustapi_core::middleware::{LayerWhen, RequestIdLayer, predicate};
+//!
+//! RustApi::new()
+//!     .layer(LayerWhen::new(
+//!         predicate::not(predicate::path_prefix("/health")),
+//!         RequestIdLayer::new(),
+//!     ))
+//!     .route("/", get(handler))
+//!     .run("127.0.0.1:8080")
+//!     .await
+//! ```
+
+use super::layer::{BoxedNext, MiddlewareLayer};
+use crate::request::Request;
+use crate::response::Response;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// A predicate over an incoming [`Request`], used by [`LayerWhen`] to decide
+/// whether to run its inner layer.
+pub type RequestPredicate = Arc<dyn Fn(&Request) -> bool + Send + Sync>;
+
+/// Match requests whose path starts with `prefix`.
+pub fn path_prefix(prefix: impl Into<String>) -> RequestPredicate {
+    let prefix = prefix.into();
+    Arc::new(move |req: &Request| req.uri().path().starts_with(&prefix))
+}
+
+/// Match requests with the given HTTP method.
+pub fn method(method: http::Method) -> RequestPredicate {
+    Arc::new(move |req: &Request| req.method() == method)
+}
+
+/// Match requests carrying a header named `name`, regardless of its value.
+pub fn has_header(name: http::header::HeaderName) -> RequestPredicate {
+    Arc::new(move |req: &Request| req.headers().contains_key(&name))
+}
+
+/// Negate a predicate.
+pub fn not(predicate: RequestPredicate) -> RequestPredicate {
+    Arc::new(move |req: &Request| !predicate(req))
+}
+
+/// Match a request when either predicate matches.
+pub fn any_of(a: RequestPredicate, b: RequestPredicate) -> RequestPredicate {
+    Arc::new(move |req: &Request| a(req) || b(req))
+}
+
+/// Match a request when both predicates match.
+pub fn all_of(a: RequestPredicate, b: RequestPredicate) -> RequestPredicate {
+    Arc::new(move |req: &Request| a(req) && b(req))
+}
+
+/// Runs `layer` only when `predicate` matches the request, otherwise calls
+/// `next` directly, skipping `layer` entirely.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_core::middleware::{LayerWhen, TracingLayer, predicate};
+///
+/// let layer = LayerWhen::new(predicate::path_prefix("/api"), TracingLayer::new());
+/// ```
+#[derive(Clone)]
+pub struct LayerWhen<L> {
+    predicate: RequestPredicate,
+    layer: L,
+}
+
+impl<L> LayerWhen<L> {
+    /// Wrap `layer` so it only runs when `predicate` matches the request.
+    pub fn new(predicate: RequestPredicate, layer: L) -> Self {
+        Self { predicate, layer }
+    }
+}
+
+impl<L: MiddlewareLayer + Clone> MiddlewareLayer for LayerWhen<L> {
+    fn call(
+        &self,
+        req: Request,
+        next: BoxedNext,
+    ) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> {
+        if (self.predicate)(&req) {
+            self.layer.call(req, next)
+        } else {
+            next(req)
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn MiddlewareLayer> {
+        Box::new(self.clone())
+    }
+
+    fn name(&self) -> &'static str {
+        self.layer.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::path_params::PathParams;
+    use bytes::Bytes;
+    use http::{Extensions, Method, StatusCode};
+
+    fn get_request(path: &str) -> Request {
+        let uri: http::Uri = path.parse().unwrap();
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .body(())
+            .unwrap();
+        let (parts, _) = req.into_parts();
+        Request::new(
+            parts,
+            crate::request::BodyVariant::Buffered(Bytes::new()),
+            Arc::new(Extensions::new()),
+            PathParams::new(),
+        )
+    }
+
+    #[derive(Clone)]
+    struct StampingLayer;
+
+    impl MiddlewareLayer for StampingLayer {
+        fn call(
+            &self,
+            _req: Request,
+            _next: BoxedNext,
+        ) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> {
+            Box::pin(async move {
+                http::Response::builder()
+                    .status(StatusCode::IM_A_TEAPOT)
+                    .body(http_body_util::Full::new(Bytes::new()))
+                    .unwrap()
+            })
+        }
+
+        fn clone_box(&self) -> Box<dyn MiddlewareLayer> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn passthrough_next() -> BoxedNext {
+        Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(StatusCode::OK)
+                    .body(http_body_util::Full::new(Bytes::new()))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        })
+    }
+
+    #[tokio::test]
+    async fn runs_layer_when_predicate_matches() {
+        let layer = LayerWhen::new(path_prefix("/api"), StampingLayer);
+        let response = layer.call(get_request("/api/users"), passthrough_next()).await;
+        assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+    }
+
+    #[tokio::test]
+    async fn skips_layer_when_predicate_does_not_match() {
+        let layer = LayerWhen::new(path_prefix("/api"), StampingLayer);
+        let response = layer.call(get_request("/health"), passthrough_next()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn not_inverts_a_predicate() {
+        let layer = LayerWhen::new(not(path_prefix("/health")), StampingLayer);
+        assert_eq!(
+            layer.call(get_request("/health"), passthrough_next()).await.status(),
+            StatusCode::OK
+        );
+        assert_eq!(
+            layer.call(get_request("/api"), passthrough_next()).await.status(),
+            StatusCode::IM_A_TEAPOT
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Request context propagation into spawned tasks
+//!
+//! Carries request-scoped identity (request id, trace id, deadline, auth
+//! identity) through a task-local, so logs and downstream calls issued from
+//! background work spawned by a handler stay correlated with the request
+//! that started it.
+
+use super::layer::{BoxedNext, MiddlewareLayer};
+use super::request_id::RequestId;
+use crate::error::{ApiError, Result};
+use crate::extract::FromRequestParts;
+use crate::request::Request;
+use crate::response::Response;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Instant;
+use tokio::task::JoinHandle;
+
+tokio::task_local! {
+    static CURRENT: RequestContext;
+}
+
+/// Request-scoped identity, available via the [`RequestContext`] extractor
+/// or [`RequestContext::current`] anywhere on the task that's handling the
+/// request (including tasks spawned with [`RequestContext::spawn`])
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    request_id: String,
+    trace_id: Option<String>,
+    deadline: Option<Instant>,
+    auth_identity: Option<String>,
+}
+
+impl RequestContext {
+    /// Create a new context for a request id
+    pub fn new(request_id: impl Into<String>) -> Self {
+        Self {
+            request_id: request_id.into(),
+            trace_id: None,
+            deadline: None,
+            auth_identity: None,
+        }
+    }
+
+    /// Attach a distributed trace id (e.g. from a `traceparent` header)
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Attach a deadline by which the request must be served
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Attach the authenticated identity for the request (e.g. a subject claim)
+    pub fn with_auth_identity(mut self, identity: impl Into<String>) -> Self {
+        self.auth_identity = Some(identity.into());
+        self
+    }
+
+    /// The originating request's id
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    /// The distributed trace id, if one was propagated
+    pub fn trace_id(&self) -> Option<&str> {
+        self.trace_id.as_deref()
+    }
+
+    /// The deadline by which the request must be served, if any
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// The authenticated identity for the request, if any
+    pub fn auth_identity(&self) -> Option<&str> {
+        self.auth_identity.as_deref()
+    }
+
+    /// The context of the request currently executing on this task, if any
+    ///
+    /// Returns `None` outside of a request handled by [`RequestContextLayer`],
+    /// or inside a spawned task that wasn't started via [`RequestContext::spawn`].
+    pub fn current() -> Option<RequestContext> {
+        CURRENT.try_with(|ctx| ctx.clone()).ok()
+    }
+
+    /// Spawn a task that inherits this context
+    ///
+    /// Background work started this way sees the same [`RequestContext::current`]
+    /// as the handler that spawned it, so its logs and downstream calls remain
+    /// correlated with the originating request even after the response has
+    /// already been sent.
+    pub fn spawn<F>(&self, fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let ctx = self.clone();
+        tokio::spawn(CURRENT.scope(ctx, fut))
+    }
+}
+
+/// Extractor for the current request's [`RequestContext`]
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_core::middleware::RequestContext;
+///
+/// async fn handler(ctx: RequestContext) -> impl IntoResponse {
+///     ctx.spawn(async move {
+///         // still sees `RequestContext::current()` inside here
+///         do_background_work().await;
+///     });
+///     "ok"
+/// }
+/// ```
+impl FromRequestParts for RequestContext {
+    fn from_request_parts(req: &Request) -> Result<Self> {
+        req.extensions().get::<RequestContext>().cloned().ok_or_else(|| {
+            ApiError::internal(
+                "RequestContext not found. Did you forget to add RequestContextLayer middleware?",
+            )
+        })
+    }
+}
+
+/// Middleware that builds a [`RequestContext`] for each request
+///
+/// The context is made available two ways: through the [`RequestContext`]
+/// extractor, and ambiently via [`RequestContext::current`] for the duration
+/// of the request (and any task spawned from it with
+/// [`RequestContext::spawn`]). Reuses the id from `RequestIdLayer` when it
+/// runs first, otherwise generates its own.
+#[derive(Clone, Default)]
+pub struct RequestContextLayer;
+
+impl RequestContextLayer {
+    /// Create a new RequestContextLayer
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl MiddlewareLayer for RequestContextLayer {
+    fn call(
+        &self,
+        mut req: Request,
+        next: BoxedNext,
+    ) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> {
+        Box::pin(async move {
+            let request_id = req
+                .extensions()
+                .get::<RequestId>()
+                .map(|id| id.as_str().to_string())
+                .unwrap_or_else(|| RequestId::new().to_string());
+
+            let ctx = RequestContext::new(request_id);
+            req.extensions_mut().insert(ctx.clone());
+
+            CURRENT.scope(ctx, next(req)).await
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn MiddlewareLayer> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::middleware::layer::LayerStack;
+    use crate::middleware::request_id::RequestIdLayer;
+    use crate::path_params::PathParams;
+    use bytes::Bytes;
+    use http::{Extensions, Method, StatusCode};
+    use std::sync::Arc;
+
+    fn create_test_request(method: Method, path: &str) -> Request {
+        let uri: http::Uri = path.parse().unwrap();
+        let builder = http::Request::builder().method(method).uri(uri);
+        let req = builder.body(()).unwrap();
+        let (parts, _) = req.into_parts();
+
+        Request::new(
+            parts,
+            crate::request::BodyVariant::Buffered(Bytes::new()),
+            Arc::new(Extensions::new()),
+            PathParams::new(),
+        )
+    }
+
+    fn ok_response() -> Response {
+        http::Response::builder()
+            .status(StatusCode::OK)
+            .body(http_body_util::Full::new(Bytes::from("ok")))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_request_context_extractor() {
+        let mut stack = LayerStack::new();
+        stack.push(Box::new(RequestContextLayer::new()));
+
+        let handler: BoxedNext = Arc::new(|req: Request| {
+            Box::pin(async move {
+                let ctx = RequestContext::from_request_parts(&req).unwrap();
+                assert!(!ctx.request_id().is_empty());
+                ok_response()
+            })
+        });
+
+        let request = create_test_request(Method::GET, "/test");
+        let response = stack.execute(request, handler).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_request_context_reuses_request_id_layer() {
+        let mut stack = LayerStack::new();
+        stack.push(Box::new(RequestIdLayer::new()));
+        stack.push(Box::new(RequestContextLayer::new()));
+
+        let handler: BoxedNext = Arc::new(|req: Request| {
+            Box::pin(async move {
+                let request_id = req.extensions().get::<RequestId>().unwrap().clone();
+                let ctx = RequestContext::from_request_parts(&req).unwrap();
+                assert_eq!(ctx.request_id(), request_id.as_str());
+                ok_response()
+            })
+        });
+
+        let request = create_test_request(Method::GET, "/test");
+        let response = stack.execute(request, handler).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_request_context_current_visible_in_spawned_task() {
+        let mut stack = LayerStack::new();
+        stack.push(Box::new(RequestContextLayer::new()));
+
+        let handler: BoxedNext = Arc::new(|req: Request| {
+            Box::pin(async move {
+                let ctx = RequestContext::from_request_parts(&req).unwrap();
+                let expected_id = ctx.request_id().to_string();
+
+                let handle = ctx.spawn(async move {
+                    RequestContext::current().map(|c| c.request_id().to_string())
+                });
+                let propagated = handle.await.unwrap();
+                assert_eq!(propagated, Some(expected_id));
+
+                ok_response()
+            })
+        });
+
+        let request = create_test_request(Method::GET, "/test");
+        let response = stack.execute(request, handler).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_request_context_builder() {
+        let deadline = Instant::now();
+        let ctx = RequestContext::new("req-1")
+            .with_trace_id("trace-1")
+            .with_deadline(deadline)
+            .with_auth_identity("user-1");
+
+        assert_eq!(ctx.request_id(), "req-1");
+        assert_eq!(ctx.trace_id(), Some("trace-1"));
+        assert_eq!(ctx.deadline(), Some(deadline));
+        assert_eq!(ctx.auth_identity(), Some("user-1"));
+    }
+
+    #[test]
+    fn test_request_context_current_outside_request_is_none() {
+        assert!(RequestContext::current().is_none());
+    }
+}
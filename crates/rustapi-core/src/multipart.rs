@@ -199,6 +199,23 @@ fn sanitize_filename(filename: &str) -> String {
         .to_string()
 }
 
+impl rustapi_openapi::OperationModifier for Multipart {
+    fn update_operation(op: &mut rustapi_openapi::Operation) {
+        let mut content = std::collections::HashMap::new();
+        content.insert(
+            "multipart/form-data".to_string(),
+            rustapi_openapi::MediaType {
+                schema: rustapi_openapi::SchemaRef::Inline(serde_json::json!({ "type": "object" })),
+            },
+        );
+
+        op.request_body = Some(rustapi_openapi::RequestBody {
+            required: true,
+            content,
+        });
+    }
+}
+
 impl FromRequest for Multipart {
     async fn from_request(req: &mut Request) -> Result<Self> {
         // Check content type
@@ -0,0 +1,307 @@
+//! Reverse proxy handler for forwarding requests to an upstream service
+//!
+//! [`RustApi::proxy`](crate::RustApi::proxy) mounts a catch-all route under a
+//! prefix that streams every request through to an upstream URL, rewriting
+//! `Host`/`X-Forwarded-*` headers along the way. This is meant for
+//! strangler-fig migrations: keep the old service behind a path prefix while
+//! new routes are implemented natively.
+//!
+//! Request bodies are streamed to the upstream without buffering. Response
+//! bodies are buffered before being sent back to the client, since
+//! [`Response`](crate::Response) is `http::Response<Full<Bytes>>` and doesn't
+//! support streaming bodies yet (see [`crate::stream::StreamBody`], which has
+//! the same limitation).
+
+use crate::error::ApiError;
+use crate::extract::ClientIp;
+use crate::request::Request;
+use crate::response::{IntoResponse, Response};
+use bytes::Bytes;
+use http::{header, HeaderName, HeaderValue, StatusCode, Uri};
+use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Full};
+use hyper_util::client::legacy::{connect::HttpConnector, Client};
+use hyper_util::rt::TokioExecutor;
+
+type ProxyBody = BoxBody<Bytes, Box<dyn std::error::Error + Send + Sync>>;
+type ProxyClient = Client<HttpConnector, ProxyBody>;
+
+/// Configuration for a [`RustApi::proxy`](crate::RustApi::proxy) route.
+#[derive(Clone)]
+pub struct ProxyConfig {
+    pub(crate) prefix: String,
+    pub(crate) upstream: Uri,
+    pub(crate) preserve_host: bool,
+    pub(crate) trust_proxy: bool,
+}
+
+impl ProxyConfig {
+    /// Proxy requests under `prefix` to `upstream` (e.g. `"http://old-service"`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `upstream` is not a valid absolute URI. Construct the config
+    /// at startup so this fails fast instead of on the first request.
+    pub fn new(prefix: impl Into<String>, upstream: impl AsRef<str>) -> Self {
+        let upstream = upstream
+            .as_ref()
+            .parse::<Uri>()
+            .expect("proxy upstream must be a valid URI");
+        Self {
+            prefix: prefix.into(),
+            upstream,
+            preserve_host: false,
+            trust_proxy: false,
+        }
+    }
+
+    /// Forward the client's original `Host` header instead of rewriting it to
+    /// the upstream's authority. Off by default, matching how most reverse
+    /// proxies (nginx, Caddy) behave out of the box.
+    pub fn preserve_host(mut self) -> Self {
+        self.preserve_host = true;
+        self
+    }
+
+    /// Derive the `X-Forwarded-For` header sent upstream from the *inbound*
+    /// `X-Forwarded-For` header the client sent, instead of this instance's
+    /// actual peer address.
+    ///
+    /// Off by default: this proxy is often the edge-facing hop (see the
+    /// module docs), so trusting an inbound `X-Forwarded-For` by default
+    /// would let any external client forge the IP handed to the upstream,
+    /// defeating IP-based rate limiting/abuse detection/geo-blocking there.
+    /// Only enable this when there's a trusted load balancer or another
+    /// proxy you control in front of this instance.
+    pub fn trust_proxy(mut self) -> Self {
+        self.trust_proxy = true;
+        self
+    }
+}
+
+fn full_body(bytes: Bytes) -> ProxyBody {
+    Full::new(bytes)
+        .map_err(|never| match never {})
+        .boxed()
+}
+
+/// Reverse proxy handler backing a single [`ProxyConfig`].
+pub(crate) struct Proxy {
+    config: ProxyConfig,
+    client: ProxyClient,
+}
+
+impl Proxy {
+    pub(crate) fn new(config: ProxyConfig) -> Self {
+        Self {
+            config,
+            client: Client::builder(TokioExecutor::new()).build(HttpConnector::new()),
+        }
+    }
+
+    fn target_uri(&self, req: &Request) -> Result<Uri, ApiError> {
+        let relative = req
+            .path()
+            .strip_prefix(self.config.prefix.trim_end_matches('/'))
+            .unwrap_or("/");
+        let relative = if relative.is_empty() { "/" } else { relative };
+
+        let mut target = self.config.upstream.to_string();
+        target.truncate(target.trim_end_matches('/').len());
+        target.push_str(relative);
+        if let Some(query) = req.query_string() {
+            target.push('?');
+            target.push_str(query);
+        }
+
+        target
+            .parse()
+            .map_err(|err| ApiError::bad_gateway(format!("invalid proxy target: {err}")))
+    }
+
+    fn forwarded_headers(&self, req: &Request) -> http::HeaderMap {
+        let mut headers = req.headers().clone();
+
+        if !self.config.preserve_host {
+            if let Some(authority) = self.config.upstream.authority() {
+                if let Ok(value) = HeaderValue::from_str(authority.as_str()) {
+                    headers.insert(header::HOST, value);
+                }
+            }
+        }
+
+        if let Some(authority) = req.uri().authority() {
+            if let Ok(value) = HeaderValue::from_str(authority.host()) {
+                headers.insert(HeaderName::from_static("x-forwarded-host"), value);
+            }
+        }
+
+        let scheme = req.uri().scheme_str().unwrap_or("http");
+        if let Ok(value) = HeaderValue::from_str(scheme) {
+            headers.insert(HeaderName::from_static("x-forwarded-proto"), value);
+        }
+
+        if let Ok(ClientIp(ip)) = ClientIp::extract_with_config(req, self.config.trust_proxy) {
+            if let Ok(value) = HeaderValue::from_str(&ip.to_string()) {
+                headers.insert(HeaderName::from_static("x-forwarded-for"), value);
+            }
+        }
+
+        headers
+    }
+
+    pub(crate) async fn handle(&self, mut req: Request) -> Response {
+        let target = match self.target_uri(&req) {
+            Ok(uri) => uri,
+            Err(err) => return err.into_response(),
+        };
+        let method = req.method().clone();
+        let headers = self.forwarded_headers(&req);
+
+        let body: ProxyBody = if let Some(stream) = req.take_stream() {
+            stream
+                .map_err(|err| Box::new(err) as Box<dyn std::error::Error + Send + Sync>)
+                .boxed()
+        } else {
+            full_body(req.take_body().unwrap_or_default())
+        };
+
+        let mut builder = hyper::Request::builder().method(method).uri(target);
+        if let Some(header_map) = builder.headers_mut() {
+            *header_map = headers;
+        }
+        let upstream_req = match builder.body(body) {
+            Ok(req) => req,
+            Err(err) => return ApiError::bad_gateway(err.to_string()).into_response(),
+        };
+
+        let upstream_res = match self.client.request(upstream_req).await {
+            Ok(res) => res,
+            Err(err) => {
+                return ApiError::bad_gateway(format!("upstream request failed: {err}"))
+                    .into_response()
+            }
+        };
+
+        let (parts, body) = upstream_res.into_parts();
+        let body = match body.collect().await {
+            Ok(collected) => collected.to_bytes(),
+            Err(err) => {
+                return ApiError::bad_gateway(format!("upstream response failed: {err}"))
+                    .into_response()
+            }
+        };
+
+        let mut response = http::Response::builder().status(parts.status);
+        if let Some(header_map) = response.headers_mut() {
+            *header_map = parts.headers;
+            header_map.remove(header::TRANSFER_ENCODING);
+            header_map.remove(header::CONNECTION);
+        }
+        response
+            .body(Full::new(body))
+            .unwrap_or_else(|_| StatusCode::BAD_GATEWAY.into_response())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    fn request(method: &str, uri: &str) -> Request {
+        Request::from_http_request(
+            http::Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(http::header::HOST, "client.example.com")
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        )
+    }
+
+    #[test]
+    fn target_uri_strips_prefix_and_keeps_query() {
+        let proxy = Proxy::new(ProxyConfig::new("/legacy", "http://old-service:8000"));
+        let req = request("GET", "/legacy/users/42?active=true");
+        let target = proxy.target_uri(&req).unwrap();
+        assert_eq!(target.to_string(), "http://old-service:8000/users/42?active=true");
+    }
+
+    #[test]
+    fn target_uri_falls_back_to_root() {
+        let proxy = Proxy::new(ProxyConfig::new("/legacy", "http://old-service:8000"));
+        let req = request("GET", "/legacy");
+        let target = proxy.target_uri(&req).unwrap();
+        assert_eq!(target.to_string(), "http://old-service:8000/");
+    }
+
+    #[test]
+    fn forwarded_headers_rewrite_host_by_default() {
+        let proxy = Proxy::new(ProxyConfig::new("/legacy", "http://old-service:8000"));
+        let req = request("GET", "/legacy/ping");
+        let headers = proxy.forwarded_headers(&req);
+        assert_eq!(headers.get(header::HOST).unwrap(), "old-service:8000");
+        assert_eq!(headers.get("x-forwarded-proto").unwrap(), "http");
+    }
+
+    #[test]
+    fn forwarded_headers_preserve_host_when_configured() {
+        let proxy = Proxy::new(
+            ProxyConfig::new("/legacy", "http://old-service:8000").preserve_host(),
+        );
+        let req = request("GET", "/legacy/ping");
+        let headers = proxy.forwarded_headers(&req);
+        assert_eq!(headers.get(header::HOST).unwrap(), "client.example.com");
+    }
+
+    #[test]
+    fn forwarded_headers_sets_x_forwarded_for_from_socket_extension() {
+        let proxy = Proxy::new(ProxyConfig::new("/legacy", "http://old-service:8000"));
+        let mut req = request("GET", "/legacy/ping");
+        req.extensions_mut()
+            .insert(std::net::SocketAddr::from(([203, 0, 113, 7], 12345)));
+        let headers = proxy.forwarded_headers(&req);
+        assert_eq!(headers.get("x-forwarded-for").unwrap(), "203.0.113.7");
+    }
+
+    fn request_with_forwarded_for(uri: &str, forwarded_for: &str) -> Request {
+        Request::from_http_request(
+            http::Request::builder()
+                .method("GET")
+                .uri(uri)
+                .header(http::header::HOST, "client.example.com")
+                .header("x-forwarded-for", forwarded_for)
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        )
+    }
+
+    #[test]
+    fn forwarded_headers_ignores_inbound_x_forwarded_for_by_default() {
+        // Without trust_proxy(), a client-forged X-Forwarded-For must never
+        // reach the upstream -- only the actual peer address should.
+        let proxy = Proxy::new(ProxyConfig::new("/legacy", "http://old-service:8000"));
+        let mut req = request_with_forwarded_for("/legacy/ping", "6.6.6.6");
+        req.extensions_mut()
+            .insert(std::net::SocketAddr::from(([203, 0, 113, 7], 12345)));
+
+        let headers = proxy.forwarded_headers(&req);
+        assert_eq!(headers.get("x-forwarded-for").unwrap(), "203.0.113.7");
+    }
+
+    #[test]
+    fn forwarded_headers_trusts_inbound_x_forwarded_for_when_configured() {
+        let proxy = Proxy::new(
+            ProxyConfig::new("/legacy", "http://old-service:8000").trust_proxy(),
+        );
+        let mut req = request_with_forwarded_for("/legacy/ping", "6.6.6.6");
+        req.extensions_mut()
+            .insert(std::net::SocketAddr::from(([203, 0, 113, 7], 12345)));
+
+        let headers = proxy.forwarded_headers(&req);
+        assert_eq!(headers.get("x-forwarded-for").unwrap(), "6.6.6.6");
+    }
+}
@@ -44,6 +44,7 @@ use bytes::Bytes;
 use http::{request::Parts, Extensions, HeaderMap, Method, Uri, Version};
 use http_body_util::BodyExt;
 use hyper::body::Incoming;
+use std::cell::RefCell;
 use std::sync::Arc;
 
 /// Internal representation of the request body state
@@ -60,7 +61,11 @@ pub struct Request {
     pub(crate) parts: Parts,
     pub(crate) body: BodyVariant,
     pub(crate) state: Arc<Extensions>,
+    pub(crate) local_state: Option<Arc<Extensions>>,
     pub(crate) path_params: PathParams,
+    /// Memoized `FromRequestParts` results, keyed by type (see `cached_extract`)
+    extractor_cache: RefCell<Extensions>,
+    extractor_cache_enabled: bool,
 }
 
 impl Request {
@@ -75,10 +80,21 @@ impl Request {
             parts,
             body,
             state,
+            local_state: None,
             path_params,
+            extractor_cache: RefCell::new(Extensions::new()),
+            extractor_cache_enabled: true,
         }
     }
 
+    /// Attach a nested router's own state, checked before the app-wide state
+    /// by [`State`](crate::extract::State) extractors for routes mounted
+    /// under that router. See [`Router::nest`](crate::Router::nest).
+    pub(crate) fn with_local_state(mut self, local_state: Option<Arc<Extensions>>) -> Self {
+        self.local_state = local_state;
+        self
+    }
+
     /// Get the HTTP method
     pub fn method(&self) -> &Method {
         &self.parts.method
@@ -109,6 +125,62 @@ impl Request {
         &mut self.parts.extensions
     }
 
+    /// Store a request-scoped typed value, readable later via the [`Local`](crate::extract::Local) extractor
+    ///
+    /// This is the formalized way for middleware to hand a typed value to
+    /// downstream handlers for the current request only, distinct from the
+    /// app-wide values reachable via `State<T>`. `JwtLayer` uses this to
+    /// publish `ValidatedClaims` for the `AuthUser<T>` extractor to read.
+    pub fn set_local<T: Clone + Send + Sync + 'static>(&mut self, value: T) {
+        self.parts.extensions.insert(value);
+    }
+
+    /// Run `compute` at most once per request, returning the cached clone on
+    /// later calls.
+    ///
+    /// This is for `FromRequestParts` impls whose parsing isn't free (e.g.
+    /// [`Cookies`](crate::extract::Cookies) walking the `Cookie` header) and
+    /// that may be invoked more than once for the same request - once from
+    /// middleware or a guard, again from the handler's own extractor. Errors
+    /// are not cached, so a failing extraction is retried on the next call.
+    ///
+    /// Disabled per-request via `disable_extractor_cache`, in which case
+    /// `compute` always runs.
+    ///
+    /// Only used by extractors behind optional features (e.g. `cookies`)
+    /// today, so it's unused - and would otherwise warn - when none of them
+    /// are enabled.
+    #[allow(dead_code)]
+    pub(crate) fn cached_extract<T, F>(&self, compute: F) -> crate::error::Result<T>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: FnOnce() -> crate::error::Result<T>,
+    {
+        if self.extractor_cache_enabled {
+            if let Some(value) = self.extractor_cache.borrow().get::<T>() {
+                return Ok(value.clone());
+            }
+        }
+
+        let value = compute()?;
+
+        if self.extractor_cache_enabled {
+            self.extractor_cache.borrow_mut().insert(value.clone());
+        }
+
+        Ok(value)
+    }
+
+    /// Opt out of `cached_extract` memoization for the rest of this request.
+    ///
+    /// Use this when a middleware rewrites data an earlier-cached extractor
+    /// depends on (e.g. replacing the `Cookie` header) and later extractor
+    /// calls must see the fresh value rather than a stale cached one.
+    pub fn disable_extractor_cache(&mut self) {
+        self.extractor_cache_enabled = false;
+        self.extractor_cache.borrow_mut().clear();
+    }
+
     /// Get the request path
     pub fn path(&self) -> &str {
         self.parts.uri.path()
@@ -166,6 +238,45 @@ impl Request {
         Ok(())
     }
 
+    /// Like [`load_body`](Self::load_body), but bails out with a 413 instead
+    /// of buffering more than `limit` bytes.
+    ///
+    /// Unlike [`BodyLimitLayer`](crate::middleware::BodyLimitLayer), which
+    /// only rejects based on a present `Content-Length` header or an
+    /// already-buffered body, this actually stops reading a chunked/streamed
+    /// body once `limit` is exceeded -- for handlers (like a CSP report
+    /// collector) that read their own body outside of that middleware and
+    /// can't otherwise be sure the client sent a `Content-Length`.
+    pub async fn load_body_limited(&mut self, limit: usize) -> Result<(), crate::error::ApiError> {
+        let new_body = match std::mem::replace(&mut self.body, BodyVariant::Consumed) {
+            BodyVariant::Streaming(incoming) => {
+                let collected = http_body_util::Limited::new(incoming, limit)
+                    .collect()
+                    .await
+                    .map_err(|_| {
+                        crate::error::ApiError::new(
+                            http::StatusCode::PAYLOAD_TOO_LARGE,
+                            "payload_too_large",
+                            format!("Request body exceeds limit of {limit} bytes"),
+                        )
+                    })?;
+                BodyVariant::Buffered(collected.to_bytes())
+            }
+            BodyVariant::Buffered(b) if b.len() > limit => {
+                self.body = BodyVariant::Buffered(b);
+                return Err(crate::error::ApiError::new(
+                    http::StatusCode::PAYLOAD_TOO_LARGE,
+                    "payload_too_large",
+                    format!("Request body exceeds limit of {limit} bytes"),
+                ));
+            }
+            BodyVariant::Buffered(b) => BodyVariant::Buffered(b),
+            BodyVariant::Consumed => BodyVariant::Consumed,
+        };
+        self.body = new_body;
+        Ok(())
+    }
+
     /// Get path parameters
     pub fn path_params(&self) -> &PathParams {
         &self.path_params
@@ -181,6 +292,13 @@ impl Request {
         &self.state
     }
 
+    /// Get the nested router's own state, if the matched route was mounted
+    /// via [`Router::nest`](crate::Router::nest) under a router that had its
+    /// own state.
+    pub(crate) fn local_state(&self) -> Option<&Arc<Extensions>> {
+        self.local_state.as_ref()
+    }
+
     /// Create a test request from an http::Request
     ///
     /// This is useful for testing middleware and extractors.
@@ -191,7 +309,10 @@ impl Request {
             parts,
             body: BodyVariant::Buffered(body),
             state: Arc::new(Extensions::new()),
+            local_state: None,
             path_params: PathParams::new(),
+            extractor_cache: RefCell::new(Extensions::new()),
+            extractor_cache_enabled: true,
         }
     }
     /// Try to clone the request.
@@ -223,7 +344,10 @@ impl Request {
             parts,
             body: new_body,
             state: self.state.clone(),
+            local_state: self.local_state.clone(),
             path_params: self.path_params.clone(),
+            extractor_cache: RefCell::new(Extensions::new()),
+            extractor_cache_enabled: self.extractor_cache_enabled,
         })
     }
 }
@@ -237,3 +361,93 @@ impl std::fmt::Debug for Request {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn test_request() -> Request {
+        let req = http::Request::builder()
+            .method(Method::GET)
+            .uri("/test")
+            .body(())
+            .unwrap();
+        let (parts, _) = req.into_parts();
+        Request::new(
+            parts,
+            BodyVariant::Buffered(Bytes::new()),
+            Arc::new(Extensions::new()),
+            PathParams::new(),
+        )
+    }
+
+    #[test]
+    fn cached_extract_runs_compute_once_per_type() {
+        let request = test_request();
+        let calls = Rc::new(Cell::new(0));
+
+        for _ in 0..3 {
+            let calls = calls.clone();
+            let value = request
+                .cached_extract(|| {
+                    calls.set(calls.get() + 1);
+                    Ok(42u32)
+                })
+                .unwrap();
+            assert_eq!(value, 42);
+        }
+
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn cached_extract_does_not_cache_errors() {
+        let request = test_request();
+        let calls = Cell::new(0);
+
+        let first = request.cached_extract::<u32, _>(|| {
+            calls.set(calls.get() + 1);
+            Err(crate::error::ApiError::bad_request("nope"))
+        });
+        assert!(first.is_err());
+
+        let second = request.cached_extract(|| {
+            calls.set(calls.get() + 1);
+            Ok(7u32)
+        });
+        assert_eq!(second.unwrap(), 7);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn disable_extractor_cache_forces_recompute() {
+        let mut request = test_request();
+        let calls = Rc::new(Cell::new(0));
+
+        {
+            let calls = calls.clone();
+            request
+                .cached_extract(|| {
+                    calls.set(calls.get() + 1);
+                    Ok(1u32)
+                })
+                .unwrap();
+        }
+        assert_eq!(calls.get(), 1);
+
+        request.disable_extractor_cache();
+
+        for _ in 0..2 {
+            let calls = calls.clone();
+            request
+                .cached_extract(|| {
+                    calls.set(calls.get() + 1);
+                    Ok(1u32)
+                })
+                .unwrap();
+        }
+        assert_eq!(calls.get(), 3);
+    }
+}
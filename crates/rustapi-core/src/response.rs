@@ -14,6 +14,7 @@
 //! | [`NoContent`] | 204 | - | No content response |
 //! | [`Html<T>`] | 200 | text/html | HTML response |
 //! | [`Redirect`] | 3xx | - | HTTP redirect |
+//! | [`Preload<T>`] | (inherits `T`) | (inherits `T`) | Adds `Link: rel=preload` hints |
 //! | [`WithStatus<T, N>`] | N | varies | Custom status code |
 //! | [`ApiError`] | varies | application/json | Error response |
 //!
@@ -412,6 +413,127 @@ impl ResponseModifier for Redirect {
     }
 }
 
+/// A single resource hint for a `Link` preload header.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_core::response::PreloadHint;
+///
+/// let hint = PreloadHint::preload("/static/app.css", "style");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreloadHint {
+    href: String,
+    rel: &'static str,
+    as_type: Option<String>,
+}
+
+impl PreloadHint {
+    /// A `rel=preload` hint for the resource at `href`, fetched as `as_type`
+    /// (e.g. `"style"`, `"script"`, `"font"`, `"image"`).
+    pub fn preload(href: impl Into<String>, as_type: impl Into<String>) -> Self {
+        Self {
+            href: href.into(),
+            rel: "preload",
+            as_type: Some(as_type.into()),
+        }
+    }
+
+    /// A `rel=modulepreload` hint for an ES module at `href`.
+    pub fn module_preload(href: impl Into<String>) -> Self {
+        Self {
+            href: href.into(),
+            rel: "modulepreload",
+            as_type: None,
+        }
+    }
+
+    /// A `rel=preconnect` hint for an origin the page is about to fetch from.
+    pub fn preconnect(href: impl Into<String>) -> Self {
+        Self {
+            href: href.into(),
+            rel: "preconnect",
+            as_type: None,
+        }
+    }
+
+    /// Render this hint as a `Link` header value.
+    pub fn header_value(&self) -> HeaderValue {
+        let value = match &self.as_type {
+            Some(as_type) => format!("<{}>; rel={}; as={}", self.href, self.rel, as_type),
+            None => format!("<{}>; rel={}", self.href, self.rel),
+        };
+        HeaderValue::from_str(&value).expect("Invalid preload hint href")
+    }
+}
+
+/// Wraps a response with one or more `Link` preload/preconnect hints.
+///
+/// This crate's HTTP server hands each request exactly one response, so it
+/// cannot emit a genuine `103 Early Hints` interim response ahead of the
+/// final one. Attaching the same `Link: rel=preload` headers to the final
+/// response is the standard fallback: browsers that support Early Hints
+/// treat these headers identically when they arrive on the real response,
+/// just without the head start of an earlier round trip.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_core::response::{Preload, PreloadHint};
+///
+/// async fn page() -> Preload<Html<String>> {
+///     Preload::new(Html("<h1>Hi</h1>".to_string()))
+///         .hint(PreloadHint::preload("/static/app.css", "style"))
+///         .hint(PreloadHint::preload("/static/app.js", "script"))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Preload<T> {
+    inner: T,
+    hints: Vec<PreloadHint>,
+}
+
+impl<T> Preload<T> {
+    /// Wrap `inner`, initially with no preload hints.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            hints: Vec::new(),
+        }
+    }
+
+    /// Add a preload hint.
+    pub fn hint(mut self, hint: PreloadHint) -> Self {
+        self.hints.push(hint);
+        self
+    }
+
+    /// Add several preload hints at once.
+    pub fn hints(mut self, hints: impl IntoIterator<Item = PreloadHint>) -> Self {
+        self.hints.extend(hints);
+        self
+    }
+}
+
+impl<T: IntoResponse> IntoResponse for Preload<T> {
+    fn into_response(self) -> Response {
+        let mut response = self.inner.into_response();
+        for hint in &self.hints {
+            response
+                .headers_mut()
+                .append(header::LINK, hint.header_value());
+        }
+        response
+    }
+}
+
+impl<T: ResponseModifier> ResponseModifier for Preload<T> {
+    fn update_response(op: &mut Operation) {
+        T::update_response(op);
+    }
+}
+
 /// Generic wrapper for returning a response with a custom status code.
 ///
 /// The status code is specified as a const generic parameter.
@@ -627,4 +749,46 @@ mod tests {
             503
         ); // Service Unavailable
     }
+
+    #[test]
+    fn preload_adds_link_headers() {
+        let response: Response = Preload::new("hello")
+            .hint(PreloadHint::preload("/app.css", "style"))
+            .hint(PreloadHint::preload("/app.js", "script"))
+            .into_response();
+
+        let links: Vec<_> = response
+            .headers()
+            .get_all(header::LINK)
+            .iter()
+            .map(|v| v.to_str().unwrap())
+            .collect();
+
+        assert_eq!(
+            links,
+            vec![
+                "</app.css>; rel=preload; as=style",
+                "</app.js>; rel=preload; as=script",
+            ]
+        );
+        assert_eq!(response.status().as_u16(), 200);
+    }
+
+    #[test]
+    fn preload_without_hints_is_a_passthrough() {
+        let response: Response = Preload::new("hello").into_response();
+        assert!(response.headers().get(header::LINK).is_none());
+    }
+
+    #[test]
+    fn preload_hint_variants_format_correctly() {
+        assert_eq!(
+            PreloadHint::module_preload("/app.mjs").header_value(),
+            "</app.mjs>; rel=modulepreload"
+        );
+        assert_eq!(
+            PreloadHint::preconnect("https://fonts.example.com").header_value(),
+            "<https://fonts.example.com>; rel=preconnect"
+        );
+    }
 }
@@ -125,6 +125,9 @@ impl std::error::Error for RouteConflictError {}
 pub struct MethodRouter {
     handlers: HashMap<Method, BoxedHandler>,
     pub(crate) operations: HashMap<Method, Operation>,
+    /// State of the router this was nested from, if any (see `Router::nest`).
+    /// Checked by `State<T>` before the app-wide state.
+    local_state: Option<Arc<Extensions>>,
 }
 
 impl Clone for MethodRouter {
@@ -132,6 +135,7 @@ impl Clone for MethodRouter {
         Self {
             handlers: self.handlers.clone(),
             operations: self.operations.clone(),
+            local_state: self.local_state.clone(),
         }
     }
 }
@@ -142,6 +146,7 @@ impl MethodRouter {
         Self {
             handlers: HashMap::new(),
             operations: HashMap::new(),
+            local_state: None,
         }
     }
 
@@ -167,9 +172,15 @@ impl MethodRouter {
         Self {
             handlers,
             operations: HashMap::new(), // Operations lost when using raw boxed handlers for now
+            local_state: None,
         }
     }
 
+    /// Get the nested router's own state, if this route came from `Router::nest`
+    pub(crate) fn local_state(&self) -> Option<&Arc<Extensions>> {
+        self.local_state.as_ref()
+    }
+
     /// Insert a pre-boxed handler and its OpenAPI operation (internal use).
     ///
     /// Panics if the same method is inserted twice for the same path.
@@ -421,18 +432,25 @@ impl Router {
     /// Nest another router under a prefix
     ///
     /// All routes from the nested router will be registered with the prefix
-    /// prepended to their paths. State from the nested router is merged into
-    /// the parent router (parent state takes precedence for type conflicts).
+    /// prepended to their paths. The nested router's own state (if any) travels
+    /// with its routes: a `State<T>` extractor on one of those routes checks
+    /// the nested router's state first, then falls back to the parent's. This
+    /// lets a self-contained feature module carry its own state without the
+    /// parent app needing to know its type.
     ///
     /// # State Merging
     ///
-    /// When nesting routers with state:
-    /// - If the parent router has state of type T, it is preserved (parent wins)
-    /// - If only the nested router has state of type T, it is added to the parent
-    /// - State type tracking is merged to enable proper conflict detection
+    /// - `state_type_ids` bookkeeping is merged so `has_state`/conflict
+    ///   detection sees both routers' types.
+    /// - Each nested route keeps a reference to the nested router's state and
+    ///   checks it before the parent's (see [`State`](crate::extract::State)).
+    ///   If the same route was already nested once before (e.g. nesting a
+    ///   router that itself came from a `nest` call), the innermost state
+    ///   wins - it stays attached rather than being replaced here.
     ///
-    /// Note: Due to limitations of `http::Extensions`, automatic state merging
-    /// requires using the `merge_state` method for specific types.
+    /// To instead merge a specific type of the nested router's state directly
+    /// into the parent's own state (so the parent's *other* routes can see
+    /// it too), use `merge_state` before or after nesting.
     ///
     /// # Example
     ///
@@ -466,7 +484,12 @@ impl Router {
             }
         }
 
-        // 3. Collect routes from the nested router before consuming it
+        // 3. The nested router's own state, to attach to its routes below.
+        // None if it never called `.state(...)`, so routes with no nested
+        // state pay no extra clone and fall straight through to the parent's.
+        let nested_state = (!router.state_type_ids.is_empty()).then(|| router.state.clone());
+
+        // 4. Collect routes from the nested router before consuming it
         // We need to iterate over registered_routes and get the corresponding MethodRouters
         let nested_routes: Vec<(String, RouteInfo, MethodRouter)> = router
             .registered_routes
@@ -479,8 +502,13 @@ impl Router {
             })
             .collect();
 
-        // 4. Register each nested route with the prefix
-        for (matchit_path, route_info, method_router) in nested_routes {
+        // 5. Register each nested route with the prefix
+        for (matchit_path, route_info, mut method_router) in nested_routes {
+            // Innermost nest() wins: if this route already carries state from
+            // a deeper nest() call, keep it rather than overwriting it here.
+            if method_router.local_state.is_none() {
+                method_router.local_state = nested_state.clone();
+            }
             // Build the prefixed path
             // The matchit_path already has the :param format
             // The route_info.path has the {param} format
@@ -586,7 +614,11 @@ impl Router {
                         .map(|(k, v)| (k.to_string(), v.to_string()))
                         .collect();
 
-                    RouteMatch::Found { handler, params }
+                    RouteMatch::Found {
+                        handler,
+                        params,
+                        local_state: method_router.local_state(),
+                    }
                 } else {
                     RouteMatch::MethodNotAllowed {
                         allowed: method_router.allowed_methods(),
@@ -624,6 +656,9 @@ pub(crate) enum RouteMatch<'a> {
     Found {
         handler: &'a BoxedHandler,
         params: PathParams,
+        /// The nested router's own state, if the matched route was mounted
+        /// via `Router::nest` under a router that had its own state.
+        local_state: Option<&'a Arc<Extensions>>,
     },
     NotFound,
     MethodNotAllowed {
@@ -1106,6 +1141,75 @@ mod tests {
             .contains(&std::any::TypeId::of::<NestedState>()));
     }
 
+    #[test]
+    fn test_nested_route_carries_nested_router_state() {
+        #[derive(Clone)]
+        struct NestedState(String);
+
+        async fn handler() -> &'static str {
+            "handler"
+        }
+
+        let nested = Router::new()
+            .route("/test", get(handler))
+            .state(NestedState("nested".to_string()));
+
+        let parent = Router::new().nest("/api", nested);
+
+        // The parent never called `.state()`, so this only works via the
+        // nested route's own local_state, not `parent.state`.
+        assert!(parent.state.get::<NestedState>().is_none());
+
+        match parent.match_route("/api/test", &Method::GET) {
+            RouteMatch::Found { local_state, .. } => {
+                let state = local_state.expect("route should carry nested state");
+                assert_eq!(state.get::<NestedState>().unwrap().0, "nested");
+            }
+            _ => panic!("route should be found"),
+        }
+    }
+
+    #[test]
+    fn test_route_without_nested_state_has_no_local_state() {
+        async fn handler() -> &'static str {
+            "handler"
+        }
+
+        let nested = Router::new().route("/test", get(handler));
+        let parent = Router::new().nest("/api", nested);
+
+        match parent.match_route("/api/test", &Method::GET) {
+            RouteMatch::Found { local_state, .. } => {
+                assert!(local_state.is_none());
+            }
+            _ => panic!("route should be found"),
+        }
+    }
+
+    #[test]
+    fn test_innermost_nest_state_wins_over_outer_nest() {
+        #[derive(Clone)]
+        struct SharedState(&'static str);
+
+        async fn handler() -> &'static str {
+            "handler"
+        }
+
+        let leaf = Router::new()
+            .route("/test", get(handler))
+            .state(SharedState("leaf"));
+        let mid = Router::new().nest("/inner", leaf).state(SharedState("mid"));
+        let top = Router::new().nest("/outer", mid);
+
+        match top.match_route("/outer/inner/test", &Method::GET) {
+            RouteMatch::Found { local_state, .. } => {
+                let state = local_state.expect("route should carry nested state");
+                assert_eq!(state.get::<SharedState>().unwrap().0, "leaf");
+            }
+            _ => panic!("route should be found"),
+        }
+    }
+
     #[test]
     #[should_panic(expected = "ROUTE CONFLICT DETECTED")]
     fn test_nested_route_conflict_with_existing_route() {
@@ -36,12 +36,70 @@ impl Server {
     }
 
     /// Run the server
-    pub async fn run(self, addr: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    ///
+    /// `acceptors` controls how many independent accept loops are spawned:
+    ///
+    /// - `1` (the default): a single `TcpListener` shared by every worker
+    ///   thread, exactly as before.
+    /// - `> 1`: one `TcpListener` per acceptor, each bound to `addr` with
+    ///   `SO_REUSEPORT` so the kernel distributes incoming connections
+    ///   across them directly, removing the single-accept-loop bottleneck
+    ///   under very high connection churn. Only supported on platforms with
+    ///   `SO_REUSEPORT` (Linux, macOS, and other BSDs); on other platforms
+    ///   this logs a warning and falls back to a single acceptor.
+    pub async fn run(
+        self,
+        addr: &str,
+        acceptors: usize,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let addr: SocketAddr = addr.parse()?;
-        let listener = TcpListener::bind(addr).await?;
 
-        info!("🚀 RustAPI server running on http://{}", addr);
+        if acceptors <= 1 {
+            info!("🚀 RustAPI server running on http://{}", addr);
+            return self.accept_loop(TcpListener::bind(addr).await?).await;
+        }
+
+        #[cfg(unix)]
+        {
+            info!(
+                "🚀 RustAPI server running on http://{} with {} SO_REUSEPORT acceptors",
+                addr, acceptors
+            );
+
+            let mut tasks = Vec::with_capacity(acceptors);
+            for _ in 0..acceptors {
+                let listener = bind_reuseport(addr)?;
+                let server = Server {
+                    router: self.router.clone(),
+                    layers: self.layers.clone(),
+                    interceptors: self.interceptors.clone(),
+                };
+                tasks.push(tokio::spawn(server.accept_loop(listener)));
+            }
+
+            for task in tasks {
+                task.await??;
+            }
+
+            Ok(())
+        }
+
+        #[cfg(not(unix))]
+        {
+            tracing::warn!(
+                "SO_REUSEPORT multi-acceptor mode was requested but is not supported on this \
+                 platform; falling back to a single acceptor"
+            );
+            info!("🚀 RustAPI server running on http://{}", addr);
+            self.accept_loop(TcpListener::bind(addr).await?).await
+        }
+    }
 
+    /// Accept connections from `listener` forever, handling each on its own task.
+    async fn accept_loop(
+        self,
+        listener: TcpListener,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         loop {
             let (stream, remote_addr) = listener.accept().await?;
             let io = TokioIo::new(stream);
@@ -73,6 +131,22 @@ impl Server {
     }
 }
 
+/// Bind a `TcpListener` to `addr` with `SO_REUSEPORT` set, so multiple
+/// sockets can share the same address and let the kernel load-balance
+/// incoming connections across them.
+#[cfg(unix)]
+fn bind_reuseport(addr: SocketAddr) -> std::io::Result<TcpListener> {
+    use socket2::{Domain, Socket, Type};
+
+    let socket = Socket::new(Domain::for_address(addr), Type::STREAM, None)?;
+    socket.set_reuse_address(true)?;
+    socket.set_reuse_port(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&addr.into())?;
+    socket.listen(1024)?;
+    TcpListener::from_std(socket.into())
+}
+
 /// Handle a single HTTP request
 async fn handle_request(
     router: Arc<Router>,
@@ -89,8 +163,12 @@ async fn handle_request(
     let (parts, body) = req.into_parts();
 
     // Match the route to get path params
-    let (handler, params) = match router.match_route(&path, &method) {
-        RouteMatch::Found { handler, params } => (handler.clone(), params),
+    let (handler, params, local_state) = match router.match_route(&path, &method) {
+        RouteMatch::Found {
+            handler,
+            params,
+            local_state,
+        } => (handler.clone(), params, local_state.cloned()),
         RouteMatch::NotFound => {
             let response = ApiError::not_found(format!("No route found for {} {}", method, path))
                 .into_response();
@@ -120,7 +198,8 @@ async fn handle_request(
         crate::request::BodyVariant::Streaming(body),
         router.state_ref(),
         params,
-    );
+    )
+    .with_local_state(local_state);
 
     // Apply request interceptors (in registration order)
     let request = interceptors.intercept_request(request);
@@ -50,8 +50,11 @@ use futures_util::Stream;
 use http::{header, StatusCode};
 use http_body_util::Full;
 use pin_project_lite::pin_project;
-use rustapi_openapi::{MediaType, Operation, ResponseModifier, ResponseSpec, SchemaRef};
+use rustapi_openapi::{
+    MediaType, MessageSchema, Operation, ResponseModifier, ResponseSpec, SchemaRef, Undocumented,
+};
 use std::fmt::Write;
+use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::time::Duration;
@@ -265,9 +268,10 @@ impl KeepAlive {
 ///         .keep_alive(KeepAlive::new().interval(Duration::from_secs(30)))
 /// }
 /// ```
-pub struct Sse<S> {
+pub struct Sse<S, Data = Undocumented> {
     stream: S,
     keep_alive: Option<KeepAlive>,
+    _data: PhantomData<Data>,
 }
 
 impl<S> Sse<S> {
@@ -276,6 +280,29 @@ impl<S> Sse<S> {
         Self {
             stream,
             keep_alive: None,
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<S, Data> Sse<S, Data> {
+    /// Document the schema of the JSON payload carried in each event's
+    /// `data` field, for docs generation (see the `ResponseModifier` impl
+    /// below). Purely a documentation annotation - `data` stays a plain
+    /// string produced by the caller; this doesn't parse or validate it.
+    ///
+    /// ```rust,ignore
+    /// use rustapi_core::sse::Sse;
+    ///
+    /// async fn events() -> Sse<impl Stream<Item = Result<SseEvent, Infallible>>, PriceUpdate> {
+    ///     Sse::new(stream).event_schema::<PriceUpdate>()
+    /// }
+    /// ```
+    pub fn event_schema<NewData>(self) -> Sse<S, NewData> {
+        Sse {
+            stream: self.stream,
+            keep_alive: self.keep_alive,
+            _data: PhantomData,
         }
     }
 
@@ -355,7 +382,7 @@ where
 // For now, we'll implement IntoResponse by collecting the stream into a single response
 // This is a simplified implementation that works with the current Response type (Full<Bytes>)
 // A full streaming implementation would require changes to the Response type
-impl<S, E> IntoResponse for Sse<S>
+impl<S, E, Data> IntoResponse for Sse<S, Data>
 where
     S: Stream<Item = Result<SseEvent, E>> + Send + 'static,
     E: std::error::Error + Send + Sync + 'static,
@@ -383,8 +410,10 @@ where
     }
 }
 
-// OpenAPI support: ResponseModifier for SSE streams
-impl<S> ResponseModifier for Sse<S> {
+// OpenAPI support: ResponseModifier for SSE streams. `Data` documents the
+// schema of each event's `data` payload (see `Sse::event_schema`); when it's
+// `Undocumented` the generic string schema below is all we can say about it.
+impl<S, Data: MessageSchema> ResponseModifier for Sse<S, Data> {
     fn update_response(op: &mut Operation) {
         let mut content = std::collections::HashMap::new();
         content.insert(
@@ -403,6 +432,13 @@ impl<S> ResponseModifier for Sse<S> {
             content: Some(content),
         };
         op.responses.insert("200".to_string(), response);
+
+        if let Some(schema) = Data::schema_ref() {
+            op.extensions.insert(
+                "x-sse-event-schema".to_string(),
+                serde_json::to_value(&schema).unwrap_or(serde_json::Value::Null),
+            );
+        }
     }
 }
 
@@ -426,6 +462,55 @@ where
     Ok(Bytes::from(buffer))
 }
 
+/// Parse a raw `text/event-stream` body back into [`SseEvent`]s
+///
+/// This is the inverse of [`SseEvent::to_sse_string`], used by
+/// [`crate::TestResponse::sse_events`] to assert on event names, data, and
+/// ids without hand-parsing the wire format.
+pub fn parse_sse_events(text: &str) -> Vec<SseEvent> {
+    let mut events = Vec::new();
+    let mut event_type: Option<String> = None;
+    let mut id: Option<String> = None;
+    let mut retry: Option<u64> = None;
+    let mut data_lines: Vec<&str> = Vec::new();
+    let mut comment: Option<String> = None;
+    let mut has_content = false;
+
+    for line in text.lines().chain(std::iter::once("")) {
+        if line.is_empty() {
+            if has_content {
+                if let Some(comment) = comment.take() {
+                    events.push(SseEvent::comment(comment));
+                } else {
+                    let mut event = SseEvent::new(data_lines.join("\n"));
+                    event.event = event_type.take();
+                    event.id = id.take();
+                    event.retry = retry.take();
+                    events.push(event);
+                }
+            }
+            data_lines.clear();
+            has_content = false;
+            continue;
+        }
+
+        has_content = true;
+        if let Some(rest) = line.strip_prefix(':') {
+            comment = Some(rest.trim_start().to_string());
+        } else if let Some(rest) = line.strip_prefix("event:") {
+            event_type = Some(rest.trim_start().to_string());
+        } else if let Some(rest) = line.strip_prefix("id:") {
+            id = Some(rest.trim_start().to_string());
+        } else if let Some(rest) = line.strip_prefix("retry:") {
+            retry = rest.trim_start().parse().ok();
+        } else if let Some(rest) = line.strip_prefix("data:") {
+            data_lines.push(rest.strip_prefix(' ').unwrap_or(rest));
+        }
+    }
+
+    events
+}
+
 /// Create an SSE response from a synchronous iterator of events
 ///
 /// This is a convenience function for simple cases with pre-computed events.
@@ -535,6 +620,41 @@ mod tests {
         assert!(output.ends_with("\n\n"));
     }
 
+    #[test]
+    fn test_parse_sse_events_round_trips_full_event() {
+        let event = SseEvent::new("Hello").event("message").id("1").retry(3000);
+        let parsed = parse_sse_events(&event.to_sse_string());
+
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].data, "Hello");
+        assert_eq!(parsed[0].event.as_deref(), Some("message"));
+        assert_eq!(parsed[0].id.as_deref(), Some("1"));
+        assert_eq!(parsed[0].retry, Some(3000));
+    }
+
+    #[test]
+    fn test_parse_sse_events_multiple_and_multiline() {
+        let mut buffer = String::new();
+        buffer.push_str(&SseEvent::new("first").to_sse_string());
+        buffer.push_str(&SseEvent::new("line 1\nline 2").event("chunk").to_sse_string());
+
+        let parsed = parse_sse_events(&buffer);
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].data, "first");
+        assert_eq!(parsed[1].data, "line 1\nline 2");
+        assert_eq!(parsed[1].event.as_deref(), Some("chunk"));
+    }
+
+    #[test]
+    fn test_parse_sse_events_returns_comments_as_separate_events() {
+        let parsed = parse_sse_events(": keep-alive\n\ndata: real\n\n");
+
+        assert_eq!(parsed.len(), 2);
+        assert!(parsed[0].data.is_empty());
+        assert_eq!(parsed[1].data, "real");
+    }
+
     #[test]
     fn test_sse_response_headers() {
         use futures_util::stream;
@@ -165,6 +165,9 @@ pub struct StaticFileConfig {
     pub last_modified: bool,
     /// Cache-Control max-age in seconds (0 = no caching)
     pub max_age: u64,
+    /// Mark responses `immutable` (for content-hashed filenames that never
+    /// change contents under the same name)
+    pub immutable: bool,
     /// Fallback file for SPA routing (e.g., "index.html")
     pub fallback: Option<String>,
 }
@@ -179,6 +182,7 @@ impl Default for StaticFileConfig {
             etag: true,
             last_modified: true,
             max_age: 3600, // 1 hour
+            immutable: false,
             fallback: None,
         }
     }
@@ -224,6 +228,13 @@ impl StaticFileConfig {
         self
     }
 
+    /// Mark responses `immutable` - appropriate for content-hashed filenames
+    /// that never change contents under the same name
+    pub fn immutable(mut self, enabled: bool) -> Self {
+        self.immutable = enabled;
+        self
+    }
+
     /// Set a fallback file for SPA routing
     pub fn fallback(mut self, file: impl Into<String>) -> Self {
         self.fallback = Some(file.into());
@@ -324,10 +335,11 @@ impl StaticFile {
 
         // Add Cache-Control
         if config.max_age > 0 {
-            builder = builder.header(
-                header::CACHE_CONTROL,
-                format!("public, max-age={}", config.max_age),
-            );
+            let mut value = format!("public, max-age={}", config.max_age);
+            if config.immutable {
+                value.push_str(", immutable");
+            }
+            builder = builder.header(header::CACHE_CONTROL, value);
         }
 
         builder
@@ -452,6 +464,7 @@ mod tests {
             .etag(true)
             .last_modified(true)
             .max_age(7200)
+            .immutable(true)
             .fallback("index.html");
 
         assert_eq!(config.root, PathBuf::from("./public"));
@@ -461,6 +474,7 @@ mod tests {
         assert!(config.etag);
         assert!(config.last_modified);
         assert_eq!(config.max_age, 7200);
+        assert!(config.immutable);
         assert_eq!(config.fallback, Some("index.html".to_string()));
     }
 
@@ -33,9 +33,10 @@ use bytes::Bytes;
 use http::{header, HeaderMap, HeaderValue, Method, StatusCode};
 use http_body_util::BodyExt;
 use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 /// Test client for integration testing without network binding
 ///
@@ -44,6 +45,7 @@ use std::sync::Arc;
 pub struct TestClient {
     router: Arc<Router>,
     layers: Arc<LayerStack>,
+    cookies: Option<Mutex<HashMap<String, String>>>,
 }
 
 impl TestClient {
@@ -67,6 +69,7 @@ impl TestClient {
         Self {
             router: Arc::new(router),
             layers: Arc::new(layers),
+            cookies: None,
         }
     }
 
@@ -81,9 +84,29 @@ impl TestClient {
         Self {
             router: Arc::new(router),
             layers: Arc::new(layers),
+            cookies: None,
         }
     }
 
+    /// Enable an in-memory cookie jar on this client
+    ///
+    /// Once enabled, every response's `Set-Cookie` headers are stored and
+    /// replayed as a `Cookie` header on subsequent requests (unless a
+    /// request already sets its own), so a login-then-access flow can be
+    /// exercised with a single client instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = TestClient::new(app).with_cookies();
+    /// client.post_json("/login", &creds).await.assert_status(200);
+    /// client.get("/me").await.assert_status(200); // session cookie replayed
+    /// ```
+    pub fn with_cookies(mut self) -> Self {
+        self.cookies = Some(Mutex::new(HashMap::new()));
+        self
+    }
+
     /// Send a GET request
     ///
     /// # Example
@@ -118,12 +141,31 @@ impl TestClient {
     /// ).await;
     /// ```
     pub async fn request(&self, req: TestRequest) -> TestResponse {
+        let mut req = req;
+        if let Some(jar) = &self.cookies {
+            let jar = jar.lock().unwrap();
+            if !jar.is_empty() && !req.headers.contains_key(header::COOKIE) {
+                let cookie_header = jar
+                    .iter()
+                    .map(|(name, value)| format!("{name}={value}"))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                if let Ok(value) = HeaderValue::from_str(&cookie_header) {
+                    req.headers.insert(header::COOKIE, value);
+                }
+            }
+        }
+
         let method = req.method.clone();
         let path = req.path.clone();
 
         // Match the route to get path params
-        let (handler, params) = match self.router.match_route(&path, &method) {
-            RouteMatch::Found { handler, params } => (handler.clone(), params),
+        let (handler, params, local_state) = match self.router.match_route(&path, &method) {
+            RouteMatch::Found {
+                handler,
+                params,
+                local_state,
+            } => (handler.clone(), params, local_state.cloned()),
             RouteMatch::NotFound => {
                 let response =
                     ApiError::not_found(format!("No route found for {} {}", method, path))
@@ -165,7 +207,8 @@ impl TestClient {
             crate::request::BodyVariant::Buffered(body_bytes),
             self.router.state_ref(),
             params,
-        );
+        )
+        .with_local_state(local_state);
 
         // Create the final handler as a BoxedNext
         let final_handler: BoxedNext = Arc::new(move |req: Request| {
@@ -177,6 +220,18 @@ impl TestClient {
         // Execute through middleware stack
         let response = self.layers.execute(request, final_handler).await;
 
+        if let Some(jar) = &self.cookies {
+            let mut jar = jar.lock().unwrap();
+            for set_cookie in response.headers().get_all(header::SET_COOKIE) {
+                if let Ok(set_cookie) = set_cookie.to_str() {
+                    if let Some((name, rest)) = set_cookie.split_once('=') {
+                        let value = rest.split(';').next().unwrap_or("").trim();
+                        jar.insert(name.trim().to_string(), value.to_string());
+                    }
+                }
+            }
+        }
+
         TestResponse::from_response(response).await
     }
 }
@@ -191,6 +246,7 @@ pub struct TestRequest {
     path: String,
     headers: HeaderMap,
     body: Option<Bytes>,
+    multipart: Vec<MultipartPart>,
 }
 
 impl TestRequest {
@@ -201,6 +257,7 @@ impl TestRequest {
             path: path.to_string(),
             headers: HeaderMap::new(),
             body: None,
+            multipart: Vec::new(),
         }
     }
 
@@ -291,6 +348,121 @@ impl TestRequest {
     pub fn content_type(self, content_type: &str) -> Self {
         self.header("content-type", content_type)
     }
+
+    /// Create a POST request with a `multipart/form-data` body, built up via
+    /// [`TestRequest::field`] and [`TestRequest::file`] - matches the wire
+    /// format the [`crate::multipart::Multipart`] extractor expects, so
+    /// upload handlers can be exercised without a real network round trip.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let req = TestRequest::multipart("/upload")
+    ///     .field("title", "My Photo")
+    ///     .file("photo", "photo.png", "image/png", png_bytes);
+    /// ```
+    pub fn multipart(path: &str) -> Self {
+        Self::new(Method::POST, path)
+    }
+
+    /// Add a plain text field to a [`TestRequest::multipart`] body.
+    pub fn field(mut self, name: &str, value: &str) -> Self {
+        self.multipart.push(MultipartPart {
+            name: name.to_string(),
+            filename: None,
+            content_type: None,
+            data: Bytes::from(value.to_string()),
+        });
+        self.encode_multipart();
+        self
+    }
+
+    /// Add a file field to a [`TestRequest::multipart`] body.
+    pub fn file(mut self, name: &str, filename: &str, content_type: &str, data: impl Into<Bytes>) -> Self {
+        self.multipart.push(MultipartPart {
+            name: name.to_string(),
+            filename: Some(filename.to_string()),
+            content_type: Some(content_type.to_string()),
+            data: data.into(),
+        });
+        self.encode_multipart();
+        self
+    }
+
+    /// Re-render `self.body`/`Content-Type` from `self.multipart` - called
+    /// after every [`TestRequest::field`]/[`TestRequest::file`] since parts
+    /// accumulate but the wire format has to be produced as a whole.
+    fn encode_multipart(&mut self) {
+        let mut body = Vec::new();
+        for part in &self.multipart {
+            body.extend_from_slice(format!("--{MULTIPART_BOUNDARY}\r\n").as_bytes());
+            match &part.filename {
+                Some(filename) => {
+                    body.extend_from_slice(
+                        format!(
+                            "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+                            part.name, filename
+                        )
+                        .as_bytes(),
+                    );
+                    if let Some(content_type) = &part.content_type {
+                        body.extend_from_slice(format!("Content-Type: {content_type}\r\n").as_bytes());
+                    }
+                }
+                None => {
+                    body.extend_from_slice(
+                        format!("Content-Disposition: form-data; name=\"{}\"\r\n", part.name).as_bytes(),
+                    );
+                }
+            }
+            body.extend_from_slice(b"\r\n");
+            body.extend_from_slice(&part.data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{MULTIPART_BOUNDARY}--\r\n").as_bytes());
+
+        self.body = Some(Bytes::from(body));
+        self.headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_str(&format!("multipart/form-data; boundary={MULTIPART_BOUNDARY}")).unwrap(),
+        );
+    }
+}
+
+/// Check that every field in `expected` is present in `actual` with a
+/// matching value, recursing into nested objects/arrays - used by
+/// [`TestResponse::assert_json_includes`].
+fn json_includes(actual: &serde_json::Value, expected: &serde_json::Value) -> bool {
+    match (actual, expected) {
+        (serde_json::Value::Object(actual_map), serde_json::Value::Object(expected_map)) => {
+            expected_map.iter().all(|(key, expected_value)| {
+                actual_map
+                    .get(key)
+                    .is_some_and(|actual_value| json_includes(actual_value, expected_value))
+            })
+        }
+        (serde_json::Value::Array(actual_items), serde_json::Value::Array(expected_items)) => {
+            actual_items.len() == expected_items.len()
+                && actual_items
+                    .iter()
+                    .zip(expected_items)
+                    .all(|(a, e)| json_includes(a, e))
+        }
+        _ => actual == expected,
+    }
+}
+
+/// Boundary used to encode [`TestRequest::multipart`] bodies. Fixed rather
+/// than randomly generated, since tests only ever decode their own request.
+const MULTIPART_BOUNDARY: &str = "RustApiTestBoundary7MA4YWxkTrZu0gW";
+
+/// A single field queued onto a [`TestRequest::multipart`] request.
+#[derive(Debug, Clone)]
+struct MultipartPart {
+    name: String,
+    filename: Option<String>,
+    content_type: Option<String>,
+    data: Bytes,
 }
 
 /// Test response with assertion helpers
@@ -353,6 +525,24 @@ impl TestResponse {
         serde_json::from_slice(&self.body)
     }
 
+    /// Parse the response body as a `text/event-stream` and return its events
+    ///
+    /// `TestClient` runs handlers to completion and buffers the whole
+    /// response before returning it, so there's no live stream to time out
+    /// on here - this just parses whatever SSE events the handler already
+    /// produced.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let response = client.get("/events").await;
+    /// let events = response.sse_events();
+    /// assert_eq!(events[0].event.as_deref(), Some("greeting"));
+    /// ```
+    pub fn sse_events(&self) -> Vec<crate::sse::SseEvent> {
+        crate::sse::parse_sse_events(&self.text())
+    }
+
     /// Assert that the response has the expected status code
     ///
     /// # Panics
@@ -424,6 +614,104 @@ impl TestResponse {
         self
     }
 
+    /// Assert that the response body is exactly the given JSON value
+    ///
+    /// Unlike [`TestResponse::assert_json`], this compares against a
+    /// `serde_json::Value` (e.g. built with `serde_json::json!`) instead of
+    /// requiring a typed struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body can't be parsed as JSON or doesn't match, printing
+    /// both sides pretty-printed for an easy diff.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// response.assert_json_eq(serde_json::json!({ "id": 1, "name": "Alice" }));
+    /// ```
+    pub fn assert_json_eq(&self, expected: serde_json::Value) -> &Self {
+        let actual: serde_json::Value = self.json().expect("Failed to parse response body as JSON");
+        assert_eq!(
+            actual,
+            expected,
+            "JSON body mismatch\n--- actual ---\n{}\n--- expected ---\n{}",
+            serde_json::to_string_pretty(&actual).unwrap_or_default(),
+            serde_json::to_string_pretty(&expected).unwrap_or_default(),
+        );
+        self
+    }
+
+    /// Assert that the response body contains at least the given JSON fields
+    ///
+    /// Objects are matched by key subset (extra actual keys are ignored),
+    /// recursively; arrays must match length and match element-by-element.
+    /// Useful when a response has fields you don't want to hard-code into
+    /// every test (timestamps, generated ids, etc).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body can't be parsed as JSON or doesn't include the
+    /// expected subset, printing both sides pretty-printed for an easy diff.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// response.assert_json_includes(serde_json::json!({ "name": "Alice" }));
+    /// ```
+    pub fn assert_json_includes(&self, expected: serde_json::Value) -> &Self {
+        let actual: serde_json::Value = self.json().expect("Failed to parse response body as JSON");
+        assert!(
+            json_includes(&actual, &expected),
+            "JSON body does not include expected subset\n--- actual ---\n{}\n--- expected subset ---\n{}",
+            serde_json::to_string_pretty(&actual).unwrap_or_default(),
+            serde_json::to_string_pretty(&expected).unwrap_or_default(),
+        );
+        self
+    }
+
+    /// Assert that the response has all of the given header values
+    ///
+    /// Unlike calling [`TestResponse::assert_header`] repeatedly, this
+    /// reports every mismatch at once instead of stopping at the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any header is missing or doesn't match, listing all
+    /// mismatches together.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// response.assert_headers_include([
+    ///     ("content-type", "application/json"),
+    ///     ("x-request-id", "abc123"),
+    /// ]);
+    /// ```
+    pub fn assert_headers_include<'e>(
+        &self,
+        expected: impl IntoIterator<Item = (&'e str, &'e str)>,
+    ) -> &Self {
+        let mismatches: Vec<String> = expected
+            .into_iter()
+            .filter_map(|(key, expected_value)| {
+                let actual_value = self.headers.get(key).and_then(|v| v.to_str().ok());
+                if actual_value == Some(expected_value) {
+                    None
+                } else {
+                    Some(format!("  {key}: expected {expected_value:?}, got {actual_value:?}"))
+                }
+            })
+            .collect();
+
+        assert!(
+            mismatches.is_empty(),
+            "Header assertions failed:\n{}",
+            mismatches.join("\n")
+        );
+        self
+    }
+
     /// Assert that the response body contains the expected string
     ///
     /// # Panics
@@ -738,6 +1026,181 @@ mod tests {
         assert_eq!(response.text(), "test body");
     }
 
+    #[tokio::test]
+    async fn test_client_multipart_upload() {
+        async fn upload(mut multipart: crate::multipart::Multipart) -> String {
+            let mut summary = Vec::new();
+            while let Some(field) = multipart.next_field().await.unwrap() {
+                let name = field.name().unwrap_or("").to_string();
+                let file_name = field.file_name().map(|s| s.to_string());
+                let text = field.text().await.unwrap_or_default();
+                summary.push(format!("{name}:{file_name:?}:{text}"));
+            }
+            summary.join(",")
+        }
+
+        let app = RustApi::new().route("/upload", crate::router::post(upload));
+        let client = TestClient::new(app);
+
+        let response = client
+            .request(
+                TestRequest::multipart("/upload")
+                    .field("title", "My Photo")
+                    .file("photo", "photo.txt", "text/plain", "file contents"),
+            )
+            .await;
+
+        response.assert_status(StatusCode::OK);
+        assert_eq!(
+            response.text(),
+            "title:None:My Photo,photo:Some(\"photo.txt\"):file contents"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_cookie_jar_persists_session_across_requests() {
+        async fn login() -> Response {
+            let mut response = "logged in".into_response();
+            response
+                .headers_mut()
+                .insert(header::SET_COOKIE, "session=abc123; Path=/".parse().unwrap());
+            response
+        }
+
+        async fn whoami(headers: crate::extract::Headers) -> String {
+            headers
+                .0
+                .get(header::COOKIE)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or("")
+                .to_string()
+        }
+
+        let app = RustApi::new()
+            .route("/login", get(login))
+            .route("/whoami", get(whoami));
+        let client = TestClient::new(app).with_cookies();
+
+        client.get("/login").await.assert_status(StatusCode::OK);
+
+        let response = client.get("/whoami").await;
+        response.assert_status(StatusCode::OK);
+        assert_eq!(response.text(), "session=abc123");
+    }
+
+    #[tokio::test]
+    async fn test_client_without_cookie_jar_does_not_replay_cookies() {
+        async fn login() -> Response {
+            let mut response = "logged in".into_response();
+            response
+                .headers_mut()
+                .insert(header::SET_COOKIE, "session=abc123; Path=/".parse().unwrap());
+            response
+        }
+
+        async fn whoami(headers: crate::extract::Headers) -> String {
+            headers
+                .0
+                .get(header::COOKIE)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or("")
+                .to_string()
+        }
+
+        let app = RustApi::new()
+            .route("/login", get(login))
+            .route("/whoami", get(whoami));
+        let client = TestClient::new(app);
+
+        client.get("/login").await.assert_status(StatusCode::OK);
+
+        let response = client.get("/whoami").await;
+        assert_eq!(response.text(), "");
+    }
+
+    #[tokio::test]
+    async fn test_response_assert_json_eq() {
+        let app = RustApi::new().route("/json", get(json_string_handler));
+        let client = TestClient::new(app);
+
+        let response = client.get("/json").await;
+        response.assert_json_eq(serde_json::json!({ "message": "test", "count": 42 }));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "JSON body mismatch")]
+    async fn test_response_assert_json_eq_panics_on_mismatch() {
+        let app = RustApi::new().route("/json", get(json_string_handler));
+        let client = TestClient::new(app);
+
+        let response = client.get("/json").await;
+        response.assert_json_eq(serde_json::json!({ "message": "wrong", "count": 42 }));
+    }
+
+    #[tokio::test]
+    async fn test_response_assert_json_includes_ignores_extra_fields() {
+        let app = RustApi::new().route("/json", get(json_string_handler));
+        let client = TestClient::new(app);
+
+        let response = client.get("/json").await;
+        response.assert_json_includes(serde_json::json!({ "message": "test" }));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "does not include expected subset")]
+    async fn test_response_assert_json_includes_panics_on_missing_field() {
+        let app = RustApi::new().route("/json", get(json_string_handler));
+        let client = TestClient::new(app);
+
+        let response = client.get("/json").await;
+        response.assert_json_includes(serde_json::json!({ "missing": "field" }));
+    }
+
+    #[tokio::test]
+    async fn test_response_assert_headers_include() {
+        let app = RustApi::new().route("/json", get(json_string_handler));
+        let client = TestClient::new(app);
+
+        let response = client.get("/json").await;
+        response.assert_headers_include([("content-type", "text/plain; charset=utf-8")]);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Header assertions failed")]
+    async fn test_response_assert_headers_include_reports_all_mismatches() {
+        let app = RustApi::new().route("/json", get(json_string_handler));
+        let client = TestClient::new(app);
+
+        let response = client.get("/json").await;
+        response.assert_headers_include([
+            ("content-type", "text/plain"),
+            ("x-missing", "value"),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_client_sse_events_parses_event_stream_body() {
+        async fn events() -> Response {
+            crate::sse::sse_response(vec![
+                crate::sse::SseEvent::new("Hello").event("greeting"),
+                crate::sse::SseEvent::new("World").id("2"),
+            ])
+        }
+
+        let app = RustApi::new().route("/events", get(events));
+        let client = TestClient::new(app);
+
+        let response = client.get("/events").await;
+        response.assert_status(StatusCode::OK);
+
+        let events = response.sse_events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].data, "Hello");
+        assert_eq!(events[0].event.as_deref(), Some("greeting"));
+        assert_eq!(events[1].data, "World");
+        assert_eq!(events[1].id.as_deref(), Some("2"));
+    }
+
     #[tokio::test]
     async fn test_client_raw_body() {
         let app = RustApi::new().route("/echo", crate::router::post(echo_body));
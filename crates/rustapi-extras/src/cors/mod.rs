@@ -13,23 +13,61 @@
 //!     .allow_methods([Method::GET, Method::POST])
 //!     .allow_credentials(true);
 //! ```
+//!
+//! # Per-route policies
+//!
+//! `CorsLayer` itself applies to every request it sees. For a policy that
+//! differs by route (e.g. a public API prefix with permissive CORS and an
+//! internal prefix with none), compose it with
+//! [`LayerWhen`](rustapi_core::middleware::LayerWhen) and a path predicate
+//! instead of building that scoping into this type:
+//!
+//! ```ignore
+//! use rustapi_core::middleware::{predicate, LayerWhen};
+//! use rustapi_extras::cors::CorsLayer;
+//!
+//! let public_api_cors = LayerWhen::new(
+//!     predicate::path_prefix("/api/public"),
+//!     CorsLayer::permissive(),
+//! );
+//! ```
 
 use bytes::Bytes;
-use http::{header, Method, StatusCode};
+use http::{header, HeaderValue, Method, StatusCode};
 use http_body_util::Full;
 use rustapi_core::middleware::{BoxedNext, MiddlewareLayer};
 use rustapi_core::{Request, Response};
+use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::time::Duration;
 
+/// A callback that decides whether an origin string is allowed, for cases a
+/// static allowlist can't express (e.g. `*.tenant.example.com` wildcard
+/// subdomains). Given the raw `Origin` header value.
+pub type OriginPredicate = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
 /// Specifies which origins are allowed for CORS requests.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub enum AllowedOrigins {
     /// Allow any origin (`Access-Control-Allow-Origin: *`).
     Any,
     /// Allow only specific origins.
     List(Vec<String>),
+    /// Allow origins accepted by a callback, e.g. a regex match against
+    /// wildcard subdomains for a multi-tenant deployment.
+    Predicate(OriginPredicate),
+}
+
+impl fmt::Debug for AllowedOrigins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => write!(f, "Any"),
+            Self::List(list) => f.debug_tuple("List").field(list).finish(),
+            Self::Predicate(_) => write!(f, "Predicate(..)"),
+        }
+    }
 }
 
 impl Default for AllowedOrigins {
@@ -49,6 +87,7 @@ pub struct CorsLayer {
     headers: Vec<String>,
     credentials: bool,
     max_age: Option<Duration>,
+    private_network: bool,
 }
 
 impl Default for CorsLayer {
@@ -66,6 +105,7 @@ impl CorsLayer {
             headers: Vec::new(),
             credentials: false,
             max_age: None,
+            private_network: false,
         }
     }
 
@@ -88,6 +128,7 @@ impl CorsLayer {
             headers: vec!["*".to_string()],
             credentials: false,
             max_age: Some(Duration::from_secs(86400)),
+            private_network: true,
         }
     }
 
@@ -112,6 +153,25 @@ impl CorsLayer {
         self
     }
 
+    /// Allow origins accepted by `predicate`, given the raw `Origin` header
+    /// value. Useful for multi-tenant deployments serving wildcard
+    /// subdomains (`https://{tenant}.example.com`) that a static
+    /// [`allow_origins`](Self::allow_origins) list can't express.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let cors = CorsLayer::new()
+    ///     .allow_origin_predicate(|origin| origin.ends_with(".example.com"));
+    /// ```
+    pub fn allow_origin_predicate<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.origins = AllowedOrigins::Predicate(Arc::new(predicate));
+        self
+    }
+
     /// Allow specific HTTP methods.
     pub fn allow_methods<I>(mut self, methods: I) -> Self
     where
@@ -137,12 +197,25 @@ impl CorsLayer {
         self
     }
 
-    /// Set the max age for preflight cache.
+    /// Set the max age for preflight cache (`Access-Control-Max-Age`), i.e.
+    /// how long a browser may reuse a preflight response before sending
+    /// another one.
     pub fn max_age(mut self, duration: Duration) -> Self {
         self.max_age = Some(duration);
         self
     }
 
+    /// Complete the [Private Network Access] handshake: when a preflight
+    /// request carries `Access-Control-Request-Private-Network: true`,
+    /// respond with `Access-Control-Allow-Private-Network: true`. Required
+    /// for public sites to call an API on a private IP or `localhost`.
+    ///
+    /// [Private Network Access]: https://developer.chrome.com/blog/private-network-access-preflight
+    pub fn allow_private_network(mut self, allow: bool) -> Self {
+        self.private_network = allow;
+        self
+    }
+
     /// Get the configured origins.
     pub fn origins(&self) -> &AllowedOrigins {
         &self.origins
@@ -168,6 +241,11 @@ impl CorsLayer {
         self.max_age
     }
 
+    /// Check if the Private Network Access handshake is enabled.
+    pub fn private_network(&self) -> bool {
+        self.private_network
+    }
+
     /// Build the Access-Control-Allow-Methods header value.
     fn methods_header_value(&self) -> String {
         self.methods
@@ -198,6 +276,7 @@ impl MiddlewareLayer for CorsLayer {
         let headers = self.headers_header_value();
         let credentials = self.credentials;
         let max_age = self.max_age;
+        let private_network = self.private_network;
         let is_any_origin = matches!(origins, AllowedOrigins::Any);
 
         // Extract origin from request
@@ -213,12 +292,19 @@ impl MiddlewareLayer for CorsLayer {
                 .headers()
                 .contains_key(header::ACCESS_CONTROL_REQUEST_METHOD);
 
+        let wants_private_network = req
+            .headers()
+            .get("access-control-request-private-network")
+            .and_then(|v| v.to_str().ok())
+            == Some("true");
+
         // Clone self for origin check
         let is_origin_allowed = origin
             .as_ref()
             .map(|o| match &origins {
                 AllowedOrigins::Any => true,
                 AllowedOrigins::List(list) => list.iter().any(|allowed| allowed == o),
+                AllowedOrigins::Predicate(predicate) => predicate(o),
             })
             .unwrap_or(false);
 
@@ -275,6 +361,14 @@ impl MiddlewareLayer for CorsLayer {
                     );
                 }
 
+                // Complete the Private Network Access handshake
+                if private_network && wants_private_network && is_origin_allowed {
+                    headers_mut.insert(
+                        header::HeaderName::from_static("access-control-allow-private-network"),
+                        HeaderValue::from_static("true"),
+                    );
+                }
+
                 return response;
             }
 
@@ -317,3 +411,149 @@ impl MiddlewareLayer for CorsLayer {
         Box::new(self.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn ok_next() -> BoxedNext {
+        Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(200)
+                    .body(Full::new(Bytes::from("OK")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        })
+    }
+
+    fn preflight_request(origin: &str) -> Request {
+        Request::from_http_request(
+            http::Request::builder()
+                .method("OPTIONS")
+                .uri("/")
+                .header(header::ORIGIN, origin)
+                .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn origin_predicate_allows_matching_subdomains() {
+        let layer = CorsLayer::new().allow_origin_predicate(|origin| {
+            origin
+                .strip_prefix("https://")
+                .and_then(|rest| rest.strip_suffix(".example.com"))
+                .is_some()
+        });
+
+        let response = layer
+            .call(preflight_request("https://tenant-a.example.com"), ok_next())
+            .await;
+        assert_eq!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .unwrap(),
+            "https://tenant-a.example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn origin_predicate_rejects_non_matching_origin() {
+        let layer = CorsLayer::new().allow_origin_predicate(|origin| origin.ends_with(".example.com"));
+
+        let response = layer
+            .call(preflight_request("https://evil.example.org"), ok_next())
+            .await;
+        assert!(response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn private_network_handshake_completed_when_enabled() {
+        let layer = CorsLayer::new()
+            .allow_any_origin()
+            .allow_private_network(true);
+
+        let req = Request::from_http_request(
+            http::Request::builder()
+                .method("OPTIONS")
+                .uri("/")
+                .header(header::ORIGIN, "https://example.com")
+                .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+                .header("access-control-request-private-network", "true")
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        );
+
+        let response = layer.call(req, ok_next()).await;
+        assert_eq!(
+            response
+                .headers()
+                .get("access-control-allow-private-network")
+                .unwrap(),
+            "true"
+        );
+    }
+
+    #[tokio::test]
+    async fn private_network_handshake_absent_when_disabled() {
+        let layer = CorsLayer::new().allow_any_origin();
+
+        let req = Request::from_http_request(
+            http::Request::builder()
+                .method("OPTIONS")
+                .uri("/")
+                .header(header::ORIGIN, "https://example.com")
+                .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+                .header("access-control-request-private-network", "true")
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        );
+
+        let response = layer.call(req, ok_next()).await;
+        assert!(response
+            .headers()
+            .get("access-control-allow-private-network")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn private_network_handshake_absent_for_disallowed_origin() {
+        let layer = CorsLayer::new()
+            .allow_origins(["https://allowed.example.com"])
+            .allow_private_network(true);
+
+        let req = Request::from_http_request(
+            http::Request::builder()
+                .method("OPTIONS")
+                .uri("/")
+                .header(header::ORIGIN, "https://evil.example.org")
+                .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+                .header("access-control-request-private-network", "true")
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        );
+
+        let response = layer.call(req, ok_next()).await;
+        assert!(response
+            .headers()
+            .get("access-control-allow-private-network")
+            .is_none());
+    }
+
+    #[test]
+    fn max_age_is_configurable() {
+        let layer = CorsLayer::new().max_age(Duration::from_secs(600));
+        assert_eq!(layer.max_age_duration(), Some(Duration::from_secs(600)));
+    }
+}
@@ -0,0 +1,207 @@
+//! Configuration for the `ErrorReportingLayer` middleware.
+
+use crate::logging::UserIdExtractor;
+use std::collections::HashSet;
+
+/// Configuration for the `ErrorReportingLayer` middleware.
+///
+/// Use the builder pattern to customize behavior:
+///
+/// ```ignore
+/// use rustapi_extras::error_reporting::ErrorReportingConfig;
+///
+/// let config = ErrorReportingConfig::new()
+///     .sample_rate(0.5)          // Report 50% of captured events
+///     .min_status(500)           // Only report 5xx, not 4xx
+///     .capture_panics(true)
+///     .scrub_field("password")
+///     .skip_path("/health");
+/// ```
+#[derive(Clone)]
+pub struct ErrorReportingConfig {
+    /// Sampling rate (0.0-1.0). 1.0 = report all captured events.
+    pub(crate) sample_rate: f64,
+    /// Minimum response status code that counts as reportable. Default: 500.
+    pub(crate) min_status: u16,
+    /// Whether to catch handler panics and report them. Default: true.
+    pub(crate) capture_panics: bool,
+    /// Paths to skip from error reporting.
+    pub(crate) skip_paths: HashSet<String>,
+    /// Tag/field keys whose values are replaced with `"[REDACTED]"` before
+    /// reporting (case-insensitive).
+    pub(crate) scrub_fields: HashSet<String>,
+    /// Resolves the `user_id` field from the request.
+    pub(crate) user_id_extractor: Option<UserIdExtractor>,
+}
+
+impl Default for ErrorReportingConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorReportingConfig {
+    /// Create a new configuration with default values.
+    ///
+    /// Defaults:
+    /// - Sample rate: 1.0 (report every captured event)
+    /// - Minimum status: 500
+    /// - Panics captured and reported
+    /// - No paths skipped
+    /// - `password`, `authorization`, `token`, and `secret` scrubbed
+    pub fn new() -> Self {
+        let mut scrub_fields = HashSet::new();
+        scrub_fields.insert("password".to_string());
+        scrub_fields.insert("authorization".to_string());
+        scrub_fields.insert("token".to_string());
+        scrub_fields.insert("secret".to_string());
+
+        Self {
+            sample_rate: 1.0,
+            min_status: 500,
+            capture_panics: true,
+            skip_paths: HashSet::new(),
+            scrub_fields,
+            user_id_extractor: None,
+        }
+    }
+
+    /// Set the sampling rate (0.0 to 1.0) applied to captured events.
+    pub fn sample_rate(mut self, rate: f64) -> Self {
+        self.sample_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the minimum response status code that counts as reportable.
+    pub fn min_status(mut self, status: u16) -> Self {
+        self.min_status = status;
+        self
+    }
+
+    /// Enable or disable panic capture.
+    pub fn capture_panics(mut self, enabled: bool) -> Self {
+        self.capture_panics = enabled;
+        self
+    }
+
+    /// Add a path to skip from error reporting.
+    pub fn skip_path(mut self, path: impl Into<String>) -> Self {
+        self.skip_paths.insert(path.into());
+        self
+    }
+
+    /// Add a tag/field key to scrub before reporting.
+    pub fn scrub_field(mut self, key: impl Into<String>) -> Self {
+        self.scrub_fields.insert(key.into().to_lowercase());
+        self
+    }
+
+    /// Set the callback used to resolve the `user_id` field.
+    pub fn user_id_extractor(mut self, extractor: UserIdExtractor) -> Self {
+        self.user_id_extractor = Some(extractor);
+        self
+    }
+
+    /// Check if a path should be skipped.
+    pub(crate) fn should_skip_path(&self, path: &str) -> bool {
+        self.skip_paths.contains(path)
+    }
+
+    /// Check if a status code meets the reportable threshold.
+    pub(crate) fn is_reportable_status(&self, status: u16) -> bool {
+        status >= self.min_status
+    }
+
+    /// Check if this event should be sampled given the configured rate.
+    pub(crate) fn should_sample(&self) -> bool {
+        if self.sample_rate >= 1.0 {
+            return true;
+        }
+        if self.sample_rate <= 0.0 {
+            return false;
+        }
+        rand_sample(self.sample_rate)
+    }
+
+    /// Scrub a tag value if its key matches a scrub rule.
+    pub(crate) fn scrub(&self, key: &str, value: String) -> String {
+        if self.scrub_fields.contains(&key.to_lowercase()) {
+            "[REDACTED]".to_string()
+        } else {
+            value
+        }
+    }
+}
+
+impl std::fmt::Debug for ErrorReportingConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErrorReportingConfig")
+            .field("sample_rate", &self.sample_rate)
+            .field("min_status", &self.min_status)
+            .field("capture_panics", &self.capture_panics)
+            .field("skip_paths", &self.skip_paths)
+            .field("scrub_fields", &self.scrub_fields)
+            .field("user_id_extractor", &self.user_id_extractor.is_some())
+            .finish()
+    }
+}
+
+/// Simple random sampling based on rate.
+fn rand_sample(rate: f64) -> bool {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos();
+
+    let threshold = (rate * u32::MAX as f64) as u32;
+    nanos < threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config() {
+        let config = ErrorReportingConfig::new();
+        assert_eq!(config.sample_rate, 1.0);
+        assert_eq!(config.min_status, 500);
+        assert!(config.capture_panics);
+        assert!(config.scrub_fields.contains("password"));
+    }
+
+    #[test]
+    fn sample_rate_clamping() {
+        let config = ErrorReportingConfig::new().sample_rate(1.5);
+        assert_eq!(config.sample_rate, 1.0);
+
+        let config = ErrorReportingConfig::new().sample_rate(-0.5);
+        assert_eq!(config.sample_rate, 0.0);
+    }
+
+    #[test]
+    fn reportable_status_threshold() {
+        let config = ErrorReportingConfig::new().min_status(500);
+        assert!(!config.is_reportable_status(404));
+        assert!(!config.is_reportable_status(499));
+        assert!(config.is_reportable_status(500));
+        assert!(config.is_reportable_status(503));
+    }
+
+    #[test]
+    fn skip_paths() {
+        let config = ErrorReportingConfig::new().skip_path("/health");
+        assert!(config.should_skip_path("/health"));
+        assert!(!config.should_skip_path("/users"));
+    }
+
+    #[test]
+    fn scrub_replaces_matching_keys() {
+        let config = ErrorReportingConfig::new().scrub_field("api_key");
+        assert_eq!(config.scrub("api_key", "secret-value".to_string()), "[REDACTED]");
+        assert_eq!(config.scrub("Authorization", "Bearer x".to_string()), "[REDACTED]");
+        assert_eq!(config.scrub("path", "/users".to_string()), "/users");
+    }
+}
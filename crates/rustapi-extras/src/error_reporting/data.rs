@@ -0,0 +1,91 @@
+//! Data structures for captured error events.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What triggered an [`ErrorEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ErrorEventKind {
+    /// A handler returned a response with a status code at or above the
+    /// configured threshold (500 by default).
+    ServerError,
+    /// A handler panicked; the layer converted it into a 500 response.
+    Panic,
+}
+
+/// A single captured error event: a 5xx response, a panic, or an
+/// application-reported error chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorEvent {
+    /// The kind of failure this event represents.
+    pub kind: ErrorEventKind,
+    /// Request ID, if `RequestIdLayer` (or similar) inserted one.
+    pub request_id: Option<String>,
+    /// HTTP method of the failing request.
+    pub method: String,
+    /// Request path (without query string).
+    pub path: String,
+    /// Response status code, when known (absent for panics that never
+    /// produced a real response).
+    pub status: Option<u16>,
+    /// Human-readable error message (panic payload or a summary of the
+    /// error chain).
+    pub message: String,
+    /// User id resolved by `ErrorReportingConfig::user_id_extractor`, if any.
+    pub user_id: Option<String>,
+    /// Unix timestamp (seconds since epoch) the event was captured at.
+    pub timestamp: u64,
+    /// Additional context, with sensitive keys already scrubbed.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tags: HashMap<String, String>,
+}
+
+impl ErrorEvent {
+    /// Create a new error event with required fields.
+    pub fn new(
+        kind: ErrorEventKind,
+        method: impl Into<String>,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            request_id: None,
+            method: method.into(),
+            path: path.into(),
+            status: None,
+            message: message.into(),
+            user_id: None,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            tags: HashMap::new(),
+        }
+    }
+
+    /// Set the response status code.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the request ID.
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Set the resolved user id.
+    pub fn with_user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    /// Add a tag. The value is stored as given; `ErrorReportingLayer`
+    /// scrubs values whose key matches a scrub rule before reporting.
+    pub fn add_tag(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.tags.insert(key.into(), value.into());
+    }
+}
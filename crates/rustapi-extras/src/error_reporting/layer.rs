@@ -0,0 +1,272 @@
+//! `ErrorReportingLayer` middleware for capturing and reporting failures.
+
+use super::config::ErrorReportingConfig;
+use super::data::{ErrorEvent, ErrorEventKind};
+use super::reporter::ErrorReporter;
+use futures_util::FutureExt;
+use rustapi_core::middleware::{BoxedNext, MiddlewareLayer, RequestId};
+use rustapi_core::{ApiError, IntoResponse, Request, Response};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Middleware that captures 5xx responses and (optionally) handler panics,
+/// and hands them to a configured [`ErrorReporter`].
+///
+/// # Example
+///
+/// ```ignore
+/// use rustapi_core::RustApi;
+/// use rustapi_extras::error_reporting::{ErrorReportingConfig, ErrorReportingLayer, TracingErrorReporter};
+///
+/// let app = RustApi::new()
+///     .layer(ErrorReportingLayer::new(TracingErrorReporter::new(), ErrorReportingConfig::new()))
+///     .run("0.0.0.0:3000");
+/// ```
+#[derive(Clone)]
+pub struct ErrorReportingLayer {
+    reporter: Arc<dyn ErrorReporter>,
+    config: ErrorReportingConfig,
+}
+
+impl ErrorReportingLayer {
+    /// Create a new error reporting layer with the given reporter and configuration.
+    pub fn new<R: ErrorReporter>(reporter: R, config: ErrorReportingConfig) -> Self {
+        Self {
+            reporter: Arc::new(reporter),
+            config,
+        }
+    }
+
+    /// Build a context template (method/path/request_id/user_id already
+    /// resolved) that both the response path and the panic path fill in a
+    /// message and status for.
+    fn context_event(&self, req: &Request, kind: ErrorEventKind) -> ErrorEvent {
+        let mut event = ErrorEvent::new(kind, req.method().to_string(), req.uri().path(), "");
+
+        if let Some(request_id) = req.extensions().get::<RequestId>() {
+            event = event.with_request_id(request_id.as_str());
+        }
+        if let Some(extractor) = &self.config.user_id_extractor {
+            if let Some(user_id) = extractor(req) {
+                event = event.with_user_id(user_id);
+            }
+        }
+        event
+    }
+
+    fn report_if_reportable(&self, mut event: ErrorEvent) {
+        let reportable = match event.kind {
+            ErrorEventKind::Panic => self.config.capture_panics,
+            ErrorEventKind::ServerError => event
+                .status
+                .is_some_and(|status| self.config.is_reportable_status(status)),
+        };
+
+        if !reportable || !self.config.should_sample() {
+            return;
+        }
+
+        for (key, value) in std::mem::take(&mut event.tags) {
+            let scrubbed = self.config.scrub(&key, value);
+            event.tags.insert(key, scrubbed);
+        }
+        self.reporter.report(&event);
+    }
+}
+
+impl MiddlewareLayer for ErrorReportingLayer {
+    fn call(
+        &self,
+        req: Request,
+        next: BoxedNext,
+    ) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> {
+        let layer = self.clone();
+        let path = req.uri().path().to_string();
+
+        Box::pin(async move {
+            if layer.config.should_skip_path(&path) {
+                return next(req).await;
+            }
+
+            let context = layer.context_event(&req, ErrorEventKind::ServerError);
+
+            if !layer.config.capture_panics {
+                let response = next(req).await;
+                let status = response.status().as_u16();
+                let mut event = context;
+                event.message = format!("handler returned status {status}");
+                event.status = Some(status);
+                layer.report_if_reportable(event);
+                return response;
+            }
+
+            match AssertUnwindSafe(next(req)).catch_unwind().await {
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    let mut event = context;
+                    event.message = format!("handler returned status {status}");
+                    event.status = Some(status);
+                    layer.report_if_reportable(event);
+                    response
+                }
+                Err(panic) => {
+                    let mut event = context;
+                    event.kind = ErrorEventKind::Panic;
+                    event.message = panic_message(&panic);
+                    layer.report_if_reportable(event);
+                    ApiError::internal("Internal server error").into_response()
+                }
+            }
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn MiddlewareLayer> {
+        Box::new(self.clone())
+    }
+}
+
+/// Render a panic payload as a human-readable message.
+fn panic_message(panic: &Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "handler panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_reporting::reporter::{CallbackErrorReporter, TracingErrorReporter};
+    use bytes::Bytes;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn get_request(path: &str) -> Request {
+        let req = http::Request::builder()
+            .method("GET")
+            .uri(path)
+            .body(())
+            .unwrap();
+        Request::from_http_request(req, Bytes::new())
+    }
+
+    #[tokio::test]
+    async fn passes_through_successful_responses() {
+        let layer = ErrorReportingLayer::new(TracingErrorReporter::new(), ErrorReportingConfig::new());
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(Bytes::from("OK")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let response = layer.call(get_request("/users"), next).await;
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn reports_server_error_responses() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_clone = count.clone();
+        let reporter = CallbackErrorReporter::new(move |event: &ErrorEvent| {
+            assert_eq!(event.kind, ErrorEventKind::ServerError);
+            assert_eq!(event.status, Some(500));
+            count_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let layer = ErrorReportingLayer::new(reporter, ErrorReportingConfig::new());
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(500)
+                    .body(http_body_util::Full::new(Bytes::from("boom")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let response = layer.call(get_request("/users"), next).await;
+        assert_eq!(response.status(), 500);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn ignores_client_error_responses() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_clone = count.clone();
+        let reporter = CallbackErrorReporter::new(move |_event: &ErrorEvent| {
+            count_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let layer = ErrorReportingLayer::new(reporter, ErrorReportingConfig::new());
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(404)
+                    .body(http_body_util::Full::new(Bytes::from("not found")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let response = layer.call(get_request("/users"), next).await;
+        assert_eq!(response.status(), 404);
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn recovers_from_handler_panics() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_clone = count.clone();
+        let reporter = CallbackErrorReporter::new(move |event: &ErrorEvent| {
+            assert_eq!(event.kind, ErrorEventKind::Panic);
+            assert_eq!(event.message, "kaboom");
+            count_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let layer = ErrorReportingLayer::new(reporter, ErrorReportingConfig::new());
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                panic!("kaboom");
+                #[allow(unreachable_code)]
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(Bytes::new()))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let response = layer.call(get_request("/users"), next).await;
+        assert_eq!(response.status(), 500);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn skips_configured_paths() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_clone = count.clone();
+        let reporter = CallbackErrorReporter::new(move |_event: &ErrorEvent| {
+            count_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let config = ErrorReportingConfig::new().skip_path("/health");
+        let layer = ErrorReportingLayer::new(reporter, config);
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(500)
+                    .body(http_body_util::Full::new(Bytes::from("boom")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let response = layer.call(get_request("/health"), next).await;
+        assert_eq!(response.status(), 500);
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+}
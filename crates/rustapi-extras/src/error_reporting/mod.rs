@@ -0,0 +1,38 @@
+//! Error reporting middleware for RustAPI
+//!
+//! This module provides `ErrorReportingLayer`, which captures 5xx
+//! responses and handler panics and hands them to a pluggable
+//! [`ErrorReporter`], along with sample-rate control, a status threshold,
+//! and scrubbing of sensitive tag values before delivery.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use rustapi_core::RustApi;
+//! use rustapi_extras::error_reporting::{ErrorReportingConfig, ErrorReportingLayer, SentryReporter};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let reporter = SentryReporter::new("https://examplekey@o1.ingest.sentry.io/1");
+//!     let config = ErrorReportingConfig::new().sample_rate(0.25);
+//!
+//!     let app = RustApi::new()
+//!         .layer(ErrorReportingLayer::new(reporter, config))
+//!         .run("0.0.0.0:3000")
+//!         .await
+//!         .unwrap();
+//! }
+//! ```
+
+mod config;
+mod data;
+mod layer;
+mod reporter;
+
+pub use config::ErrorReportingConfig;
+pub use data::{ErrorEvent, ErrorEventKind};
+pub use layer::ErrorReportingLayer;
+pub use reporter::{
+    CallbackErrorReporter, CompositeErrorReporter, ErrorReporter, SentryReporter,
+    TracingErrorReporter,
+};
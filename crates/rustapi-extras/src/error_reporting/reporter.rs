@@ -0,0 +1,348 @@
+//! The `ErrorReporter` trait and built-in reporters.
+
+use super::data::ErrorEvent;
+use std::sync::Arc;
+
+/// Trait for delivering captured [`ErrorEvent`]s to an external system.
+///
+/// Implement this to send errors to Sentry, a custom aggregator, PagerDuty,
+/// or anywhere else; [`TracingErrorReporter`] is the zero-configuration
+/// default and [`SentryReporter`] covers the common case of a hosted Sentry
+/// (or Sentry-compatible) project.
+pub trait ErrorReporter: Send + Sync + 'static {
+    /// Report a single captured error event.
+    fn report(&self, event: &ErrorEvent);
+
+    /// Clone this reporter into a boxed trait object.
+    fn clone_reporter(&self) -> Box<dyn ErrorReporter>;
+}
+
+/// Reports error events via `tracing::error!`.
+///
+/// The default reporter: requires no configuration or outbound network
+/// calls, and works with whatever tracing subscriber the application
+/// already has installed.
+#[derive(Clone, Default)]
+pub struct TracingErrorReporter;
+
+impl TracingErrorReporter {
+    /// Create a new tracing-based reporter.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ErrorReporter for TracingErrorReporter {
+    fn report(&self, event: &ErrorEvent) {
+        tracing::error!(
+            kind = ?event.kind,
+            request_id = event.request_id.as_deref().unwrap_or("N/A"),
+            method = %event.method,
+            path = %event.path,
+            status = event.status,
+            user_id = event.user_id.as_deref().unwrap_or("N/A"),
+            "{}",
+            event.message
+        );
+    }
+
+    fn clone_reporter(&self) -> Box<dyn ErrorReporter> {
+        Box::new(self.clone())
+    }
+}
+
+/// A callback-based reporter that invokes a function for each error event.
+///
+/// # Example
+///
+/// ```ignore
+/// use rustapi_extras::error_reporting::CallbackErrorReporter;
+///
+/// let reporter = CallbackErrorReporter::new(|event| {
+///     eprintln!("captured: {} {}", event.method, event.path);
+/// });
+/// ```
+pub struct CallbackErrorReporter<F>
+where
+    F: Fn(&ErrorEvent) + Send + Sync + 'static,
+{
+    callback: Arc<F>,
+}
+
+impl<F> CallbackErrorReporter<F>
+where
+    F: Fn(&ErrorEvent) + Send + Sync + 'static,
+{
+    /// Create a new callback reporter.
+    pub fn new(callback: F) -> Self {
+        Self {
+            callback: Arc::new(callback),
+        }
+    }
+}
+
+impl<F> Clone for CallbackErrorReporter<F>
+where
+    F: Fn(&ErrorEvent) + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            callback: self.callback.clone(),
+        }
+    }
+}
+
+impl<F> ErrorReporter for CallbackErrorReporter<F>
+where
+    F: Fn(&ErrorEvent) + Send + Sync + 'static,
+{
+    fn report(&self, event: &ErrorEvent) {
+        (self.callback)(event);
+    }
+
+    fn clone_reporter(&self) -> Box<dyn ErrorReporter> {
+        Box::new(self.clone())
+    }
+}
+
+/// A composite reporter that fans out to multiple destinations.
+///
+/// # Example
+///
+/// ```ignore
+/// use rustapi_extras::error_reporting::{CompositeErrorReporter, TracingErrorReporter, SentryReporter};
+///
+/// let reporter = CompositeErrorReporter::new()
+///     .with_reporter(TracingErrorReporter::new())
+///     .with_reporter(SentryReporter::new("https://key@sentry.example.com/1"));
+/// ```
+#[derive(Default)]
+pub struct CompositeErrorReporter {
+    reporters: Vec<Box<dyn ErrorReporter>>,
+}
+
+impl Clone for CompositeErrorReporter {
+    fn clone(&self) -> Self {
+        let reporters = self.reporters.iter().map(|r| r.clone_reporter()).collect();
+        Self { reporters }
+    }
+}
+
+impl CompositeErrorReporter {
+    /// Create a new composite reporter.
+    pub fn new() -> Self {
+        Self {
+            reporters: Vec::new(),
+        }
+    }
+
+    /// Add a reporter to the composite.
+    pub fn with_reporter<R: ErrorReporter>(mut self, reporter: R) -> Self {
+        self.reporters.push(Box::new(reporter));
+        self
+    }
+}
+
+impl ErrorReporter for CompositeErrorReporter {
+    fn report(&self, event: &ErrorEvent) {
+        for reporter in &self.reporters {
+            reporter.report(event);
+        }
+    }
+
+    fn clone_reporter(&self) -> Box<dyn ErrorReporter> {
+        let reporters = self.reporters.iter().map(|r| r.clone_reporter()).collect();
+        Box::new(CompositeErrorReporter { reporters })
+    }
+}
+
+/// Reports error events to a Sentry (or Sentry-compatible) project via its
+/// HTTP store endpoint, parsed from a standard Sentry DSN
+/// (`https://<public_key>@<host>/<project_id>`).
+///
+/// Sending is a best-effort, fire-and-forget POST: a failed or slow delivery
+/// never delays or fails the request that triggered it. Requires the
+/// `webhook` feature for the actual HTTP client; without it, events are
+/// logged at debug level instead of sent.
+#[derive(Clone)]
+pub struct SentryReporter {
+    endpoint: String,
+    public_key: String,
+    #[cfg(feature = "webhook")]
+    client: reqwest::Client,
+}
+
+impl SentryReporter {
+    /// Create a new reporter from a Sentry DSN.
+    ///
+    /// Panics if `dsn` isn't a valid Sentry DSN URL, mirroring how other
+    /// layers in this crate fail fast on invalid configuration at startup.
+    pub fn new(dsn: impl AsRef<str>) -> Self {
+        let (endpoint, public_key) =
+            parse_dsn(dsn.as_ref()).expect("SentryReporter: invalid Sentry DSN");
+
+        Self {
+            endpoint,
+            public_key,
+            #[cfg(feature = "webhook")]
+            client: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(5))
+                .build()
+                .expect("Failed to build HTTP client"),
+        }
+    }
+
+    #[cfg(feature = "webhook")]
+    fn send(&self, event: &ErrorEvent) {
+        let client = self.client.clone();
+        let endpoint = self.endpoint.clone();
+        let auth_header = format!(
+            "Sentry sentry_version=7, sentry_client=rustapi/1.0, sentry_key={}",
+            self.public_key
+        );
+        let payload = serde_json::json!({
+            "message": event.message,
+            "level": "error",
+            "platform": "rust",
+            "tags": event.tags,
+            "extra": {
+                "request_id": event.request_id,
+                "method": event.method,
+                "path": event.path,
+                "status": event.status,
+                "user_id": event.user_id,
+                "kind": event.kind,
+            },
+            "timestamp": event.timestamp,
+        });
+
+        tokio::spawn(async move {
+            let result = client
+                .post(&endpoint)
+                .header("X-Sentry-Auth", auth_header)
+                .json(&payload)
+                .send()
+                .await;
+
+            if let Err(err) = result {
+                tracing::warn!(error = %err, "Failed to deliver event to Sentry");
+            }
+        });
+    }
+
+    #[cfg(not(feature = "webhook"))]
+    fn send(&self, event: &ErrorEvent) {
+        tracing::debug!(
+            endpoint = %self.endpoint,
+            public_key = %self.public_key,
+            message = %event.message,
+            "Would send event to Sentry (enable the 'webhook' feature for actual HTTP)"
+        );
+    }
+}
+
+impl ErrorReporter for SentryReporter {
+    fn report(&self, event: &ErrorEvent) {
+        self.send(event);
+    }
+
+    fn clone_reporter(&self) -> Box<dyn ErrorReporter> {
+        Box::new(self.clone())
+    }
+}
+
+/// Parse a Sentry DSN into its store endpoint and public key.
+///
+/// `https://<public_key>@<host>/<project_id>` becomes
+/// `https://<host>/api/<project_id>/store/`.
+fn parse_dsn(dsn: &str) -> Option<(String, String)> {
+    let after_scheme = dsn.split_once("://")?;
+    let scheme = after_scheme.0;
+    let rest = after_scheme.1;
+
+    let (credentials, host_and_path) = rest.split_once('@')?;
+    let public_key = credentials.split(':').next()?.to_string();
+    let (host, project_id) = host_and_path.split_once('/')?;
+
+    if public_key.is_empty() || host.is_empty() || project_id.is_empty() {
+        return None;
+    }
+
+    Some((
+        format!("{scheme}://{host}/api/{project_id}/store/"),
+        public_key,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_reporting::data::ErrorEventKind;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn tracing_reporter_reports_without_panicking() {
+        let reporter = TracingErrorReporter::new();
+        let event = ErrorEvent::new(ErrorEventKind::ServerError, "GET", "/users", "boom");
+        reporter.report(&event);
+    }
+
+    #[test]
+    fn callback_reporter_invokes_callback() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_clone = count.clone();
+
+        let reporter = CallbackErrorReporter::new(move |_event| {
+            count_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let event = ErrorEvent::new(ErrorEventKind::Panic, "GET", "/users", "boom");
+        reporter.report(&event);
+        reporter.report(&event);
+
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn composite_reporter_fans_out() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_clone = count.clone();
+
+        let composite = CompositeErrorReporter::new()
+            .with_reporter(TracingErrorReporter::new())
+            .with_reporter(CallbackErrorReporter::new(move |_| {
+                count_clone.fetch_add(1, Ordering::SeqCst);
+            }));
+
+        let event = ErrorEvent::new(ErrorEventKind::ServerError, "GET", "/users", "boom");
+        composite.report(&event);
+
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn parse_dsn_extracts_endpoint_and_key() {
+        let (endpoint, key) = parse_dsn("https://abc123@o1.ingest.sentry.io/42").unwrap();
+        assert_eq!(endpoint, "https://o1.ingest.sentry.io/api/42/store/");
+        assert_eq!(key, "abc123");
+    }
+
+    #[test]
+    fn parse_dsn_rejects_malformed_input() {
+        assert!(parse_dsn("not-a-dsn").is_none());
+        assert!(parse_dsn("https://o1.ingest.sentry.io/42").is_none());
+        assert!(parse_dsn("https://abc123@o1.ingest.sentry.io").is_none());
+    }
+
+    #[test]
+    fn sentry_reporter_parses_valid_dsn() {
+        let reporter = SentryReporter::new("https://abc123@o1.ingest.sentry.io/42");
+        assert_eq!(reporter.endpoint, "https://o1.ingest.sentry.io/api/42/store/");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid Sentry DSN")]
+    fn sentry_reporter_panics_on_invalid_dsn() {
+        SentryReporter::new("not-a-dsn");
+    }
+}
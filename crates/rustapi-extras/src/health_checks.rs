@@ -0,0 +1,170 @@
+//! Built-in [`HealthCheck`](rustapi_core::health::HealthCheck) providers for common dependencies
+//!
+//! These are check functions meant to be registered with a
+//! [`HealthCheckBuilder`](rustapi_core::health::HealthCheckBuilder), alongside
+//! any database checks from the `sqlx`/`diesel` modules:
+//!
+//! ```rust,ignore
+//! use rustapi_core::health::HealthCheckBuilder;
+//! use rustapi_extras::health_checks;
+//!
+//! let health = HealthCheckBuilder::new(false)
+//!     .add_check("redis", health_checks::redis(redis_client))
+//!     .add_check("payments-api", health_checks::http("https://payments.internal/health"))
+//!     .add_check("disk", health_checks::disk_space("/", 1024 * 1024 * 1024))
+//!     .add_check("memory", health_checks::memory(256 * 1024 * 1024))
+//!     .build();
+//! ```
+
+use rustapi_core::health::HealthStatus;
+use std::future::Future;
+use std::pin::Pin;
+
+/// A boxed, cloneable async check function accepted by [`HealthCheckBuilder::add_check`](rustapi_core::health::HealthCheckBuilder::add_check)
+type CheckFn = Pin<Box<dyn Future<Output = HealthStatus> + Send>>;
+
+/// Health check that pings a Redis server
+///
+/// Verifies connectivity by issuing a `PING` command over a fresh multiplexed connection.
+#[cfg(feature = "health-check-redis")]
+pub fn redis(
+    client: std::sync::Arc<redis::Client>,
+) -> impl Fn() -> CheckFn + Clone + Send + Sync + 'static {
+    move || {
+        let client = client.clone();
+        Box::pin(async move {
+            let mut conn = match client.get_multiplexed_async_connection().await {
+                Ok(conn) => conn,
+                Err(e) => return HealthStatus::unhealthy(format!("Redis connection failed: {e}")),
+            };
+
+            match redis::cmd("PING")
+                .query_async::<_, String>(&mut conn)
+                .await
+            {
+                Ok(_) => HealthStatus::healthy(),
+                Err(e) => HealthStatus::unhealthy(format!("Redis PING failed: {e}")),
+            }
+        })
+    }
+}
+
+/// Health check that verifies an outbound HTTP dependency responds with an expected status
+///
+/// Defaults to expecting `200 OK`; use [`http_expecting`] to check for a different status.
+#[cfg(feature = "health-check-http")]
+pub fn http(url: impl Into<String>) -> impl Fn() -> CheckFn + Clone + Send + Sync + 'static {
+    http_expecting(url, http::StatusCode::OK)
+}
+
+/// Like [`http`], but checks for a caller-supplied expected status code
+#[cfg(feature = "health-check-http")]
+pub fn http_expecting(
+    url: impl Into<String>,
+    expected_status: http::StatusCode,
+) -> impl Fn() -> CheckFn + Clone + Send + Sync + 'static {
+    let url = url.into();
+    move || {
+        let url = url.clone();
+        Box::pin(async move {
+            let response = match reqwest::get(&url).await {
+                Ok(response) => response,
+                Err(e) => return HealthStatus::unhealthy(format!("Request to {url} failed: {e}")),
+            };
+
+            if response.status() == expected_status {
+                HealthStatus::healthy()
+            } else {
+                HealthStatus::unhealthy(format!(
+                    "{url} returned {}, expected {expected_status}",
+                    response.status()
+                ))
+            }
+        })
+    }
+}
+
+/// Health check that verifies free disk space on the filesystem containing `path` is above `min_free_bytes`
+///
+/// Reports [`HealthStatus::degraded`](HealthStatus::degraded) rather than unhealthy, since low
+/// disk space is rarely an immediate outage.
+#[cfg(feature = "health-check-system")]
+pub fn disk_space(
+    path: impl Into<std::path::PathBuf>,
+    min_free_bytes: u64,
+) -> impl Fn() -> CheckFn + Clone + Send + Sync + 'static {
+    let path = path.into();
+    move || {
+        let path = path.clone();
+        Box::pin(async move {
+            let disks = sysinfo::Disks::new_with_refreshed_list();
+            let disk = disks
+                .list()
+                .iter()
+                .filter(|disk| path.starts_with(disk.mount_point()))
+                .max_by_key(|disk| disk.mount_point().as_os_str().len());
+
+            match disk {
+                Some(disk) if disk.available_space() >= min_free_bytes => HealthStatus::healthy(),
+                Some(disk) => HealthStatus::degraded(format!(
+                    "only {} bytes free on {}, expected at least {min_free_bytes}",
+                    disk.available_space(),
+                    disk.mount_point().display()
+                )),
+                None => HealthStatus::unhealthy(format!(
+                    "could not find a mounted filesystem for {}",
+                    path.display()
+                )),
+            }
+        })
+    }
+}
+
+/// Health check that verifies available system memory is above `min_free_bytes`
+///
+/// Reports [`HealthStatus::degraded`](HealthStatus::degraded) rather than unhealthy, since low
+/// memory is rarely an immediate outage.
+#[cfg(feature = "health-check-system")]
+pub fn memory(min_free_bytes: u64) -> impl Fn() -> CheckFn + Clone + Send + Sync + 'static {
+    move || {
+        Box::pin(async move {
+            let mut system = sysinfo::System::new();
+            system.refresh_memory();
+            let available = system.available_memory();
+
+            if available >= min_free_bytes {
+                HealthStatus::healthy()
+            } else {
+                HealthStatus::degraded(format!(
+                    "only {available} bytes of memory available, expected at least {min_free_bytes}"
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(all(test, feature = "health-check-system"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn memory_check_healthy_when_threshold_trivially_met() {
+        let check = memory(0);
+        assert!(check().await.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn memory_check_degraded_when_threshold_impossible() {
+        let check = memory(u64::MAX);
+        assert!(check().await.is_degraded());
+    }
+
+    #[tokio::test]
+    async fn disk_space_check_not_degraded_when_threshold_is_zero() {
+        let check = disk_space("/", 0);
+        assert!(matches!(
+            check().await,
+            HealthStatus::Healthy | HealthStatus::Unhealthy { .. }
+        ));
+    }
+}
@@ -41,27 +41,27 @@ pub struct InsightData {
     pub client_ip: String,
 
     /// Captured request headers (based on whitelist)
-    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub request_headers: HashMap<String, String>,
 
     /// Captured response headers (based on whitelist)
-    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub response_headers: HashMap<String, String>,
 
     /// Request body (if capture enabled and within size limit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub request_body: Option<String>,
 
     /// Response body (if capture enabled and within size limit)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response_body: Option<String>,
 
     /// Route pattern that matched (e.g., "/users/{id}")
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub route_pattern: Option<String>,
 
     /// Custom tags/labels for categorization
-    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub tags: HashMap<String, String>,
 }
 
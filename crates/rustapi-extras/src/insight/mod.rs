@@ -146,8 +146,16 @@ mod data;
 pub mod export;
 mod layer;
 mod store;
+#[cfg(feature = "insight-redis")]
+mod store_redis;
+#[cfg(feature = "insight-sqlite")]
+mod store_sqlite;
 
 pub use config::InsightConfig;
 pub use data::{InsightData, InsightStats};
 pub use layer::InsightLayer;
-pub use store::{InMemoryInsightStore, InsightStore, NullInsightStore};
+pub use store::{InMemoryInsightStore, InsightStore, NullInsightStore, RetentionPolicy};
+#[cfg(feature = "insight-redis")]
+pub use store_redis::RedisInsightStore;
+#[cfg(feature = "insight-sqlite")]
+pub use store_sqlite::SqliteInsightStore;
@@ -7,6 +7,7 @@ use super::data::{InsightData, InsightStats};
 use dashmap::DashMap;
 use std::collections::VecDeque;
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Trait for storing and retrieving insight data.
 ///
@@ -30,6 +31,21 @@ pub trait InsightStore: Send + Sync + 'static {
     /// Get aggregated statistics.
     fn get_stats(&self) -> InsightStats;
 
+    /// Get aggregated statistics for insights within the last `window`, relative to now.
+    ///
+    /// The default implementation filters [`InsightStore::get_all`] client-side; backends
+    /// that persist a timestamp index (e.g. a SQL or Redis store) may override this to push
+    /// the range filter down to the query instead.
+    fn get_stats_window(&self, window: Duration) -> InsightStats {
+        let cutoff = current_timestamp().saturating_sub(window.as_secs());
+        let recent: Vec<InsightData> = self
+            .get_all()
+            .into_iter()
+            .filter(|insight| insight.timestamp >= cutoff)
+            .collect();
+        InsightStats::from_insights(&recent)
+    }
+
     /// Clear all stored insights.
     fn clear(&self);
 
@@ -40,6 +56,50 @@ pub trait InsightStore: Send + Sync + 'static {
     fn clone_store(&self) -> Box<dyn InsightStore>;
 }
 
+/// Retention policy applied by persistent [`InsightStore`] backends.
+///
+/// Unlike [`InMemoryInsightStore`], which is bounded purely by its ring buffer
+/// capacity, persistent backends (SQLite, Redis) keep growing until something
+/// prunes them, so they accept a policy describing when to drop old entries.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RetentionPolicy {
+    /// Drop entries older than this, relative to their timestamp. `None` means unbounded.
+    pub max_age: Option<Duration>,
+    /// Keep at most this many entries, evicting the oldest first. `None` means unbounded.
+    pub max_entries: Option<usize>,
+}
+
+impl RetentionPolicy {
+    /// No retention limits; entries are kept forever.
+    pub fn unbounded() -> Self {
+        Self::default()
+    }
+
+    /// Drop entries older than `max_age`.
+    pub fn max_age(max_age: Duration) -> Self {
+        Self {
+            max_age: Some(max_age),
+            max_entries: None,
+        }
+    }
+
+    /// Keep at most `max_entries`, evicting the oldest first.
+    pub fn max_entries(max_entries: usize) -> Self {
+        Self {
+            max_age: None,
+            max_entries: Some(max_entries),
+        }
+    }
+}
+
+/// Current Unix timestamp in seconds.
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
 /// In-memory insight store using a ring buffer.
 ///
 /// This store keeps the most recent N insights in memory with thread-safe access.
@@ -336,6 +396,33 @@ mod tests {
         assert_eq!(stats.client_errors, 1);
     }
 
+    #[test]
+    fn test_stats_window_excludes_old_entries() {
+        let store = InMemoryInsightStore::new(10);
+
+        let mut old = create_test_insight("1", "/users", 200);
+        old.timestamp = 0;
+        store.store(old);
+        store.store(create_test_insight("2", "/users", 200));
+
+        let stats = store.get_stats_window(Duration::from_secs(3600));
+        assert_eq!(stats.total_requests, 1);
+    }
+
+    #[test]
+    fn test_retention_policy_constructors() {
+        assert_eq!(RetentionPolicy::unbounded().max_age, None);
+        assert_eq!(RetentionPolicy::unbounded().max_entries, None);
+
+        let age = RetentionPolicy::max_age(Duration::from_secs(60));
+        assert_eq!(age.max_age, Some(Duration::from_secs(60)));
+        assert_eq!(age.max_entries, None);
+
+        let entries = RetentionPolicy::max_entries(50);
+        assert_eq!(entries.max_entries, Some(50));
+        assert_eq!(entries.max_age, None);
+    }
+
     #[test]
     fn test_null_store() {
         let store = NullInsightStore;
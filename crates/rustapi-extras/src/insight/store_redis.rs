@@ -0,0 +1,216 @@
+//! Redis-backed [`InsightStore`], so traffic insight survives restarts and can
+//! be shared across multiple app instances.
+//!
+//! Entries are indexed in a sorted set keyed by timestamp (`{prefix}:index`),
+//! with the JSON payloads kept in a companion hash (`{prefix}:data`). The
+//! sorted set makes both count-based and age-based retention, as well as
+//! [`InsightStore::get_stats_window`], simple range operations.
+
+use super::data::{InsightData, InsightStats};
+use super::store::{InsightStore, RetentionPolicy};
+use redis::Commands;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Redis-backed insight store.
+///
+/// # Example
+///
+/// ```ignore
+/// use rustapi_extras::insight::{RedisInsightStore, RetentionPolicy};
+/// use std::time::Duration;
+///
+/// let store = RedisInsightStore::new(
+///     "redis://127.0.0.1/",
+///     RetentionPolicy::max_age(Duration::from_secs(24 * 3600)),
+/// )?;
+/// ```
+#[derive(Clone)]
+pub struct RedisInsightStore {
+    client: redis::Client,
+    prefix: String,
+    retention: RetentionPolicy,
+}
+
+impl RedisInsightStore {
+    /// Connect to Redis at `url`, using the default key prefix `rustapi:insight`.
+    pub fn new(url: &str, retention: RetentionPolicy) -> redis::RedisResult<Self> {
+        Self::with_prefix(url, "rustapi:insight", retention)
+    }
+
+    /// Connect to Redis at `url`, namespacing keys under a custom `prefix`.
+    pub fn with_prefix(
+        url: &str,
+        prefix: impl Into<String>,
+        retention: RetentionPolicy,
+    ) -> redis::RedisResult<Self> {
+        Ok(Self {
+            client: redis::Client::open(url)?,
+            prefix: prefix.into(),
+            retention,
+        })
+    }
+
+    fn index_key(&self) -> String {
+        format!("{}:index", self.prefix)
+    }
+
+    fn data_key(&self) -> String {
+        format!("{}:data", self.prefix)
+    }
+
+    fn enforce_retention(&self, conn: &mut redis::Connection) -> redis::RedisResult<()> {
+        let index_key = self.index_key();
+        let data_key = self.data_key();
+
+        if let Some(max_age) = self.retention.max_age {
+            let cutoff = current_timestamp().saturating_sub(max_age.as_secs());
+            let expired: Vec<String> = conn.zrangebyscore(&index_key, 0, cutoff)?;
+            if !expired.is_empty() {
+                conn.zrembyscore::<_, _, _, ()>(&index_key, 0, cutoff)?;
+                conn.hdel::<_, _, ()>(&data_key, &expired)?;
+            }
+        }
+
+        if let Some(max_entries) = self.retention.max_entries {
+            let total: usize = conn.zcard(&index_key)?;
+            if total > max_entries {
+                let overflow = total - max_entries;
+                let stale: Vec<String> = conn.zrange(&index_key, 0, overflow as isize - 1)?;
+                if !stale.is_empty() {
+                    conn.zremrangebyrank::<_, ()>(&index_key, 0, overflow as isize - 1)?;
+                    conn.hdel::<_, _, ()>(&data_key, &stale)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn insights_for_ids(conn: &mut redis::Connection, data_key: &str, ids: &[String]) -> Vec<InsightData> {
+        if ids.is_empty() {
+            return Vec::new();
+        }
+
+        let payloads: Vec<Option<String>> = conn.hget(data_key, ids).unwrap_or_default();
+        payloads
+            .into_iter()
+            .filter_map(|payload| payload.and_then(|json| serde_json::from_str(&json).ok()))
+            .collect()
+    }
+}
+
+impl InsightStore for RedisInsightStore {
+    fn store(&self, insight: InsightData) {
+        let Ok(mut conn) = self.client.get_connection() else {
+            return;
+        };
+
+        let Ok(json) = serde_json::to_string(&insight) else {
+            return;
+        };
+
+        let index_key = self.index_key();
+        let data_key = self.data_key();
+
+        let _: redis::RedisResult<()> = conn.hset(&data_key, &insight.request_id, json);
+        let _: redis::RedisResult<()> =
+            conn.zadd(&index_key, &insight.request_id, insight.timestamp as f64);
+
+        let _ = self.enforce_retention(&mut conn);
+    }
+
+    fn get_recent(&self, limit: usize) -> Vec<InsightData> {
+        let Ok(mut conn) = self.client.get_connection() else {
+            return Vec::new();
+        };
+
+        let ids: Vec<String> = conn
+            .zrevrange(self.index_key(), 0, limit as isize - 1)
+            .unwrap_or_default();
+        Self::insights_for_ids(&mut conn, &self.data_key(), &ids)
+    }
+
+    fn get_all(&self) -> Vec<InsightData> {
+        let Ok(mut conn) = self.client.get_connection() else {
+            return Vec::new();
+        };
+
+        let ids: Vec<String> = conn.zrevrange(self.index_key(), 0, -1).unwrap_or_default();
+        Self::insights_for_ids(&mut conn, &self.data_key(), &ids)
+    }
+
+    fn get_by_path(&self, path_pattern: &str) -> Vec<InsightData> {
+        self.get_all()
+            .into_iter()
+            .filter(|i| i.path.contains(path_pattern))
+            .collect()
+    }
+
+    fn get_by_status(&self, min_status: u16, max_status: u16) -> Vec<InsightData> {
+        self.get_all()
+            .into_iter()
+            .filter(|i| i.status >= min_status && i.status <= max_status)
+            .collect()
+    }
+
+    fn get_stats(&self) -> InsightStats {
+        InsightStats::from_insights(&self.get_all())
+    }
+
+    fn get_stats_window(&self, window: Duration) -> InsightStats {
+        let Ok(mut conn) = self.client.get_connection() else {
+            return InsightStats::default();
+        };
+
+        let cutoff = current_timestamp().saturating_sub(window.as_secs());
+        let ids: Vec<String> = conn
+            .zrangebyscore(self.index_key(), cutoff, "+inf")
+            .unwrap_or_default();
+        let insights = Self::insights_for_ids(&mut conn, &self.data_key(), &ids);
+        InsightStats::from_insights(&insights)
+    }
+
+    fn clear(&self) {
+        if let Ok(mut conn) = self.client.get_connection() {
+            let _: redis::RedisResult<()> = conn.del(&[self.index_key(), self.data_key()]);
+        }
+    }
+
+    fn count(&self) -> usize {
+        let Ok(mut conn) = self.client.get_connection() else {
+            return 0;
+        };
+        conn.zcard(self.index_key()).unwrap_or(0)
+    }
+
+    fn clone_store(&self) -> Box<dyn InsightStore> {
+        Box::new(self.clone())
+    }
+}
+
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These exercise connection-failure fallbacks without requiring a live Redis
+    // server; behavior against a real server is covered by the crate's manual
+    // integration testing since CI doesn't provision Redis for extras tests.
+    #[test]
+    fn test_unreachable_redis_degrades_gracefully() {
+        let store =
+            RedisInsightStore::new("redis://127.0.0.1:1", RetentionPolicy::unbounded()).unwrap();
+
+        store.store(InsightData::new("1", "GET", "/users"));
+
+        assert_eq!(store.count(), 0);
+        assert!(store.get_all().is_empty());
+        assert!(store.get_recent(10).is_empty());
+    }
+}
@@ -0,0 +1,259 @@
+//! SQLite-backed [`InsightStore`], so traffic insight survives restarts.
+//!
+//! Reads and writes go through a single shared [`rusqlite::Connection`] guarded
+//! by a mutex; SQLite itself serializes writers, so this doesn't cost more
+//! contention than the connection already implies.
+
+use super::data::{InsightData, InsightStats};
+use super::store::{InsightStore, RetentionPolicy};
+use rusqlite::{params, Connection};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// SQLite-backed insight store.
+///
+/// # Example
+///
+/// ```ignore
+/// use rustapi_extras::insight::{SqliteInsightStore, RetentionPolicy};
+/// use std::time::Duration;
+///
+/// let store = SqliteInsightStore::open(
+///     "insights.db",
+///     RetentionPolicy::max_age(Duration::from_secs(7 * 24 * 3600)),
+/// )?;
+/// ```
+#[derive(Clone)]
+pub struct SqliteInsightStore {
+    conn: Arc<Mutex<Connection>>,
+    retention: RetentionPolicy,
+}
+
+impl SqliteInsightStore {
+    /// Open (or create) a SQLite database file at `path`.
+    pub fn open(path: impl AsRef<Path>, retention: RetentionPolicy) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        Self::from_connection(conn, retention)
+    }
+
+    /// Open an in-memory SQLite database. Useful for tests, or short-lived processes
+    /// that still want the SQL query surface without persistence to disk.
+    pub fn in_memory(retention: RetentionPolicy) -> rusqlite::Result<Self> {
+        let conn = Connection::open_in_memory()?;
+        Self::from_connection(conn, retention)
+    }
+
+    fn from_connection(conn: Connection, retention: RetentionPolicy) -> rusqlite::Result<Self> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS insights (
+                request_id TEXT PRIMARY KEY,
+                timestamp  INTEGER NOT NULL,
+                data       TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_insights_timestamp ON insights(timestamp);",
+        )?;
+
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+            retention,
+        })
+    }
+
+    fn enforce_retention(&self, conn: &Connection) {
+        if let Some(max_age) = self.retention.max_age {
+            let cutoff = current_timestamp().saturating_sub(max_age.as_secs()) as i64;
+            let _ = conn.execute("DELETE FROM insights WHERE timestamp < ?1", params![cutoff]);
+        }
+
+        if let Some(max_entries) = self.retention.max_entries {
+            let _ = conn.execute(
+                "DELETE FROM insights WHERE request_id NOT IN (
+                    SELECT request_id FROM insights ORDER BY timestamp DESC LIMIT ?1
+                )",
+                params![max_entries as i64],
+            );
+        }
+    }
+
+    fn rows_to_insights(rows: rusqlite::Result<Vec<String>>) -> Vec<InsightData> {
+        rows.unwrap_or_default()
+            .into_iter()
+            .filter_map(|json| serde_json::from_str(&json).ok())
+            .collect()
+    }
+
+    fn select_all(&self, query: &str) -> rusqlite::Result<Vec<String>> {
+        let conn = self.conn.lock().map_err(|_| rusqlite::Error::InvalidQuery)?;
+        let mut stmt = conn.prepare(query)?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(rows)
+    }
+}
+
+impl InsightStore for SqliteInsightStore {
+    fn store(&self, insight: InsightData) {
+        let Ok(conn) = self.conn.lock() else {
+            return;
+        };
+
+        let Ok(json) = serde_json::to_string(&insight) else {
+            return;
+        };
+
+        let _ = conn.execute(
+            "INSERT OR REPLACE INTO insights (request_id, timestamp, data) VALUES (?1, ?2, ?3)",
+            params![insight.request_id, insight.timestamp as i64, json],
+        );
+
+        self.enforce_retention(&conn);
+    }
+
+    fn get_recent(&self, limit: usize) -> Vec<InsightData> {
+        Self::rows_to_insights(self.select_all(&format!(
+            "SELECT data FROM insights ORDER BY timestamp DESC LIMIT {limit}"
+        )))
+    }
+
+    fn get_all(&self) -> Vec<InsightData> {
+        Self::rows_to_insights(self.select_all("SELECT data FROM insights ORDER BY timestamp DESC"))
+    }
+
+    fn get_by_path(&self, path_pattern: &str) -> Vec<InsightData> {
+        self.get_all()
+            .into_iter()
+            .filter(|i| i.path.contains(path_pattern))
+            .collect()
+    }
+
+    fn get_by_status(&self, min_status: u16, max_status: u16) -> Vec<InsightData> {
+        self.get_all()
+            .into_iter()
+            .filter(|i| i.status >= min_status && i.status <= max_status)
+            .collect()
+    }
+
+    fn get_stats(&self) -> InsightStats {
+        InsightStats::from_insights(&self.get_all())
+    }
+
+    fn get_stats_window(&self, window: Duration) -> InsightStats {
+        let cutoff = current_timestamp().saturating_sub(window.as_secs()) as i64;
+        let rows = self.select_all(&format!(
+            "SELECT data FROM insights WHERE timestamp >= {cutoff} ORDER BY timestamp DESC"
+        ));
+        InsightStats::from_insights(&Self::rows_to_insights(rows))
+    }
+
+    fn clear(&self) {
+        if let Ok(conn) = self.conn.lock() {
+            let _ = conn.execute("DELETE FROM insights", []);
+        }
+    }
+
+    fn count(&self) -> usize {
+        let Ok(conn) = self.conn.lock() else {
+            return 0;
+        };
+        conn.query_row("SELECT COUNT(*) FROM insights", [], |row| row.get(0))
+            .unwrap_or(0)
+    }
+
+    fn clone_store(&self) -> Box<dyn InsightStore> {
+        Box::new(self.clone())
+    }
+}
+
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration as StdDuration;
+
+    fn insight(id: &str, path: &str, status: u16, timestamp: u64) -> InsightData {
+        let mut insight = InsightData::new(id, "GET", path).with_status(status);
+        insight.timestamp = timestamp;
+        insight
+    }
+
+    #[test]
+    fn test_store_and_retrieve() {
+        let store = SqliteInsightStore::in_memory(RetentionPolicy::unbounded()).unwrap();
+
+        store.store(insight("1", "/users", 200, 100));
+        store.store(insight("2", "/items", 404, 200));
+
+        assert_eq!(store.count(), 2);
+        let recent = store.get_recent(10);
+        assert_eq!(recent[0].request_id, "2");
+        assert_eq!(recent[1].request_id, "1");
+    }
+
+    #[test]
+    fn test_survives_reopen_of_same_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("insights.db");
+
+        {
+            let store = SqliteInsightStore::open(&path, RetentionPolicy::unbounded()).unwrap();
+            store.store(insight("1", "/users", 200, 100));
+        }
+
+        let reopened = SqliteInsightStore::open(&path, RetentionPolicy::unbounded()).unwrap();
+        assert_eq!(reopened.count(), 1);
+    }
+
+    #[test]
+    fn test_max_entries_retention_evicts_oldest() {
+        let store = SqliteInsightStore::in_memory(RetentionPolicy::max_entries(2)).unwrap();
+
+        store.store(insight("1", "/a", 200, 100));
+        store.store(insight("2", "/b", 200, 200));
+        store.store(insight("3", "/c", 200, 300));
+
+        assert_eq!(store.count(), 2);
+        let ids: Vec<_> = store.get_all().into_iter().map(|i| i.request_id).collect();
+        assert!(!ids.contains(&"1".to_string()));
+    }
+
+    #[test]
+    fn test_max_age_retention_evicts_old_entries() {
+        let store =
+            SqliteInsightStore::in_memory(RetentionPolicy::max_age(StdDuration::from_secs(10)))
+                .unwrap();
+
+        store.store(insight("old", "/a", 200, 0));
+        store.store(insight("new", "/b", 200, current_timestamp()));
+
+        let ids: Vec<_> = store.get_all().into_iter().map(|i| i.request_id).collect();
+        assert!(!ids.contains(&"old".to_string()));
+        assert!(ids.contains(&"new".to_string()));
+    }
+
+    #[test]
+    fn test_get_stats_window() {
+        let store = SqliteInsightStore::in_memory(RetentionPolicy::unbounded()).unwrap();
+
+        store.store(insight("old", "/a", 200, 0));
+        store.store(insight("new", "/b", 200, current_timestamp()));
+
+        let stats = store.get_stats_window(StdDuration::from_secs(3600));
+        assert_eq!(stats.total_requests, 1);
+    }
+
+    #[test]
+    fn test_clear() {
+        let store = SqliteInsightStore::in_memory(RetentionPolicy::unbounded()).unwrap();
+        store.store(insight("1", "/a", 200, 100));
+        store.clear();
+        assert_eq!(store.count(), 0);
+    }
+}
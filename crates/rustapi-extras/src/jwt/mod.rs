@@ -1,7 +1,10 @@
 //! JWT authentication middleware and extractors.
 //!
 //! This module provides JWT token validation middleware and the `AuthUser<T>`
-//! extractor for accessing decoded claims in handlers.
+//! extractor for accessing decoded claims in handlers, plus two variants for
+//! mixed public/private endpoints: `OptionalAuthUser<T>` (`None` instead of a
+//! 401 when there's no token) and `Scopes<T>`/`RequireScope<T, S>` for
+//! validating an OAuth2-style scopes claim.
 //!
 //! # Example
 //!
@@ -209,9 +212,8 @@ impl<T: DeserializeOwned + Clone + Send + Sync + 'static> MiddlewareLayer for Jw
 
             match decode::<T>(&token, &decoding_key, &jwt_validation) {
                 Ok(token_data) => {
-                    // Store the validated claims in request extensions
-                    req.extensions_mut()
-                        .insert(ValidatedClaims(token_data.claims));
+                    // Store the validated claims as a request-local value
+                    req.set_local(ValidatedClaims(token_data.claims));
 
                     // Continue to the next handler
                     next(req).await
@@ -358,6 +360,189 @@ impl<T> OperationModifier for AuthUser<T> {
     }
 }
 
+/// Like [`AuthUser`], but extracts `None` instead of failing with 401 when no
+/// token was validated for this request.
+///
+/// Useful for handlers that behave differently for authenticated and
+/// anonymous callers (e.g. personalizing a public listing) without needing
+/// separate authenticated/public route trees.
+///
+/// # Example
+///
+/// ```ignore
+/// use rustapi_extras::jwt::OptionalAuthUser;
+///
+/// async fn listing(OptionalAuthUser(claims): OptionalAuthUser<Claims>) -> String {
+///     match claims {
+///         Some(claims) => format!("Hello, {}", claims.sub),
+///         None => "Hello, anonymous".to_string(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OptionalAuthUser<T>(pub Option<T>);
+
+impl<T: Clone + Send + Sync + 'static> FromRequestParts for OptionalAuthUser<T> {
+    fn from_request_parts(req: &Request) -> Result<Self> {
+        Ok(OptionalAuthUser(
+            req.extensions()
+                .get::<ValidatedClaims<T>>()
+                .map(|claims| claims.0.clone()),
+        ))
+    }
+}
+
+impl<T> OperationModifier for OptionalAuthUser<T> {
+    fn update_operation(_op: &mut Operation) {
+        // Unlike AuthUser, a missing or invalid token isn't an error here,
+        // so there's no extra response to document.
+    }
+}
+
+/// Claims types that carry OAuth2-style scopes, used by [`Scopes`] and
+/// [`RequireScope`]. Implement this however your tokens encode scopes (a
+/// space-separated `scope` string, a `scopes` array, a custom claim name) -
+/// this crate doesn't assume a shape.
+///
+/// # Example
+///
+/// ```ignore
+/// use rustapi_extras::jwt::ScopeClaims;
+///
+/// #[derive(Deserialize, Clone)]
+/// struct Claims {
+///     sub: String,
+///     scope: String,
+/// }
+///
+/// impl ScopeClaims for Claims {
+///     fn scopes(&self) -> Vec<String> {
+///         self.scope.split(' ').map(str::to_string).collect()
+///     }
+/// }
+/// ```
+pub trait ScopeClaims {
+    /// The scopes granted to this token.
+    fn scopes(&self) -> Vec<String>;
+}
+
+/// The scopes granted to the authenticated user's token, or an empty list if
+/// the request has no validated token.
+///
+/// Like [`OptionalAuthUser`], this never fails on its own - use
+/// [`RequireScope`] to reject requests missing a specific scope.
+#[derive(Debug, Clone)]
+pub struct Scopes<T>(pub Vec<String>, PhantomData<T>);
+
+impl<T: ScopeClaims + Clone + Send + Sync + 'static> FromRequestParts for Scopes<T> {
+    fn from_request_parts(req: &Request) -> Result<Self> {
+        let scopes = req
+            .extensions()
+            .get::<ValidatedClaims<T>>()
+            .map(|claims| claims.0.scopes())
+            .unwrap_or_default();
+        Ok(Scopes(scopes, PhantomData))
+    }
+}
+
+impl<T> OperationModifier for Scopes<T> {
+    fn update_operation(_op: &mut Operation) {}
+}
+
+/// A single OAuth2-style scope, checked by [`RequireScope`].
+///
+/// Define one implementation per scope your API needs:
+///
+/// ```ignore
+/// use rustapi_extras::jwt::ScopeMarker;
+///
+/// struct UsersWrite;
+/// impl ScopeMarker for UsersWrite {
+///     const SCOPE: &'static str = "users:write";
+/// }
+/// ```
+pub trait ScopeMarker {
+    /// The scope string this marker requires.
+    const SCOPE: &'static str;
+}
+
+/// Rejects the request with 403 Forbidden unless the authenticated user's
+/// token carries `S::SCOPE`. Requires a validated token - a missing or
+/// invalid token is still a 401 from the underlying [`AuthUser`] check, not a
+/// 403.
+///
+/// # Example
+///
+/// ```ignore
+/// use rustapi_extras::jwt::{RequireScope, ScopeMarker};
+///
+/// struct UsersWrite;
+/// impl ScopeMarker for UsersWrite {
+///     const SCOPE: &'static str = "users:write";
+/// }
+///
+/// async fn delete_user(
+///     _scope: RequireScope<Claims, UsersWrite>,
+///     Path(id): Path<i64>,
+/// ) -> Result<()> {
+///     // ...
+/// }
+/// ```
+pub struct RequireScope<T, S>(PhantomData<(T, S)>);
+
+impl<T, S> std::fmt::Debug for RequireScope<T, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequireScope").finish()
+    }
+}
+
+impl<T, S> FromRequestParts for RequireScope<T, S>
+where
+    T: ScopeClaims + Clone + Send + Sync + 'static,
+    S: ScopeMarker,
+{
+    fn from_request_parts(req: &Request) -> Result<Self> {
+        let AuthUser(claims) = AuthUser::<T>::from_request_parts(req)?;
+        if claims.scopes().iter().any(|scope| scope == S::SCOPE) {
+            Ok(RequireScope(PhantomData))
+        } else {
+            Err(ApiError::forbidden(format!(
+                "Missing required scope: {}",
+                S::SCOPE
+            )))
+        }
+    }
+}
+
+impl<T, S: ScopeMarker> OperationModifier for RequireScope<T, S> {
+    fn update_operation(op: &mut Operation) {
+        // A missing token is still a 401 (AuthUser's check runs first).
+        AuthUser::<T>::update_operation(op);
+
+        use rustapi_openapi::{MediaType, ResponseSpec, SchemaRef};
+        use std::collections::HashMap;
+
+        op.responses.insert(
+            "403".to_string(),
+            ResponseSpec {
+                description: format!("Forbidden - missing required scope: {}", S::SCOPE),
+                content: {
+                    let mut map = HashMap::new();
+                    map.insert(
+                        "application/json".to_string(),
+                        MediaType {
+                            schema: SchemaRef::Ref {
+                                reference: "#/components/schemas/ErrorSchema".to_string(),
+                            },
+                        },
+                    );
+                    Some(map)
+                },
+            },
+        );
+    }
+}
+
 /// Helper function to create a JWT token (useful for testing)
 ///
 /// # Example
@@ -835,4 +1020,116 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    /// Test claims that also carry a scopes claim, for the Scopes/RequireScope tests
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct TestScopedClaims {
+        sub: String,
+        exp: u64,
+        scope: String,
+    }
+
+    impl ScopeClaims for TestScopedClaims {
+        fn scopes(&self) -> Vec<String> {
+            self.scope.split(' ').map(str::to_string).collect()
+        }
+    }
+
+    struct UsersWrite;
+    impl ScopeMarker for UsersWrite {
+        const SCOPE: &'static str = "users:write";
+    }
+
+    #[test]
+    fn test_optional_auth_user_without_middleware() {
+        let request = create_test_request(None);
+        let result = OptionalAuthUser::<TestClaims>::from_request_parts(&request);
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().0.is_none());
+    }
+
+    #[test]
+    fn test_optional_auth_user_with_validated_claims() {
+        let claims = TestClaims {
+            sub: "user123".to_string(),
+            exp: future_timestamp(3600),
+            custom_field: None,
+        };
+
+        let mut request = create_test_request(None);
+        request.set_local(ValidatedClaims(claims.clone()));
+
+        let result = OptionalAuthUser::<TestClaims>::from_request_parts(&request);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().0, Some(claims));
+    }
+
+    #[test]
+    fn test_scopes_without_validated_claims_is_empty() {
+        let request = create_test_request(None);
+        let result = Scopes::<TestScopedClaims>::from_request_parts(&request);
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn test_scopes_from_validated_claims() {
+        let claims = TestScopedClaims {
+            sub: "user123".to_string(),
+            exp: future_timestamp(3600),
+            scope: "users:read users:write".to_string(),
+        };
+
+        let mut request = create_test_request(None);
+        request.set_local(ValidatedClaims(claims));
+
+        let result = Scopes::<TestScopedClaims>::from_request_parts(&request);
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap().0,
+            vec!["users:read".to_string(), "users:write".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_require_scope_without_token_is_unauthorized() {
+        let request = create_test_request(None);
+        let result = RequireScope::<TestScopedClaims, UsersWrite>::from_request_parts(&request);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn test_require_scope_missing_scope_is_forbidden() {
+        let claims = TestScopedClaims {
+            sub: "user123".to_string(),
+            exp: future_timestamp(3600),
+            scope: "users:read".to_string(),
+        };
+
+        let mut request = create_test_request(None);
+        request.set_local(ValidatedClaims(claims));
+
+        let result = RequireScope::<TestScopedClaims, UsersWrite>::from_request_parts(&request);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().status, StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_require_scope_with_matching_scope_succeeds() {
+        let claims = TestScopedClaims {
+            sub: "user123".to_string(),
+            exp: future_timestamp(3600),
+            scope: "users:read users:write".to_string(),
+        };
+
+        let mut request = create_test_request(None);
+        request.set_local(ValidatedClaims(claims));
+
+        let result = RequireScope::<TestScopedClaims, UsersWrite>::from_request_parts(&request);
+        assert!(result.is_ok());
+    }
 }
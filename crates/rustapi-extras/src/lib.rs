@@ -13,7 +13,15 @@
 //! - `config` - Configuration management with `.env` file support
 //! - `cookies` - Cookie parsing extractor
 //! - `sqlx` - SQLx database error conversion to ApiError
+//! - `mongodb` - MongoDB error conversion, client builder, and health check
 //! - `insight` - Traffic insight middleware for analytics and debugging
+//! - `insight-sqlite` - Persistent SQLite backend for traffic insight
+//! - `insight-redis` - Persistent Redis backend for traffic insight
+//! - `health-check-redis` - Redis ping health check provider
+//! - `health-check-http` - Outbound HTTP dependency health check provider
+//! - `health-check-system` - Disk space and memory health check providers
+//! - `redis-pool` - Redis connection pool builder, health check, and `Redis` extractor
+//! - `llm-guard` - LLM guardrail middleware (prompt-injection / secret / PII scanning)
 //! - `extras` - Meta feature enabling jwt, cors, and rate-limit
 //! - `full` - All features enabled
 //!
@@ -51,6 +59,10 @@ pub mod sqlx;
 #[cfg(feature = "diesel")]
 pub mod diesel;
 
+// MongoDB database integration module
+#[cfg(feature = "mongodb")]
+pub mod mongodb;
+
 // Traffic insight module
 #[cfg(feature = "insight")]
 pub mod insight;
@@ -67,6 +79,10 @@ pub mod guard;
 #[cfg(feature = "logging")]
 pub mod logging;
 
+// Error reporting middleware (5xx responses, panics) with pluggable reporters
+#[cfg(feature = "error-reporting")]
+pub mod error_reporting;
+
 // Circuit breaker middleware
 #[cfg(feature = "circuit-breaker")]
 pub mod circuit_breaker;
@@ -91,6 +107,10 @@ pub mod security_headers;
 #[cfg(feature = "api-key")]
 pub mod api_key;
 
+// LLM guardrail middleware
+#[cfg(feature = "llm-guard")]
+pub mod llm_guard;
+
 // Response caching
 #[cfg(feature = "cache")]
 pub mod cache;
@@ -103,9 +123,28 @@ pub mod otel;
 #[cfg(feature = "structured-logging")]
 pub mod structured_logging;
 
+// Health check providers for common dependencies (Redis, HTTP, disk, memory)
+#[cfg(any(
+    feature = "health-check-redis",
+    feature = "health-check-http",
+    feature = "health-check-system"
+))]
+pub mod health_checks;
+
+// Object storage: `ObjectStore` trait with local-disk, S3 and GCS backends
+#[cfg(feature = "storage")]
+pub mod storage;
+
+// Redis connection pool: pool builder, health check, and a `Redis` extractor
+#[cfg(feature = "redis-pool")]
+pub mod redis;
+
 // Re-exports for convenience
 #[cfg(feature = "jwt")]
-pub use jwt::{create_token, AuthUser, JwtError, JwtLayer, JwtValidation, ValidatedClaims};
+pub use jwt::{
+    create_token, AuthUser, JwtError, JwtLayer, JwtValidation, OptionalAuthUser, RequireScope,
+    ScopeClaims, ScopeMarker, Scopes, ValidatedClaims,
+};
 
 #[cfg(feature = "cors")]
 pub use cors::{AllowedOrigins, CorsLayer};
@@ -125,6 +164,15 @@ pub use sqlx::{convert_sqlx_error, PoolError, SqlxErrorExt, SqlxPoolBuilder, Sql
 #[cfg(feature = "diesel")]
 pub use diesel::{DieselPoolBuilder, DieselPoolConfig, DieselPoolError};
 
+#[cfg(feature = "diesel-async")]
+pub use diesel::DieselAsyncPoolBuilder;
+
+#[cfg(feature = "diesel-async-postgres")]
+pub use diesel::AsyncDieselConn;
+
+#[cfg(feature = "mongodb")]
+pub use mongodb::{MongoClientBuilder, MongoClientConfig, MongoClientError, MongoErrorExt};
+
 #[cfg(feature = "insight")]
 pub use insight::{
     InMemoryInsightStore, InsightConfig, InsightData, InsightLayer, InsightStats, InsightStore,
@@ -132,13 +180,22 @@ pub use insight::{
 
 // Phase 11 re-exports
 #[cfg(feature = "timeout")]
-pub use timeout::TimeoutLayer;
+pub use timeout::{Deadline, TimeoutLayer};
 
 #[cfg(feature = "guard")]
 pub use guard::{PermissionGuard, RoleGuard};
 
 #[cfg(feature = "logging")]
-pub use logging::{LogFormat, LoggingConfig, LoggingLayer};
+pub use logging::{
+    AccessLogConfig, AccessLogFields, AccessLogFormat, LogFormat, LoggingConfig, LoggingLayer,
+    RouteExtractor, UserIdExtractor,
+};
+
+#[cfg(feature = "error-reporting")]
+pub use error_reporting::{
+    ErrorEvent, ErrorEventKind, ErrorReporter, ErrorReportingConfig, ErrorReportingLayer,
+    SentryReporter, TracingErrorReporter,
+};
 
 #[cfg(feature = "circuit-breaker")]
 pub use circuit_breaker::{CircuitBreakerLayer, CircuitBreakerStats, CircuitState};
@@ -147,11 +204,17 @@ pub use circuit_breaker::{CircuitBreakerLayer, CircuitBreakerStats, CircuitState
 pub use retry::{RetryLayer, RetryStrategy};
 
 #[cfg(feature = "security-headers")]
-pub use security_headers::{HstsConfig, ReferrerPolicy, SecurityHeadersLayer, XFrameOptions};
+pub use security_headers::{
+    CspReportSink, HstsConfig, ReferrerPolicy, ReportToConfig, SecurityHeadersLayer,
+    TracingCspReportSink, XFrameOptions, DEFAULT_REPORT_PATH,
+};
 
 #[cfg(feature = "api-key")]
 pub use api_key::ApiKeyLayer;
 
+#[cfg(feature = "llm-guard")]
+pub use llm_guard::{GuardFinding, GuardMode, LlmGuardLayer};
+
 #[cfg(feature = "cache")]
 pub use cache::{CacheConfig, CacheLayer};
 
@@ -199,3 +262,15 @@ pub use audit::{
     AuditAction, AuditEvent, AuditQuery, AuditQueryBuilder, AuditSeverity, AuditStore,
     ComplianceInfo, FileAuditStore, InMemoryAuditStore,
 };
+
+#[cfg(feature = "storage")]
+pub use storage::{upload_multipart_field, LocalDiskStore, ObjectMeta, ObjectStore, StorageError};
+
+#[cfg(feature = "storage-s3")]
+pub use storage::{S3Config, S3Store};
+
+#[cfg(feature = "storage-gcs")]
+pub use storage::{GcsConfig, GcsStore};
+
+#[cfg(feature = "redis-pool")]
+pub use redis::{convert_pool_error, Redis, RedisPoolBuilder, RedisPoolConfig, RedisPoolError};
@@ -20,12 +20,14 @@
 //! ```
 
 use rustapi_core::{
-    middleware::{BoxedNext, MiddlewareLayer},
+    middleware::{BoxedNext, MiddlewareLayer, RequestId},
     Request, Response,
 };
 use std::future::Future;
+use std::net::IpAddr;
 use std::pin::Pin;
-use std::time::Instant;
+use std::sync::Arc;
+use std::time::{Instant, SystemTime};
 
 /// Logging format
 #[derive(Clone, Debug)]
@@ -49,6 +51,9 @@ pub struct LoggingConfig {
     pub log_response_headers: bool,
     /// Paths to skip logging
     pub skip_paths: Vec<String>,
+    /// Access-log mode. When set, the layer emits exactly one formatted
+    /// line per request instead of the multi-event `format` logging above.
+    pub access_log: Option<AccessLogConfig>,
 }
 
 impl Default for LoggingConfig {
@@ -58,10 +63,131 @@ impl Default for LoggingConfig {
             log_request_headers: false,
             log_response_headers: false,
             skip_paths: vec!["/health".to_string(), "/metrics".to_string()],
+            access_log: None,
         }
     }
 }
 
+/// Access log line format
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessLogFormat {
+    /// NCSA Common Log Format: `%h %l %u %t "%r" %>s %b`
+    Common,
+    /// NCSA Combined Log Format: [`AccessLogFormat::Common`] plus referer and user-agent
+    Combined,
+    /// One JSON object per line, with a selectable set of extra fields
+    Json,
+}
+
+/// Which optional fields to attach to [`AccessLogFormat::Json`] lines.
+///
+/// `Common` and `Combined` have a fixed field layout defined by their
+/// format and ignore this selection.
+#[derive(Clone, Debug)]
+pub struct AccessLogFields {
+    /// Include request duration in milliseconds
+    pub latency: bool,
+    /// Include response size in bytes, read from the `Content-Length` header
+    pub bytes: bool,
+    /// Include the user id resolved by [`AccessLogConfig::user_id_extractor`]
+    pub user_id: bool,
+    /// Include the request ID inserted by `RequestIdLayer`
+    pub request_id: bool,
+    /// Include the route template resolved by [`AccessLogConfig::route_extractor`]
+    pub route: bool,
+}
+
+impl Default for AccessLogFields {
+    fn default() -> Self {
+        Self {
+            latency: true,
+            bytes: true,
+            user_id: false,
+            request_id: true,
+            route: true,
+        }
+    }
+}
+
+/// Resolves a user identifier from a request, for the access log `user_id` field.
+///
+/// Takes a closure rather than a fixed extractor type since the user's
+/// identity (e.g. `AuthUser<Claims>`) is generic over the application's own
+/// claims type.
+pub type UserIdExtractor = Arc<dyn Fn(&Request) -> Option<String> + Send + Sync>;
+
+/// Resolves the route template (e.g. `/users/{id}`) for a request, for the
+/// access log `route` field.
+///
+/// Defaults to the request path when unset, since the router doesn't expose
+/// the matched pattern to middleware; set this if something earlier in the
+/// stack records the matched pattern into request extensions.
+pub type RouteExtractor = Arc<dyn Fn(&Request) -> Option<String> + Send + Sync>;
+
+/// Access log configuration
+#[derive(Clone)]
+pub struct AccessLogConfig {
+    /// Output format
+    pub format: AccessLogFormat,
+    /// Fields to include when `format` is [`AccessLogFormat::Json`]
+    pub fields: AccessLogFields,
+    /// Resolves the `user_id` field
+    pub user_id_extractor: Option<UserIdExtractor>,
+    /// Resolves the `route` field
+    pub route_extractor: Option<RouteExtractor>,
+}
+
+impl Default for AccessLogConfig {
+    fn default() -> Self {
+        Self {
+            format: AccessLogFormat::Common,
+            fields: AccessLogFields::default(),
+            user_id_extractor: None,
+            route_extractor: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for AccessLogConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessLogConfig")
+            .field("format", &self.format)
+            .field("fields", &self.fields)
+            .field("user_id_extractor", &self.user_id_extractor.is_some())
+            .field("route_extractor", &self.route_extractor.is_some())
+            .finish()
+    }
+}
+
+impl AccessLogConfig {
+    /// Create a new access log configuration in the given format, with
+    /// default field selection and no user/route extractors.
+    pub fn new(format: AccessLogFormat) -> Self {
+        Self {
+            format,
+            ..Default::default()
+        }
+    }
+
+    /// Select which fields are included in [`AccessLogFormat::Json`] lines
+    pub fn fields(mut self, fields: AccessLogFields) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Set the callback used to resolve the `user_id` field
+    pub fn user_id_extractor(mut self, extractor: UserIdExtractor) -> Self {
+        self.user_id_extractor = Some(extractor);
+        self
+    }
+
+    /// Set the callback used to resolve the `route` field
+    pub fn route_extractor(mut self, extractor: RouteExtractor) -> Self {
+        self.route_extractor = Some(extractor);
+        self
+    }
+}
+
 /// Logging middleware layer
 #[derive(Clone)]
 pub struct LoggingLayer {
@@ -104,6 +230,18 @@ impl LoggingLayer {
         self.config.skip_paths.push(path.into());
         self
     }
+
+    /// Enable access-log mode
+    ///
+    /// When set, the layer emits exactly one formatted line per request
+    /// (Common/Combined/JSON) instead of the multi-event logging driven by
+    /// [`LoggingLayer::format`]. The line is emitted as a plain `tracing`
+    /// event on the `access_log` target, independent of any tracing spans,
+    /// so it reads the same whether or not span-based tracing is enabled.
+    pub fn access_log(mut self, config: AccessLogConfig) -> Self {
+        self.config.access_log = Some(config);
+        self
+    }
 }
 
 impl Default for LoggingLayer {
@@ -130,6 +268,10 @@ impl MiddlewareLayer for LoggingLayer {
                 return next(req).await;
             }
 
+            if let Some(access_log) = config.access_log.clone() {
+                return run_access_log(access_log, req, next, method, uri, version).await;
+            }
+
             // Get request ID from extensions if available
             let request_id = req
                 .extensions()
@@ -246,11 +388,199 @@ impl MiddlewareLayer for LoggingLayer {
     }
 }
 
+/// Run a single request through access-log mode: resolve fields before
+/// handing `req` off to `next`, then emit exactly one formatted line.
+async fn run_access_log(
+    access_log: AccessLogConfig,
+    req: Request,
+    next: BoxedNext,
+    method: String,
+    uri: String,
+    version: String,
+) -> Response {
+    let path = req.uri().path().to_string();
+    let client_ip = extract_client_ip(&req);
+    let referer = req
+        .headers()
+        .get(http::header::REFERER)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("-")
+        .to_string();
+    let user_agent = req
+        .headers()
+        .get(http::header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("-")
+        .to_string();
+    let request_id = req
+        .extensions()
+        .get::<RequestId>()
+        .map(|id| id.as_str().to_string());
+    let user_id = access_log
+        .user_id_extractor
+        .as_ref()
+        .and_then(|extractor| extractor(&req));
+    let route = access_log
+        .route_extractor
+        .as_ref()
+        .and_then(|extractor| extractor(&req))
+        .unwrap_or_else(|| path.clone());
+
+    let start = Instant::now();
+    let response = next(req).await;
+    let duration_ms = start.elapsed().as_millis();
+    let status = response.status().as_u16();
+    let bytes_sent = response
+        .headers()
+        .get(http::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(0);
+
+    let line = match access_log.format {
+        AccessLogFormat::Common => format!(
+            "{host} - {user} [{ts}] \"{method} {uri} {version}\" {status} {bytes}",
+            host = client_ip,
+            user = user_id.as_deref().unwrap_or("-"),
+            ts = access_log_timestamp(),
+            method = method,
+            uri = uri,
+            version = version,
+            status = status,
+            bytes = bytes_sent,
+        ),
+        AccessLogFormat::Combined => format!(
+            "{host} - {user} [{ts}] \"{method} {uri} {version}\" {status} {bytes} \"{referer}\" \"{user_agent}\"",
+            host = client_ip,
+            user = user_id.as_deref().unwrap_or("-"),
+            ts = access_log_timestamp(),
+            method = method,
+            uri = uri,
+            version = version,
+            status = status,
+            bytes = bytes_sent,
+            referer = referer,
+            user_agent = user_agent,
+        ),
+        AccessLogFormat::Json => {
+            let mut json = serde_json::json!({
+                "host": client_ip,
+                "method": method,
+                "uri": uri,
+                "status": status,
+            });
+            let fields = &access_log.fields;
+            if fields.latency {
+                json["latency_ms"] = serde_json::json!(duration_ms);
+            }
+            if fields.bytes {
+                json["bytes"] = serde_json::json!(bytes_sent);
+            }
+            if fields.user_id {
+                json["user_id"] = serde_json::json!(user_id);
+            }
+            if fields.request_id {
+                json["request_id"] = serde_json::json!(request_id);
+            }
+            if fields.route {
+                json["route"] = serde_json::json!(route);
+            }
+            json.to_string()
+        }
+    };
+
+    // A plain event on a dedicated target, not a span: access logs stay
+    // one line per request whether or not span-based tracing is enabled.
+    tracing::info!(target: "access_log", "{}", line);
+
+    response
+}
+
+/// Extract client IP from request headers, checking `X-Forwarded-For` then
+/// `X-Real-IP` before falling back to localhost.
+fn extract_client_ip(req: &Request) -> String {
+    if let Some(forwarded) = req.headers().get("x-forwarded-for") {
+        if let Ok(forwarded_str) = forwarded.to_str() {
+            if let Some(first_ip) = forwarded_str.split(',').next() {
+                let ip_str = first_ip.trim();
+                if ip_str.parse::<IpAddr>().is_ok() {
+                    return ip_str.to_string();
+                }
+            }
+        }
+    }
+
+    if let Some(real_ip) = req.headers().get("x-real-ip") {
+        if let Ok(ip_str) = real_ip.to_str() {
+            let ip_str = ip_str.trim();
+            if ip_str.parse::<IpAddr>().is_ok() {
+                return ip_str.to_string();
+            }
+        }
+    }
+
+    "127.0.0.1".to_string()
+}
+
+/// Format the current time in NCSA Common/Combined Log Format:
+/// `10/Oct/2000:13:55:36 +0000`.
+fn access_log_timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let days = secs / 86400;
+    let remaining = secs % 86400;
+    let hours = remaining / 3600;
+    let minutes = (remaining % 3600) / 60;
+    let seconds = remaining % 60;
+
+    let month_names = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let mut year: i64 = 1970;
+    let mut remaining_days = days as i64;
+    loop {
+        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+        if remaining_days < days_in_year {
+            break;
+        }
+        remaining_days -= days_in_year;
+        year += 1;
+    }
+
+    let days_in_months = if is_leap_year(year) {
+        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+    } else {
+        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+    };
+
+    let mut month = 0;
+    for (i, &days_in_month) in days_in_months.iter().enumerate() {
+        if remaining_days < days_in_month {
+            month = i;
+            break;
+        }
+        remaining_days -= days_in_month;
+    }
+    let day = remaining_days + 1;
+
+    format!(
+        "{:02}/{}/{}:{:02}:{:02}:{:02} +0000",
+        day, month_names[month], year, hours, minutes, seconds
+    )
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use bytes::Bytes;
-    use std::sync::Arc;
 
     #[tokio::test]
     async fn logging_middleware_logs_request() {
@@ -299,4 +629,66 @@ mod tests {
         let response = layer.call(req, next).await;
         assert_eq!(response.status(), 200);
     }
+
+    #[tokio::test]
+    async fn access_log_common_format_completes_request() {
+        let layer = LoggingLayer::new().access_log(AccessLogConfig::new(AccessLogFormat::Common));
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(bytes::Bytes::from("OK")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let req = http::Request::builder()
+            .method("GET")
+            .uri("/test")
+            .body(())
+            .unwrap();
+        let req = Request::from_http_request(req, Bytes::new());
+
+        let response = layer.call(req, next).await;
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn access_log_json_format_with_extractors_completes_request() {
+        let layer = LoggingLayer::new().access_log(
+            AccessLogConfig::new(AccessLogFormat::Json)
+                .user_id_extractor(Arc::new(|_req| Some("user-42".to_string())))
+                .route_extractor(Arc::new(|_req| Some("/users/{id}".to_string()))),
+        );
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(201)
+                    .body(http_body_util::Full::new(bytes::Bytes::from("created")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let req = http::Request::builder()
+            .method("POST")
+            .uri("/users/42")
+            .body(())
+            .unwrap();
+        let req = Request::from_http_request(req, Bytes::new());
+
+        let response = layer.call(req, next).await;
+        assert_eq!(response.status(), 201);
+    }
+
+    #[test]
+    fn access_log_fields_default_selection() {
+        let fields = AccessLogFields::default();
+        assert!(fields.latency);
+        assert!(fields.bytes);
+        assert!(fields.request_id);
+        assert!(fields.route);
+        assert!(!fields.user_id);
+    }
 }
@@ -25,6 +25,77 @@ use rustapi_core::{
 };
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default path the built-in report collection endpoint listens on; see
+/// [`SecurityHeadersLayer::report_path`].
+pub const DEFAULT_REPORT_PATH: &str = "/csp-report";
+
+/// Cap on how much of a violation report body [`handle_csp_report`] will
+/// buffer. Real CSP/Reporting-API reports are a few hundred bytes; this is
+/// generous headroom without letting an unauthenticated, no-Content-Length
+/// POST to the report endpoint exhaust memory.
+const MAX_CSP_REPORT_BODY_BYTES: usize = 64 * 1024;
+
+/// Receives parsed violation reports posted to the report collection
+/// endpoint (CSP `report-uri`/`report-to`, or any other `application/json`
+/// / `application/reports+json` / `application/csp-report` report body).
+///
+/// Implement this to forward reports somewhere other than `tracing`, e.g.
+/// into an `InsightStore` alongside ordinary traffic data.
+pub trait CspReportSink: Send + Sync + 'static {
+    /// Handle one parsed report body.
+    fn report(&self, report: &serde_json::Value);
+}
+
+/// Logs violation reports at `warn` level via `tracing`. The default sink.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingCspReportSink;
+
+impl CspReportSink for TracingCspReportSink {
+    fn report(&self, report: &serde_json::Value) {
+        tracing::warn!(report = %report, "security policy violation report");
+    }
+}
+
+/// [Reporting API](https://developer.mozilla.org/en-US/docs/Web/HTTP/Reference/Headers/Reporting-Endpoints)
+/// configuration: where browsers should POST CSP/Permissions-Policy/etc.
+/// violation reports, via the modern `Reporting-Endpoints` header and the
+/// still-widely-supported `Report-To` header.
+#[derive(Debug, Clone)]
+pub struct ReportToConfig {
+    /// The `report-to` group name referenced from `Content-Security-Policy`.
+    pub group: String,
+    /// How long browsers should cache this endpoint configuration.
+    pub max_age: Duration,
+    /// URL reports are POSTed to.
+    pub endpoint: String,
+}
+
+impl ReportToConfig {
+    /// Report violations to `endpoint` under `group`, cached for `max_age`.
+    pub fn new(group: impl Into<String>, endpoint: impl Into<String>, max_age: Duration) -> Self {
+        Self {
+            group: group.into(),
+            max_age,
+            endpoint: endpoint.into(),
+        }
+    }
+
+    fn report_to_header_value(&self) -> String {
+        serde_json::json!({
+            "group": self.group,
+            "max_age": self.max_age.as_secs(),
+            "endpoints": [{ "url": self.endpoint }],
+        })
+        .to_string()
+    }
+
+    fn reporting_endpoints_header_value(&self) -> String {
+        format!("{}=\"{}\"", self.group, self.endpoint)
+    }
+}
 
 /// Security headers configuration
 #[derive(Clone)]
@@ -43,6 +114,9 @@ pub struct SecurityHeadersConfig {
     pub referrer_policy: Option<ReferrerPolicy>,
     /// Permissions-Policy: Controls browser features
     pub permissions_policy: Option<String>,
+    /// Reporting API configuration: emits `Report-To`/`Reporting-Endpoints`
+    /// headers and appends `report-to <group>` to the CSP, if set.
+    pub report_to: Option<ReportToConfig>,
 }
 
 /// X-Frame-Options values
@@ -137,6 +211,7 @@ impl Default for SecurityHeadersConfig {
             csp: Some("default-src 'self'".to_string()),
             referrer_policy: Some(ReferrerPolicy::StrictOriginWhenCrossOrigin),
             permissions_policy: Some("geolocation=(), microphone=(), camera=()".to_string()),
+            report_to: None,
         }
     }
 }
@@ -145,6 +220,8 @@ impl Default for SecurityHeadersConfig {
 #[derive(Clone)]
 pub struct SecurityHeadersLayer {
     config: SecurityHeadersConfig,
+    report_path: String,
+    report_sink: Arc<dyn CspReportSink>,
 }
 
 impl SecurityHeadersLayer {
@@ -152,31 +229,74 @@ impl SecurityHeadersLayer {
     pub fn new() -> Self {
         Self {
             config: SecurityHeadersConfig::default(),
+            report_path: DEFAULT_REPORT_PATH.to_string(),
+            report_sink: Arc::new(TracingCspReportSink),
         }
     }
 
     /// Create with strict security settings (recommended for production)
     pub fn strict() -> Self {
-        Self {
-            config: SecurityHeadersConfig {
-                x_content_type_options: true,
-                x_frame_options: Some(XFrameOptions::Deny),
-                x_xss_protection: true,
-                hsts: Some(HstsConfig {
-                    max_age: 63072000, // 2 years
-                    include_subdomains: true,
-                    preload: true,
-                }),
-                csp: Some(
-                    "default-src 'self'; script-src 'self'; style-src 'self' 'unsafe-inline'; img-src 'self' data:; font-src 'self'; connect-src 'self'; frame-ancestors 'none'"
-                        .to_string(),
-                ),
-                referrer_policy: Some(ReferrerPolicy::NoReferrer),
-                permissions_policy: Some(
-                    "geolocation=(), microphone=(), camera=(), payment=(), usb=()".to_string(),
-                ),
-            },
-        }
+        Self::new().with_config(SecurityHeadersConfig {
+            x_content_type_options: true,
+            x_frame_options: Some(XFrameOptions::Deny),
+            x_xss_protection: true,
+            hsts: Some(HstsConfig {
+                max_age: 63072000, // 2 years
+                include_subdomains: true,
+                preload: true,
+            }),
+            csp: Some(
+                "default-src 'self'; script-src 'self'; style-src 'self' 'unsafe-inline'; img-src 'self' data:; font-src 'self'; connect-src 'self'; frame-ancestors 'none'"
+                    .to_string(),
+            ),
+            referrer_policy: Some(ReferrerPolicy::NoReferrer),
+            permissions_policy: Some(
+                "geolocation=(), microphone=(), camera=(), payment=(), usb=()".to_string(),
+            ),
+            report_to: None,
+        })
+    }
+
+    /// Create with settings suited to a JSON API that serves no HTML: skips
+    /// the browser-rendering-focused headers (CSP, the deprecated
+    /// X-XSS-Protection) that have nothing to protect on a JSON response.
+    pub fn api_only() -> Self {
+        Self::new().with_config(SecurityHeadersConfig {
+            x_content_type_options: true,
+            x_frame_options: Some(XFrameOptions::Deny),
+            x_xss_protection: false,
+            hsts: Some(HstsConfig {
+                max_age: 31536000,
+                include_subdomains: true,
+                preload: false,
+            }),
+            csp: None,
+            referrer_policy: Some(ReferrerPolicy::NoReferrer),
+            permissions_policy: None,
+            report_to: None,
+        })
+    }
+
+    /// Create with everything disabled, for local development over
+    /// plain HTTP where HSTS/CSP would just get in the way (e.g. blocking
+    /// `http://localhost` resources or a webpack dev server's eval'd JS).
+    /// Not recommended for anything internet-facing.
+    pub fn relaxed_dev() -> Self {
+        Self::new().with_config(SecurityHeadersConfig {
+            x_content_type_options: false,
+            x_frame_options: None,
+            x_xss_protection: false,
+            hsts: None,
+            csp: None,
+            referrer_policy: None,
+            permissions_policy: None,
+            report_to: None,
+        })
+    }
+
+    fn with_config(mut self, config: SecurityHeadersConfig) -> Self {
+        self.config = config;
+        self
     }
 
     /// Disable X-Content-Type-Options
@@ -232,6 +352,27 @@ impl SecurityHeadersLayer {
         self.config.permissions_policy = Some(policy.into());
         self
     }
+
+    /// Configure the Reporting API: emits `Report-To`/`Reporting-Endpoints`
+    /// headers, and appends `; report-to <group>` to the CSP if one is set.
+    pub fn report_to(mut self, config: ReportToConfig) -> Self {
+        self.config.report_to = Some(config);
+        self
+    }
+
+    /// Change the path the built-in report collection endpoint listens on.
+    /// Defaults to [`DEFAULT_REPORT_PATH`].
+    pub fn report_path(mut self, path: impl Into<String>) -> Self {
+        self.report_path = path.into();
+        self
+    }
+
+    /// Send collected reports to `sink` instead of the default
+    /// [`TracingCspReportSink`].
+    pub fn report_sink(mut self, sink: impl CspReportSink) -> Self {
+        self.report_sink = Arc::new(sink);
+        self
+    }
 }
 
 impl Default for SecurityHeadersLayer {
@@ -243,12 +384,18 @@ impl Default for SecurityHeadersLayer {
 impl MiddlewareLayer for SecurityHeadersLayer {
     fn call(
         &self,
-        req: Request,
+        mut req: Request,
         next: BoxedNext,
     ) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> {
         let config = self.config.clone();
+        let report_path = self.report_path.clone();
+        let report_sink = self.report_sink.clone();
 
         Box::pin(async move {
+            if req.method() == http::Method::POST && req.uri().path() == report_path {
+                return handle_csp_report(&mut req, report_sink.as_ref()).await;
+            }
+
             let mut response = next(req).await;
 
             // Add security headers to response
@@ -289,7 +436,11 @@ impl MiddlewareLayer for SecurityHeadersLayer {
             }
 
             // Content-Security-Policy
-            if let Some(csp) = config.csp {
+            if let Some(mut csp) = config.csp {
+                if let Some(report_to) = &config.report_to {
+                    csp.push_str("; report-to ");
+                    csp.push_str(&report_to.group);
+                }
                 if let Ok(value) = http::header::HeaderValue::from_str(&csp) {
                     headers.insert(
                         http::header::HeaderName::from_static("content-security-policy"),
@@ -298,6 +449,24 @@ impl MiddlewareLayer for SecurityHeadersLayer {
                 }
             }
 
+            // Reporting API: Report-To (legacy, widely supported) and
+            // Reporting-Endpoints (current spec)
+            if let Some(report_to) = &config.report_to {
+                if let Ok(value) =
+                    http::header::HeaderValue::from_str(&report_to.report_to_header_value())
+                {
+                    headers.insert(http::header::HeaderName::from_static("report-to"), value);
+                }
+                if let Ok(value) = http::header::HeaderValue::from_str(
+                    &report_to.reporting_endpoints_header_value(),
+                ) {
+                    headers.insert(
+                        http::header::HeaderName::from_static("reporting-endpoints"),
+                        value,
+                    );
+                }
+            }
+
             // Referrer-Policy
             if let Some(referrer_policy) = config.referrer_policy {
                 headers.insert(
@@ -325,6 +494,29 @@ impl MiddlewareLayer for SecurityHeadersLayer {
     }
 }
 
+/// Parse a violation report body posted to the report collection endpoint
+/// and hand it to `sink`. Browsers send these as `application/csp-report`,
+/// `application/reports+json`, or plain `application/json` - all of them are
+/// just JSON on the wire, so no content-type sniffing is needed.
+async fn handle_csp_report(req: &mut Request, sink: &dyn CspReportSink) -> Response {
+    match req.load_body_limited(MAX_CSP_REPORT_BODY_BYTES).await {
+        Ok(()) => {
+            if let Some(body) = req.take_body() {
+                match serde_json::from_slice::<serde_json::Value>(&body) {
+                    Ok(report) => sink.report(&report),
+                    Err(err) => tracing::debug!(error = %err, "discarding malformed report body"),
+                }
+            }
+        }
+        Err(err) => tracing::debug!(error = %err, "discarding oversized report body"),
+    }
+
+    http::Response::builder()
+        .status(http::StatusCode::NO_CONTENT)
+        .body(http_body_util::Full::new(bytes::Bytes::new()))
+        .unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -412,4 +604,193 @@ mod tests {
             "max-age=31536000; includeSubDomains; preload"
         );
     }
+
+    #[tokio::test]
+    async fn api_only_preset_omits_csp() {
+        let layer = SecurityHeadersLayer::api_only();
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(Bytes::from("{}")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let req = Request::from_http_request(
+            http::Request::builder()
+                .method("GET")
+                .uri("/api/users")
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        );
+
+        let response = layer.call(req, next).await;
+        assert!(response
+            .headers()
+            .get("content-security-policy")
+            .is_none());
+        assert!(response.headers().contains_key("x-content-type-options"));
+    }
+
+    #[tokio::test]
+    async fn relaxed_dev_preset_adds_no_headers() {
+        let layer = SecurityHeadersLayer::relaxed_dev();
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(Bytes::from("hi")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let req = Request::from_http_request(
+            http::Request::builder()
+                .method("GET")
+                .uri("/")
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        );
+
+        let response = layer.call(req, next).await;
+        assert!(response.headers().is_empty());
+    }
+
+    #[tokio::test]
+    async fn report_to_config_adds_headers_and_csp_directive() {
+        let layer = SecurityHeadersLayer::new().report_to(ReportToConfig::new(
+            "default",
+            "https://reports.example.com/csp",
+            Duration::from_secs(86400),
+        ));
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(Bytes::from("OK")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let req = Request::from_http_request(
+            http::Request::builder()
+                .method("GET")
+                .uri("/")
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        );
+
+        let response = layer.call(req, next).await;
+        let csp = response
+            .headers()
+            .get("content-security-policy")
+            .unwrap()
+            .to_str()
+            .unwrap();
+        assert!(csp.contains("report-to default"));
+        assert!(response.headers().contains_key("report-to"));
+        assert!(response.headers().contains_key("reporting-endpoints"));
+    }
+
+    struct CollectingSink(Arc<std::sync::Mutex<Vec<serde_json::Value>>>);
+
+    impl CspReportSink for CollectingSink {
+        fn report(&self, report: &serde_json::Value) {
+            self.0.lock().unwrap().push(report.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn csp_report_endpoint_forwards_parsed_body_to_sink() {
+        let reports = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let layer = SecurityHeadersLayer::new().report_sink(CollectingSink(reports.clone()));
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(Bytes::new()))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let body = Bytes::from(r#"{"csp-report":{"violated-directive":"script-src"}}"#);
+        let req = Request::from_http_request(
+            http::Request::builder()
+                .method("POST")
+                .uri(DEFAULT_REPORT_PATH)
+                .body(())
+                .unwrap(),
+            body,
+        );
+
+        let response = layer.call(req, next).await;
+        assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+        assert_eq!(reports.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn csp_report_endpoint_path_is_configurable() {
+        let layer = SecurityHeadersLayer::new().report_path("/reports/csp");
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(Bytes::new()))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let req = Request::from_http_request(
+            http::Request::builder()
+                .method("POST")
+                .uri(DEFAULT_REPORT_PATH)
+                .body(())
+                .unwrap(),
+            Bytes::new(),
+        );
+
+        // Default path moved, so a POST to the old default falls through to `next`.
+        let response = layer.call(req, next).await;
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn csp_report_endpoint_discards_oversized_body_without_buffering_it() {
+        let reports = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let layer = SecurityHeadersLayer::new().report_sink(CollectingSink(reports.clone()));
+
+        let next: BoxedNext = Arc::new(|_req: Request| {
+            Box::pin(async {
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(Bytes::new()))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        // No Content-Length header, so BodyLimitLayer-style checks wouldn't
+        // catch this -- only load_body_limited's streaming cap does.
+        let oversized = Bytes::from(vec![b'x'; MAX_CSP_REPORT_BODY_BYTES + 1]);
+        let req = Request::from_http_request(
+            http::Request::builder()
+                .method("POST")
+                .uri(DEFAULT_REPORT_PATH)
+                .body(())
+                .unwrap(),
+            oversized,
+        );
+
+        let response = layer.call(req, next).await;
+        assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+        assert!(reports.lock().unwrap().is_empty());
+    }
 }
@@ -0,0 +1,36 @@
+//! Bridge from the `Multipart` extractor to an [`super::ObjectStore`]
+
+use super::{ObjectMeta, ObjectStore, StorageResult};
+use rustapi_core::multipart::MultipartField;
+
+/// Upload a multipart field's contents to `store` under `key`, using the
+/// field's declared content type (if any) as the object's content type.
+///
+/// This is the "accept upload, put in S3" glue: read a file field off a
+/// `Multipart` extractor and hand it straight to the store without an
+/// extra buffering step of your own.
+///
+/// ```rust,ignore
+/// use rustapi_core::Multipart;
+/// use rustapi_extras::storage::{upload_multipart_field, ObjectStore};
+///
+/// async fn upload(mut form: Multipart, store: impl ObjectStore) -> impl IntoResponse {
+///     for field in form.into_fields() {
+///         if let Some(file_name) = field.file_name() {
+///             upload_multipart_field(&store, &field, file_name).await?;
+///         }
+///     }
+///     "uploaded"
+/// }
+/// ```
+pub async fn upload_multipart_field(
+    store: &dyn ObjectStore,
+    field: &MultipartField,
+    key: &str,
+) -> StorageResult<ObjectMeta> {
+    let data = field
+        .bytes()
+        .await
+        .map_err(|err| super::StorageError::Backend(err.to_string()))?;
+    store.put(key, data, field.content_type()).await
+}
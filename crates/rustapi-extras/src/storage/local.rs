@@ -0,0 +1,186 @@
+//! Local-disk `ObjectStore` backend
+
+use super::{BoxFuture, ObjectMeta, ObjectStore, StorageError, StorageResult};
+use bytes::Bytes;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Stores objects as files under a root directory. Meant for local
+/// development and tests -- swap in [`super::S3Store`] for production
+/// without touching handler code.
+#[derive(Clone)]
+pub struct LocalDiskStore {
+    root: PathBuf,
+}
+
+impl LocalDiskStore {
+    /// Store objects under `root`, creating it if it doesn't already exist.
+    pub fn new(root: impl Into<PathBuf>) -> StorageResult<Self> {
+        let root = root.into();
+        std::fs::create_dir_all(&root)?;
+        Ok(Self { root })
+    }
+
+    /// Resolve `key` to a path under `root`, rejecting any component that
+    /// would escape it (`..`, empty segments, absolute paths).
+    fn path_for(&self, key: &str) -> StorageResult<PathBuf> {
+        let mut path = self.root.clone();
+        let mut has_segment = false;
+        for part in key.split('/') {
+            if part.is_empty() || part == "." || part == ".." {
+                return Err(StorageError::Backend(format!("invalid object key: {key}")));
+            }
+            path.push(part);
+            has_segment = true;
+        }
+        if !has_segment {
+            return Err(StorageError::Backend(format!("invalid object key: {key}")));
+        }
+        Ok(path)
+    }
+
+    async fn read_meta(key: &str, path: &Path) -> StorageResult<ObjectMeta> {
+        let metadata = tokio::fs::metadata(path)
+            .await
+            .map_err(|err| not_found_or_io(key, err))?;
+        Ok(ObjectMeta {
+            key: key.to_string(),
+            size: metadata.len(),
+            content_type: None,
+            etag: None,
+        })
+    }
+}
+
+fn not_found_or_io(key: &str, err: std::io::Error) -> StorageError {
+    if err.kind() == std::io::ErrorKind::NotFound {
+        StorageError::NotFound(key.to_string())
+    } else {
+        StorageError::Io(err)
+    }
+}
+
+impl ObjectStore for LocalDiskStore {
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        data: Bytes,
+        content_type: Option<&'a str>,
+    ) -> BoxFuture<'a, StorageResult<ObjectMeta>> {
+        Box::pin(async move {
+            let path = self.path_for(key)?;
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            let size = data.len() as u64;
+            tokio::fs::write(&path, &data).await?;
+            Ok(ObjectMeta {
+                key: key.to_string(),
+                size,
+                content_type: content_type.map(str::to_string),
+                etag: None,
+            })
+        })
+    }
+
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, StorageResult<Bytes>> {
+        Box::pin(async move {
+            let path = self.path_for(key)?;
+            let data = tokio::fs::read(&path)
+                .await
+                .map_err(|err| not_found_or_io(key, err))?;
+            Ok(Bytes::from(data))
+        })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, StorageResult<()>> {
+        Box::pin(async move {
+            let path = self.path_for(key)?;
+            match tokio::fs::remove_file(&path).await {
+                Ok(()) => Ok(()),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                Err(err) => Err(StorageError::Io(err)),
+            }
+        })
+    }
+
+    fn head<'a>(&'a self, key: &'a str) -> BoxFuture<'a, StorageResult<ObjectMeta>> {
+        Box::pin(async move {
+            let path = self.path_for(key)?;
+            Self::read_meta(key, &path).await
+        })
+    }
+
+    /// Local disk has no HTTP front end of its own, so there's no URL to
+    /// hand back -- mount the store behind a route (e.g. `serve_dir`) and
+    /// build the URL from that route instead.
+    fn presigned_url(&self, _key: &str, _expires_in: Duration) -> StorageResult<String> {
+        Err(StorageError::Unsupported(
+            "LocalDiskStore has no HTTP front end to generate a URL for; serve it via a route instead",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn put_then_get_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalDiskStore::new(dir.path()).unwrap();
+
+        let meta = store
+            .put("uploads/avatar.png", Bytes::from_static(b"pretend-png"), Some("image/png"))
+            .await
+            .unwrap();
+        assert_eq!(meta.size, 11);
+        assert_eq!(meta.content_type.as_deref(), Some("image/png"));
+
+        let data = store.get("uploads/avatar.png").await.unwrap();
+        assert_eq!(data, Bytes::from_static(b"pretend-png"));
+    }
+
+    #[tokio::test]
+    async fn get_missing_key_is_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalDiskStore::new(dir.path()).unwrap();
+
+        let err = store.get("missing").await.unwrap_err();
+        assert!(matches!(err, StorageError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn exists_reflects_put_and_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalDiskStore::new(dir.path()).unwrap();
+
+        assert!(!store.exists("file.txt").await.unwrap());
+        store.put("file.txt", Bytes::from_static(b"hi"), None).await.unwrap();
+        assert!(store.exists("file.txt").await.unwrap());
+        store.delete("file.txt").await.unwrap();
+        assert!(!store.exists("file.txt").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn rejects_path_traversal() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalDiskStore::new(dir.path()).unwrap();
+
+        let err = store
+            .put("../../etc/passwd", Bytes::from_static(b"pwned"), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StorageError::Backend(_)));
+    }
+
+    #[test]
+    fn presigned_url_is_unsupported() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalDiskStore::new(dir.path()).unwrap();
+        assert!(matches!(
+            store.presigned_url("file.txt", Duration::from_secs(60)),
+            Err(StorageError::Unsupported(_))
+        ));
+    }
+}
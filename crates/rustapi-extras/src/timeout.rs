@@ -20,10 +20,87 @@
 //! }
 //! ```
 
-use rustapi_core::{middleware::BoxedNext, middleware::MiddlewareLayer, Request, Response};
+use rustapi_core::{
+    middleware::BoxedNext, middleware::MiddlewareLayer, ApiError, FromRequestParts, Request,
+    Response, Result,
+};
 use std::future::Future;
 use std::pin::Pin;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+/// A deadline by which a request must be served
+///
+/// Set by [`TimeoutLayer`], or derived from an `X-Request-Timeout` (milliseconds)
+/// or `grpc-timeout` (e.g. `500m`, `10S`) header when no `TimeoutLayer` is
+/// configured. Handlers and DB helpers can consult [`Deadline::remaining`] to
+/// avoid doing work the caller has already stopped waiting for.
+#[derive(Debug, Clone, Copy)]
+pub struct Deadline(Instant);
+
+impl Deadline {
+    /// A deadline that expires after `duration` from now
+    pub fn after(duration: Duration) -> Self {
+        Self(Instant::now() + duration)
+    }
+
+    /// Time remaining until the deadline, or `Duration::ZERO` if it has passed
+    pub fn remaining(&self) -> Duration {
+        self.0.saturating_duration_since(Instant::now())
+    }
+
+    /// Whether the deadline has already passed
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.0
+    }
+}
+
+impl FromRequestParts for Deadline {
+    fn from_request_parts(req: &Request) -> Result<Self> {
+        if let Some(deadline) = req.extensions().get::<Deadline>() {
+            return Ok(*deadline);
+        }
+
+        parse_deadline_headers(req).ok_or_else(|| {
+            ApiError::bad_request(
+                "No deadline set for this request. Add a TimeoutLayer or send an \
+                 X-Request-Timeout or grpc-timeout header",
+            )
+        })
+    }
+}
+
+/// Parse `X-Request-Timeout` (milliseconds) or `grpc-timeout` (value + unit,
+/// e.g. `500m`, `10S`) into a [`Deadline`] measured from now
+fn parse_deadline_headers(req: &Request) -> Option<Deadline> {
+    if let Some(value) = req.headers().get("x-request-timeout") {
+        let millis: u64 = value.to_str().ok()?.trim().parse().ok()?;
+        return Some(Deadline::after(Duration::from_millis(millis)));
+    }
+
+    if let Some(value) = req.headers().get("grpc-timeout") {
+        return parse_grpc_timeout(value.to_str().ok()?).map(Deadline::after);
+    }
+
+    None
+}
+
+/// Parse a gRPC-style timeout: an ASCII digit string followed by a unit
+/// (`H` hours, `M` minutes, `S` seconds, `m` milliseconds, `u` microseconds, `n` nanoseconds)
+fn parse_grpc_timeout(raw: &str) -> Option<Duration> {
+    let raw = raw.trim();
+    let unit = raw.chars().last()?;
+    let amount: u64 = raw[..raw.len() - 1].parse().ok()?;
+
+    match unit {
+        'H' => Some(Duration::from_secs(amount * 3600)),
+        'M' => Some(Duration::from_secs(amount * 60)),
+        'S' => Some(Duration::from_secs(amount)),
+        'm' => Some(Duration::from_millis(amount)),
+        'u' => Some(Duration::from_micros(amount)),
+        'n' => Some(Duration::from_nanos(amount)),
+        _ => None,
+    }
+}
 
 /// Middleware that enforces request timeouts
 #[derive(Clone)]
@@ -76,10 +153,11 @@ impl TimeoutLayer {
 impl MiddlewareLayer for TimeoutLayer {
     fn call(
         &self,
-        req: Request,
+        mut req: Request,
         next: BoxedNext,
     ) -> Pin<Box<dyn Future<Output = Response> + Send + 'static>> {
         let timeout = self.timeout;
+        req.extensions_mut().insert(Deadline::after(timeout));
 
         Box::pin(async move {
             // Use tokio::time::timeout to enforce the timeout
@@ -170,4 +248,79 @@ mod tests {
         let response = timeout_layer.call(req, next).await;
         assert_eq!(response.status(), 200);
     }
+
+    #[test]
+    fn deadline_remaining_shrinks_toward_zero() {
+        let deadline = Deadline::after(Duration::from_millis(50));
+        assert!(deadline.remaining() <= Duration::from_millis(50));
+        assert!(!deadline.is_expired());
+    }
+
+    #[test]
+    fn deadline_expired_reports_zero_remaining() {
+        let deadline = Deadline::after(Duration::from_millis(0));
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(deadline.is_expired());
+        assert_eq!(deadline.remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn parse_grpc_timeout_units() {
+        assert_eq!(parse_grpc_timeout("10S"), Some(Duration::from_secs(10)));
+        assert_eq!(parse_grpc_timeout("500m"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_grpc_timeout("2H"), Some(Duration::from_secs(7200)));
+        assert_eq!(parse_grpc_timeout("bogus"), None);
+    }
+
+    #[tokio::test]
+    async fn timeout_layer_sets_deadline_extractor() {
+        let timeout_layer = TimeoutLayer::from_millis(200);
+
+        let next: BoxedNext = Arc::new(|req: Request| {
+            Box::pin(async move {
+                let deadline = Deadline::from_request_parts(&req).unwrap();
+                assert!(deadline.remaining() <= Duration::from_millis(200));
+                http::Response::builder()
+                    .status(200)
+                    .body(http_body_util::Full::new(bytes::Bytes::from("OK")))
+                    .unwrap()
+            }) as Pin<Box<dyn Future<Output = Response> + Send + 'static>>
+        });
+
+        let req = http::Request::builder()
+            .method("GET")
+            .uri("/")
+            .body(())
+            .unwrap();
+        let req = Request::from_http_request(req, Bytes::new());
+
+        let response = timeout_layer.call(req, next).await;
+        assert_eq!(response.status(), 200);
+    }
+
+    #[test]
+    fn deadline_extractor_falls_back_to_request_timeout_header() {
+        let req = http::Request::builder()
+            .method("GET")
+            .uri("/")
+            .header("x-request-timeout", "100")
+            .body(())
+            .unwrap();
+        let req = Request::from_http_request(req, Bytes::new());
+
+        let deadline = Deadline::from_request_parts(&req).unwrap();
+        assert!(deadline.remaining() <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn deadline_extractor_errors_without_source() {
+        let req = http::Request::builder()
+            .method("GET")
+            .uri("/")
+            .body(())
+            .unwrap();
+        let req = Request::from_http_request(req, Bytes::new());
+
+        assert!(Deadline::from_request_parts(&req).is_err());
+    }
 }
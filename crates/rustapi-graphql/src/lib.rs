@@ -0,0 +1,53 @@
+//! # rustapi-graphql
+//!
+//! GraphQL integration for the RustAPI framework, built on `async-graphql`.
+//!
+//! This crate provides an extractor and response type for handling GraphQL
+//! queries and mutations over regular HTTP requests, plus a WebSocket handler
+//! for GraphQL subscriptions.
+//!
+//! ## Features
+//!
+//! - **Queries & Mutations**: [`GraphQLRequest`] parses `POST` requests (JSON or
+//!   `multipart/form-data`, per the GraphQL multipart request spec) and `GET`
+//!   requests (query string), and [`GraphQLResponse`] serializes the result.
+//! - **Subscriptions**: [`graphql_ws`] implements the `graphql-transport-ws`
+//!   protocol on top of [`rustapi_ws::WebSocketStream`].
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+//! use rustapi_graphql::{GraphQLRequest, GraphQLResponse};
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn hello(&self) -> &str {
+//!         "world"
+//!     }
+//! }
+//!
+//! type AppSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+//!
+//! async fn graphql_handler(schema: State<AppSchema>, req: GraphQLRequest) -> GraphQLResponse {
+//!     schema.execute(req.into_inner()).await.into()
+//! }
+//! ```
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_crate_level_docs)]
+
+mod request;
+mod response;
+mod subscription;
+
+pub use request::GraphQLRequest;
+pub use response::GraphQLResponse;
+pub use subscription::graphql_ws;
+
+/// Prelude module for convenient imports
+pub mod prelude {
+    pub use crate::{graphql_ws, GraphQLRequest, GraphQLResponse};
+}
@@ -0,0 +1,71 @@
+//! GraphQL request extractor
+
+use async_graphql::http::MultipartOptions;
+use futures_util::io::Cursor;
+use http::{header, Method};
+use rustapi_core::{ApiError, FromRequest, Request, Result};
+use rustapi_openapi::{Operation, OperationModifier};
+
+/// Extracts a GraphQL request from an incoming HTTP request
+///
+/// `GET` requests are parsed from the `query`/`variables`/`operationName` query
+/// string parameters. `POST` requests are parsed from the body, which may be
+/// `application/json` or a `multipart/form-data` upload following the
+/// [GraphQL multipart request spec](https://github.com/jaydenseric/graphql-multipart-request-spec).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_graphql::{GraphQLRequest, GraphQLResponse};
+///
+/// async fn graphql_handler(schema: State<AppSchema>, req: GraphQLRequest) -> GraphQLResponse {
+///     schema.execute(req.into_inner()).await.into()
+/// }
+/// ```
+pub struct GraphQLRequest(pub async_graphql::Request);
+
+impl GraphQLRequest {
+    /// Unwrap into the inner `async_graphql::Request`
+    pub fn into_inner(self) -> async_graphql::Request {
+        self.0
+    }
+}
+
+impl FromRequest for GraphQLRequest {
+    async fn from_request(req: &mut Request) -> Result<Self> {
+        if req.method() == Method::GET {
+            let query = req.query_string().unwrap_or_default();
+            let request = async_graphql::http::parse_query_string(query)
+                .map_err(|e| ApiError::bad_request(e.to_string()))?;
+            return Ok(Self(request));
+        }
+
+        let content_type = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
+        req.load_body().await?;
+        let body = req
+            .take_body()
+            .ok_or_else(|| ApiError::internal("Body already consumed"))?;
+
+        let request = async_graphql::http::receive_body(
+            content_type,
+            Cursor::new(body.to_vec()),
+            MultipartOptions::default(),
+        )
+        .await
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+
+        Ok(Self(request))
+    }
+}
+
+impl OperationModifier for GraphQLRequest {
+    fn update_operation(_op: &mut Operation) {
+        // GraphQL exposes a single endpoint with a query/variables body rather
+        // than per-field parameters, so there's nothing to add here.
+    }
+}
@@ -0,0 +1,34 @@
+//! GraphQL response wrapper
+
+use bytes::Bytes;
+use http::{header, StatusCode};
+use http_body_util::Full;
+use rustapi_core::{IntoResponse, Response};
+
+/// Wraps an `async_graphql::Response` so it can be returned directly from a handler
+///
+/// # Example
+///
+/// ```rust,ignore
+/// async fn graphql_handler(schema: State<AppSchema>, req: GraphQLRequest) -> GraphQLResponse {
+///     schema.execute(req.into_inner()).await.into()
+/// }
+/// ```
+pub struct GraphQLResponse(pub async_graphql::Response);
+
+impl From<async_graphql::Response> for GraphQLResponse {
+    fn from(response: async_graphql::Response) -> Self {
+        Self(response)
+    }
+}
+
+impl IntoResponse for GraphQLResponse {
+    fn into_response(self) -> Response {
+        let body = serde_json::to_vec(&self.0).unwrap_or_default();
+        http::Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Full::new(Bytes::from(body)))
+            .unwrap()
+    }
+}
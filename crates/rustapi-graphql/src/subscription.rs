@@ -0,0 +1,67 @@
+//! GraphQL subscriptions over WebSocket
+
+use async_graphql::http::{WebSocket as GraphQLWebSocket, WebSocketProtocols, WsMessage};
+use async_graphql::{ObjectType, Schema, SubscriptionType};
+use futures_util::{stream, StreamExt};
+use rustapi_core::IntoResponse;
+use rustapi_ws::{CloseCode, Message, WebSocket};
+
+/// Handle GraphQL subscriptions over a WebSocket, speaking the
+/// `graphql-transport-ws` protocol (falling back to the older `graphql-ws`
+/// protocol if that's what the client asked for)
+///
+/// # Example
+///
+/// ```rust,ignore
+/// async fn graphql_subscriptions(schema: State<AppSchema>, ws: WebSocket) -> impl Responder {
+///     graphql_ws((*schema).clone(), ws)
+/// }
+/// ```
+pub fn graphql_ws<Q, M, S>(schema: Schema<Q, M, S>, ws: WebSocket) -> impl IntoResponse
+where
+    Q: ObjectType + 'static,
+    M: ObjectType + 'static,
+    S: SubscriptionType + 'static,
+{
+    let protocol = ws
+        .protocols()
+        .first()
+        .and_then(|p| p.parse::<WebSocketProtocols>().ok())
+        .unwrap_or(WebSocketProtocols::GraphQLWS);
+
+    ws.on_upgrade(move |socket| async move {
+        let (mut sender, receiver) = socket.split();
+
+        // `async_graphql`'s `WebSocket` drives the whole protocol state machine
+        // (connection_init/ack, subscribe/next/complete, ping/pong); we only need
+        // to feed it text frames and forward the messages it produces.
+        let incoming = stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Some(Ok(msg)) if msg.is_close() => return None,
+                    Some(Ok(msg)) => {
+                        if let Some(text) = msg.into_text() {
+                            return Some((text, receiver));
+                        }
+                        // Ignore ping/pong/binary frames and keep polling.
+                    }
+                    Some(Err(_)) | None => return None,
+                }
+            }
+        });
+
+        let mut outgoing = Box::pin(GraphQLWebSocket::new(schema, incoming, protocol));
+        while let Some(message) = outgoing.next().await {
+            let sent = match message {
+                WsMessage::Text(text) => sender.send(Message::text(text)).await,
+                WsMessage::Close(code, reason) => {
+                    let _ = sender.send(Message::close_with(CloseCode::from(code), reason)).await;
+                    break;
+                }
+            };
+            if sent.is_err() {
+                break;
+            }
+        }
+    })
+}
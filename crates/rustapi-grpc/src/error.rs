@@ -0,0 +1,100 @@
+//! Conversions between [`rustapi_core::ApiError`] and [`tonic::Status`].
+
+use http::StatusCode;
+use rustapi_core::ApiError;
+use tonic::{Code, Status};
+
+/// Converts between [`ApiError`] and gRPC [`Status`].
+///
+/// Both types are defined outside this crate, so the conversions can't be
+/// plain `From` impls (orphan rules) — this trait carries them instead.
+pub trait GrpcStatusExt: Sized {
+    /// Map this error onto the closest gRPC [`Status`], per the HTTP-to-gRPC
+    /// mapping in [Google's API design guide](https://cloud.google.com/apis/design/errors#generating_errors).
+    fn into_status(self) -> Status;
+
+    /// Map a gRPC [`Status`] back onto the closest `Self`, for services that
+    /// need to surface a failed gRPC call through the same error type the
+    /// rest of the app uses.
+    fn from_status(status: Status) -> Self;
+}
+
+impl GrpcStatusExt for ApiError {
+    fn into_status(self) -> Status {
+        let code = http_status_to_grpc_code(self.status);
+        Status::new(code, self.to_string())
+    }
+
+    fn from_status(status: Status) -> Self {
+        let http_status = grpc_code_to_http_status(status.code());
+        ApiError::new(http_status, "grpc_error", status.message().to_string())
+    }
+}
+
+fn http_status_to_grpc_code(status: StatusCode) -> Code {
+    match status {
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Code::InvalidArgument,
+        StatusCode::UNAUTHORIZED => Code::Unauthenticated,
+        StatusCode::FORBIDDEN => Code::PermissionDenied,
+        StatusCode::NOT_FOUND => Code::NotFound,
+        StatusCode::CONFLICT => Code::AlreadyExists,
+        StatusCode::TOO_MANY_REQUESTS => Code::ResourceExhausted,
+        StatusCode::PRECONDITION_FAILED => Code::FailedPrecondition,
+        StatusCode::GATEWAY_TIMEOUT => Code::DeadlineExceeded,
+        StatusCode::NOT_IMPLEMENTED => Code::Unimplemented,
+        StatusCode::SERVICE_UNAVAILABLE => Code::Unavailable,
+        s if s.is_server_error() => Code::Internal,
+        _ => Code::Unknown,
+    }
+}
+
+fn grpc_code_to_http_status(code: Code) -> StatusCode {
+    match code {
+        Code::Ok => StatusCode::OK,
+        Code::InvalidArgument | Code::OutOfRange => StatusCode::BAD_REQUEST,
+        Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        Code::PermissionDenied => StatusCode::FORBIDDEN,
+        Code::NotFound => StatusCode::NOT_FOUND,
+        Code::AlreadyExists | Code::Aborted => StatusCode::CONFLICT,
+        Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        Code::FailedPrecondition => StatusCode::PRECONDITION_FAILED,
+        Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        Code::Cancelled | Code::Unknown | Code::Internal | Code::DataLoss => {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_found_maps_to_grpc_not_found() {
+        let status = ApiError::not_found("missing").into_status();
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(status.message(), "not_found: missing");
+    }
+
+    #[test]
+    fn validation_maps_to_invalid_argument() {
+        let status = ApiError::validation(vec![]).into_status();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[test]
+    fn internal_maps_to_grpc_internal() {
+        let status = ApiError::internal("boom").into_status();
+        assert_eq!(status.code(), Code::Internal);
+    }
+
+    #[test]
+    fn status_round_trips_back_to_api_error() {
+        let status = Status::new(Code::PermissionDenied, "no access");
+        let err = ApiError::from_status(status);
+        assert_eq!(err.status, StatusCode::FORBIDDEN);
+        assert_eq!(err.message, "no access");
+    }
+}
@@ -0,0 +1,37 @@
+//! Runs a tonic gRPC server alongside a `rustapi-core` HTTP app.
+//!
+//! `rustapi-core`'s server (see `rustapi_core::server`) speaks HTTP/1.1 only, so
+//! gRPC's HTTP/2 framing can't be multiplexed onto the same TCP listener as the
+//! REST app today. [`GrpcGateway`] is the pragmatic alternative: it runs the
+//! tonic h2 server on its own port. Sharing application state between the two
+//! is the caller's job — build the same `Arc<S>` once and pass it into both
+//! `RustApi::new().state(state.clone())` and the gRPC service. If the core
+//! server grows HTTP/2 support, this is the type that would grow real
+//! same-port multiplexing.
+
+use tonic::transport::server::Router;
+use tonic::transport::Error as TransportError;
+
+/// Serves a tonic gRPC [`Router`] on its own address.
+///
+/// Build `router` the same way you would for a standalone tonic server, then
+/// hand it to [`GrpcGateway::new`] and run it alongside `RustApi::run`.
+pub struct GrpcGateway {
+    router: Router,
+}
+
+impl GrpcGateway {
+    /// Wrap a tonic service router (e.g. `Server::builder().add_service(..).into_router()`).
+    pub fn new(router: Router) -> Self {
+        Self { router }
+    }
+
+    /// Serve gRPC on `addr` until the process is terminated or the connection fails.
+    pub async fn run(self, addr: &str) -> Result<(), TransportError> {
+        let addr = addr
+            .parse()
+            .unwrap_or_else(|_| panic!("invalid gRPC listen address: {addr}"));
+        tracing::info!("🚀 RustAPI gRPC gateway running on http://{}", addr);
+        self.router.serve(addr).await
+    }
+}
@@ -0,0 +1,40 @@
+//! # rustapi-grpc
+//!
+//! gRPC integration for the RustAPI framework, built on `tonic`.
+//!
+//! This crate provides [`ApiError`](rustapi_core::ApiError) <-> [`tonic::Status`]
+//! conversion and [`GrpcGateway`], a thin runner for a tonic service.
+//!
+//! ## A note on "the same listener"
+//!
+//! `rustapi-core`'s server currently speaks HTTP/1.1 only (see
+//! `rustapi_core::server`), so it cannot multiplex gRPC's HTTP/2 framing onto
+//! the same TCP listener as the REST app. [`GrpcGateway`] runs the tonic h2
+//! server on its own port instead — see its docs for how to share application
+//! state between the two servers. Hybrid REST+gRPC services still need two
+//! listeners, but at least share error handling and application state.
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use rustapi_grpc::{tonic, GrpcGateway, GrpcStatusExt};
+//!
+//! let router = tonic::transport::Server::builder()
+//!     .add_service(my_service)
+//!     .into_router();
+//!
+//! GrpcGateway::new(router).run("127.0.0.1:3001").await?;
+//! ```
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_crate_level_docs)]
+
+mod error;
+mod gateway;
+
+pub use error::GrpcStatusExt;
+pub use gateway::GrpcGateway;
+
+// Re-exported so downstream crates don't need a separate `tonic` dependency
+// (and version) just to build a `Server`/`Router` to hand to `GrpcGateway`.
+pub use tonic;
@@ -0,0 +1,146 @@
+//! Fluent-backed message catalogs
+
+use crate::error::{I18nError, Result};
+use crate::locale::Locale;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use unic_langid::LanguageIdentifier;
+
+/// A set of Fluent message catalogs, one per locale, with fallback to a
+/// default locale for keys missing from the requested one.
+///
+/// Cheap to clone (internals are `Arc`-shared) so it can be registered as
+/// app state via `.state(catalogs)` and pulled out with `State<Catalogs>`.
+#[derive(Clone)]
+pub struct Catalogs {
+    bundles: Arc<RwLock<HashMap<LanguageIdentifier, FluentBundle<FluentResource>>>>,
+    default_locale: LanguageIdentifier,
+}
+
+impl Catalogs {
+    /// Create an empty set of catalogs falling back to `default_locale`.
+    pub fn new(default_locale: LanguageIdentifier) -> Self {
+        Self {
+            bundles: Arc::new(RwLock::new(HashMap::new())),
+            default_locale,
+        }
+    }
+
+    /// Parse an FTL source string and register it under `locale`, replacing
+    /// any catalog already loaded for that locale.
+    pub fn add_locale(&self, locale: LanguageIdentifier, ftl_source: impl Into<String>) -> Result<()> {
+        let resource = FluentResource::try_new(ftl_source.into())
+            .map_err(|(_, errors)| I18nError::Parse(format!("{errors:?}")))?;
+
+        let mut bundle = FluentBundle::new_concurrent(vec![locale.clone()]);
+        bundle.set_use_isolating(false);
+        bundle
+            .add_resource(resource)
+            .map_err(|errors| I18nError::Bundle(format!("{errors:?}")))?;
+
+        self.bundles
+            .write()
+            .expect("catalog lock poisoned")
+            .insert(locale, bundle);
+        Ok(())
+    }
+
+    /// Load one `.ftl` file per locale from `dir`, named `<locale>.ftl`
+    /// (e.g. `en-US.ftl`, `fr.ftl`).
+    pub fn load_dir(dir: impl AsRef<Path>, default_locale: LanguageIdentifier) -> Result<Self> {
+        let catalogs = Self::new(default_locale);
+
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("ftl") {
+                continue;
+            }
+
+            let stem = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .ok_or_else(|| I18nError::InvalidLocale(path.display().to_string()))?;
+            let locale = LanguageIdentifier::from_str(stem)
+                .map_err(|_| I18nError::InvalidLocale(stem.to_string()))?;
+
+            catalogs.add_locale(locale, std::fs::read_to_string(&path)?)?;
+        }
+
+        Ok(catalogs)
+    }
+
+    /// The locale used when a request or key doesn't resolve to a loaded one.
+    pub fn default_locale(&self) -> &LanguageIdentifier {
+        &self.default_locale
+    }
+
+    /// Translate `key` for `locale`, falling back to the default locale and
+    /// finally to `key` itself if neither has a matching message.
+    pub fn translate(&self, locale: &Locale, key: &str, args: Option<&FluentArgs>) -> String {
+        self.format(&locale.0, key, args)
+            .or_else(|| self.format(&self.default_locale, key, args))
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    fn format(
+        &self,
+        locale: &LanguageIdentifier,
+        key: &str,
+        args: Option<&FluentArgs>,
+    ) -> Option<String> {
+        let bundles = self.bundles.read().expect("catalog lock poisoned");
+        let bundle = bundles.get(locale)?;
+        let msg = bundle.get_message(key)?;
+        let pattern = msg.value()?;
+
+        let mut errors = Vec::new();
+        let value = bundle.format_pattern(pattern, args, &mut errors);
+        if !errors.is_empty() {
+            tracing::warn!("Fluent formatting errors for `{key}`: {errors:?}");
+        }
+        Some(value.into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unic_langid::langid;
+
+    #[test]
+    fn test_translate_with_args() {
+        let catalogs = Catalogs::new(langid!("en-US"));
+        catalogs
+            .add_locale(langid!("en-US"), "hello = Hello, { $name }!")
+            .unwrap();
+
+        let mut args = FluentArgs::new();
+        args.set("name", "World");
+
+        let result = catalogs.translate(&Locale(langid!("en-US")), "hello", Some(&args));
+        assert_eq!(result, "Hello, World!");
+    }
+
+    #[test]
+    fn test_translate_falls_back_to_default_locale() {
+        let catalogs = Catalogs::new(langid!("en-US"));
+        catalogs
+            .add_locale(langid!("en-US"), "greeting = Hi there")
+            .unwrap();
+        catalogs.add_locale(langid!("fr"), "other = Autre chose").unwrap();
+
+        let result = catalogs.translate(&Locale(langid!("fr")), "greeting", None);
+        assert_eq!(result, "Hi there");
+    }
+
+    #[test]
+    fn test_translate_falls_back_to_key() {
+        let catalogs = Catalogs::new(langid!("en-US"));
+        let result = catalogs.translate(&Locale(langid!("en-US")), "missing", None);
+        assert_eq!(result, "missing");
+    }
+}
@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// Errors from catalog loading and message formatting.
+#[derive(Debug, Error)]
+pub enum I18nError {
+    /// The FTL source failed to parse.
+    #[error("Failed to parse Fluent resource: {0}")]
+    Parse(String),
+
+    /// A parsed resource conflicted with one already in the bundle.
+    #[error("Failed to add Fluent resource to bundle: {0}")]
+    Bundle(String),
+
+    /// A locale file name or tag isn't a valid locale identifier.
+    #[error("Invalid locale identifier: {0}")]
+    InvalidLocale(String),
+
+    /// Reading a catalog file from disk failed.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Result alias for fallible catalog operations.
+pub type Result<T> = std::result::Result<T, I18nError>;
@@ -0,0 +1,51 @@
+//! # rustapi-i18n
+//!
+//! Internationalization for the RustAPI framework.
+//!
+//! - **Fluent Catalogs**: [`Catalogs`] loads `.ftl` message files per
+//!   locale, with fallback to a default locale for missing keys.
+//! - **`Locale` Extractor**: [`Locale`] negotiates the request's locale from
+//!   `Accept-Language`, a `locale` cookie, or a `lang` query parameter.
+//! - **Template Integration**: with the `tera` feature, [`Catalogs`]
+//!   implements `rustapi_view::Translator`, so
+//!   `Templates::with_translations` can register a `t(key = "...")`
+//!   template helper.
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use rustapi_i18n::{Catalogs, Locale};
+//! use unic_langid::langid;
+//!
+//! let catalogs = Catalogs::new(langid!("en-US"));
+//! catalogs.add_locale(langid!("en-US"), "hello = Hello, { $name }!")?;
+//! catalogs.add_locale(langid!("fr"), "hello = Bonjour, { $name }!")?;
+//!
+//! async fn handler(locale: Locale, State(catalogs): State<Catalogs>) -> impl IntoResponse {
+//!     let mut args = FluentArgs::new();
+//!     args.set("name", "World");
+//!     catalogs.translate(&locale, "hello", Some(&args))
+//! }
+//! ```
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_crate_level_docs)]
+
+mod catalog;
+mod error;
+mod locale;
+#[cfg(feature = "tera")]
+mod tera;
+
+pub use catalog::Catalogs;
+pub use error::{I18nError, Result};
+pub use locale::Locale;
+
+// Re-export the Fluent types callers need to build translation arguments.
+pub use fluent_bundle::{FluentArgs, FluentValue};
+pub use unic_langid::{langid, LanguageIdentifier};
+
+/// Prelude module for convenient imports
+pub mod prelude {
+    pub use crate::{Catalogs, FluentArgs, Locale};
+}
@@ -0,0 +1,92 @@
+//! `Locale` extractor - resolves a request's language from a `lang` query
+//! parameter, a `locale` cookie, or the `Accept-Language` header.
+
+use crate::Catalogs;
+use rustapi_core::{FromRequestParts, Request, Result};
+use std::fmt;
+use std::str::FromStr;
+use unic_langid::LanguageIdentifier;
+
+/// The locale negotiated for the current request.
+///
+/// Resolution order: `?lang=` query parameter, `locale` cookie, then the
+/// `Accept-Language` header - falling back to the app's default (the
+/// [`Catalogs`] registered via `.state()`, or `en-US` if none is) if nothing
+/// in the request names a locale rustapi-i18n understands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale(pub LanguageIdentifier);
+
+impl Locale {
+    /// Resolve a locale from `req`, falling back to `default`.
+    pub fn resolve(req: &Request, default: &LanguageIdentifier) -> Self {
+        if let Some(query) = req.query_string() {
+            if let Some(lang) = query_param(query, "lang") {
+                if let Ok(id) = LanguageIdentifier::from_str(lang) {
+                    return Locale(id);
+                }
+            }
+        }
+
+        if let Some(cookie) = req
+            .headers()
+            .get(http::header::COOKIE)
+            .and_then(|v| v.to_str().ok())
+        {
+            if let Some(lang) = cookie_value(cookie, "locale") {
+                if let Ok(id) = LanguageIdentifier::from_str(lang) {
+                    return Locale(id);
+                }
+            }
+        }
+
+        if let Some(header) = req
+            .headers()
+            .get(http::header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok())
+        {
+            for tag in accept_language::parse(header) {
+                if let Ok(id) = LanguageIdentifier::from_str(&tag) {
+                    return Locale(id);
+                }
+            }
+        }
+
+        Locale(default.clone())
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromRequestParts for Locale {
+    fn from_request_parts(req: &Request) -> Result<Self> {
+        let default = req
+            .state()
+            .get::<Catalogs>()
+            .map(|catalogs| catalogs.default_locale().clone())
+            .unwrap_or_else(|| "en-US".parse().expect("valid default locale"));
+
+        Ok(Self::resolve(req, &default))
+    }
+}
+
+/// Extract `name`'s value from a `key=value&key=value` query string.
+fn query_param<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .find_map(|pair| pair.split_once('=').filter(|(k, _)| *k == name))
+        .map(|(_, v)| v)
+}
+
+/// Extract `name`'s value from a `key=value; key=value` `Cookie` header.
+fn cookie_value<'a>(cookie_header: &'a str, name: &str) -> Option<&'a str> {
+    cookie_header.split(';').find_map(|pair| {
+        pair.trim()
+            .split_once('=')
+            .filter(|(k, _)| *k == name)
+            .map(|(_, v)| v)
+    })
+}
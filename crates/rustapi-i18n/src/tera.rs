@@ -0,0 +1,71 @@
+//! Tera integration: implements [`rustapi_view::Translator`] for
+//! [`Catalogs`] so `Templates::with_translations` can register the
+//! `t(key = "...")` template helper.
+
+use crate::{Catalogs, Locale};
+use fluent_bundle::FluentArgs;
+use rustapi_view::Translator;
+use std::collections::HashMap;
+use std::str::FromStr;
+use unic_langid::LanguageIdentifier;
+
+impl Translator for Catalogs {
+    fn translate(&self, locale: Option<&str>, key: &str, args: &HashMap<String, String>) -> String {
+        let locale = locale
+            .and_then(|l| LanguageIdentifier::from_str(l).ok())
+            .unwrap_or_else(|| self.default_locale().clone());
+
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(name.clone(), value.clone());
+        }
+
+        Catalogs::translate(self, &Locale(locale), key, Some(&fluent_args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustapi_view::Templates;
+    use std::sync::Arc;
+    use unic_langid::langid;
+
+    #[tokio::test]
+    async fn test_t_function_renders_translation() {
+        let catalogs = Catalogs::new(langid!("en-US"));
+        catalogs
+            .add_locale(langid!("en-US"), "hello = Hello, { $name }!")
+            .unwrap();
+        catalogs
+            .add_locale(langid!("fr"), "hello = Bonjour, { $name }!")
+            .unwrap();
+
+        let templates = Templates::empty()
+            .with_translations(Arc::new(catalogs))
+            .await;
+        templates
+            .add_template("test", r#"{{ t(key="hello", name="World") }}"#)
+            .await
+            .unwrap();
+        templates
+            .add_template(
+                "test_fr",
+                r#"{{ t(key="hello", locale="fr", name="World") }}"#,
+            )
+            .await
+            .unwrap();
+
+        let result = templates
+            .render_with("test", &serde_json::json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, "Hello, World!");
+
+        let result_fr = templates
+            .render_with("test_fr", &serde_json::json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result_fr, "Bonjour, World!");
+    }
+}
@@ -1,7 +1,9 @@
 use crate::error::Result;
+use crate::status::{JobProgress, JobRecord};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 pub mod memory;
 
@@ -22,6 +24,11 @@ pub struct JobRequest {
     pub max_attempts: u32,
     pub last_error: Option<String>,
     pub run_at: Option<DateTime<Utc>>,
+    /// Deduplication key set via [`crate::EnqueueOptions::unique_key`].
+    pub unique_key: Option<String>,
+    /// How long a successful run's result should be kept, set via
+    /// [`crate::EnqueueOptions::result_ttl`].
+    pub result_ttl: Option<Duration>,
 }
 
 /// Backend storage for jobs
@@ -30,6 +37,21 @@ pub trait JobBackend: Send + Sync {
     /// Push a new job to the queue
     async fn push(&self, job: JobRequest) -> Result<()>;
 
+    /// Push a job, skipping the insert if a pending job with the same
+    /// `unique_key` already exists.
+    ///
+    /// Returns the id of the job that now represents this work: `job`'s own
+    /// id if it was inserted, or the existing pending job's id if this push
+    /// was deduplicated. Jobs without a `unique_key` are never deduplicated.
+    ///
+    /// The default implementation has no way to check for an existing key
+    /// without backend-specific storage, so it always inserts.
+    async fn push_unique(&self, job: JobRequest) -> Result<String> {
+        let id = job.id.clone();
+        self.push(job).await?;
+        Ok(id)
+    }
+
     /// Pop the next available job
     /// Should return None if no job is available or ready
     async fn pop(&self) -> Result<Option<JobRequest>>;
@@ -40,4 +62,57 @@ pub trait JobBackend: Send + Sync {
     /// Mark a job as failed
     /// The manager will decide whether to retry (re-push) or move to DLQ
     async fn fail(&self, job_id: &str, error: &str) -> Result<()>;
+
+    /// Snapshot every job the backend currently has bookkeeping for, for the
+    /// status/dashboard API.
+    ///
+    /// Backends that don't keep this bookkeeping return an empty list - see
+    /// e.g. the `pop` docs on [`crate::PostgresBackend`]/[`crate::RedisBackend`],
+    /// which delete rows as soon as they're popped.
+    async fn list_records(&self) -> Result<Vec<JobRecord>> {
+        Ok(Vec::new())
+    }
+
+    /// Look up a single job's record by id.
+    async fn get_record(&self, _job_id: &str) -> Result<Option<JobRecord>> {
+        Ok(None)
+    }
+
+    /// Reset a dead job's attempts and put it back in the queue.
+    ///
+    /// Returns `false` if the backend has no record of `job_id`.
+    async fn requeue(&self, _job_id: &str) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Best-effort count of jobs this backend has completed since it was
+    /// created, e.g. for a dashboard's throughput display. Not a precise
+    /// metric - not persisted, not decremented, not shared across processes.
+    async fn completed_count(&self) -> Result<u64> {
+        Ok(0)
+    }
+
+    /// Record the most recent progress update for a running job.
+    ///
+    /// Default implementation has nowhere to keep this - see
+    /// [`crate::InMemoryBackend`].
+    async fn set_progress(&self, _job_id: &str, _progress: JobProgress) -> Result<()> {
+        Ok(())
+    }
+
+    /// Look up the last reported progress for a job, if any.
+    async fn get_progress(&self, _job_id: &str) -> Result<Option<JobProgress>> {
+        Ok(None)
+    }
+
+    /// Store a completed job's result, to be retrieved via [`JobBackend::get_result`]
+    /// until `ttl` elapses.
+    async fn store_result(&self, _job_id: &str, _value: serde_json::Value, _ttl: Duration) -> Result<()> {
+        Ok(())
+    }
+
+    /// Fetch a stored result, if it exists and hasn't expired.
+    async fn get_result(&self, _job_id: &str) -> Result<Option<serde_json::Value>> {
+        Ok(None)
+    }
 }
@@ -1,61 +1,268 @@
 use super::{JobBackend, JobRequest};
 use crate::error::{JobError, Result};
+use crate::status::{JobProgress, JobRecord, JobStatus};
 use async_trait::async_trait;
-use std::collections::VecDeque;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Bookkeeping kept alongside the queue itself so the status/dashboard API
+/// has something to report - the queue's `VecDeque` only ever holds jobs
+/// that are pending, not ones that are running or dead.
+#[derive(Debug, Clone)]
+struct TrackedJob {
+    req: JobRequest,
+    in_flight: bool,
+    permanently_failed: bool,
+    updated_at: DateTime<Utc>,
+}
+
+impl TrackedJob {
+    fn record(&self) -> JobRecord {
+        let status = if self.in_flight {
+            JobStatus::Running
+        } else if self.permanently_failed {
+            JobStatus::Dead
+        } else if self.req.last_error.is_some() {
+            JobStatus::Failed
+        } else {
+            JobStatus::Pending
+        };
+
+        JobRecord {
+            id: self.req.id.clone(),
+            name: self.req.name.clone(),
+            status,
+            attempts: self.req.attempts,
+            max_attempts: self.req.max_attempts,
+            last_error: self.req.last_error.clone(),
+            created_at: self.req.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// A stored job result along with when it expires.
+type StoredResult = (serde_json::Value, DateTime<Utc>);
 
 /// In-memory job backend (not persistent, for testing/dev)
 #[derive(Debug, Clone, Default)]
 pub struct InMemoryBackend {
     queue: Arc<Mutex<VecDeque<JobRequest>>>,
-    // In a real system we'd track processing jobs separately for reliability
+    records: Arc<Mutex<HashMap<String, TrackedJob>>>,
+    completed_count: Arc<AtomicU64>,
+    progress: Arc<Mutex<HashMap<String, JobProgress>>>,
+    results: Arc<Mutex<HashMap<String, StoredResult>>>,
 }
 
 impl InMemoryBackend {
     pub fn new() -> Self {
         Self::default()
     }
+
+    fn lock_poisoned() -> JobError {
+        JobError::BackendError("Lock poisoned".to_string())
+    }
 }
 
 #[async_trait]
 impl JobBackend for InMemoryBackend {
     async fn push(&self, job: JobRequest) -> Result<()> {
-        let mut q = self
-            .queue
+        self.records
             .lock()
-            .map_err(|_| JobError::BackendError("Lock poisoned".to_string()))?;
-        q.push_back(job);
+            .map_err(|_| Self::lock_poisoned())?
+            .insert(
+                job.id.clone(),
+                TrackedJob {
+                    req: job.clone(),
+                    in_flight: false,
+                    permanently_failed: false,
+                    updated_at: Utc::now(),
+                },
+            );
+
+        self.queue
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .push_back(job);
         Ok(())
     }
 
+    async fn push_unique(&self, job: JobRequest) -> Result<String> {
+        let Some(key) = job.unique_key.clone() else {
+            let id = job.id.clone();
+            self.push(job).await?;
+            return Ok(id);
+        };
+
+        let mut q = self.queue.lock().map_err(|_| Self::lock_poisoned())?;
+
+        if let Some(existing) = q.iter().find(|j| j.unique_key.as_deref() == Some(key.as_str())) {
+            return Ok(existing.id.clone());
+        }
+
+        let id = job.id.clone();
+        q.push_back(job.clone());
+        drop(q);
+
+        self.records
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .insert(
+                id.clone(),
+                TrackedJob {
+                    req: job,
+                    in_flight: false,
+                    permanently_failed: false,
+                    updated_at: Utc::now(),
+                },
+            );
+        Ok(id)
+    }
+
     async fn pop(&self) -> Result<Option<JobRequest>> {
-        let mut q = self
-            .queue
-            .lock()
-            .map_err(|_| JobError::BackendError("Lock poisoned".to_string()))?;
-
-        // Simple FIFO for now, ignoring run_at logic complexity for basic in-memory
-        // In reality we should scan for ready jobs
-        if let Some(job) = q.front() {
-            if let Some(run_at) = job.run_at {
-                if run_at > chrono::Utc::now() {
-                    return Ok(None);
-                }
+        let mut q = self.queue.lock().map_err(|_| Self::lock_poisoned())?;
+
+        // FIFO among ready jobs, but a delayed job at the front shouldn't
+        // block a ready job behind it - scan for the first one whose run_at
+        // has passed.
+        let now = Utc::now();
+        let ready_index = q
+            .iter()
+            .position(|job| job.run_at.map(|run_at| run_at <= now).unwrap_or(true));
+
+        let popped = ready_index.and_then(|i| q.remove(i));
+        drop(q);
+
+        if let Some(job) = &popped {
+            if let Some(tracked) = self
+                .records
+                .lock()
+                .map_err(|_| Self::lock_poisoned())?
+                .get_mut(&job.id)
+            {
+                tracked.in_flight = true;
+                tracked.updated_at = Utc::now();
             }
-        } else {
-            return Ok(None);
         }
 
-        Ok(q.pop_front())
+        Ok(popped)
+    }
+
+    async fn complete(&self, job_id: &str) -> Result<()> {
+        // The queue already dropped this job on pop; the job's done, so we
+        // stop tracking its record too.
+        self.records
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .remove(job_id);
+        self.completed_count.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn fail(&self, job_id: &str, error: &str) -> Result<()> {
+        // In a real implementation we might move to DLQ or re-queue.
+        // A retryable failure re-enters via `push` with an incremented
+        // attempt count, so reaching `fail` means this job is dead.
+        if let Some(tracked) = self
+            .records
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .get_mut(job_id)
+        {
+            tracked.in_flight = false;
+            tracked.permanently_failed = true;
+            tracked.req.last_error = Some(error.to_string());
+            tracked.updated_at = Utc::now();
+        }
+        Ok(())
+    }
+
+    async fn list_records(&self) -> Result<Vec<JobRecord>> {
+        Ok(self
+            .records
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .values()
+            .map(TrackedJob::record)
+            .collect())
+    }
+
+    async fn get_record(&self, job_id: &str) -> Result<Option<JobRecord>> {
+        Ok(self
+            .records
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .get(job_id)
+            .map(TrackedJob::record))
+    }
+
+    async fn requeue(&self, job_id: &str) -> Result<bool> {
+        let mut records = self.records.lock().map_err(|_| Self::lock_poisoned())?;
+        let Some(tracked) = records.get_mut(job_id) else {
+            return Ok(false);
+        };
+
+        tracked.req.attempts = 0;
+        tracked.req.last_error = None;
+        tracked.req.run_at = None;
+        tracked.in_flight = false;
+        tracked.permanently_failed = false;
+        tracked.updated_at = Utc::now();
+        let req = tracked.req.clone();
+        drop(records);
+
+        self.queue
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .push_back(req);
+        Ok(true)
+    }
+
+    async fn completed_count(&self) -> Result<u64> {
+        Ok(self.completed_count.load(Ordering::Relaxed))
     }
 
-    async fn complete(&self, _job_id: &str) -> Result<()> {
-        // No-op for simple in-memory queue that removes on pop
+    async fn set_progress(&self, job_id: &str, progress: JobProgress) -> Result<()> {
+        self.progress
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .insert(job_id.to_string(), progress);
         Ok(())
     }
 
-    async fn fail(&self, _job_id: &str, _error: &str) -> Result<()> {
-        // In a real implementation we might move to DLQ or re-queue
+    async fn get_progress(&self, job_id: &str) -> Result<Option<JobProgress>> {
+        Ok(self
+            .progress
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .get(job_id)
+            .cloned())
+    }
+
+    async fn store_result(&self, job_id: &str, value: serde_json::Value, ttl: Duration) -> Result<()> {
+        let expires_at = Utc::now()
+            + chrono::Duration::from_std(ttl).unwrap_or_else(|_| chrono::Duration::seconds(0));
+        self.results
+            .lock()
+            .map_err(|_| Self::lock_poisoned())?
+            .insert(job_id.to_string(), (value, expires_at));
         Ok(())
     }
+
+    async fn get_result(&self, job_id: &str) -> Result<Option<serde_json::Value>> {
+        let mut results = self.results.lock().map_err(|_| Self::lock_poisoned())?;
+        let Some((value, expires_at)) = results.get(job_id) else {
+            return Ok(None);
+        };
+
+        if *expires_at <= Utc::now() {
+            results.remove(job_id);
+            return Ok(None);
+        }
+
+        Ok(Some(value.clone()))
+    }
 }
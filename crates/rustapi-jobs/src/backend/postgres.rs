@@ -18,11 +18,15 @@ impl PostgresBackend {
         }
     }
 
-    /// Initialize the database schema
-    pub async fn ensure_schema(&self) -> Result<()> {
-        let query = format!(
+    /// The DDL that creates this backend's table and its `run_at` index.
+    ///
+    /// Exposed standalone so it can be dropped into a project's own
+    /// `sqlx migrate` / `refinery` migration files instead of relying on
+    /// [`ensure_schema`](Self::ensure_schema) to run it at startup.
+    pub fn migration_sql(table_name: &str) -> String {
+        format!(
             r#"
-            CREATE TABLE IF NOT EXISTS {} (
+            CREATE TABLE IF NOT EXISTS {table_name} (
                 id TEXT PRIMARY KEY,
                 name TEXT NOT NULL,
                 payload JSONB NOT NULL,
@@ -30,14 +34,22 @@ impl PostgresBackend {
                 run_at TIMESTAMPTZ,
                 attempts INT DEFAULT 0,
                 max_attempts INT DEFAULT 3,
-                last_error TEXT
+                last_error TEXT,
+                unique_key TEXT
             );
-            CREATE INDEX IF NOT EXISTS idx_{}_run_at ON {} (run_at);
-            "#,
-            self.table_name, self.table_name, self.table_name
-        );
+            CREATE INDEX IF NOT EXISTS idx_{table_name}_run_at ON {table_name} (run_at);
+            CREATE UNIQUE INDEX IF NOT EXISTS idx_{table_name}_unique_key ON {table_name} (unique_key) WHERE unique_key IS NOT NULL;
+            "#
+        )
+    }
 
-        sqlx::query(&query)
+    /// Initialize the database schema by running [`migration_sql`](Self::migration_sql) directly.
+    ///
+    /// Convenient for local development and tests; production deployments
+    /// should generally run `migration_sql` through their own migration
+    /// tool instead.
+    pub async fn ensure_schema(&self) -> Result<()> {
+        sqlx::query(&Self::migration_sql(&self.table_name))
             .execute(&self.pool)
             .await
             .map_err(|e| JobError::BackendError(e.to_string()))?;
@@ -51,8 +63,8 @@ impl JobBackend for PostgresBackend {
     async fn push(&self, job: JobRequest) -> Result<()> {
         let query = format!(
             r#"
-            INSERT INTO {} (id, name, payload, created_at, run_at, attempts, max_attempts, last_error)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO {} (id, name, payload, created_at, run_at, attempts, max_attempts, last_error, unique_key)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#,
             self.table_name
         );
@@ -66,6 +78,7 @@ impl JobBackend for PostgresBackend {
             .bind(job.attempts as i32)
             .bind(job.max_attempts as i32)
             .bind(&job.last_error)
+            .bind(&job.unique_key)
             .execute(&self.pool)
             .await
             .map_err(|e| JobError::BackendError(e.to_string()))?;
@@ -73,6 +86,52 @@ impl JobBackend for PostgresBackend {
         Ok(())
     }
 
+    async fn push_unique(&self, job: JobRequest) -> Result<String> {
+        let Some(key) = job.unique_key.clone() else {
+            let id = job.id.clone();
+            self.push(job).await?;
+            return Ok(id);
+        };
+
+        let query = format!(
+            r#"
+            INSERT INTO {} (id, name, payload, created_at, run_at, attempts, max_attempts, last_error, unique_key)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (unique_key) DO NOTHING
+            RETURNING id
+            "#,
+            self.table_name
+        );
+
+        let inserted = sqlx::query(&query)
+            .bind(&job.id)
+            .bind(&job.name)
+            .bind(&job.payload)
+            .bind(job.created_at)
+            .bind(job.run_at)
+            .bind(job.attempts as i32)
+            .bind(job.max_attempts as i32)
+            .bind(&job.last_error)
+            .bind(&key)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| JobError::BackendError(e.to_string()))?;
+
+        if let Some(row) = inserted {
+            Ok(row.get("id"))
+        } else {
+            let existing = sqlx::query(&format!(
+                "SELECT id FROM {} WHERE unique_key = $1",
+                self.table_name
+            ))
+            .bind(&key)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| JobError::BackendError(e.to_string()))?;
+            Ok(existing.get("id"))
+        }
+    }
+
     async fn pop(&self) -> Result<Option<JobRequest>> {
         // Atomic pop using DELETE ... RETURNING with locking
         let query = format!(
@@ -86,7 +145,7 @@ impl JobBackend for PostgresBackend {
                 FOR UPDATE SKIP LOCKED
                 LIMIT 1
             )
-            RETURNING id, name, payload, created_at, run_at, attempts, max_attempts, last_error
+            RETURNING id, name, payload, created_at, run_at, attempts, max_attempts, last_error, unique_key
             "#,
             self.table_name, self.table_name
         );
@@ -111,6 +170,9 @@ impl JobBackend for PostgresBackend {
                 attempts: row.get::<i32, _>("attempts") as u32,
                 max_attempts: row.get::<i32, _>("max_attempts") as u32,
                 last_error: row.get("last_error"),
+                unique_key: row.get("unique_key"),
+                // Not stored in this table, so a popped job carries no result TTL.
+                result_ttl: None,
             }))
         } else {
             Ok(None)
@@ -8,21 +8,32 @@ use redis::{AsyncCommands, Client, Script};
 pub struct RedisBackend {
     client: Client,
     queue_key: String,
+    // Key of a hash mapping unique_key -> job id, for pending jobs enqueued
+    // via push_unique.
+    unique_key_map: String,
     // Script is cheap to clone (Arc internal) or re-create
     pop_script: Script,
+    push_unique_script: Script,
 }
 
 impl RedisBackend {
     pub fn new(url: &str, queue_key: &str) -> Result<Self> {
         let client = Client::open(url).map_err(|e| JobError::ConfigError(e.to_string()))?;
 
-        // Lua script to atomically pop the first ready job
+        // Lua script to atomically pop the first ready job. Also clears the
+        // job's entry from the unique_key map (if any) so a later push_unique
+        // with the same key isn't deduplicated against a job that's no
+        // longer pending.
         // ZRANGEBYSCORE key -inf now LIMIT 0 1
         let pop_script = Script::new(
             r#"
             local jobs = redis.call('ZRANGEBYSCORE', KEYS[1], '-inf', ARGV[1], 'LIMIT', 0, 1)
             if #jobs > 0 then
                 redis.call('ZREM', KEYS[1], jobs[1])
+                local job = cjson.decode(jobs[1])
+                if job.unique_key and job.unique_key ~= cjson.null then
+                    redis.call('HDEL', KEYS[2], job.unique_key)
+                end
                 return jobs[1]
             else
                 return nil
@@ -30,10 +41,26 @@ impl RedisBackend {
         "#,
         );
 
+        // Lua script that only enqueues if `unique_key` isn't already
+        // pending, returning the id that now represents the work either way.
+        let push_unique_script = Script::new(
+            r#"
+            local existing = redis.call('HGET', KEYS[2], ARGV[3])
+            if existing then
+                return existing
+            end
+            redis.call('ZADD', KEYS[1], ARGV[1], ARGV[2])
+            redis.call('HSET', KEYS[2], ARGV[3], ARGV[4])
+            return ARGV[4]
+        "#,
+        );
+
         Ok(Self {
             client,
+            unique_key_map: format!("{queue_key}:unique"),
             queue_key: queue_key.to_string(),
             pop_script,
+            push_unique_script,
         })
     }
 }
@@ -57,6 +84,37 @@ impl JobBackend for RedisBackend {
         Ok(())
     }
 
+    async fn push_unique(&self, job: JobRequest) -> Result<String> {
+        let Some(key) = job.unique_key.clone() else {
+            let id = job.id.clone();
+            self.push(job).await?;
+            return Ok(id);
+        };
+
+        let mut conn = self
+            .client
+            .get_async_connection()
+            .await
+            .map_err(|e| JobError::BackendError(e.to_string()))?;
+
+        let score = job.run_at.unwrap_or(chrono::Utc::now()).timestamp() as f64;
+        let payload = serde_json::to_string(&job)?;
+
+        let id: String = self
+            .push_unique_script
+            .key(&self.queue_key)
+            .key(&self.unique_key_map)
+            .arg(score)
+            .arg(&payload)
+            .arg(&key)
+            .arg(&job.id)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| JobError::BackendError(e.to_string()))?;
+
+        Ok(id)
+    }
+
     async fn pop(&self) -> Result<Option<JobRequest>> {
         let mut conn = self
             .client
@@ -69,6 +127,7 @@ impl JobBackend for RedisBackend {
         let result: Option<String> = self
             .pop_script
             .key(&self.queue_key)
+            .key(&self.unique_key_map)
             .arg(now)
             .invoke_async(&mut conn)
             .await
@@ -0,0 +1,170 @@
+//! An optional HTML/JSON dashboard for a [`JobQueue`], mountable onto a
+//! [`RustApi`] app.
+//!
+//! ```rust,ignore
+//! use rustapi_jobs::JobsDashboardExt;
+//!
+//! RustApi::new()
+//!     .jobs_dashboard("/jobs", queue.clone())
+//! ```
+
+use crate::queue::JobQueue;
+use crate::status::{JobRecord, JobStatus};
+use bytes::Bytes;
+use http_body_util::Full;
+use rustapi_core::{get, post, Path, Response, RustApi, State};
+
+fn json_response(body: &impl serde::Serialize) -> Response {
+    let json = serde_json::to_string(body).unwrap_or_default();
+    http::Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(Full::new(Bytes::from(json)))
+        .unwrap()
+}
+
+fn not_found(job_id: &str) -> Response {
+    let json = serde_json::json!({ "error": format!("no such job: {job_id}") }).to_string();
+    http::Response::builder()
+        .status(http::StatusCode::NOT_FOUND)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(Full::new(Bytes::from(json)))
+        .unwrap()
+}
+
+async fn list_by_status(queue: &JobQueue, status: Option<JobStatus>) -> Response {
+    match queue.list_jobs(status).await {
+        Ok(records) => json_response(&records),
+        Err(e) => json_response(&serde_json::json!({ "error": e.to_string() })),
+    }
+}
+
+async fn list_pending(State(queue): State<JobQueue>) -> Response {
+    list_by_status(&queue, Some(JobStatus::Pending)).await
+}
+
+async fn list_running(State(queue): State<JobQueue>) -> Response {
+    list_by_status(&queue, Some(JobStatus::Running)).await
+}
+
+async fn list_failed(State(queue): State<JobQueue>) -> Response {
+    list_by_status(&queue, Some(JobStatus::Failed)).await
+}
+
+async fn list_dead(State(queue): State<JobQueue>) -> Response {
+    list_by_status(&queue, Some(JobStatus::Dead)).await
+}
+
+async fn job_status(State(queue): State<JobQueue>, Path(id): Path<String>) -> Response {
+    match queue.status(&id).await {
+        Ok(Some(record)) => json_response(&record),
+        Ok(None) => not_found(&id),
+        Err(e) => json_response(&serde_json::json!({ "error": e.to_string() })),
+    }
+}
+
+async fn retry_job(State(queue): State<JobQueue>, Path(id): Path<String>) -> Response {
+    match queue.retry(&id).await {
+        Ok(true) => json_response(&serde_json::json!({ "requeued": true })),
+        Ok(false) => not_found(&id),
+        Err(e) => json_response(&serde_json::json!({ "error": e.to_string() })),
+    }
+}
+
+fn row_html(job: &JobRecord) -> String {
+    let error_cell = job.last_error.as_deref().unwrap_or("-");
+    format!(
+        "<tr><td>{id}</td><td>{name}</td><td class=\"status-{status:?}\">{status:?}</td>\
+         <td>{attempts}/{max_attempts}</td><td>{error}</td></tr>",
+        id = job.id,
+        name = job.name,
+        status = job.status,
+        attempts = job.attempts,
+        max_attempts = job.max_attempts,
+        error = error_cell,
+    )
+}
+
+async fn dashboard_page(State(queue): State<JobQueue>) -> Response {
+    let records = queue.list_jobs(None).await.unwrap_or_default();
+    let completed = queue.completed_count().await.unwrap_or(0);
+
+    let pending = records.iter().filter(|r| r.status == JobStatus::Pending).count();
+    let running = records.iter().filter(|r| r.status == JobStatus::Running).count();
+    let failed = records.iter().filter(|r| r.status == JobStatus::Failed).count();
+    let dead = records.iter().filter(|r| r.status == JobStatus::Dead).count();
+
+    let rows: String = records.iter().map(row_html).collect();
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>RustAPI Jobs</title>
+<style>
+body {{ font-family: sans-serif; margin: 2rem; color: #1a1a1a; }}
+table {{ border-collapse: collapse; width: 100%; }}
+th, td {{ border: 1px solid #ddd; padding: 0.5rem; text-align: left; }}
+th {{ background: #f5f5f5; }}
+.summary span {{ margin-right: 1.5rem; }}
+</style>
+</head>
+<body>
+<h1>Jobs</h1>
+<p class="summary">
+<span>Pending: {pending}</span>
+<span>Running: {running}</span>
+<span>Failed: {failed}</span>
+<span>Dead: {dead}</span>
+<span>Completed: {completed}</span>
+</p>
+<table>
+<tr><th>ID</th><th>Job</th><th>Status</th><th>Attempts</th><th>Last error</th></tr>
+{rows}
+</table>
+</body>
+</html>"#
+    );
+
+    http::Response::builder()
+        .status(http::StatusCode::OK)
+        .header(http::header::CONTENT_TYPE, "text/html; charset=utf-8")
+        .body(Full::new(Bytes::from(html)))
+        .unwrap()
+}
+
+/// Mounts a jobs dashboard onto a [`RustApi`] app.
+pub trait JobsDashboardExt {
+    /// Mount an HTML dashboard and a small JSON status API at `path`.
+    ///
+    /// - `GET {path}` - an HTML overview of queue depth, throughput, and jobs
+    /// - `GET {path}/api/pending`, `/running`, `/failed`, `/dead` - JSON lists
+    /// - `GET {path}/api/jobs/{id}` - a single job's status
+    /// - `POST {path}/api/jobs/{id}/retry` - reset a dead job and re-enqueue it
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use rustapi_jobs::JobsDashboardExt;
+    ///
+    /// RustApi::new()
+    ///     .jobs_dashboard("/jobs", queue.clone())
+    /// ```
+    fn jobs_dashboard(self, path: &str, queue: JobQueue) -> Self;
+}
+
+impl JobsDashboardExt for RustApi {
+    fn jobs_dashboard(self, path: &str, queue: JobQueue) -> Self {
+        let path = path.trim_end_matches('/');
+
+        self.state(queue)
+            .route(path, get(dashboard_page))
+            .route(&format!("{path}/api/pending"), get(list_pending))
+            .route(&format!("{path}/api/running"), get(list_running))
+            .route(&format!("{path}/api/failed"), get(list_failed))
+            .route(&format!("{path}/api/dead"), get(list_dead))
+            .route(&format!("{path}/api/jobs/{{id}}"), get(job_status))
+            .route(&format!("{path}/api/jobs/{{id}}/retry"), post(retry_job))
+    }
+}
@@ -0,0 +1,63 @@
+//! A `Jobs` extractor for enqueueing from handlers without manually
+//! threading `Arc<JobQueue>` through [`rustapi_core::State`].
+//!
+//! ```rust,ignore
+//! use rustapi_core::{Json, StatusCode};
+//! use rustapi_jobs::{Jobs, JobsExt};
+//!
+//! RustApi::new().jobs(queue)
+//!
+//! async fn send_email(jobs: Jobs, Json(input): Json<SendEmailInput>) -> impl IntoResponse {
+//!     jobs.enqueue::<SendEmail>(input.into()).await?;
+//!     (StatusCode::ACCEPTED, Json(serde_json::json!({ "queued": true })))
+//! }
+//! ```
+
+use crate::queue::JobQueue;
+use rustapi_core::{ApiError, FromRequestParts, Request, Result, RustApi};
+use rustapi_openapi::{Operation, OperationModifier};
+use std::ops::Deref;
+
+/// Extracts the [`JobQueue`] registered via [`JobsExt::jobs`], for enqueueing
+/// jobs directly from a handler.
+#[derive(Clone)]
+pub struct Jobs(pub JobQueue);
+
+impl FromRequestParts for Jobs {
+    fn from_request_parts(req: &Request) -> Result<Self> {
+        req.state()
+            .get::<JobQueue>()
+            .cloned()
+            .map(Jobs)
+            .ok_or_else(|| {
+                ApiError::internal(
+                    "No JobQueue registered. Did you forget to call .jobs(queue)?",
+                )
+            })
+    }
+}
+
+impl Deref for Jobs {
+    type Target = JobQueue;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl OperationModifier for Jobs {
+    fn update_operation(_op: &mut Operation) {}
+}
+
+/// Registers a [`JobQueue`] with a [`RustApi`] app so handlers can use the
+/// [`Jobs`] extractor.
+pub trait JobsExt {
+    /// Make `queue` available to the [`Jobs`] extractor.
+    fn jobs(self, queue: JobQueue) -> Self;
+}
+
+impl JobsExt for RustApi {
+    fn jobs(self, queue: JobQueue) -> Self {
+        self.state(queue)
+    }
+}
@@ -1,14 +1,45 @@
+use crate::backend::JobBackend;
 use crate::error::Result;
+use crate::status::JobProgress;
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Serialize};
 use std::fmt::Debug;
+use std::sync::Arc;
 
 /// Context passed to job execution
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct JobContext {
     pub job_id: String,
     pub attempt: u32,
     pub created_at: chrono::DateTime<chrono::Utc>,
+    pub(crate) backend: Arc<dyn JobBackend>,
+}
+
+impl Debug for JobContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JobContext")
+            .field("job_id", &self.job_id)
+            .field("attempt", &self.attempt)
+            .field("created_at", &self.created_at)
+            .finish_non_exhaustive()
+    }
+}
+
+impl JobContext {
+    /// Report progress on this job's execution, e.g. `ctx.progress(40, "resizing").await`.
+    ///
+    /// Best-effort: only backends that keep this bookkeeping surface it - see
+    /// [`JobBackend::set_progress`].
+    pub async fn progress(&self, percent: u8, message: impl Into<String>) {
+        let progress = JobProgress {
+            percent,
+            message: message.into(),
+            updated_at: chrono::Utc::now(),
+        };
+        if let Err(e) = self.backend.set_progress(&self.job_id, progress).await {
+            tracing::warn!("Failed to record progress for job {}: {}", self.job_id, e);
+        }
+    }
 }
 
 /// A job that can be executed
@@ -20,20 +51,25 @@ pub trait Job: Send + Sync + 'static {
     /// The data required by the job
     type Data: Serialize + DeserializeOwned + Send + Sync + Debug;
 
+    /// The value produced by a successful run, retrievable via
+    /// [`crate::JobQueue::result`]. Use `()` for jobs with nothing to report.
+    type Output: Serialize + DeserializeOwned + Send + Sync + Debug;
+
     /// Execute the job
-    async fn execute(&self, ctx: JobContext, data: Self::Data) -> Result<()>;
+    async fn execute(&self, ctx: JobContext, data: Self::Data) -> Result<Self::Output>;
 }
 
 /// A type-erased job handler
 #[async_trait]
 pub trait JobHandler: Send + Sync {
-    async fn handle(&self, ctx: JobContext, data: serde_json::Value) -> Result<()>;
+    async fn handle(&self, ctx: JobContext, data: serde_json::Value) -> Result<serde_json::Value>;
 }
 
 #[async_trait]
 impl<J: Job> JobHandler for J {
-    async fn handle(&self, ctx: JobContext, data: serde_json::Value) -> Result<()> {
+    async fn handle(&self, ctx: JobContext, data: serde_json::Value) -> Result<serde_json::Value> {
         let data: J::Data = serde_json::from_value(data)?;
-        self.execute(ctx, data).await
+        let output = self.execute(ctx, data).await?;
+        Ok(serde_json::to_value(output)?)
     }
 }
@@ -0,0 +1,24 @@
+//! Leader election, so only one worker in a fleet runs a given [`crate::Scheduler`]
+
+use crate::error::Result;
+use async_trait::async_trait;
+use std::time::Duration;
+
+pub mod memory;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "redis")]
+pub mod redis;
+
+/// A distributed lock used to elect a single leader for a named resource
+/// (e.g. a scheduler) across a fleet of worker processes sharing a backend.
+#[async_trait]
+pub trait LeaderElection: Send + Sync {
+    /// Attempt to become, or remain, leader for `key`, holding the lease for
+    /// `ttl` from now. Returns `true` if `holder` is the leader after this
+    /// call - callers should re-call before `ttl` elapses to stay leader.
+    async fn try_acquire(&self, key: &str, holder: &str, ttl: Duration) -> Result<bool>;
+
+    /// Give up leadership of `key`, if currently held by `holder`.
+    async fn release(&self, key: &str, holder: &str) -> Result<()>;
+}
@@ -0,0 +1,22 @@
+//! Single-process leader election - always wins, since there's only one
+//! process around to contend for leadership.
+
+use super::LeaderElection;
+use crate::error::Result;
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// Trivial [`LeaderElection`] for a single-process deployment.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlwaysLeader;
+
+#[async_trait]
+impl LeaderElection for AlwaysLeader {
+    async fn try_acquire(&self, _key: &str, _holder: &str, _ttl: Duration) -> Result<bool> {
+        Ok(true)
+    }
+
+    async fn release(&self, _key: &str, _holder: &str) -> Result<()> {
+        Ok(())
+    }
+}
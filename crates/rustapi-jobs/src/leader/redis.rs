@@ -0,0 +1,94 @@
+//! Redis-backed leader election using a value-checked `SET`/`DEL`, scripted
+//! for atomicity the same way [`crate::backend::redis::RedisBackend`] scripts its pop.
+
+use super::LeaderElection;
+use crate::error::{JobError, Result};
+use async_trait::async_trait;
+use redis::{Client, Script};
+use std::time::Duration;
+
+/// [`LeaderElection`] backed by a Redis key, so a fleet of workers sharing
+/// a Redis instance can agree on a single leader.
+#[derive(Debug, Clone)]
+pub struct RedisLeaderElection {
+    client: Client,
+    acquire_script: Script,
+    release_script: Script,
+}
+
+impl RedisLeaderElection {
+    /// Create an election against the Redis instance at `url`.
+    pub fn new(url: &str) -> Result<Self> {
+        let client = Client::open(url).map_err(|e| JobError::ConfigError(e.to_string()))?;
+
+        // Only take the key if it's unheld or already ours - avoids two
+        // workers both believing they're leader after a racing SET.
+        let acquire_script = Script::new(
+            r#"
+            local current = redis.call('GET', KEYS[1])
+            if current == false or current == ARGV[1] then
+                redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2])
+                return 1
+            else
+                return 0
+            end
+        "#,
+        );
+
+        let release_script = Script::new(
+            r#"
+            local current = redis.call('GET', KEYS[1])
+            if current == ARGV[1] then
+                redis.call('DEL', KEYS[1])
+            end
+            return 1
+        "#,
+        );
+
+        Ok(Self {
+            client,
+            acquire_script,
+            release_script,
+        })
+    }
+}
+
+#[async_trait]
+impl LeaderElection for RedisLeaderElection {
+    async fn try_acquire(&self, key: &str, holder: &str, ttl: Duration) -> Result<bool> {
+        let mut conn = self
+            .client
+            .get_async_connection()
+            .await
+            .map_err(|e| JobError::BackendError(e.to_string()))?;
+
+        let acquired: i64 = self
+            .acquire_script
+            .key(key)
+            .arg(holder)
+            .arg(ttl.as_millis() as u64)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| JobError::BackendError(e.to_string()))?;
+
+        Ok(acquired == 1)
+    }
+
+    async fn release(&self, key: &str, holder: &str) -> Result<()> {
+        let mut conn = self
+            .client
+            .get_async_connection()
+            .await
+            .map_err(|e| JobError::BackendError(e.to_string()))?;
+
+        let _: i64 = self
+            .release_script
+            .key(key)
+            .arg(holder)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| JobError::BackendError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -5,12 +5,41 @@
 //! This crate provides a flexible background job processing system.
 
 pub mod backend;
+#[cfg(feature = "dashboard")]
+pub mod dashboard;
 pub mod error;
+#[cfg(feature = "extract")]
+pub mod extract;
 pub mod job;
+pub mod leader;
+pub mod middleware;
 pub mod queue;
+pub mod schedule;
+pub mod scheduler;
+pub mod status;
+pub mod worker;
 
 pub use backend::memory::InMemoryBackend;
+#[cfg(feature = "postgres")]
+pub use backend::postgres::PostgresBackend;
+#[cfg(feature = "redis")]
+pub use backend::redis::RedisBackend;
 pub use backend::{JobBackend, JobRequest};
+#[cfg(feature = "dashboard")]
+pub use dashboard::JobsDashboardExt;
 pub use error::{JobError, Result};
+#[cfg(feature = "extract")]
+pub use extract::{Jobs, JobsExt};
 pub use job::{Job, JobContext};
+pub use leader::memory::AlwaysLeader;
+#[cfg(feature = "postgres")]
+pub use leader::postgres::PostgresLeaderElection;
+#[cfg(feature = "redis")]
+pub use leader::redis::RedisLeaderElection;
+pub use leader::LeaderElection;
+pub use middleware::{BoxedJobNext, JobMiddleware, JobMiddlewareStack};
 pub use queue::{EnqueueOptions, JobQueue};
+pub use schedule::{MissedRunPolicy, Schedule};
+pub use scheduler::Scheduler;
+pub use status::{JobProgress, JobRecord, JobStatus};
+pub use worker::{WorkerPool, WorkerPoolConfig};
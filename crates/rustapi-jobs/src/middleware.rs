@@ -0,0 +1,220 @@
+//! Job middleware, mirroring the ergonomics of `rustapi_core`'s HTTP
+//! `MiddlewareLayer`: implement [`JobMiddleware::call`], calling `next` to
+//! continue the chain, so tracing spans, metrics, and error reporting can be
+//! attached uniformly to every job instead of duplicated in every handler.
+
+use crate::error::Result;
+use crate::job::JobContext;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// A boxed "next" continuation in a job middleware chain.
+pub type BoxedJobNext = Arc<
+    dyn Fn(
+            JobContext,
+            serde_json::Value,
+        ) -> Pin<Box<dyn Future<Output = Result<serde_json::Value>> + Send + 'static>>
+        + Send
+        + Sync,
+>;
+
+/// Middleware that wraps every job's execution.
+///
+/// Implement `call`, running code before and/or after invoking `next` (or
+/// skipping it entirely to short-circuit the job without running its
+/// handler), then register it with [`crate::JobQueue::use_middleware`].
+pub trait JobMiddleware: Send + Sync + 'static {
+    /// Handle a job, calling `next` to continue the chain.
+    fn call(
+        &self,
+        ctx: JobContext,
+        payload: serde_json::Value,
+        next: BoxedJobNext,
+    ) -> Pin<Box<dyn Future<Output = Result<serde_json::Value>> + Send + 'static>>;
+
+    /// Clone this middleware into a boxed trait object.
+    fn clone_box(&self) -> Box<dyn JobMiddleware>;
+}
+
+impl Clone for Box<dyn JobMiddleware> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// A stack of job middleware, executed outermost-first around a handler.
+#[derive(Clone, Default)]
+pub struct JobMiddlewareStack {
+    layers: Vec<Box<dyn JobMiddleware>>,
+}
+
+impl JobMiddlewareStack {
+    /// Create a new empty middleware stack.
+    pub fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// Add a middleware to the stack.
+    ///
+    /// Middleware run in the order they're added (outermost first).
+    pub fn push(&mut self, middleware: Box<dyn JobMiddleware>) {
+        self.layers.push(middleware);
+    }
+
+    /// Check if the stack is empty.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Run `handler` wrapped in the middleware stack.
+    pub fn execute(
+        &self,
+        ctx: JobContext,
+        payload: serde_json::Value,
+        handler: BoxedJobNext,
+    ) -> Pin<Box<dyn Future<Output = Result<serde_json::Value>> + Send + 'static>> {
+        if self.layers.is_empty() {
+            return handler(ctx, payload);
+        }
+
+        // Build the chain from inside out, same as rustapi_core's LayerStack:
+        // the first middleware added ends up outermost, so it runs first.
+        let mut next = handler;
+
+        for layer in self.layers.iter().rev() {
+            let layer = layer.clone_box();
+            let current_next = next;
+            next = Arc::new(move |ctx: JobContext, payload: serde_json::Value| {
+                let layer = layer.clone_box();
+                let next = current_next.clone();
+                Box::pin(async move { layer.call(ctx, payload, next).await })
+                    as Pin<Box<dyn Future<Output = Result<serde_json::Value>> + Send + 'static>>
+            });
+        }
+
+        next(ctx, payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::InMemoryBackend;
+    use crate::error::JobError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn test_ctx() -> JobContext {
+        JobContext {
+            job_id: "job-1".to_string(),
+            attempt: 1,
+            created_at: chrono::Utc::now(),
+            backend: Arc::new(InMemoryBackend::new()),
+        }
+    }
+
+    #[derive(Clone)]
+    struct OrderTrackingMiddleware {
+        id: usize,
+        order: Arc<std::sync::Mutex<Vec<(usize, &'static str)>>>,
+    }
+
+    impl JobMiddleware for OrderTrackingMiddleware {
+        fn call(
+            &self,
+            ctx: JobContext,
+            payload: serde_json::Value,
+            next: BoxedJobNext,
+        ) -> Pin<Box<dyn Future<Output = Result<serde_json::Value>> + Send + 'static>> {
+            let id = self.id;
+            let order = self.order.clone();
+            Box::pin(async move {
+                order.lock().unwrap().push((id, "before"));
+                let result = next(ctx, payload).await;
+                order.lock().unwrap().push((id, "after"));
+                result
+            })
+        }
+
+        fn clone_box(&self) -> Box<dyn JobMiddleware> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[derive(Clone)]
+    struct ShortCircuitMiddleware;
+
+    impl JobMiddleware for ShortCircuitMiddleware {
+        fn call(
+            &self,
+            _ctx: JobContext,
+            _payload: serde_json::Value,
+            _next: BoxedJobNext,
+        ) -> Pin<Box<dyn Future<Output = Result<serde_json::Value>> + Send + 'static>> {
+            Box::pin(async move { Err(JobError::WorkerError("short-circuited".to_string())) })
+        }
+
+        fn clone_box(&self) -> Box<dyn JobMiddleware> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn noop_next() -> BoxedJobNext {
+        Arc::new(|_ctx, payload| Box::pin(async move { Ok(payload) }))
+    }
+
+    #[tokio::test]
+    async fn empty_stack_calls_handler_directly() {
+        let stack = JobMiddlewareStack::new();
+        let result = stack
+            .execute(test_ctx(), serde_json::json!({"ok": true}), noop_next())
+            .await
+            .unwrap();
+        assert_eq!(result, serde_json::json!({"ok": true}));
+    }
+
+    #[tokio::test]
+    async fn middleware_run_outermost_first() {
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut stack = JobMiddlewareStack::new();
+        stack.push(Box::new(OrderTrackingMiddleware {
+            id: 0,
+            order: order.clone(),
+        }));
+        stack.push(Box::new(OrderTrackingMiddleware {
+            id: 1,
+            order: order.clone(),
+        }));
+
+        stack
+            .execute(test_ctx(), serde_json::Value::Null, noop_next())
+            .await
+            .unwrap();
+
+        let recorded = order.lock().unwrap().clone();
+        assert_eq!(
+            recorded,
+            vec![(0, "before"), (1, "before"), (1, "after"), (0, "after")]
+        );
+    }
+
+    #[tokio::test]
+    async fn middleware_can_short_circuit_without_calling_next() {
+        let handler_called = Arc::new(AtomicUsize::new(0));
+        let handler_called_clone = handler_called.clone();
+        let next: BoxedJobNext = Arc::new(move |_ctx, payload| {
+            handler_called_clone.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { Ok(payload) })
+        });
+
+        let mut stack = JobMiddlewareStack::new();
+        stack.push(Box::new(ShortCircuitMiddleware));
+
+        let result = stack
+            .execute(test_ctx(), serde_json::Value::Null, next)
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(handler_called.load(Ordering::SeqCst), 0);
+    }
+}
@@ -1,16 +1,24 @@
 use crate::backend::{JobBackend, JobRequest};
 use crate::error::Result;
 use crate::job::{Job, JobContext, JobHandler};
+use crate::middleware::{JobMiddleware, JobMiddlewareStack};
+use crate::status::{JobProgress, JobRecord, JobStatus};
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+/// How long a job's result is kept when [`EnqueueOptions::result_ttl`] isn't set.
+const DEFAULT_RESULT_TTL: Duration = Duration::from_secs(3600);
+
 /// Main job queue manager
 #[derive(Clone)]
 pub struct JobQueue {
     backend: Arc<dyn JobBackend>,
-    handlers: Arc<RwLock<HashMap<String, Box<dyn JobHandler>>>>,
+    handlers: Arc<RwLock<HashMap<String, Arc<dyn JobHandler>>>>,
+    middleware: Arc<RwLock<JobMiddlewareStack>>,
 }
 
 impl JobQueue {
@@ -19,13 +27,24 @@ impl JobQueue {
         Self {
             backend: Arc::new(backend),
             handlers: Arc::new(RwLock::new(HashMap::new())),
+            middleware: Arc::new(RwLock::new(JobMiddlewareStack::new())),
         }
     }
 
     /// Register a job handler
     pub async fn register_job<J: Job + Clone>(&self, job: J) {
         let mut handlers = self.handlers.write().await;
-        handlers.insert(J::NAME.to_string(), Box::new(job));
+        handlers.insert(J::NAME.to_string(), Arc::new(job));
+    }
+
+    /// Attach a [`JobMiddleware`] that wraps every job's execution, e.g. to
+    /// record tracing spans, emit metrics, or report failures to an error
+    /// tracker.
+    ///
+    /// Middleware run in the order they're registered (outermost first),
+    /// mirroring [`rustapi_core`]'s HTTP `.layer()` ergonomics.
+    pub async fn use_middleware<M: JobMiddleware>(&self, middleware: M) {
+        self.middleware.write().await.push(Box::new(middleware));
     }
 
     /// Enqueue a job
@@ -52,63 +71,145 @@ impl JobQueue {
             max_attempts: opts.max_attempts,
             last_error: None,
             run_at: opts.run_at,
+            unique_key: opts.unique_key,
+            result_ttl: opts.result_ttl,
         };
 
-        self.backend.push(request).await?;
-        Ok(id)
+        self.backend.push_unique(request).await
     }
 
     /// Process a single job (for testing or manual control)
     pub async fn process_one(&self) -> Result<bool> {
-        if let Some(req) = self.backend.pop().await? {
-            let handlers = self.handlers.read().await;
-            if let Some(handler) = handlers.get(&req.name) {
-                let ctx = JobContext {
-                    job_id: req.id.clone(),
-                    attempt: req.attempts + 1,
-                    created_at: req.created_at,
-                };
+        let Some(req) = self.backend.pop().await? else {
+            return Ok(false);
+        };
 
-                match handler.handle(ctx, req.payload.clone()).await {
-                    Ok(_) => {
-                        self.backend.complete(&req.id).await?;
-                        Ok(true)
-                    }
-                    Err(e) => {
-                        let mut new_req = req.clone();
-                        new_req.attempts += 1;
-                        new_req.last_error = Some(e.to_string());
-
-                        if new_req.attempts < new_req.max_attempts {
-                            // Exponential backoff: 2^attempts seconds (e.g. 2, 4, 8, 16...)
-                            // Limit max backoff to some reasonable value (e.g. 24 hours)?
-                            // For now basic exponential.
-                            let backoff_secs = 2u64.saturating_pow(new_req.attempts).min(86400);
-                            let retry_delay = chrono::Duration::seconds(backoff_secs as i64);
-                            new_req.run_at = Some(chrono::Utc::now() + retry_delay);
-
-                            // Re-push the job for retry
-                            self.backend.push(new_req).await?;
-                        } else {
-                            // Job failed permanently
-                            self.backend.fail(&req.id, &e.to_string()).await?;
-
-                            // TODO: If we implemented a real DLQ, we would push it there now.
-                            // Currently fail() is where backend would handle that.
-                        }
-                        Ok(true)
-                    }
-                }
-            } else {
-                // Handler not found
-                // For now, treat as permanent failure
-                self.backend
-                    .fail(&req.id, &format!("No handler for job: {}", req.name))
-                    .await?;
-                Ok(true)
+        let handler = self.handlers.read().await.get(&req.name).cloned();
+        let Some(handler) = handler else {
+            // Handler not found - for now, treat as permanent failure
+            self.backend
+                .fail(&req.id, &format!("No handler for job: {}", req.name))
+                .await?;
+            return Ok(true);
+        };
+
+        let ctx = JobContext {
+            job_id: req.id.clone(),
+            attempt: req.attempts + 1,
+            created_at: req.created_at,
+            backend: self.backend.clone(),
+        };
+        let result_ttl = req.result_ttl;
+        let middleware = self.middleware.read().await.clone();
+        let payload = req.payload.clone();
+
+        match middleware.execute(ctx, payload, Self::handler_next(handler)).await {
+            Ok(output) => self.complete_with_result(&req.id, output, result_ttl).await?,
+            Err(e) => self.retry_or_fail(req, e.to_string()).await?,
+        }
+        Ok(true)
+    }
+
+    /// Wrap a handler as the innermost `next` in a [`JobMiddlewareStack`].
+    fn handler_next(handler: Arc<dyn JobHandler>) -> crate::middleware::BoxedJobNext {
+        Arc::new(move |ctx, payload| {
+            let handler = handler.clone();
+            Box::pin(async move { handler.handle(ctx, payload).await })
+        })
+    }
+
+    /// Process a single job, but abort it and re-queue it for another
+    /// worker if it's still running past `visibility_timeout`.
+    ///
+    /// Runs the handler on its own task so a panic inside it doesn't take
+    /// down the caller - used by [`crate::worker::WorkerPool`] to isolate
+    /// concurrent jobs from each other.
+    pub async fn process_one_with_timeout(
+        &self,
+        visibility_timeout: std::time::Duration,
+    ) -> Result<bool> {
+        let Some(req) = self.backend.pop().await? else {
+            return Ok(false);
+        };
+
+        let handler = self.handlers.read().await.get(&req.name).cloned();
+        let Some(handler) = handler else {
+            self.backend
+                .fail(&req.id, &format!("No handler for job: {}", req.name))
+                .await?;
+            return Ok(true);
+        };
+
+        let ctx = JobContext {
+            job_id: req.id.clone(),
+            attempt: req.attempts + 1,
+            created_at: req.created_at,
+            backend: self.backend.clone(),
+        };
+        let payload = req.payload.clone();
+        let result_ttl = req.result_ttl;
+        let middleware = self.middleware.read().await.clone();
+
+        let mut task =
+            tokio::spawn(async move { middleware.execute(ctx, payload, Self::handler_next(handler)).await });
+
+        match tokio::time::timeout(visibility_timeout, &mut task).await {
+            Ok(Ok(Ok(output))) => self.complete_with_result(&req.id, output, result_ttl).await?,
+            Ok(Ok(Err(e))) => self.retry_or_fail(req, e.to_string()).await?,
+            Ok(Err(join_err)) => {
+                self.retry_or_fail(req, format!("Job panicked: {join_err}"))
+                    .await?
+            }
+            Err(_) => {
+                // Handler is still running past its visibility timeout -
+                // isolate it and let another worker pick the job back up.
+                tracing::warn!(
+                    "Job `{}` ({}) exceeded its visibility timeout; re-queuing",
+                    req.name,
+                    req.id
+                );
+                task.abort();
+                self.backend.push(req).await?;
             }
+        }
+        Ok(true)
+    }
+
+    /// Mark a job complete and, if it produced a result, stash it for
+    /// [`JobQueue::result`] to pick up later.
+    async fn complete_with_result(
+        &self,
+        job_id: &str,
+        output: serde_json::Value,
+        result_ttl: Option<Duration>,
+    ) -> Result<()> {
+        self.backend.complete(job_id).await?;
+        if !output.is_null() {
+            self.backend
+                .store_result(job_id, output, result_ttl.unwrap_or(DEFAULT_RESULT_TTL))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Retry `req` with exponential backoff, or fail it permanently once
+    /// `max_attempts` is exhausted.
+    async fn retry_or_fail(&self, req: JobRequest, error: String) -> Result<()> {
+        let mut new_req = req;
+        new_req.attempts += 1;
+        new_req.last_error = Some(error.clone());
+
+        if new_req.attempts < new_req.max_attempts {
+            // Exponential backoff: 2^attempts seconds (e.g. 2, 4, 8, 16...),
+            // capped at 24 hours.
+            let backoff_secs = 2u64.saturating_pow(new_req.attempts).min(86400);
+            new_req.run_at = Some(chrono::Utc::now() + chrono::Duration::seconds(backoff_secs as i64));
+            self.backend.push(new_req).await
         } else {
-            Ok(false)
+            // Job failed permanently.
+            // TODO: If we implemented a real DLQ, we would push it there now.
+            // Currently fail() is where backend would handle that.
+            self.backend.fail(&new_req.id, &error).await
         }
     }
 
@@ -129,6 +230,54 @@ impl JobQueue {
             }
         }
     }
+
+    /// Look up a single job's current status.
+    ///
+    /// Only backends that keep this bookkeeping return a record - see
+    /// [`JobBackend::get_record`].
+    pub async fn status(&self, job_id: &str) -> Result<Option<JobRecord>> {
+        self.backend.get_record(job_id).await
+    }
+
+    /// List known jobs, optionally filtered to a single [`JobStatus`].
+    pub async fn list_jobs(&self, status: Option<JobStatus>) -> Result<Vec<JobRecord>> {
+        let records = self.backend.list_records().await?;
+        Ok(match status {
+            Some(status) => records.into_iter().filter(|r| r.status == status).collect(),
+            None => records,
+        })
+    }
+
+    /// Reset a dead job's attempts and put it back in the queue.
+    ///
+    /// Returns `false` if the backend has no record of `job_id`.
+    pub async fn retry(&self, job_id: &str) -> Result<bool> {
+        self.backend.requeue(job_id).await
+    }
+
+    /// Best-effort count of jobs completed since this queue's backend was
+    /// created - see [`JobBackend::completed_count`].
+    pub async fn completed_count(&self) -> Result<u64> {
+        self.backend.completed_count().await
+    }
+
+    /// Look up the last progress update a running job reported via
+    /// [`crate::JobContext::progress`].
+    pub async fn progress(&self, job_id: &str) -> Result<Option<JobProgress>> {
+        self.backend.get_progress(job_id).await
+    }
+
+    /// Fetch a completed job's result, if it's still within its TTL - see
+    /// [`EnqueueOptions::result_ttl`].
+    ///
+    /// Returns `Ok(None)` if the job hasn't completed yet, produced no
+    /// result, or its result has expired.
+    pub async fn result<T: DeserializeOwned>(&self, job_id: &str) -> Result<Option<T>> {
+        let Some(value) = self.backend.get_result(job_id).await? else {
+            return Ok(None);
+        };
+        Ok(Some(serde_json::from_value(value)?))
+    }
 }
 
 /// Options for enqueueing a job
@@ -136,6 +285,8 @@ impl JobQueue {
 pub struct EnqueueOptions {
     pub max_attempts: u32,
     pub run_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub unique_key: Option<String>,
+    pub result_ttl: Option<Duration>,
 }
 
 impl EnqueueOptions {
@@ -152,6 +303,27 @@ impl EnqueueOptions {
         self.run_at = Some(chrono::Utc::now() + chrono::Duration::from_std(duration).unwrap());
         self
     }
+
+    /// Don't make the job available until `at`.
+    pub fn run_at(mut self, at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.run_at = Some(at);
+        self
+    }
+
+    /// Deduplicate against other pending jobs sharing this key: enqueueing
+    /// while a pending job with the same key still exists is a no-op that
+    /// returns the existing job's id instead of enqueueing a duplicate.
+    pub fn unique_key(mut self, key: impl Into<String>) -> Self {
+        self.unique_key = Some(key.into());
+        self
+    }
+
+    /// How long a successful run's result is kept for [`JobQueue::result`].
+    /// Defaults to one hour if not set.
+    pub fn result_ttl(mut self, ttl: Duration) -> Self {
+        self.result_ttl = Some(ttl);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -190,6 +362,7 @@ mod property_tests {
     impl Job for TestJob {
         const NAME: &'static str = "test_job";
         type Data = TestJobData;
+        type Output = ();
 
         async fn execute(&self, _ctx: JobContext, data: Self::Data) -> Result<()> {
             let mut count = self.execution_count.write().await;
@@ -370,6 +543,42 @@ mod property_tests {
             })?;
         }
 
+        /// Property 22: A delayed job doesn't block a ready job queued after it
+        #[test]
+        fn prop_ready_job_not_blocked_by_earlier_delayed_job(value in -100i32..100) {
+            tokio::runtime::Runtime::new().unwrap().block_on(async {
+                let backend = MemoryBackend::new();
+                let queue = JobQueue::new(backend);
+
+                let test_job = TestJob {
+                    should_fail: Arc::new(RwLock::new(false)),
+                    execution_count: Arc::new(RwLock::new(0)),
+                };
+                queue.register_job(test_job.clone()).await;
+
+                // Enqueue a far-future job first, then a ready one.
+                let delayed_opts = EnqueueOptions::new().delay(std::time::Duration::from_secs(3600));
+                queue
+                    .enqueue_opts::<TestJob>(TestJobData { value }, delayed_opts)
+                    .await
+                    .unwrap();
+                queue.enqueue::<TestJob>(TestJobData { value }).await.unwrap();
+
+                // The ready job MUST be processed even though it's behind the delayed one
+                let processed = queue.process_one().await.unwrap();
+                prop_assert!(processed);
+
+                let count = *test_job.execution_count.read().await;
+                prop_assert_eq!(count, 1);
+
+                // Only the ready job should have run - the delayed one stays queued
+                let processed_again = queue.process_one().await.unwrap();
+                prop_assert!(!processed_again);
+
+                Ok(())
+            })?;
+        }
+
         /// Property 22: Successful job is completed and removed
         #[test]
         fn prop_successful_job_completed(value in -100i32..100) {
@@ -507,3 +716,170 @@ mod property_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod unique_key_tests {
+    use super::*;
+    use crate::backend::memory::InMemoryBackend;
+    use async_trait::async_trait;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct SyncData {
+        user_id: u32,
+    }
+
+    #[derive(Clone, Default)]
+    struct SyncJob;
+
+    #[async_trait]
+    impl Job for SyncJob {
+        const NAME: &'static str = "sync_job";
+        type Data = SyncData;
+        type Output = ();
+
+        async fn execute(&self, _ctx: JobContext, _data: Self::Data) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn enqueue_with_existing_unique_key_is_a_no_op() {
+        let queue = JobQueue::new(InMemoryBackend::new());
+        queue.register_job(SyncJob).await;
+
+        let first = queue
+            .enqueue_opts::<SyncJob>(
+                SyncData { user_id: 42 },
+                EnqueueOptions::new().unique_key("sync-user-42"),
+            )
+            .await
+            .unwrap();
+
+        let second = queue
+            .enqueue_opts::<SyncJob>(
+                SyncData { user_id: 42 },
+                EnqueueOptions::new().unique_key("sync-user-42"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+
+        // Only one of the two enqueues should have actually landed on the queue.
+        assert!(queue.process_one().await.unwrap());
+        assert!(!queue.process_one().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn enqueue_without_unique_key_is_never_deduplicated() {
+        let queue = JobQueue::new(InMemoryBackend::new());
+        queue.register_job(SyncJob).await;
+
+        queue
+            .enqueue::<SyncJob>(SyncData { user_id: 1 })
+            .await
+            .unwrap();
+        queue
+            .enqueue::<SyncJob>(SyncData { user_id: 1 })
+            .await
+            .unwrap();
+
+        assert!(queue.process_one().await.unwrap());
+        assert!(queue.process_one().await.unwrap());
+    }
+}
+
+#[cfg(test)]
+mod progress_and_result_tests {
+    use super::*;
+    use crate::backend::memory::InMemoryBackend;
+    use async_trait::async_trait;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct ResizeData {
+        width: u32,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct ResizeOutput {
+        thumbnail_url: String,
+    }
+
+    #[derive(Clone, Default)]
+    struct ResizeJob;
+
+    #[async_trait]
+    impl Job for ResizeJob {
+        const NAME: &'static str = "resize_job";
+        type Data = ResizeData;
+        type Output = ResizeOutput;
+
+        async fn execute(&self, ctx: JobContext, data: Self::Data) -> Result<Self::Output> {
+            ctx.progress(50, "resizing").await;
+            Ok(ResizeOutput {
+                thumbnail_url: format!("https://example.com/thumb-{}.png", data.width),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn completed_job_result_is_retrievable_and_typed() {
+        let queue = JobQueue::new(InMemoryBackend::new());
+        queue.register_job(ResizeJob).await;
+
+        let job_id = queue
+            .enqueue::<ResizeJob>(ResizeData { width: 128 })
+            .await
+            .unwrap();
+
+        assert!(queue.process_one().await.unwrap());
+
+        let result: ResizeOutput = queue.result(&job_id).await.unwrap().unwrap();
+        assert_eq!(
+            result,
+            ResizeOutput {
+                thumbnail_url: "https://example.com/thumb-128.png".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn job_progress_reported_while_running_is_visible() {
+        let queue = JobQueue::new(InMemoryBackend::new());
+        queue.register_job(ResizeJob).await;
+
+        let job_id = queue
+            .enqueue::<ResizeJob>(ResizeData { width: 64 })
+            .await
+            .unwrap();
+        assert!(queue.progress(&job_id).await.unwrap().is_none());
+
+        queue.process_one().await.unwrap();
+
+        let progress = queue.progress(&job_id).await.unwrap().unwrap();
+        assert_eq!(progress.percent, 50);
+        assert_eq!(progress.message, "resizing");
+    }
+
+    #[tokio::test]
+    async fn result_is_gone_once_its_ttl_elapses() {
+        let queue = JobQueue::new(InMemoryBackend::new());
+        queue.register_job(ResizeJob).await;
+
+        let job_id = queue
+            .enqueue_opts::<ResizeJob>(
+                ResizeData { width: 32 },
+                EnqueueOptions::new().result_ttl(Duration::from_millis(0)),
+            )
+            .await
+            .unwrap();
+
+        queue.process_one().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let result: Option<ResizeOutput> = queue.result(&job_id).await.unwrap();
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,90 @@
+//! Cron and fixed-interval schedules for [`crate::Scheduler`]
+
+use crate::error::{JobError, Result};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// When a scheduled job should next fire.
+#[derive(Clone)]
+pub enum Schedule {
+    /// A standard cron expression (`cron` crate syntax: `sec min hour dom month dow`),
+    /// evaluated in the given timezone.
+    Cron(Box<cron::Schedule>, Tz),
+    /// A fixed interval, evaluated relative to the previous fire time.
+    Interval(Duration),
+}
+
+impl Schedule {
+    /// Parse a cron expression, evaluated in `tz`.
+    pub fn cron(expression: &str, tz: Tz) -> Result<Self> {
+        let schedule = cron::Schedule::from_str(expression)
+            .map_err(|e| JobError::ConfigError(format!("invalid cron expression: {e}")))?;
+        Ok(Self::Cron(Box::new(schedule), tz))
+    }
+
+    /// Fire every `interval`, starting one `interval` after registration.
+    pub fn every(interval: Duration) -> Self {
+        Self::Interval(interval)
+    }
+
+    /// The next fire time strictly after `after`.
+    pub(crate) fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Schedule::Cron(schedule, tz) => schedule
+                .after(&after.with_timezone(tz))
+                .next()
+                .map(|dt| dt.with_timezone(&Utc)),
+            Schedule::Interval(interval) => {
+                chrono::Duration::from_std(*interval).ok().map(|d| after + d)
+            }
+        }
+    }
+}
+
+/// How a [`crate::Scheduler`] should handle fire times that passed while it
+/// wasn't ticking (e.g. the process was busy, or its tick interval is
+/// coarser than the schedule).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissedRunPolicy {
+    /// Drop every missed fire time and resume from the next one after now.
+    #[default]
+    Skip,
+    /// Enqueue a single catch-up run for the whole missed window, then
+    /// resume from the next fire time after now.
+    RunOnce,
+    /// Enqueue one run per missed fire time, capped at `max_catch_up`.
+    RunAll {
+        /// Upper bound on catch-up runs enqueued for a single tick, to
+        /// avoid a thundering herd after a long pause.
+        max_catch_up: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::UTC;
+
+    #[test]
+    fn test_interval_schedule_advances_by_interval() {
+        let schedule = Schedule::every(Duration::from_secs(60));
+        let start: DateTime<Utc> = "2026-01-01T00:00:00Z".parse().unwrap();
+        let next = schedule.next_after(start).unwrap();
+        assert_eq!(next, start + chrono::Duration::seconds(60));
+    }
+
+    #[test]
+    fn test_cron_schedule_finds_next_minute_boundary() {
+        let schedule = Schedule::cron("0 * * * * *", UTC).unwrap();
+        let start: DateTime<Utc> = "2026-01-01T00:00:30Z".parse().unwrap();
+        let next = schedule.next_after(start).unwrap();
+        assert_eq!(next, "2026-01-01T00:01:00Z".parse::<DateTime<Utc>>().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_cron_expression_is_rejected() {
+        assert!(Schedule::cron("not a cron expression", UTC).is_err());
+    }
+}
@@ -0,0 +1,287 @@
+//! Cron/interval-driven scheduling on top of a [`JobQueue`]
+
+use crate::error::Result;
+use crate::job::Job;
+use crate::leader::memory::AlwaysLeader;
+use crate::leader::LeaderElection;
+use crate::queue::JobQueue;
+use crate::schedule::{MissedRunPolicy, Schedule};
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+type EnqueueFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
+type EnqueueFn = Box<dyn Fn() -> EnqueueFuture + Send + Sync>;
+
+struct ScheduleEntry {
+    name: String,
+    schedule: Schedule,
+    policy: MissedRunPolicy,
+    next_run: DateTime<Utc>,
+    enqueue: EnqueueFn,
+}
+
+/// Runs cron- and interval-scheduled jobs against a [`JobQueue`].
+///
+/// Registering a schedule doesn't register a job handler - call
+/// [`JobQueue::register_job`] as usual so a worker can execute the jobs this
+/// scheduler enqueues.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_jobs::{JobQueue, Scheduler, Schedule, MissedRunPolicy};
+/// use std::time::Duration;
+///
+/// let scheduler = Scheduler::new(queue.clone());
+/// scheduler
+///     .register::<SendDigest>(
+///         Schedule::cron("0 0 9 * * *", chrono_tz::US::Eastern)?,
+///         MissedRunPolicy::Skip,
+///         || DigestData::default(),
+///     )
+///     .await;
+///
+/// scheduler.run(Duration::from_secs(1)).await?;
+/// ```
+#[derive(Clone)]
+pub struct Scheduler {
+    queue: JobQueue,
+    election: Arc<dyn LeaderElection>,
+    holder: String,
+    entries: Arc<RwLock<Vec<ScheduleEntry>>>,
+}
+
+impl Scheduler {
+    /// Create a scheduler with no leader election - correct when only one
+    /// process ever runs it.
+    pub fn new(queue: JobQueue) -> Self {
+        Self::with_election(queue, Arc::new(AlwaysLeader))
+    }
+
+    /// Create a scheduler that uses `election` to ensure only one worker in
+    /// a fleet sharing a backend actually ticks the schedule.
+    pub fn with_election(queue: JobQueue, election: Arc<dyn LeaderElection>) -> Self {
+        Self {
+            queue,
+            election,
+            holder: Uuid::new_v4().to_string(),
+            entries: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// Register `J` to run on `schedule`, enqueued with the data
+    /// `data_factory` produces each time it fires.
+    pub async fn register<J: Job>(
+        &self,
+        schedule: Schedule,
+        policy: MissedRunPolicy,
+        data_factory: impl Fn() -> J::Data + Send + Sync + 'static,
+    ) {
+        let queue = self.queue.clone();
+        let enqueue: EnqueueFn = Box::new(move || {
+            let queue = queue.clone();
+            let data = data_factory();
+            Box::pin(async move { queue.enqueue::<J>(data).await.map(|_| ()) })
+        });
+
+        let next_run = schedule.next_after(Utc::now()).unwrap_or_else(Utc::now);
+        self.entries.write().await.push(ScheduleEntry {
+            name: J::NAME.to_string(),
+            schedule,
+            policy,
+            next_run,
+            enqueue,
+        });
+    }
+
+    /// Check every registered schedule once, enqueueing any that are due.
+    ///
+    /// Called on a timer by [`Self::run`]; exposed directly for tests and
+    /// manual control.
+    pub async fn tick(&self) -> Result<()> {
+        const LEASE_KEY: &str = "scheduler";
+        let ttl = Duration::from_secs(30);
+        if !self.election.try_acquire(LEASE_KEY, &self.holder, ttl).await? {
+            // Another worker holds the lease this tick.
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        let mut entries = self.entries.write().await;
+        for entry in entries.iter_mut() {
+            if entry.next_run > now {
+                continue;
+            }
+
+            let runs = match entry.policy {
+                MissedRunPolicy::Skip | MissedRunPolicy::RunOnce => 1,
+                MissedRunPolicy::RunAll { max_catch_up } => {
+                    count_missed(&entry.schedule, entry.next_run, now)
+                        .min(max_catch_up)
+                        .max(1)
+                }
+            };
+
+            for _ in 0..runs {
+                if let Err(e) = (entry.enqueue)().await {
+                    tracing::error!("Failed to enqueue scheduled job `{}`: {}", entry.name, e);
+                }
+            }
+
+            entry.next_run = entry
+                .schedule
+                .next_after(now)
+                .unwrap_or_else(|| now + chrono::Duration::seconds(1));
+        }
+
+        Ok(())
+    }
+
+    /// Tick every `interval` in a loop - the long-running task for a
+    /// scheduler process.
+    pub async fn run(&self, interval: Duration) -> Result<()> {
+        loop {
+            if let Err(e) = self.tick().await {
+                tracing::error!("Scheduler tick failed: {}", e);
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+/// How many scheduled fire times fall in `[from, until]`, `from` being the
+/// entry's overdue fire time, used to size a
+/// [`MissedRunPolicy::RunAll`] catch-up.
+fn count_missed(schedule: &Schedule, from: DateTime<Utc>, until: DateTime<Utc>) -> usize {
+    match schedule {
+        Schedule::Interval(interval) => match chrono::Duration::from_std(*interval) {
+            Ok(d) if d > chrono::Duration::zero() => {
+                ((until - from).num_milliseconds() / d.num_milliseconds()).max(0) as usize + 1
+            }
+            _ => 1,
+        },
+        Schedule::Cron(cron_schedule, tz) => {
+            let from_tz = from.with_timezone(tz);
+            let until_tz = until.with_timezone(tz);
+            cron_schedule
+                .after(&from_tz)
+                .take_while(|dt| *dt <= until_tz)
+                .count()
+                + 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::InMemoryBackend;
+    use crate::job::JobContext;
+    use async_trait::async_trait;
+    use serde::{Deserialize, Serialize};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct TickData;
+
+    #[derive(Clone, Default)]
+    struct CountingJob {
+        runs: Arc<AtomicU32>,
+    }
+
+    #[async_trait]
+    impl Job for CountingJob {
+        const NAME: &'static str = "counting_job";
+        type Data = TickData;
+        type Output = ();
+
+        async fn execute(&self, _ctx: JobContext, _data: Self::Data) -> Result<()> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tick_enqueues_due_interval_job() {
+        let queue = JobQueue::new(InMemoryBackend::new());
+        let job = CountingJob::default();
+        queue.register_job(job.clone()).await;
+
+        let scheduler = Scheduler::new(queue.clone());
+        // A 0-duration interval is always due, so the first tick enqueues it.
+        scheduler
+            .register::<CountingJob>(Schedule::every(Duration::ZERO), MissedRunPolicy::Skip, || {
+                TickData
+            })
+            .await;
+
+        scheduler.tick().await.unwrap();
+        assert!(queue.process_one().await.unwrap());
+        assert_eq!(job.runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_all_catches_up_missed_interval_runs() {
+        let queue = JobQueue::new(InMemoryBackend::new());
+        let job = CountingJob::default();
+        queue.register_job(job.clone()).await;
+
+        let scheduler = Scheduler::new(queue.clone());
+        scheduler
+            .register::<CountingJob>(
+                Schedule::every(Duration::from_secs(1)),
+                MissedRunPolicy::RunAll { max_catch_up: 10 },
+                || TickData,
+            )
+            .await;
+
+        // Simulate the scheduler having missed 5 seconds' worth of ticks.
+        {
+            let mut entries = scheduler.entries.write().await;
+            entries[0].next_run = Utc::now() - chrono::Duration::seconds(5);
+        }
+
+        scheduler.tick().await.unwrap();
+        let mut processed = 0;
+        while queue.process_one().await.unwrap() {
+            processed += 1;
+        }
+        assert_eq!(processed, job.runs.load(Ordering::SeqCst) as usize);
+        assert!(processed > 1, "expected more than one catch-up run, got {processed}");
+    }
+
+    #[tokio::test]
+    async fn test_leader_election_blocks_non_leader_tick() {
+        use crate::leader::LeaderElection;
+
+        struct NeverLeader;
+        #[async_trait]
+        impl LeaderElection for NeverLeader {
+            async fn try_acquire(&self, _key: &str, _holder: &str, _ttl: Duration) -> Result<bool> {
+                Ok(false)
+            }
+            async fn release(&self, _key: &str, _holder: &str) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        let queue = JobQueue::new(InMemoryBackend::new());
+        let job = CountingJob::default();
+        queue.register_job(job.clone()).await;
+
+        let scheduler = Scheduler::with_election(queue.clone(), Arc::new(NeverLeader));
+        scheduler
+            .register::<CountingJob>(Schedule::every(Duration::ZERO), MissedRunPolicy::Skip, || {
+                TickData
+            })
+            .await;
+
+        scheduler.tick().await.unwrap();
+        assert!(!queue.process_one().await.unwrap());
+    }
+}
@@ -0,0 +1,56 @@
+//! Job status tracking, backing [`crate::JobQueue::status`]/[`crate::JobQueue::list_jobs`]
+//! and the optional [`crate::dashboard`].
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Where a job currently sits in its lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Sitting in the queue, waiting to be picked up (or waiting for `run_at`).
+    Pending,
+    /// Currently being executed by a worker.
+    Running,
+    /// A recent attempt failed and it's scheduled for retry.
+    Failed,
+    /// Every retry has been exhausted; needs manual intervention.
+    Dead,
+}
+
+impl FromStr for JobStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pending" => Ok(Self::Pending),
+            "running" => Ok(Self::Running),
+            "failed" => Ok(Self::Failed),
+            "dead" => Ok(Self::Dead),
+            other => Err(format!("unknown job status: {other}")),
+        }
+    }
+}
+
+/// A point-in-time snapshot of a job, for the status/dashboard API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobRecord {
+    pub id: String,
+    pub name: String,
+    pub status: JobStatus,
+    pub attempts: u32,
+    pub max_attempts: u32,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A progress update reported by a running job via [`crate::JobContext::progress`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobProgress {
+    /// Caller-defined completion percentage, e.g. `40` for "40% done".
+    pub percent: u8,
+    pub message: String,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,246 @@
+//! A concurrent worker pool for draining a [`JobQueue`]
+
+use crate::error::Result;
+use crate::queue::JobQueue;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Configuration for a [`WorkerPool`].
+#[derive(Debug, Clone)]
+pub struct WorkerPoolConfig {
+    /// Number of jobs processed concurrently.
+    pub concurrency: usize,
+    /// How long a handler may run before it's considered stuck, aborted,
+    /// and re-queued for another worker to pick up.
+    pub visibility_timeout: Duration,
+    /// How long an idle worker sleeps before polling the queue again.
+    pub poll_interval: Duration,
+}
+
+impl Default for WorkerPoolConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            visibility_timeout: Duration::from_secs(30),
+            poll_interval: Duration::from_millis(100),
+        }
+    }
+}
+
+impl WorkerPoolConfig {
+    /// Start from the defaults (4 workers, 30s visibility timeout, 100ms poll interval).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of jobs processed concurrently.
+    pub fn concurrency(mut self, n: usize) -> Self {
+        self.concurrency = n;
+        self
+    }
+
+    /// How long a handler may run before it's aborted and re-queued.
+    pub fn visibility_timeout(mut self, timeout: Duration) -> Self {
+        self.visibility_timeout = timeout;
+        self
+    }
+
+    /// How long an idle worker sleeps before polling the queue again.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+}
+
+/// Runs `concurrency` workers pulling from a [`JobQueue`] concurrently.
+///
+/// - **Concurrency control**: `concurrency` jobs run at a time, each in its
+///   own task ([`JobQueue::process_one_with_timeout`]), so one panicking or
+///   hung handler can't block the others.
+/// - **Heartbeats**: a handler still running past `visibility_timeout` is
+///   aborted and re-queued for another worker instead of stalling its slot
+///   forever.
+/// - **Graceful shutdown**: [`WorkerPool::shutdown`] stops workers from
+///   picking up new jobs; [`WorkerPool::run`] only returns once every
+///   in-flight job has finished draining.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_jobs::{JobQueue, WorkerPool, WorkerPoolConfig};
+///
+/// let pool = WorkerPool::new(queue, WorkerPoolConfig::new().concurrency(8));
+/// let handle = tokio::spawn({
+///     let pool = pool.clone();
+///     async move { pool.run().await }
+/// });
+///
+/// // On SIGTERM:
+/// pool.shutdown();
+/// handle.await??;
+/// ```
+#[derive(Clone)]
+pub struct WorkerPool {
+    queue: JobQueue,
+    config: WorkerPoolConfig,
+    shutting_down: Arc<AtomicBool>,
+}
+
+impl WorkerPool {
+    /// Create a pool draining `queue` according to `config`.
+    pub fn new(queue: JobQueue, config: WorkerPoolConfig) -> Self {
+        Self {
+            queue,
+            config,
+            shutting_down: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Stop every worker from picking up new jobs. Already-running jobs are
+    /// left to finish - await [`Self::run`]'s return to know they have.
+    pub fn shutdown(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    /// Run `concurrency` worker loops until [`Self::shutdown`] is called,
+    /// draining in-flight jobs before returning.
+    pub async fn run(&self) -> Result<()> {
+        let workers = (0..self.config.concurrency).map(|_| self.worker_loop());
+        futures_util::future::join_all(workers).await;
+        Ok(())
+    }
+
+    async fn worker_loop(&self) {
+        while !self.shutting_down.load(Ordering::SeqCst) {
+            match self
+                .queue
+                .process_one_with_timeout(self.config.visibility_timeout)
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => tokio::time::sleep(self.config.poll_interval).await,
+                Err(e) => {
+                    tracing::error!("Worker error: {}", e);
+                    tokio::time::sleep(self.config.poll_interval).await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::memory::InMemoryBackend;
+    use crate::job::JobContext;
+    use async_trait::async_trait;
+    use serde::{Deserialize, Serialize};
+    use std::sync::atomic::AtomicU32;
+    use std::time::Duration as StdDuration;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct CountData;
+
+    #[derive(Clone, Default)]
+    struct CountingJob {
+        runs: Arc<AtomicU32>,
+    }
+
+    #[async_trait]
+    impl crate::job::Job for CountingJob {
+        const NAME: &'static str = "counting_job";
+        type Data = CountData;
+        type Output = ();
+
+        async fn execute(&self, _ctx: JobContext, _data: Self::Data) -> Result<()> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct HangingJob;
+
+    #[async_trait]
+    impl crate::job::Job for HangingJob {
+        const NAME: &'static str = "hanging_job";
+        type Data = CountData;
+        type Output = ();
+
+        async fn execute(&self, _ctx: JobContext, _data: Self::Data) -> Result<()> {
+            std::future::pending::<()>().await;
+            unreachable!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_worker_pool_drains_and_shuts_down() {
+        let queue = JobQueue::new(InMemoryBackend::new());
+        let job = CountingJob::default();
+        queue.register_job(job.clone()).await;
+
+        for _ in 0..10 {
+            queue.enqueue::<CountingJob>(CountData).await.unwrap();
+        }
+
+        let pool = WorkerPool::new(
+            queue.clone(),
+            WorkerPoolConfig::new()
+                .concurrency(3)
+                .poll_interval(StdDuration::from_millis(5)),
+        );
+        let run_handle = tokio::spawn({
+            let pool = pool.clone();
+            async move { pool.run().await }
+        });
+
+        tokio::time::timeout(StdDuration::from_secs(2), async {
+            loop {
+                if job.runs.load(Ordering::SeqCst) == 10 {
+                    break;
+                }
+                tokio::time::sleep(StdDuration::from_millis(5)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        pool.shutdown();
+        // run() only completes once every worker loop observes the shutdown
+        // signal and its current job (if any) has finished.
+        tokio::time::timeout(StdDuration::from_secs(2), run_handle)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(job.runs.load(Ordering::SeqCst), 10);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stuck_job_is_requeued_after_visibility_timeout() {
+        let queue = JobQueue::new(InMemoryBackend::new());
+        queue.register_job(HangingJob).await;
+        queue.enqueue::<HangingJob>(CountData).await.unwrap();
+
+        // First attempt hangs forever - it should be aborted and re-queued
+        // once it exceeds the visibility timeout.
+        let processed = tokio::time::timeout(
+            StdDuration::from_secs(10),
+            queue.process_one_with_timeout(StdDuration::from_millis(50)),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert!(processed);
+
+        // The job should be back on the queue, ready to be popped again.
+        let backend_has_job = tokio::time::timeout(
+            StdDuration::from_millis(100),
+            queue.process_one_with_timeout(StdDuration::from_secs(10)),
+        )
+        .await;
+        assert!(backend_has_job.is_err(), "second attempt should also hang, proving the job was re-queued");
+    }
+}
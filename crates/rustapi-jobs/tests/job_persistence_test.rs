@@ -20,6 +20,7 @@ struct EmailJob {
 impl Job for EmailJob {
     const NAME: &'static str = "email_job";
     type Data = EmailJobData;
+    type Output = ();
 
     async fn execute(&self, _ctx: JobContext, data: Self::Data) -> Result<()> {
         self.processed.lock().unwrap().push(data);
@@ -106,6 +107,7 @@ struct FailingJob {
 impl Job for FailingJob {
     const NAME: &'static str = "failing_job";
     type Data = (); // No data needed
+    type Output = ();
 
     async fn execute(&self, _ctx: JobContext, _data: Self::Data) -> Result<()> {
         let mut attempts = self.attempts.lock().unwrap();
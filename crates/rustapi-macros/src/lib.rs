@@ -8,6 +8,7 @@
 //! - `#[rustapi::put("/path")]` - PUT route handler
 //! - `#[rustapi::patch("/path")]` - PATCH route handler
 //! - `#[rustapi::delete("/path")]` - DELETE route handler
+//! - `#[rustapi::query_params]` - Defaults/aliases for query parameter structs
 //! - `#[derive(Validate)]` - Validation derive macro
 //!
 //! ## Debugging
@@ -38,7 +39,23 @@ use syn::{
 /// ```
 #[proc_macro_attribute]
 pub fn schema(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(item as syn::Item);
+    let mut input = parse_macro_input!(item as syn::Item);
+
+    // Propagate `#[validate(length/range/email/regex)]` constraints onto matching
+    // `#[schema(...)]` attributes so the generated OpenAPI schema doesn't drift
+    // from what the validator actually enforces.
+    if let syn::Item::Struct(s) = &mut input {
+        if let Fields::Named(fields) = &mut s.fields {
+            for field in &mut fields.named {
+                if field.attrs.iter().any(|a| a.path().is_ident("schema")) {
+                    continue;
+                }
+                if let Some(constraint) = validate_attrs_to_schema_constraint(&field.attrs) {
+                    field.attrs.push(syn::parse_quote!(#[schema(#constraint)]));
+                }
+            }
+        }
+    }
 
     let (ident, generics) = match &input {
         syn::Item::Struct(s) => (&s.ident, &s.generics),
@@ -83,6 +100,170 @@ pub fn schema(_attr: TokenStream, item: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+/// Give query parameter fields a default value and/or a serde alias.
+///
+/// Attach this above a query struct that also derives `Deserialize` and
+/// `IntoParams`. A field's `#[query(default = ..., alias = "...")]`
+/// attribute is translated into the equivalent `#[serde(default = "...")]`
+/// / `#[serde(alias = "...")]` attributes (so missing/renamed query params
+/// parse correctly) and a matching `#[param(default = ...)]` attribute (so
+/// the generated OpenAPI docs show the same default).
+///
+/// ```rust,ignore
+/// use rustapi_rs::prelude::*;
+///
+/// #[rustapi_rs::query_params]
+/// #[derive(Debug, Deserialize, IntoParams)]
+/// struct Pagination {
+///     #[query(default = 1)]
+///     page: u32,
+///     #[query(default = 20, alias = "per_page")]
+///     page_size: u32,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn query_params(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(item as syn::Item);
+
+    let syn::Item::Struct(s) = &mut input else {
+        return syn::Error::new_spanned(
+            &input,
+            "#[rustapi_rs::query_params] can only be used on structs",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    if !s.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &s.generics,
+            "#[rustapi_rs::query_params] does not support generic types",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let struct_ident = s.ident.clone();
+
+    let Fields::Named(fields) = &mut s.fields else {
+        return syn::Error::new_spanned(
+            &s.fields,
+            "#[rustapi_rs::query_params] requires named fields",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let mut default_fns = Vec::new();
+
+    for field in &mut fields.named {
+        let field_ident = field.ident.clone().unwrap();
+        let field_ty = field.ty.clone();
+        let mut default_expr: Option<Expr> = None;
+        let mut alias: Option<LitStr> = None;
+
+        field.attrs.retain(|attr| {
+            if !attr.path().is_ident("query") {
+                return true;
+            }
+            if let Ok(nested) = attr.parse_args_with(
+                syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+            ) {
+                for meta in nested {
+                    if let Meta::NameValue(nv) = &meta {
+                        if nv.path.is_ident("default") {
+                            default_expr = Some(nv.value.clone());
+                        } else if nv.path.is_ident("alias") {
+                            if let Expr::Lit(expr_lit) = &nv.value {
+                                if let Lit::Str(lit) = &expr_lit.lit {
+                                    alias = Some(lit.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            false
+        });
+
+        if let Some(lit) = alias {
+            field.attrs.push(syn::parse_quote!(#[serde(alias = #lit)]));
+        }
+
+        if let Some(expr) = default_expr {
+            let fn_ident = syn::Ident::new(
+                &format!("__query_default_{}_{}", struct_ident, field_ident),
+                proc_macro2::Span::call_site(),
+            );
+            let fn_name = fn_ident.to_string();
+            default_fns.push(quote! {
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                fn #fn_ident() -> #field_ty { #expr }
+            });
+            field
+                .attrs
+                .push(syn::parse_quote!(#[serde(default = #fn_name)]));
+            field.attrs.push(syn::parse_quote!(#[param(default = #expr)]));
+        }
+    }
+
+    let expanded = quote! {
+        #(#default_fns)*
+        #input
+    };
+
+    debug_output("query_params", &expanded);
+    expanded.into()
+}
+
+/// Translate a field's `#[validate(...)]` rules into a `#[schema(...)]` token
+/// stream (minLength/maxLength, minimum/maximum, format, pattern), so the
+/// generated OpenAPI schema and the actual validation rules stay in sync.
+fn validate_attrs_to_schema_constraint(attrs: &[Attribute]) -> Option<proc_macro2::TokenStream> {
+    let rules = parse_validate_attrs(attrs);
+    let mut constraints = Vec::new();
+
+    for rule in &rules {
+        match rule.rule_type.as_str() {
+            "length" => {
+                for (key, value) in &rule.params {
+                    let value: proc_macro2::TokenStream = value.parse().ok()?;
+                    match key.as_str() {
+                        "min" => constraints.push(quote! { min_length = #value }),
+                        "max" => constraints.push(quote! { max_length = #value }),
+                        _ => {}
+                    }
+                }
+            }
+            "range" => {
+                for (key, value) in &rule.params {
+                    let value: proc_macro2::TokenStream = value.parse().ok()?;
+                    match key.as_str() {
+                        "min" => constraints.push(quote! { minimum = #value }),
+                        "max" => constraints.push(quote! { maximum = #value }),
+                        _ => {}
+                    }
+                }
+            }
+            "email" => constraints.push(quote! { format = "email" }),
+            "url" => constraints.push(quote! { format = "uri" }),
+            "regex" => {
+                if let Some((_, pattern)) = rule.params.first() {
+                    constraints.push(quote! { pattern = #pattern });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if constraints.is_empty() {
+        None
+    } else {
+        Some(quote! { #(#constraints),* })
+    }
+}
+
 fn extract_schema_types(ty: &Type, out: &mut Vec<Type>, allow_leaf: bool) {
     match ty {
         Type::Reference(r) => extract_schema_types(&r.elem, out, allow_leaf),
@@ -173,6 +354,46 @@ fn debug_output(name: &str, tokens: &proc_macro2::TokenStream) {
 /// Validate route path syntax at compile time
 ///
 /// Returns Ok(()) if the path is valid, or Err with a descriptive error message.
+/// Pull a summary/description pair out of a handler's `///` doc comments,
+/// FastAPI-docstring style: the first non-empty line is the summary, and any
+/// remaining lines (after a blank-line separator) become the description.
+fn extract_doc_summary_description(attrs: &[Attribute]) -> Option<(String, Option<String>)> {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("doc") {
+            continue;
+        }
+        if let Meta::NameValue(meta) = &attr.meta {
+            if let Expr::Lit(expr_lit) = &meta.value {
+                if let Lit::Str(lit) = &expr_lit.lit {
+                    lines.push(lit.value().trim().to_string());
+                }
+            }
+        }
+    }
+
+    // Drop leading blank lines produced by `///` with no trailing text.
+    while lines.first().is_some_and(|l| l.is_empty()) {
+        lines.remove(0);
+    }
+    if lines.is_empty() {
+        return None;
+    }
+
+    let summary = lines.remove(0);
+    // Skip the blank-line separator between summary and description, if present.
+    while lines.first().is_some_and(|l| l.is_empty()) {
+        lines.remove(0);
+    }
+    let description = if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    };
+
+    Some((summary, description))
+}
+
 fn validate_path_syntax(path: &str, span: proc_macro2::Span) -> Result<(), syn::Error> {
     // Path must start with /
     if !path.starts_with('/') {
@@ -265,17 +486,15 @@ fn validate_path_syntax(path: &str, span: proc_macro2::Span) -> Result<(), syn::
                 param_start = None;
             }
             // Check for invalid characters in path (outside of parameters)
-            _ if brace_depth == 0 => {
-                // Allow alphanumeric, -, _, ., /, and common URL characters
-                if !ch.is_alphanumeric() && !"-_./*".contains(ch) {
-                    return Err(syn::Error::new(
-                        span,
-                        format!(
-                            "invalid character '{}' at position {} in route path: \"{}\"",
-                            ch, i, path
-                        ),
-                    ));
-                }
+            // Allow alphanumeric, -, _, ., /, and common URL characters
+            _ if brace_depth == 0 && !ch.is_alphanumeric() && !"-_./*".contains(ch) => {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "invalid character '{}' at position {} in route path: \"{}\"",
+                        ch, i, path
+                    ),
+                ));
             }
             _ => {}
         }
@@ -334,6 +553,14 @@ pub fn main(_attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Parse the `status, Type` argument list of `#[response(status, Type)]`.
+fn parse_response_attr(input: syn::parse::ParseStream) -> syn::Result<(syn::LitInt, Type)> {
+    let status: syn::LitInt = input.parse()?;
+    input.parse::<syn::Token![,]>()?;
+    let ty: Type = input.parse()?;
+    Ok((status, ty))
+}
+
 /// Internal helper to generate route handler macros
 fn generate_route_handler(method: &str, attr: TokenStream, item: TokenStream) -> TokenStream {
     let path = parse_macro_input!(attr as LitStr);
@@ -348,7 +575,7 @@ fn generate_route_handler(method: &str, attr: TokenStream, item: TokenStream) ->
     let fn_block = &input.block;
     let fn_generics = &input.sig.generics;
 
-    let schema_types = collect_handler_schema_types(&input);
+    let mut schema_types = collect_handler_schema_types(&input);
 
     let path_value = path.value();
 
@@ -379,6 +606,13 @@ fn generate_route_handler(method: &str, attr: TokenStream, item: TokenStream) ->
 
     // Extract metadata from attributes to chain builder methods
     let mut chained_calls = quote!();
+    let mut has_summary = false;
+    let mut has_description = false;
+    // Types named by #[request_body(..)]/#[response(..)] aren't visible in the
+    // handler signature (that's the whole point - raw Request/Response
+    // handlers), so they need to be collected separately for schema
+    // auto-registration.
+    let mut extra_schema_types: Vec<Type> = Vec::new();
 
     for attr in fn_attrs {
         // Check for tag, summary, description
@@ -394,11 +628,66 @@ fn generate_route_handler(method: &str, attr: TokenStream, item: TokenStream) ->
                 if let Ok(lit) = attr.parse_args::<LitStr>() {
                     let val = lit.value();
                     chained_calls = quote! { #chained_calls .summary(#val) };
+                    has_summary = true;
                 }
             } else if ident_str == "description" {
                 if let Ok(lit) = attr.parse_args::<LitStr>() {
                     let val = lit.value();
                     chained_calls = quote! { #chained_calls .description(#val) };
+                    has_description = true;
+                }
+            } else if ident_str == "doc_group" {
+                if let Ok(lit) = attr.parse_args::<LitStr>() {
+                    let val = lit.value();
+                    chained_calls = quote! { #chained_calls .doc_group(#val) };
+                }
+            } else if ident_str == "doc" {
+                if let Ok(arg) = attr.parse_args::<syn::Ident>() {
+                    if arg == "hidden" {
+                        chained_calls = quote! { #chained_calls .hidden() };
+                    }
+                }
+            } else if ident_str == "request_body" {
+                if let Ok(ty) = attr.parse_args::<Type>() {
+                    chained_calls = quote! { #chained_calls .request_body::<#ty>() };
+                    extra_schema_types.push(ty);
+                }
+            } else if ident_str == "response" {
+                if let Ok((status, ty)) = attr.parse_args_with(parse_response_attr) {
+                    chained_calls = quote! {
+                        #chained_calls .response::<#ty>(
+                            ::rustapi_rs::__private::http::StatusCode::from_u16(#status).unwrap()
+                        )
+                    };
+                    extra_schema_types.push(ty);
+                }
+            }
+        }
+    }
+
+    // Merge in types named by #[request_body(..)]/#[response(..)], deduping
+    // the same way collect_handler_schema_types does.
+    {
+        let mut seen = HashSet::<String>::new();
+        schema_types.retain(|t| seen.insert(quote!(#t).to_string()));
+        for ty in extra_schema_types {
+            if seen.insert(quote!(#ty).to_string()) {
+                schema_types.push(ty);
+            }
+        }
+    }
+
+    // Fall back to the handler's `///` doc comment when `#[rustapi::summary]` /
+    // `#[rustapi::description]` are absent, mirroring FastAPI's docstring behavior:
+    // the first line becomes the summary, the remaining lines become the description.
+    if !has_summary || !has_description {
+        if let Some((doc_summary, doc_description)) = extract_doc_summary_description(fn_attrs) {
+            if !has_summary {
+                chained_calls = quote! { #chained_calls .summary(#doc_summary) };
+            }
+            if !has_description {
+                if let Some(desc) = doc_description {
+                    chained_calls = quote! { #chained_calls .description(#desc) };
                 }
             }
         }
@@ -456,6 +745,15 @@ fn generate_route_handler(method: &str, attr: TokenStream, item: TokenStream) ->
 /// async fn get_user(Path(id): Path<i64>) -> Result<User> {
 ///     Ok(User { id, name: "John".into() })
 /// }
+///
+/// // Handlers that take/return a raw Request/Response have no extractor to
+/// // infer the body schema from, so document it explicitly.
+/// #[rustapi::post("/users")]
+/// #[request_body(CreateUser)]
+/// #[response(201, User)]
+/// async fn create_user_raw(req: Request) -> Response {
+///     // ...
+/// }
 /// ```
 #[proc_macro_attribute]
 pub fn get(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -587,6 +885,77 @@ pub fn description(attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Documentation group macro, for splitting a single route table into multiple
+/// OpenAPI specs (e.g. "public" vs "internal") via
+/// [`RustApi::docs_group`](https://docs.rs/rustapi-rs/*/rustapi_rs/struct.RustApi.html#method.docs_group).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[rustapi::get("/admin/users")]
+/// #[rustapi::doc_group("internal")]
+/// async fn list_users_admin() -> Json<Vec<User>> {
+///     Json(vec![])
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn doc_group(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let group = parse_macro_input!(attr as LitStr);
+    let input = parse_macro_input!(item as ItemFn);
+
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let sig = &input.sig;
+    let block = &input.block;
+    let group_value = group.value();
+
+    let expanded = quote! {
+        #[doc = concat!("**Doc group:** ", #group_value)]
+        #(#attrs)*
+        #vis #sig #block
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Hide a route from the generated OpenAPI document, e.g. health checks,
+/// metrics, and debug endpoints that should keep serving requests but not
+/// show up in the public spec.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[rustapi::get("/healthz")]
+/// #[rustapi::doc(hidden)]
+/// async fn healthz() -> &'static str {
+///     "ok"
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn doc(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let arg = parse_macro_input!(attr as syn::Ident);
+    let input = parse_macro_input!(item as ItemFn);
+
+    if arg != "hidden" {
+        return syn::Error::new(arg.span(), "rustapi::doc only supports `hidden`")
+            .to_compile_error()
+            .into();
+    }
+
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let sig = &input.sig;
+    let block = &input.block;
+
+    let expanded = quote! {
+        #[doc(hidden)]
+        #(#attrs)*
+        #vis #sig #block
+    };
+
+    TokenStream::from(expanded)
+}
+
 // ============================================
 // Validation Derive Macro
 // ============================================
@@ -705,16 +1074,118 @@ fn expr_to_string(expr: &Expr) -> Option<String> {
     }
 }
 
+/// A single `#[transform(...)]` operation, in the order it was written.
+enum TransformOp {
+    Trim,
+    Lowercase,
+    Truncate(usize),
+}
+
+/// Parse `#[transform(trim, lowercase, truncate = 255)]` from a field's attributes.
+fn parse_transform_attrs(attrs: &[Attribute]) -> Vec<TransformOp> {
+    let mut ops = Vec::new();
+
+    for attr in attrs {
+        if !attr.path().is_ident("transform") {
+            continue;
+        }
+
+        let Ok(nested) = attr
+            .parse_args_with(syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated)
+        else {
+            continue;
+        };
+
+        for meta in nested {
+            match &meta {
+                Meta::Path(path) if path.is_ident("trim") => ops.push(TransformOp::Trim),
+                Meta::Path(path) if path.is_ident("lowercase") => ops.push(TransformOp::Lowercase),
+                Meta::NameValue(nv) if nv.path.is_ident("truncate") => {
+                    if let Some(len) = expr_to_string(&nv.value).and_then(|v| v.parse().ok()) {
+                        ops.push(TransformOp::Truncate(len));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    ops
+}
+
+/// Generate the field mutation for a single `#[transform(...)]` op.
+fn generate_transform_op(field_name: &str, op: &TransformOp) -> proc_macro2::TokenStream {
+    let field_ident = syn::Ident::new(field_name, proc_macro2::Span::call_site());
+
+    match op {
+        TransformOp::Trim => quote! {
+            ::rustapi_validate::v2::Transformable::trim_transform(&mut self.#field_ident);
+        },
+        TransformOp::Lowercase => quote! {
+            ::rustapi_validate::v2::Transformable::lowercase_transform(&mut self.#field_ident);
+        },
+        TransformOp::Truncate(max_len) => quote! {
+            ::rustapi_validate::v2::Transformable::truncate_transform(&mut self.#field_ident, #max_len);
+        },
+    }
+}
+
+/// If `ty` is `Vec<T>`, return `T`.
+fn vec_item_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(tp) = ty else {
+        return None;
+    };
+    let seg = tp.path.segments.last()?;
+    if seg.ident != "Vec" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &seg.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
 /// Generate validation code for a single rule
 fn generate_rule_validation(
     field_name: &str,
-    _field_type: &Type,
+    field_type: &Type,
     rule: &ValidationRuleInfo,
 ) -> proc_macro2::TokenStream {
     let field_ident = syn::Ident::new(field_name, proc_macro2::Span::call_site());
     let field_name_str = field_name;
 
     match rule.rule_type.as_str() {
+        "nested" => {
+            if vec_item_type(field_type).is_some() {
+                // Vec<Item>: validate each element, prefixing its errors with
+                // an indexed path like "items[2].name".
+                quote! {
+                    {
+                        for (__idx, __item) in self.#field_ident.iter().enumerate() {
+                            if let Err(__item_errors) = ::rustapi_validate::v2::Validate::validate(__item) {
+                                for (__sub_field, __sub_errors) in __item_errors.fields {
+                                    errors.add_all(format!("{}[{}].{}", #field_name_str, __idx, __sub_field), __sub_errors);
+                                }
+                            }
+                        }
+                    }
+                }
+            } else {
+                // A single nested struct: prefix its errors with "field.subfield".
+                quote! {
+                    {
+                        if let Err(__item_errors) = ::rustapi_validate::v2::Validate::validate(&self.#field_ident) {
+                            for (__sub_field, __sub_errors) in __item_errors.fields {
+                                errors.add_all(format!("{}.{}", #field_name_str, __sub_field), __sub_errors);
+                            }
+                        }
+                    }
+                }
+            }
+        }
         "email" => {
             let message = rule
                 .message
@@ -863,6 +1334,56 @@ fn generate_rule_validation(
                 }
             }
         }
+        "required_if" => {
+            let other_field = rule
+                .params
+                .iter()
+                .find(|(k, _)| k == "required_if" || k == "field")
+                .map(|(_, v)| v.clone())
+                .unwrap_or_default();
+            let other_ident = syn::Ident::new(&other_field, proc_macro2::Span::call_site());
+            let message = rule
+                .message
+                .as_ref()
+                .map(|m| quote! { .with_message(#m) })
+                .unwrap_or_default();
+
+            quote! {
+                {
+                    if ::rustapi_validate::v2::FieldPresence::is_present(&self.#other_ident) {
+                        let rule = ::rustapi_validate::v2::RequiredRule::new() #message;
+                        if let Err(e) = ::rustapi_validate::v2::ValidationRule::validate(&rule, &self.#field_ident) {
+                            errors.add(#field_name_str, e);
+                        }
+                    }
+                }
+            }
+        }
+        "must_match" => {
+            let other_field = rule
+                .params
+                .iter()
+                .find(|(k, _)| k == "must_match" || k == "field")
+                .map(|(_, v)| v.clone())
+                .unwrap_or_default();
+            let other_ident = syn::Ident::new(&other_field, proc_macro2::Span::call_site());
+            let message = rule
+                .message
+                .clone()
+                .unwrap_or_else(|| "Fields do not match".to_string());
+
+            quote! {
+                {
+                    if self.#field_ident != self.#other_ident {
+                        errors.add(
+                            #field_name_str,
+                            ::rustapi_validate::v2::RuleError::new("must_match", #message)
+                                .param("field", #other_field),
+                        );
+                    }
+                }
+            }
+        }
         _ => {
             // Unknown rule - skip
             quote! {}
@@ -957,6 +1478,28 @@ fn generate_async_rule_validation(
                 }
             }
         }
+        "custom_async" => {
+            let name = rule
+                .params
+                .iter()
+                .find(|(k, _)| k == "custom_async" || k == "name")
+                .map(|(_, v)| v.clone())
+                .unwrap_or_default();
+            let message = rule
+                .message
+                .as_ref()
+                .map(|m| quote! { .with_message(#m) })
+                .unwrap_or_default();
+
+            quote! {
+                {
+                    let rule = ::rustapi_validate::v2::AsyncCustomRule::new(#name) #message;
+                    if let Err(e) = ::rustapi_validate::v2::AsyncValidationRule::validate_async(&rule, &self.#field_ident, ctx).await {
+                        errors.add(#field_name_str, e);
+                    }
+                }
+            }
+        }
         _ => {
             // Not an async rule
             quote! {}
@@ -968,7 +1511,7 @@ fn generate_async_rule_validation(
 fn is_async_rule(rule: &ValidationRuleInfo) -> bool {
     matches!(
         rule.rule_type.as_str(),
-        "async_unique" | "async_exists" | "async_api"
+        "async_unique" | "async_exists" | "async_api" | "custom_async"
     )
 }
 
@@ -992,9 +1535,18 @@ fn is_async_rule(rule: &ValidationRuleInfo) -> bool {
 ///     
 ///     #[validate(async_unique(table = "users", column = "email"))]
 ///     email: String,
+///
+///     #[validate(custom_async = "is_unique_email")]
+///     unique_email: String,
+///
+///     #[validate(must_match = "password")]
+///     password_confirm: String,
+///
+///     #[transform(trim, lowercase, truncate = 255)]
+///     display_name: String,
 /// }
 /// ```
-#[proc_macro_derive(Validate, attributes(validate))]
+#[proc_macro_derive(Validate, attributes(validate, transform))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -1024,6 +1576,7 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
     // Collect sync and async validation code for each field
     let mut sync_validations = Vec::new();
     let mut async_validations = Vec::new();
+    let mut transform_ops = Vec::new();
     let mut has_async_rules = false;
 
     for field in fields {
@@ -1041,6 +1594,35 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
                 sync_validations.push(validation);
             }
         }
+
+        for op in parse_transform_attrs(&field.attrs) {
+            transform_ops.push(generate_transform_op(&field_name, &op));
+        }
+    }
+
+    // Struct-level `#[validate(schema(function = "..."))]` rules run last and
+    // report under the synthetic "__all__" field path, for constraints that
+    // span the whole struct rather than a single field.
+    for rule in parse_validate_attrs(&input.attrs) {
+        if rule.rule_type != "schema" {
+            continue;
+        }
+        let Some(function) = rule
+            .params
+            .iter()
+            .find(|(k, _)| k == "function")
+            .map(|(_, v)| v.clone())
+        else {
+            continue;
+        };
+        let function_ident = syn::Ident::new(&function, proc_macro2::Span::call_site());
+        sync_validations.push(quote! {
+            {
+                if let Err(message) = #function_ident(self) {
+                    errors.add("__all__", ::rustapi_validate::v2::RuleError::new("schema", message));
+                }
+            }
+        });
     }
 
     // Generate the Validate impl
@@ -1059,7 +1641,7 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
     // Generate the AsyncValidate impl if there are async rules
     let async_validate_impl = if has_async_rules {
         quote! {
-            #[::async_trait::async_trait]
+            #[::rustapi_validate::async_trait::async_trait]
             impl #impl_generics ::rustapi_validate::v2::AsyncValidate for #name #ty_generics #where_clause {
                 async fn validate_async(&self, ctx: &::rustapi_validate::v2::ValidationContext) -> Result<(), ::rustapi_validate::v2::ValidationErrors> {
                     let mut errors = ::rustapi_validate::v2::ValidationErrors::new();
@@ -1073,7 +1655,7 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
     } else {
         // Provide a default AsyncValidate impl that just returns Ok
         quote! {
-            #[::async_trait::async_trait]
+            #[::rustapi_validate::async_trait::async_trait]
             impl #impl_generics ::rustapi_validate::v2::AsyncValidate for #name #ty_generics #where_clause {
                 async fn validate_async(&self, _ctx: &::rustapi_validate::v2::ValidationContext) -> Result<(), ::rustapi_validate::v2::ValidationErrors> {
                     Ok(())
@@ -1082,9 +1664,20 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
         }
     };
 
+    // Generate the Transform impl. Structs without `#[transform(...)]` attributes
+    // get a no-op body, so extractors can call `transform()` unconditionally.
+    let transform_impl = quote! {
+        impl #impl_generics ::rustapi_validate::v2::Transform for #name #ty_generics #where_clause {
+            fn transform(&mut self) {
+                #(#transform_ops)*
+            }
+        }
+    };
+
     let expanded = quote! {
         #validate_impl
         #async_validate_impl
+        #transform_impl
     };
 
     debug_output("Validate derive", &expanded);
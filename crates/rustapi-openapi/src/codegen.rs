@@ -0,0 +1,285 @@
+//! TypeScript client generation from an in-memory [`OpenApiSpec`].
+//!
+//! Behind the `codegen` feature. [`generate_typescript_client`] emits a
+//! typed `fetch`-based TypeScript client directly from the spec, so
+//! front-end types always match the handlers without a separate codegen
+//! CLI or build step.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rustapi_openapi::codegen::generate_typescript_client;
+//!
+//! let ts = generate_typescript_client(app.openapi_spec());
+//! std::fs::write("client.ts", ts)?;
+//! ```
+
+use crate::spec::{Operation, Parameter, PathItem, SchemaRef};
+use crate::OpenApiSpec;
+use std::fmt::Write as _;
+
+const METHODS: [&str; 5] = ["GET", "POST", "PUT", "PATCH", "DELETE"];
+
+fn method_ops(item: &PathItem) -> Vec<(&'static str, &Operation)> {
+    METHODS
+        .iter()
+        .filter_map(|&method| operation(item, method).map(|op| (method, op)))
+        .collect()
+}
+
+fn operation<'a>(item: &'a PathItem, method: &str) -> Option<&'a Operation> {
+    match method {
+        "GET" => item.get.as_ref(),
+        "POST" => item.post.as_ref(),
+        "PUT" => item.put.as_ref(),
+        "PATCH" => item.patch.as_ref(),
+        "DELETE" => item.delete.as_ref(),
+        _ => None,
+    }
+}
+
+/// Generate a typed TypeScript `fetch` client module from an OpenAPI spec.
+pub fn generate_typescript_client(spec: &OpenApiSpec) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "// Auto-generated by rustapi-openapi. Do not edit by hand.");
+    let _ = writeln!(out);
+
+    let mut schema_names: Vec<_> = spec.schemas.keys().collect();
+    schema_names.sort();
+    for name in schema_names {
+        write_interface(&mut out, name, &spec.schemas[name]);
+    }
+
+    let _ = writeln!(out, "export class ApiClient {{");
+    let _ = writeln!(out, "  constructor(private baseUrl: string) {{}}");
+    let _ = writeln!(out);
+
+    let mut paths: Vec<_> = spec.paths.iter().collect();
+    paths.sort_by_key(|(path, _)| path.to_string());
+    for (path, item) in paths {
+        for (method, op) in method_ops(item) {
+            write_method(&mut out, path, method, op);
+        }
+    }
+
+    let _ = writeln!(out, "}}");
+    out
+}
+
+fn write_interface(out: &mut String, name: &str, schema: &serde_json::Value) {
+    let _ = writeln!(out, "export interface {name} {{");
+    if let Some(properties) = schema["properties"].as_object() {
+        let required = schema["required"]
+            .as_array()
+            .map(|a| a.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let mut fields: Vec<_> = properties.keys().collect();
+        fields.sort();
+        for field in fields {
+            let optional = if required.contains(&field.as_str()) { "" } else { "?" };
+            let ts_type = ts_type_from_json_schema(&properties[field]);
+            let _ = writeln!(out, "  {field}{optional}: {ts_type};");
+        }
+    }
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out);
+}
+
+fn ts_type_from_schema_ref(schema_ref: &SchemaRef) -> String {
+    match schema_ref {
+        SchemaRef::Ref { reference } => schema_name_from_ref(reference),
+        SchemaRef::Inline(value) => ts_type_from_json_schema(value),
+    }
+}
+
+fn schema_name_from_ref(reference: &str) -> String {
+    reference
+        .rsplit('/')
+        .next()
+        .unwrap_or(reference)
+        .to_string()
+}
+
+fn ts_type_from_json_schema(value: &serde_json::Value) -> String {
+    if let Some(reference) = value["$ref"].as_str() {
+        return schema_name_from_ref(reference);
+    }
+    if let Some(values) = value["enum"].as_array() {
+        return values
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(|v| format!("{v:?}"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+    }
+    match value["type"].as_str() {
+        Some("integer") | Some("number") => "number".to_string(),
+        Some("boolean") => "boolean".to_string(),
+        Some("string") => "string".to_string(),
+        Some("array") => format!("{}[]", ts_type_from_json_schema(&value["items"])),
+        Some("object") | None => "Record<string, unknown>".to_string(),
+        Some(_) => "unknown".to_string(),
+    }
+}
+
+fn operation_name(method: &str, path: &str) -> String {
+    let mut name = method.to_lowercase();
+    for segment in path.split('/').filter(|s| !s.is_empty()) {
+        if let Some(param) = segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            name.push_str("By");
+            name.push_str(&capitalize(param));
+        } else {
+            name.push_str(&capitalize(segment));
+        }
+    }
+    name
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn success_return_type(op: &Operation) -> String {
+    op.responses
+        .iter()
+        .filter(|(status, _)| status.starts_with('2'))
+        .min_by_key(|(status, _)| (*status).clone())
+        .and_then(|(_, resp)| resp.content.as_ref())
+        .and_then(|content| content.get("application/json"))
+        .map(|media| ts_type_from_schema_ref(&media.schema))
+        .unwrap_or_else(|| "void".to_string())
+}
+
+fn write_method(out: &mut String, path: &str, method: &str, op: &Operation) {
+    let name = operation_name(method, path);
+    let return_type = success_return_type(op);
+
+    let path_params: Vec<&Parameter> = op
+        .parameters
+        .iter()
+        .flatten()
+        .filter(|p| p.location == "path")
+        .collect();
+    let query_params: Vec<&Parameter> = op
+        .parameters
+        .iter()
+        .flatten()
+        .filter(|p| p.location == "query")
+        .collect();
+
+    let mut args: Vec<String> = path_params
+        .iter()
+        .map(|p| format!("{}: {}", p.name, ts_type_from_schema_ref(&p.schema)))
+        .collect();
+    if !query_params.is_empty() {
+        let fields: Vec<String> = query_params
+            .iter()
+            .map(|p| {
+                let optional = if p.required { "" } else { "?" };
+                format!("{}{}: {}", p.name, optional, ts_type_from_schema_ref(&p.schema))
+            })
+            .collect();
+        args.push(format!("query: {{ {} }}", fields.join("; ")));
+    }
+    let has_body = matches!(method, "POST" | "PUT" | "PATCH") && op.request_body.is_some();
+    if has_body {
+        args.push("body: unknown".to_string());
+    }
+
+    if let Some(summary) = &op.summary {
+        let _ = writeln!(out, "  /** {summary} */");
+    }
+
+    let _ = writeln!(out, "  async {name}({}): Promise<{return_type}> {{", args.join(", "));
+
+    let mut url_expr = format!("`${{this.baseUrl}}{path}`");
+    for param in &path_params {
+        url_expr = url_expr.replace(&format!("{{{}}}", param.name), &format!("${{{}}}", param.name));
+    }
+    let _ = writeln!(out, "    const url = {url_expr};");
+
+    let mut fetch_opts = format!("method: {method:?}");
+    if has_body {
+        let _ = writeln!(out, "    const res = await fetch(url, {{");
+        let _ = writeln!(out, "      {fetch_opts},");
+        let _ = writeln!(out, "      headers: {{ 'Content-Type': 'application/json' }},");
+        let _ = writeln!(out, "      body: JSON.stringify(body),");
+        let _ = writeln!(out, "    }});");
+    } else {
+        let _ = write!(fetch_opts, "");
+        let _ = writeln!(out, "    const res = await fetch(url, {{ {fetch_opts} }});");
+    }
+    let _ = writeln!(out, "    if (!res.ok) {{ throw new Error(`request failed: ${{res.status}}`); }}");
+    if return_type == "void" {
+        let _ = writeln!(out, "    return;");
+    } else {
+        let _ = writeln!(out, "    return res.json() as Promise<{return_type}>;");
+    }
+    let _ = writeln!(out, "  }}");
+    let _ = writeln!(out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{MediaType, ResponseSpec};
+    use std::collections::HashMap;
+
+    #[test]
+    fn generates_interface_from_schema() {
+        let spec = OpenApiSpec::new("Test API", "1.0.0").schema(
+            "User",
+            serde_json::json!({
+                "type": "object",
+                "properties": { "id": {"type": "integer"}, "name": {"type": "string"} },
+                "required": ["id", "name"],
+            }),
+        );
+
+        let ts = generate_typescript_client(&spec);
+
+        assert!(ts.contains("export interface User {"));
+        assert!(ts.contains("id: number;"));
+        assert!(ts.contains("name: string;"));
+    }
+
+    #[test]
+    fn generates_method_for_path_with_param() {
+        let mut responses = HashMap::new();
+        responses.insert(
+            "200".to_string(),
+            ResponseSpec {
+                description: "OK".to_string(),
+                content: Some(HashMap::from([(
+                    "application/json".to_string(),
+                    MediaType {
+                        schema: SchemaRef::Ref {
+                            reference: "#/components/schemas/User".to_string(),
+                        },
+                    },
+                )])),
+            },
+        );
+        let op = Operation {
+            responses,
+            parameters: Some(vec![Parameter {
+                name: "id".to_string(),
+                location: "path".to_string(),
+                required: true,
+                description: None,
+                schema: SchemaRef::Inline(serde_json::json!({"type": "integer"})),
+            }]),
+            ..Operation::new()
+        };
+        let spec = OpenApiSpec::new("Test API", "1.0.0").path("/users/{id}", "GET", op);
+
+        let ts = generate_typescript_client(&spec);
+
+        assert!(ts.contains("async getUsersById(id: number): Promise<User>"));
+        assert!(ts.contains("`${this.baseUrl}/users/${id}`"));
+    }
+}
@@ -1,5 +1,8 @@
 //! OpenAPI configuration
 
+use crate::spec::{ExternalDocs, TagInfo};
+use std::collections::HashMap;
+
 /// Configuration for OpenAPI documentation
 #[derive(Debug, Clone)]
 pub struct OpenApiConfig {
@@ -13,6 +16,12 @@ pub struct OpenApiConfig {
     pub json_path: String,
     /// Path to serve Swagger UI
     pub docs_path: String,
+    /// Named `components.securitySchemes` entries, keyed by scheme name
+    pub security_schemes: HashMap<String, serde_json::Value>,
+    /// Global `security` requirements applied to every operation by default
+    pub global_security: Vec<HashMap<String, Vec<String>>>,
+    /// Top-level `tags` document metadata, in registration order (see [`OpenApiConfig::tag`])
+    pub tags: Vec<TagInfo>,
 }
 
 impl Default for OpenApiConfig {
@@ -23,6 +32,9 @@ impl Default for OpenApiConfig {
             description: None,
             json_path: "/openapi.json".to_string(),
             docs_path: "/docs".to_string(),
+            security_schemes: HashMap::new(),
+            global_security: Vec::new(),
+            tags: Vec::new(),
         }
     }
 }
@@ -54,4 +66,107 @@ impl OpenApiConfig {
         self.docs_path = path.into();
         self
     }
+
+    /// Register an HTTP bearer (JWT) security scheme named `bearerAuth` and make it
+    /// a global default.
+    pub fn bearer_auth(self) -> Self {
+        self.bearer_auth_named("bearerAuth")
+    }
+
+    /// Register an HTTP bearer (JWT) security scheme under a custom name.
+    pub fn bearer_auth_named(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.security_schemes.insert(
+            name.clone(),
+            serde_json::json!({
+                "type": "http",
+                "scheme": "bearer",
+                "bearerFormat": "JWT",
+            }),
+        );
+        self.global_security.push(HashMap::from([(name, vec![])]));
+        self
+    }
+
+    /// Register an API key security scheme read from a request header, named
+    /// `apiKeyAuth`, and make it a global default.
+    pub fn api_key_header(self, header_name: impl Into<String>) -> Self {
+        self.api_key_header_named("apiKeyAuth", header_name)
+    }
+
+    /// Register an API key security scheme read from a request header under a
+    /// custom scheme name.
+    pub fn api_key_header_named(
+        mut self,
+        scheme_name: impl Into<String>,
+        header_name: impl Into<String>,
+    ) -> Self {
+        let scheme_name = scheme_name.into();
+        self.security_schemes.insert(
+            scheme_name.clone(),
+            serde_json::json!({
+                "type": "apiKey",
+                "in": "header",
+                "name": header_name.into(),
+            }),
+        );
+        self.global_security
+            .push(HashMap::from([(scheme_name, vec![])]));
+        self
+    }
+
+    /// Register an OAuth2 security scheme with the given flows (as raw OpenAPI
+    /// `flows` JSON) and make it a global default requiring `scopes`.
+    pub fn oauth2_flows(
+        mut self,
+        name: impl Into<String>,
+        flows: serde_json::Value,
+        scopes: Vec<String>,
+    ) -> Self {
+        let name = name.into();
+        self.security_schemes.insert(
+            name.clone(),
+            serde_json::json!({
+                "type": "oauth2",
+                "flows": flows,
+            }),
+        );
+        self.global_security.push(HashMap::from([(name, scopes)]));
+        self
+    }
+
+    /// Add a top-level `tags` document entry, in the order it should appear
+    /// in the spec.
+    ///
+    /// Swagger UI groups operations by tag in this order, so calling this
+    /// once per tag (in the desired order) is how tag ordering is controlled -
+    /// without it, tags only show up implicitly, in whatever order routes
+    /// happened to register, and with no description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustapi_openapi::{ExternalDocs, OpenApiConfig};
+    ///
+    /// let config = OpenApiConfig::default()
+    ///     .tag("Users", Some("User account management".to_string()), None)
+    ///     .tag(
+    ///         "Orders",
+    ///         Some("Order lifecycle".to_string()),
+    ///         Some(ExternalDocs::new("https://example.com/docs/orders")),
+    ///     );
+    /// ```
+    pub fn tag(
+        mut self,
+        name: impl Into<String>,
+        description: Option<String>,
+        external_docs: Option<ExternalDocs>,
+    ) -> Self {
+        self.tags.push(TagInfo {
+            name: name.into(),
+            description,
+            external_docs,
+        });
+        self
+    }
 }
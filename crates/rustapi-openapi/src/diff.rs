@@ -0,0 +1,318 @@
+//! Structural diffing between two OpenAPI specs.
+//!
+//! [`diff`] compares an "old" and a "new" [`OpenApiSpec`] and produces a
+//! machine-readable [`SpecDiff`] classifying every detected change as
+//! [`ChangeSeverity::Breaking`] or [`ChangeSeverity::NonBreaking`]. Tests can
+//! assert [`SpecDiff::has_breaking_changes`] to gate a release on API
+//! compatibility.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rustapi_openapi::diff;
+//!
+//! let report = diff::diff(&old_spec, &new_spec);
+//! assert!(!report.has_breaking_changes(), "{report:#?}");
+//! ```
+
+use crate::spec::{Operation, PathItem};
+use crate::OpenApiSpec;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Whether a detected change can break existing API consumers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChangeSeverity {
+    Breaking,
+    NonBreaking,
+}
+
+/// A single detected difference between two OpenAPI specs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Change {
+    pub severity: ChangeSeverity,
+    pub description: String,
+}
+
+impl Change {
+    fn breaking(description: impl Into<String>) -> Self {
+        Self {
+            severity: ChangeSeverity::Breaking,
+            description: description.into(),
+        }
+    }
+
+    fn non_breaking(description: impl Into<String>) -> Self {
+        Self {
+            severity: ChangeSeverity::NonBreaking,
+            description: description.into(),
+        }
+    }
+}
+
+/// Machine-readable report produced by [`diff`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SpecDiff {
+    pub changes: Vec<Change>,
+}
+
+impl SpecDiff {
+    /// True if any detected change would break existing consumers.
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|c| c.severity == ChangeSeverity::Breaking)
+    }
+
+    /// Iterate over only the breaking changes.
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|c| c.severity == ChangeSeverity::Breaking)
+    }
+}
+
+/// Compare two OpenAPI specs and classify every detected change as breaking
+/// or non-breaking.
+pub fn diff(old: &OpenApiSpec, new: &OpenApiSpec) -> SpecDiff {
+    let mut changes = Vec::new();
+    diff_routes(old, new, &mut changes);
+    diff_schemas(old, new, &mut changes);
+    SpecDiff { changes }
+}
+
+const METHODS: [&str; 5] = ["GET", "POST", "PUT", "PATCH", "DELETE"];
+
+fn operation<'a>(item: &'a PathItem, method: &str) -> Option<&'a Operation> {
+    match method {
+        "GET" => item.get.as_ref(),
+        "POST" => item.post.as_ref(),
+        "PUT" => item.put.as_ref(),
+        "PATCH" => item.patch.as_ref(),
+        "DELETE" => item.delete.as_ref(),
+        _ => None,
+    }
+}
+
+fn diff_routes(old: &OpenApiSpec, new: &OpenApiSpec, changes: &mut Vec<Change>) {
+    for (path, old_item) in &old.paths {
+        let new_item = new.paths.get(path);
+        for method in METHODS {
+            let had_it = operation(old_item, method).is_some();
+            let has_it = new_item.and_then(|item| operation(item, method)).is_some();
+            if had_it && !has_it {
+                changes.push(Change::breaking(format!("{method} {path} was removed")));
+            }
+        }
+    }
+
+    for (path, new_item) in &new.paths {
+        let old_item = old.paths.get(path);
+        for method in METHODS {
+            let has_it = operation(new_item, method).is_some();
+            let had_it = old_item.and_then(|item| operation(item, method)).is_some();
+            if has_it && !had_it {
+                changes.push(Change::non_breaking(format!("{method} {path} was added")));
+            }
+        }
+    }
+}
+
+fn diff_schemas(old: &OpenApiSpec, new: &OpenApiSpec, changes: &mut Vec<Change>) {
+    for (name, old_schema) in &old.schemas {
+        match new.schemas.get(name) {
+            None => changes.push(Change::breaking(format!("schema `{name}` was removed"))),
+            Some(new_schema) => diff_schema(name, old_schema, new_schema, changes),
+        }
+    }
+
+    for name in new.schemas.keys() {
+        if !old.schemas.contains_key(name) {
+            changes.push(Change::non_breaking(format!("schema `{name}` was added")));
+        }
+    }
+}
+
+fn string_set(value: &serde_json::Value) -> HashSet<String> {
+    value
+        .as_array()
+        .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(String::from).collect())
+        .unwrap_or_default()
+}
+
+fn diff_schema(name: &str, old: &serde_json::Value, new: &serde_json::Value, changes: &mut Vec<Change>) {
+    diff_enum(name, None, old, new, changes);
+
+    let old_props = old["properties"].as_object();
+    let new_props = new["properties"].as_object();
+    let old_required = string_set(&old["required"]);
+    let new_required = string_set(&new["required"]);
+
+    if let Some(old_props) = old_props {
+        for field in old_props.keys() {
+            let still_present = new_props.is_some_and(|p| p.contains_key(field));
+            if !still_present {
+                changes.push(Change::breaking(format!(
+                    "field `{field}` was removed from schema `{name}`"
+                )));
+                continue;
+            }
+            if !old_required.contains(field) && new_required.contains(field) {
+                changes.push(Change::breaking(format!(
+                    "field `{field}` on schema `{name}` became required"
+                )));
+            }
+            if old_required.contains(field) && !new_required.contains(field) {
+                changes.push(Change::non_breaking(format!(
+                    "field `{field}` on schema `{name}` is no longer required"
+                )));
+            }
+            if let (Some(old_field), Some(new_field)) =
+                (old["properties"].get(field), new["properties"].get(field))
+            {
+                diff_enum(name, Some(field), old_field, new_field, changes);
+            }
+        }
+    }
+
+    if let Some(new_props) = new_props {
+        for field in new_props.keys() {
+            let existed = old_props.is_some_and(|p| p.contains_key(field));
+            if !existed {
+                changes.push(Change::non_breaking(format!(
+                    "field `{field}` was added to schema `{name}`"
+                )));
+            }
+        }
+    }
+}
+
+fn diff_enum(
+    schema_name: &str,
+    field: Option<&str>,
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    changes: &mut Vec<Change>,
+) {
+    let old_values = string_set(&old["enum"]);
+    let new_values = string_set(&new["enum"]);
+    if old_values.is_empty() && new_values.is_empty() {
+        return;
+    }
+
+    let where_ = match field {
+        Some(field) => format!("field `{field}` on schema `{schema_name}`"),
+        None => format!("schema `{schema_name}`"),
+    };
+
+    let removed: Vec<_> = old_values.difference(&new_values).collect();
+    if !removed.is_empty() {
+        changes.push(Change::breaking(format!(
+            "enum on {where_} was narrowed (removed: {removed:?})"
+        )));
+    }
+
+    let added: Vec<_> = new_values.difference(&old_values).collect();
+    if !added.is_empty() {
+        changes.push(Change::non_breaking(format!(
+            "enum on {where_} was widened (added: {added:?})"
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::Operation;
+
+    fn spec_with_route(path: &str, method: &str) -> OpenApiSpec {
+        OpenApiSpec::new("Test API", "1.0.0").path(path, method, Operation::new())
+    }
+
+    #[test]
+    fn detects_removed_route_as_breaking() {
+        let old = spec_with_route("/users", "GET");
+        let new = OpenApiSpec::new("Test API", "1.0.0");
+
+        let report = diff(&old, &new);
+
+        assert!(report.has_breaking_changes());
+        assert!(report
+            .breaking_changes()
+            .any(|c| c.description.contains("GET /users was removed")));
+    }
+
+    #[test]
+    fn detects_added_route_as_non_breaking() {
+        let old = OpenApiSpec::new("Test API", "1.0.0");
+        let new = spec_with_route("/users", "GET");
+
+        let report = diff(&old, &new);
+
+        assert!(!report.has_breaking_changes());
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].severity, ChangeSeverity::NonBreaking);
+    }
+
+    #[test]
+    fn detects_removed_required_field_as_breaking() {
+        let old = OpenApiSpec::new("Test API", "1.0.0").schema(
+            "User",
+            serde_json::json!({
+                "type": "object",
+                "properties": { "id": {"type": "integer"}, "name": {"type": "string"} },
+                "required": ["id", "name"],
+            }),
+        );
+        let new = OpenApiSpec::new("Test API", "1.0.0").schema(
+            "User",
+            serde_json::json!({
+                "type": "object",
+                "properties": { "id": {"type": "integer"} },
+                "required": ["id"],
+            }),
+        );
+
+        let report = diff(&old, &new);
+
+        assert!(report.has_breaking_changes());
+        assert!(report
+            .breaking_changes()
+            .any(|c| c.description.contains("field `name` was removed")));
+    }
+
+    #[test]
+    fn detects_narrowed_enum_as_breaking() {
+        let old = OpenApiSpec::new("Test API", "1.0.0").schema(
+            "Status",
+            serde_json::json!({"type": "string", "enum": ["active", "inactive", "pending"]}),
+        );
+        let new = OpenApiSpec::new("Test API", "1.0.0").schema(
+            "Status",
+            serde_json::json!({"type": "string", "enum": ["active", "inactive"]}),
+        );
+
+        let report = diff(&old, &new);
+
+        assert!(report.has_breaking_changes());
+        assert!(report
+            .breaking_changes()
+            .any(|c| c.description.contains("was narrowed")));
+    }
+
+    #[test]
+    fn widened_enum_is_non_breaking() {
+        let old = OpenApiSpec::new("Test API", "1.0.0").schema(
+            "Status",
+            serde_json::json!({"type": "string", "enum": ["active", "inactive"]}),
+        );
+        let new = OpenApiSpec::new("Test API", "1.0.0").schema(
+            "Status",
+            serde_json::json!({"type": "string", "enum": ["active", "inactive", "pending"]}),
+        );
+
+        let report = diff(&old, &new);
+
+        assert!(!report.has_breaking_changes());
+    }
+}
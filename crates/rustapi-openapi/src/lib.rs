@@ -13,6 +13,8 @@
 //! - **API versioning** with multiple strategies (path, header, query, accept)
 //! - **JSON Schema 2020-12** support for OpenAPI 3.1
 //! - **Webhook definitions** support
+//! - **Spec diffing** to catch breaking changes between releases (see [`diff`])
+//! - **TypeScript client generation** behind the `codegen` feature (see [`codegen`])
 //!
 //! # OpenAPI 3.1 Usage
 //!
@@ -59,7 +61,14 @@
 //!     .await
 //! ```
 
+#[cfg(feature = "codegen")]
+pub mod codegen;
 mod config;
+pub mod diff;
+#[cfg(feature = "swagger-ui")]
+mod redoc;
+#[cfg(feature = "swagger-ui")]
+mod scalar;
 mod schemas;
 mod spec;
 #[cfg(feature = "swagger-ui")]
@@ -77,8 +86,9 @@ pub use schemas::{
     ValidationErrorSchema,
 };
 pub use spec::{
-    ApiInfo, MediaType, OpenApiSpec, Operation, OperationModifier, Parameter, PathItem,
-    RequestBody, ResponseModifier, ResponseSpec, SchemaRef,
+    ApiInfo, ExternalDocs, MediaType, MessageSchema, OpenApiSpec, Operation, OperationModifier,
+    Parameter, PathItem, RequestBody, ResponseModifier, ResponseSpec, SchemaRef, SpecFormat,
+    TagInfo, Undocumented,
 };
 
 // Re-export utoipa's ToSchema derive macro as Schema
@@ -110,10 +120,86 @@ pub fn openapi_json(spec: &OpenApiSpec) -> Response<Full<Bytes>> {
     }
 }
 
-/// Generate Swagger UI HTML response
+#[cfg(feature = "swagger-ui")]
+pub use swagger::SwaggerAssets;
+
+/// Generate Swagger UI HTML response using the embedded (offline) assets.
 #[cfg(feature = "swagger-ui")]
 pub fn swagger_ui_html(openapi_url: &str) -> Response<Full<Bytes>> {
-    let html = swagger::generate_swagger_html(openapi_url);
+    swagger_ui_html_with_assets(openapi_url, SwaggerAssets::Embedded)
+}
+
+/// Generate Swagger UI HTML response, choosing between embedded and CDN-hosted assets.
+#[cfg(feature = "swagger-ui")]
+pub fn swagger_ui_html_with_assets(
+    openapi_url: &str,
+    assets: SwaggerAssets,
+) -> Response<Full<Bytes>> {
+    let html = swagger::generate_swagger_html_with_assets(openapi_url, assets);
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+        .body(Full::new(Bytes::from(html)))
+        .unwrap()
+}
+
+/// A pre-rendered Swagger UI page plus a strong ETag derived from its body.
+///
+/// With [`SwaggerAssets::Embedded`], the rendered HTML inlines the whole
+/// Swagger UI bundle (CSS and JS) and can run into the hundreds of KB.
+/// [`swagger_ui_html`] and [`swagger_ui_html_with_assets`] re-render that
+/// string on every call, which is fine for a one-off response but wasteful
+/// for a server that wants to hand the same bytes back on every request.
+/// `SwaggerUiAsset` renders once so the caller can cache the result and
+/// serve conditional (`If-None-Match`) and compressed responses from it
+/// instead.
+#[cfg(feature = "swagger-ui")]
+pub struct SwaggerUiAsset {
+    /// The rendered HTML body.
+    pub html: Bytes,
+    /// A strong ETag (RFC 7232) derived from `html`, so it only changes when
+    /// the rendered page does.
+    pub etag: String,
+}
+
+#[cfg(feature = "swagger-ui")]
+impl SwaggerUiAsset {
+    /// Render `assets` for `openapi_url` and compute its ETag.
+    pub fn new(openapi_url: &str, assets: SwaggerAssets) -> Self {
+        let html = Bytes::from(swagger::generate_swagger_html_with_assets(
+            openapi_url,
+            assets,
+        ));
+        let etag = strong_etag(&html);
+        Self { html, etag }
+    }
+}
+
+#[cfg(feature = "swagger-ui")]
+fn strong_etag(bytes: &Bytes) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    format!("\"{:x}\"", hasher.finish())
+}
+
+/// Generate ReDoc HTML response
+#[cfg(feature = "swagger-ui")]
+pub fn redoc_html(openapi_url: &str) -> Response<Full<Bytes>> {
+    let html = redoc::generate_redoc_html(openapi_url);
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+        .body(Full::new(Bytes::from(html)))
+        .unwrap()
+}
+
+/// Generate Scalar HTML response
+#[cfg(feature = "swagger-ui")]
+pub fn scalar_html(openapi_url: &str) -> Response<Full<Bytes>> {
+    let html = scalar::generate_scalar_html(openapi_url);
     Response::builder()
         .status(StatusCode::OK)
         .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
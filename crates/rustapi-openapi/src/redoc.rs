@@ -0,0 +1,31 @@
+//! ReDoc UI HTML generation
+
+/// Generate ReDoc HTML page
+pub fn generate_redoc_html(openapi_url: &str) -> String {
+    let mut html = String::with_capacity(1024);
+    html.push_str(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>API Documentation - RustAPI</title>
+    <style>
+        body {
+            margin: 0;
+            padding: 0;
+        }
+    </style>
+</head>
+<body>
+    <redoc spec-url=""#,
+    );
+    html.push_str(openapi_url);
+    html.push_str(
+        r#""></redoc>
+    <script src="https://cdn.jsdelivr.net/npm/redoc@2/bundles/redoc.standalone.js"></script>
+</body>
+</html>"#,
+    );
+    html
+}
@@ -0,0 +1,25 @@
+//! Scalar UI HTML generation
+
+/// Generate Scalar HTML page
+pub fn generate_scalar_html(openapi_url: &str) -> String {
+    let mut html = String::with_capacity(1024);
+    html.push_str(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>API Documentation - RustAPI</title>
+</head>
+<body>
+    <script id="api-reference" data-url=""#,
+    );
+    html.push_str(openapi_url);
+    html.push_str(
+        r#""></script>
+    <script src="https://cdn.jsdelivr.net/npm/@scalar/api-reference"></script>
+</body>
+</html>"#,
+    );
+    html
+}
@@ -18,6 +18,49 @@ pub struct OpenApiSpec {
     pub info: ApiInfo,
     pub paths: HashMap<String, PathItem>,
     pub schemas: HashMap<String, serde_json::Value>,
+    pub security_schemes: HashMap<String, serde_json::Value>,
+    pub global_security: Vec<HashMap<String, Vec<String>>>,
+    /// Top-level `tags` document metadata, in the order they should appear in
+    /// the spec. Swagger UI groups operations by tag in this order, so this
+    /// is also how tag ordering is controlled (see [`OpenApiConfig::tag`](crate::OpenApiConfig::tag)).
+    pub tags: Vec<TagInfo>,
+}
+
+/// Metadata for a top-level OpenAPI `tags` entry: a human-readable description
+/// and an optional link to further documentation, shown by Swagger UI next to
+/// the tag's operations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagInfo {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "externalDocs")]
+    pub external_docs: Option<ExternalDocs>,
+}
+
+/// A link to further documentation, as used by [`TagInfo`] and (per the
+/// OpenAPI spec) operations and the document root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalDocs {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl ExternalDocs {
+    /// Create an external docs link pointing at `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            description: None,
+        }
+    }
+
+    /// Set the description shown alongside the link.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
 }
 
 /// Path item in OpenAPI spec
@@ -50,6 +93,23 @@ pub struct Operation {
     #[serde(rename = "requestBody")]
     pub request_body: Option<RequestBody>,
     pub responses: HashMap<String, ResponseSpec>,
+    /// Vendor extension fields (e.g. `x-websocket`), flattened into the
+    /// operation object as OpenAPI's extension mechanism requires. Keys
+    /// should start with `x-`; see [`Operation::extension`].
+    #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: HashMap<String, serde_json::Value>,
+    /// Documentation group this operation belongs to (e.g. "public", "internal").
+    ///
+    /// Not part of the OpenAPI document itself - used by [`OpenApiSpec::filter_by_group`]
+    /// to build separate specs per audience. `None` means the operation is included
+    /// in every group.
+    #[serde(skip)]
+    pub doc_group: Option<String>,
+    /// When `true`, this operation is excluded from the generated OpenAPI
+    /// document entirely (e.g. health checks, metrics, debug endpoints),
+    /// while the route itself keeps serving requests normally.
+    #[serde(skip)]
+    pub hidden: bool,
 }
 
 /// Parameter in OpenAPI spec
@@ -96,6 +156,82 @@ pub enum SchemaRef {
     Inline(serde_json::Value),
 }
 
+/// Placeholder for a real-time (WebSocket/SSE) message type that hasn't been
+/// given its own `#[derive(Schema)]` type, so no schema can be documented for
+/// it. This is the default for types like `WebSocketUpgrade` and `Sse` so
+/// that documenting message schemas stays opt-in.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Undocumented;
+
+/// Resolve a real-time message type to a schema reference for documentation,
+/// or `None` if it opts out (see [`Undocumented`]).
+///
+/// Mirrors how `Json<T>`'s [`OperationModifier`] impl references `T`'s
+/// schema by name rather than inlining it: the schema itself is expected to
+/// be registered separately via [`OpenApiSpec::register`].
+pub trait MessageSchema {
+    /// The schema reference for this type, or `None` if undocumented.
+    fn schema_ref() -> Option<SchemaRef> {
+        None
+    }
+}
+
+impl MessageSchema for Undocumented {}
+
+impl<T: for<'a> utoipa::ToSchema<'a>> MessageSchema for T {
+    fn schema_ref() -> Option<SchemaRef> {
+        let (name, _) = T::schema();
+        Some(SchemaRef::Ref {
+            reference: format!("#/components/schemas/{}", name),
+        })
+    }
+}
+
+/// Derive a collision-resistant OpenAPI component name for `T`, given the
+/// base name utoipa's `ToSchema::schema()` produced for it (which respects a
+/// `#[schema(as = "...")]` override, if any, but is otherwise just the type's
+/// own name - the same for every instantiation of a generic type).
+///
+/// For non-generic types this returns `base` unchanged. For generics, it
+/// appends the concrete type arguments (read off `std::any::type_name`,
+/// which - unlike `ToSchema::schema()` - does vary per instantiation),
+/// e.g. `Paginated<User>` becomes `Paginated_User` instead of colliding with
+/// `Paginated<Order>` under the bare name `Paginated`.
+fn generic_schema_name<T: ?Sized>(base: &'static str) -> String {
+    let type_name = std::any::type_name::<T>();
+    let Some(args_start) = type_name.find('<') else {
+        return base.to_string();
+    };
+
+    fn last_segment(path: &str) -> &str {
+        path.trim().rsplit("::").next().unwrap_or(path).trim()
+    }
+
+    let mut suffix = String::new();
+    let mut current = String::new();
+    for ch in type_name[args_start..].chars() {
+        match ch {
+            '<' | ',' | '>' => {
+                let segment = last_segment(&current);
+                if !segment.is_empty() {
+                    if !suffix.is_empty() {
+                        suffix.push('_');
+                    }
+                    suffix.push_str(segment);
+                }
+                current.clear();
+            }
+            _ => current.push(ch),
+        }
+    }
+
+    if suffix.is_empty() {
+        base.to_string()
+    } else {
+        format!("{base}_{suffix}")
+    }
+}
+
 impl OpenApiSpec {
     /// Create a new OpenAPI specification
     pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
@@ -107,6 +243,9 @@ impl OpenApiSpec {
             },
             paths: HashMap::new(),
             schemas: HashMap::new(),
+            security_schemes: HashMap::new(),
+            global_security: Vec::new(),
+            tags: Vec::new(),
         }
     }
 
@@ -118,6 +257,9 @@ impl OpenApiSpec {
 
     /// Add a path operation
     pub fn path(mut self, path: &str, method: &str, operation: Operation) -> Self {
+        if operation.hidden {
+            return self;
+        }
         let item = self.paths.entry(path.to_string()).or_default();
         match method.to_uppercase().as_str() {
             "GET" => item.get = Some(operation),
@@ -136,26 +278,182 @@ impl OpenApiSpec {
         self
     }
 
-    /// Register a type that implements Schema (utoipa::ToSchema)
+    /// Register a type that implements Schema (utoipa::ToSchema).
+    ///
+    /// Generic wrapper types (e.g. `Paginated<User>`) are named
+    /// `<Base>_<Args>` (e.g. `Paginated_User`) so that different
+    /// instantiations don't collide under utoipa's generic-agnostic
+    /// `ToSchema::schema()` name - see [`generic_schema_name`]. Use
+    /// [`OpenApiSpec::register_as`] to override the name entirely.
     pub fn register<T: for<'a> utoipa::ToSchema<'a>>(mut self) -> Self {
         let (name, schema) = T::schema();
+        let name = generic_schema_name::<T>(name);
         if let Ok(json_schema) = serde_json::to_value(schema) {
-            self.schemas.insert(name.to_string(), json_schema);
+            self.schemas.insert(name, json_schema);
         }
         self
     }
 
+    /// Register a type's schema under an explicit component name, bypassing
+    /// the automatic (and, for generics, synthesized) name from
+    /// [`OpenApiSpec::register`].
+    ///
+    /// ```rust,ignore
+    /// spec.register_as::<Paginated<User>>("PaginatedUsers")
+    /// ```
+    pub fn register_as<T: for<'a> utoipa::ToSchema<'a>>(mut self, name: impl Into<String>) -> Self {
+        let (_, schema) = T::schema();
+        if let Ok(json_schema) = serde_json::to_value(schema) {
+            self.schemas.insert(name.into(), json_schema);
+        }
+        self
+    }
+
+    /// Register an HTTP bearer (JWT) security scheme named `bearerAuth` and make it
+    /// a global default, so every operation requires it unless overridden.
+    pub fn bearer_auth(self) -> Self {
+        self.bearer_auth_named("bearerAuth")
+    }
+
+    /// Register an HTTP bearer (JWT) security scheme under a custom name.
+    pub fn bearer_auth_named(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.security_schemes.insert(
+            name.clone(),
+            serde_json::json!({
+                "type": "http",
+                "scheme": "bearer",
+                "bearerFormat": "JWT",
+            }),
+        );
+        self.global_security.push(HashMap::from([(name, vec![])]));
+        self
+    }
+
+    /// Register an API key security scheme read from a request header, named
+    /// `apiKeyAuth`, and make it a global default.
+    pub fn api_key_header(self, header_name: impl Into<String>) -> Self {
+        self.api_key_header_named("apiKeyAuth", header_name)
+    }
+
+    /// Register an API key security scheme read from a request header under a
+    /// custom scheme name.
+    pub fn api_key_header_named(
+        mut self,
+        scheme_name: impl Into<String>,
+        header_name: impl Into<String>,
+    ) -> Self {
+        let scheme_name = scheme_name.into();
+        self.security_schemes.insert(
+            scheme_name.clone(),
+            serde_json::json!({
+                "type": "apiKey",
+                "in": "header",
+                "name": header_name.into(),
+            }),
+        );
+        self.global_security
+            .push(HashMap::from([(scheme_name, vec![])]));
+        self
+    }
+
+    /// Register an OAuth2 security scheme with the given flows (as raw OpenAPI
+    /// `flows` JSON, e.g. `authorizationCode`, `clientCredentials`) and make it a
+    /// global default requiring `scopes`.
+    pub fn oauth2_flows(
+        mut self,
+        name: impl Into<String>,
+        flows: serde_json::Value,
+        scopes: Vec<String>,
+    ) -> Self {
+        let name = name.into();
+        self.security_schemes.insert(
+            name.clone(),
+            serde_json::json!({
+                "type": "oauth2",
+                "flows": flows,
+            }),
+        );
+        self.global_security.push(HashMap::from([(name, scopes)]));
+        self
+    }
+
+    /// Merge in security schemes, global security requirements, and tag
+    /// metadata from an [`OpenApiConfig`](crate::OpenApiConfig).
+    pub fn apply_security_config(mut self, config: &crate::OpenApiConfig) -> Self {
+        self.security_schemes
+            .extend(config.security_schemes.clone());
+        self.global_security.extend(config.global_security.clone());
+        self.tags.extend(config.tags.clone());
+        self
+    }
+
     /// Register a type into this spec in-place.
     ///
     /// This is useful for zero-config registration paths where the spec is stored
     /// by value in another struct (e.g., the application builder).
     pub fn register_in_place<T: for<'a> utoipa::ToSchema<'a>>(&mut self) {
         let (name, schema) = T::schema();
+        let name = generic_schema_name::<T>(name);
         if let Ok(json_schema) = serde_json::to_value(schema) {
-            self.schemas.insert(name.to_string(), json_schema);
+            self.schemas.insert(name, json_schema);
         }
     }
 
+    /// Build a copy of this spec containing only operations that belong to `group`
+    /// (via [`Operation::doc_group`]) or have no group assigned.
+    ///
+    /// Useful for serving a "public" spec alongside a more detailed "internal" one
+    /// from the same route table, e.g.:
+    ///
+    /// ```rust,ignore
+    /// spec.path("/admin/users", "GET", op.doc_group("internal"));
+    /// let public_spec = spec.filter_by_group("public");
+    /// ```
+    pub fn filter_by_group(&self, group: &str) -> Self {
+        let mut filtered = self.clone();
+        filtered.paths.clear();
+
+        for (path, item) in &self.paths {
+            let keep = |op: &Option<Operation>| {
+                op.as_ref()
+                    .map(|o| match o.doc_group.as_deref() {
+                        Some(g) => g == group,
+                        None => true,
+                    })
+                    .unwrap_or(true)
+            };
+
+            let mut new_item = PathItem::default();
+            if keep(&item.get) {
+                new_item.get = item.get.clone();
+            }
+            if keep(&item.post) {
+                new_item.post = item.post.clone();
+            }
+            if keep(&item.put) {
+                new_item.put = item.put.clone();
+            }
+            if keep(&item.patch) {
+                new_item.patch = item.patch.clone();
+            }
+            if keep(&item.delete) {
+                new_item.delete = item.delete.clone();
+            }
+
+            if new_item.get.is_some()
+                || new_item.post.is_some()
+                || new_item.put.is_some()
+                || new_item.patch.is_some()
+                || new_item.delete.is_some()
+            {
+                filtered.paths.insert(path.clone(), new_item);
+            }
+        }
+
+        filtered
+    }
+
     /// Convert to JSON value
     pub fn to_json(&self) -> serde_json::Value {
         let mut spec = serde_json::json!({
@@ -164,14 +462,62 @@ impl OpenApiSpec {
             "paths": self.paths,
         });
 
-        if !self.schemas.is_empty() {
-            spec["components"] = serde_json::json!({
-                "schemas": self.schemas
-            });
+        if !self.schemas.is_empty() || !self.security_schemes.is_empty() {
+            let mut components = serde_json::Map::new();
+            if !self.schemas.is_empty() {
+                components.insert(
+                    "schemas".to_string(),
+                    serde_json::to_value(&self.schemas).unwrap_or_default(),
+                );
+            }
+            if !self.security_schemes.is_empty() {
+                components.insert(
+                    "securitySchemes".to_string(),
+                    serde_json::to_value(&self.security_schemes).unwrap_or_default(),
+                );
+            }
+            spec["components"] = serde_json::Value::Object(components);
+        }
+
+        if !self.global_security.is_empty() {
+            spec["security"] = serde_json::to_value(&self.global_security).unwrap_or_default();
+        }
+
+        if !self.tags.is_empty() {
+            spec["tags"] = serde_json::to_value(&self.tags).unwrap_or_default();
         }
 
         spec
     }
+
+    /// Convert to a YAML document
+    pub fn to_yaml(&self) -> String {
+        serde_yaml::to_string(&self.to_json()).unwrap_or_default()
+    }
+
+    /// Serialize the spec and write it to disk, in the given [`SpecFormat`].
+    ///
+    /// Handy for CI jobs that want to commit or diff the generated spec.
+    pub fn write_to_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        format: SpecFormat,
+    ) -> std::io::Result<()> {
+        let content = match format {
+            SpecFormat::Json => {
+                serde_json::to_string_pretty(&self.to_json()).unwrap_or_default()
+            }
+            SpecFormat::Yaml => self.to_yaml(),
+        };
+        std::fs::write(path, content)
+    }
+}
+
+/// On-disk format for an exported OpenAPI spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecFormat {
+    Json,
+    Yaml,
 }
 
 impl Operation {
@@ -190,9 +536,21 @@ impl Operation {
                     content: None,
                 },
             )]),
+            extensions: HashMap::new(),
+            doc_group: None,
+            hidden: false,
         }
     }
 
+    /// Add a vendor extension field. `name` must start with `x-` to be a
+    /// valid OpenAPI extension; this isn't enforced, since some non-standard
+    /// tooling (e.g. AsyncAPI companion generators) reads these fields as
+    /// plain data.
+    pub fn extension(mut self, name: impl Into<String>, value: serde_json::Value) -> Self {
+        self.extensions.insert(name.into(), value);
+        self
+    }
+
     /// Set summary
     pub fn summary(mut self, summary: impl Into<String>) -> Self {
         self.summary = Some(summary.into());
@@ -205,6 +563,18 @@ impl Operation {
         self
     }
 
+    /// Assign this operation to a documentation group (see [`OpenApiSpec::filter_by_group`])
+    pub fn doc_group(mut self, group: impl Into<String>) -> Self {
+        self.doc_group = Some(group.into());
+        self
+    }
+
+    /// Exclude this operation from the generated OpenAPI document (see [`OpenApiSpec::path`])
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
     /// Add tags
     pub fn tags(mut self, tags: Vec<String>) -> Self {
         self.tags = Some(tags);
@@ -1,8 +1,27 @@
 //! Swagger UI HTML generation
 
-/// Generate Swagger UI HTML page
-pub fn generate_swagger_html(openapi_url: &str) -> String {
-    let mut html = String::with_capacity(2000000); // Pre-allocate ~2MB for assets
+/// Where the Swagger UI's JS/CSS assets are loaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SwaggerAssets {
+    /// Bundle the assets into the binary and inline them into the page.
+    ///
+    /// Works fully offline, which is what `/docs` needs in air-gapped
+    /// deployments. This is the default, matching RustAPI's existing behavior.
+    #[default]
+    Embedded,
+    /// Load the assets from the jsDelivr CDN instead of embedding them.
+    ///
+    /// Produces a much smaller binary at the cost of requiring outbound
+    /// network access when `/docs` is opened.
+    Cdn,
+}
+
+/// Generate Swagger UI HTML page, choosing between embedded and CDN-hosted assets.
+pub fn generate_swagger_html_with_assets(openapi_url: &str, assets: SwaggerAssets) -> String {
+    let mut html = String::with_capacity(match assets {
+        SwaggerAssets::Embedded => 2_000_000, // Pre-allocate ~2MB for assets
+        SwaggerAssets::Cdn => 2_000,
+    });
     html.push_str(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -10,12 +29,25 @@ pub fn generate_swagger_html(openapi_url: &str) -> String {
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
     <title>API Documentation - RustAPI</title>
-    <style>
 "#,
     );
-    html.push_str(include_str!("assets/swagger-ui.css"));
+
+    match assets {
+        SwaggerAssets::Embedded => {
+            html.push_str("    <style>\n");
+            html.push_str(include_str!("assets/swagger-ui.css"));
+            html.push_str("\n    </style>\n");
+        }
+        SwaggerAssets::Cdn => {
+            html.push_str(
+                r#"    <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui.css">
+"#,
+            );
+        }
+    }
+
     html.push_str(
-        r#"
+        r#"    <style>
         body {
             margin: 0;
             padding: 0;
@@ -30,21 +62,28 @@ pub fn generate_swagger_html(openapi_url: &str) -> String {
 </head>
 <body>
     <div id="swagger-ui"></div>
-    <script>
 "#,
     );
-    html.push_str(include_str!("assets/swagger-ui-bundle.js"));
-    html.push_str(
-        r#"
-    </script>
-    <script>
+
+    match assets {
+        SwaggerAssets::Embedded => {
+            html.push_str("    <script>\n");
+            html.push_str(include_str!("assets/swagger-ui-bundle.js"));
+            html.push_str("\n    </script>\n    <script>\n");
+            html.push_str(include_str!("assets/swagger-ui-standalone-preset.js"));
+            html.push_str("\n    </script>\n");
+        }
+        SwaggerAssets::Cdn => {
+            html.push_str(
+                r#"    <script src="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+    <script src="https://cdn.jsdelivr.net/npm/swagger-ui-dist@5/swagger-ui-standalone-preset.js"></script>
 "#,
-    );
-    html.push_str(include_str!("assets/swagger-ui-standalone-preset.js"));
+            );
+        }
+    }
+
     html.push_str(
-        r#"
-    </script>
-    <script>
+        r#"    <script>
         window.onload = function() {
             SwaggerUIBundle({
                 url: ""#,
@@ -0,0 +1,63 @@
+//! Standard JSON-RPC 2.0 error codes and the [`RpcError`] object.
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// A JSON-RPC 2.0 error object, returned in place of `result` on failure.
+///
+/// The reserved code ranges follow the
+/// [JSON-RPC 2.0 spec](https://www.jsonrpc.org/specification#error_object):
+/// `-32700..=-32600` and `-32603..=-32000` are reserved for the protocol
+/// itself, and application errors should use codes outside `-32768..=-32000`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RpcError {
+    /// Error code.
+    pub code: i64,
+    /// Short, human-readable error message.
+    pub message: String,
+    /// Optional additional error information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl RpcError {
+    /// Create an error with an application-defined `code` and `message`.
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    /// Attach structured `data` to this error.
+    pub fn with_data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Invalid JSON was received by the server (code `-32700`).
+    pub fn parse_error() -> Self {
+        Self::new(-32700, "Parse error")
+    }
+
+    /// The JSON sent is not a valid Request object (code `-32600`).
+    pub fn invalid_request() -> Self {
+        Self::new(-32600, "Invalid Request")
+    }
+
+    /// The requested method does not exist (code `-32601`).
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(-32601, "Method not found").with_data(serde_json::json!({ "method": method }))
+    }
+
+    /// Invalid method parameter(s) (code `-32602`).
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(-32602, message)
+    }
+
+    /// Internal JSON-RPC error (code `-32603`).
+    pub fn internal_error(message: impl Into<String>) -> Self {
+        Self::new(-32603, message)
+    }
+}
@@ -0,0 +1,22 @@
+//! HTTP handler for mounting an [`RpcModule`] at a single endpoint.
+
+use crate::RpcModule;
+use rustapi_core::{Body, IntoResponse, Json, NoContent, Response, State};
+use std::sync::Arc;
+
+/// `POST` handler that dispatches JSON-RPC requests (single or batch) to an
+/// [`RpcModule`] held in app state.
+///
+/// ```rust,ignore
+/// RustApi::new()
+///     .state(Arc::new(rpc_module))
+///     .route("/rpc", post(rustapi_rpc::rpc_handler))
+///     .run("127.0.0.1:3000")
+///     .await
+/// ```
+pub async fn rpc_handler(State(module): State<Arc<RpcModule>>, Body(body): Body) -> Response {
+    match module.handle_raw(&body).await {
+        Some(response) => Json(response).into_response(),
+        None => NoContent.into_response(),
+    }
+}
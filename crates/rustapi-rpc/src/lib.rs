@@ -0,0 +1,63 @@
+//! # rustapi-rpc
+//!
+//! JSON-RPC 2.0 integration for the RustAPI framework.
+//!
+//! Register typed methods on an [`RpcModule`], mount it at a single `POST`
+//! endpoint with [`rpc_handler`], and optionally publish an
+//! [OpenRPC](https://spec.open-rpc.org/) document describing them with
+//! [`openrpc_document`].
+//!
+//! ## Quick Start
+//!
+//! ```rust,ignore
+//! use rustapi_rs::prelude::*;
+//! use rustapi_rpc::{rpc_handler, RpcError, RpcModule};
+//! use serde::{Deserialize, Serialize};
+//! use std::sync::Arc;
+//! use utoipa::ToSchema;
+//!
+//! #[derive(Deserialize, ToSchema)]
+//! struct SumParams {
+//!     a: i64,
+//!     b: i64,
+//! }
+//!
+//! #[derive(Serialize, ToSchema)]
+//! struct SumResult {
+//!     total: i64,
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//!     let rpc = RpcModule::new().method("sum", |params: SumParams| async move {
+//!         Ok::<_, RpcError>(SumResult { total: params.a + params.b })
+//!     });
+//!
+//!     RustApi::new()
+//!         .state(Arc::new(rpc))
+//!         .route("/rpc", post(rpc_handler))
+//!         .run("127.0.0.1:8080")
+//!         .await
+//! }
+//! ```
+//!
+//! ## Batch requests and notifications
+//!
+//! [`RpcModule::handle_raw`] accepts either a single request object or a batch
+//! array, per the JSON-RPC 2.0 spec. Requests without an `id` are treated as
+//! notifications: they're still dispatched, but no response is included for
+//! them (a batch of only notifications, or a single notification, yields no
+//! response body at all).
+
+#![warn(missing_docs)]
+#![warn(rustdoc::missing_crate_level_docs)]
+
+mod error;
+mod handler;
+mod module;
+mod openrpc;
+
+pub use error::RpcError;
+pub use handler::rpc_handler;
+pub use module::RpcModule;
+pub use openrpc::{openrpc_document, OpenRpcInfo};
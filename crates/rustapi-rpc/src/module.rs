@@ -0,0 +1,291 @@
+//! Typed JSON-RPC method registration and dispatch.
+
+use crate::error::RpcError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use utoipa::openapi::{RefOr, Schema};
+use utoipa::ToSchema;
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+type BoxedHandler = Arc<dyn Fn(Value) -> BoxFuture<'static, Result<Value, RpcError>> + Send + Sync>;
+
+pub(crate) struct MethodEntry {
+    pub(crate) handler: BoxedHandler,
+    pub(crate) params_name: &'static str,
+    pub(crate) params_schema: RefOr<Schema>,
+    pub(crate) result_name: &'static str,
+    pub(crate) result_schema: RefOr<Schema>,
+}
+
+/// A registry of typed JSON-RPC methods, dispatched by [`RpcModule::handle_raw`].
+///
+/// Build one with [`RpcModule::new`] and [`RpcModule::method`], then mount it
+/// behind a single `POST` route with [`crate::rpc_handler`]:
+///
+/// ```rust
+/// use rustapi_rpc::{RpcError, RpcModule};
+/// use serde::{Deserialize, Serialize};
+/// use utoipa::ToSchema;
+///
+/// #[derive(Deserialize, ToSchema)]
+/// struct SumParams {
+///     a: i64,
+///     b: i64,
+/// }
+///
+/// #[derive(Serialize, ToSchema)]
+/// struct SumResult {
+///     total: i64,
+/// }
+///
+/// # async fn build() -> RpcModule {
+/// RpcModule::new().method("sum", |params: SumParams| async move {
+///     Ok::<_, RpcError>(SumResult { total: params.a + params.b })
+/// })
+/// # }
+/// ```
+#[derive(Default)]
+pub struct RpcModule {
+    pub(crate) methods: HashMap<String, MethodEntry>,
+}
+
+impl RpcModule {
+    /// Create an empty module with no registered methods.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a typed method under `name`.
+    ///
+    /// `handler` receives the deserialized params and returns a result (or an
+    /// [`RpcError`], typically built with one of its constructors). Params and
+    /// result types must implement [`ToSchema`] so the method can be described
+    /// in the module's [OpenRPC document](crate::openrpc_document).
+    pub fn method<P, R, F, Fut>(mut self, name: impl Into<String>, handler: F) -> Self
+    where
+        P: DeserializeOwned + for<'s> ToSchema<'s> + Send + 'static,
+        R: Serialize + for<'s> ToSchema<'s> + Send + 'static,
+        F: Fn(P) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, RpcError>> + Send + 'static,
+    {
+        let (params_name, params_schema) = P::schema();
+        let (result_name, result_schema) = R::schema();
+        let handler = Arc::new(handler);
+
+        let boxed: BoxedHandler = Arc::new(move |value: Value| -> BoxFuture<'static, Result<Value, RpcError>> {
+            let handler = handler.clone();
+            Box::pin(async move {
+                let params: P = serde_json::from_value(value)
+                    .map_err(|err| RpcError::invalid_params(err.to_string()))?;
+                let result = handler(params).await?;
+                serde_json::to_value(result)
+                    .map_err(|err| RpcError::internal_error(err.to_string()))
+            })
+        });
+
+        self.methods.insert(
+            name.into(),
+            MethodEntry {
+                handler: boxed,
+                params_name,
+                params_schema,
+                result_name,
+                result_schema,
+            },
+        );
+        self
+    }
+
+    /// Handle a raw JSON-RPC request or batch and return the raw JSON response,
+    /// or `None` if nothing should be sent back (a notification, or a batch
+    /// made up entirely of notifications).
+    pub async fn handle_raw(&self, body: &[u8]) -> Option<Value> {
+        let value: Value = match serde_json::from_slice(body) {
+            Ok(value) => value,
+            Err(_) => return Some(error_response(Value::Null, RpcError::parse_error())),
+        };
+
+        match value {
+            Value::Array(items) => {
+                if items.is_empty() {
+                    return Some(error_response(Value::Null, RpcError::invalid_request()));
+                }
+
+                let mut responses = Vec::with_capacity(items.len());
+                for item in items {
+                    if let Some(response) = self.handle_single(item).await {
+                        responses.push(response);
+                    }
+                }
+
+                if responses.is_empty() {
+                    None
+                } else {
+                    Some(Value::Array(responses))
+                }
+            }
+            single => self.handle_single(single).await,
+        }
+    }
+
+    async fn handle_single(&self, value: Value) -> Option<Value> {
+        let request: RpcRequest = match serde_json::from_value(value) {
+            Ok(request) => request,
+            Err(_) => return Some(error_response(Value::Null, RpcError::invalid_request())),
+        };
+
+        if request.jsonrpc.as_deref() != Some("2.0") {
+            return Some(error_response(
+                request.id.unwrap_or(Value::Null),
+                RpcError::invalid_request(),
+            ));
+        }
+
+        let is_notification = request.id.is_none();
+        let id = request.id.unwrap_or(Value::Null);
+
+        let result = match self.methods.get(&request.method) {
+            Some(entry) => (entry.handler)(request.params).await,
+            None => Err(RpcError::method_not_found(&request.method)),
+        };
+
+        if is_notification {
+            return None;
+        }
+
+        Some(match result {
+            Ok(value) => success_response(id, value),
+            Err(err) => error_response(id, err),
+        })
+    }
+
+    pub(crate) fn methods(&self) -> impl Iterator<Item = (&str, &MethodEntry)> {
+        self.methods.iter().map(|(name, entry)| (name.as_str(), entry))
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct RpcRequest {
+    jsonrpc: Option<String>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+    #[serde(default)]
+    id: Option<Value>,
+}
+
+fn success_response(id: Value, result: Value) -> Value {
+    serde_json::json!({ "jsonrpc": "2.0", "result": result, "id": id })
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    serde_json::json!({ "jsonrpc": "2.0", "error": error, "id": id })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, ToSchema)]
+    struct SumParams {
+        a: i64,
+        b: i64,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    struct SumResult {
+        total: i64,
+    }
+
+    fn module() -> RpcModule {
+        RpcModule::new().method("sum", |params: SumParams| async move {
+            Ok::<_, RpcError>(SumResult {
+                total: params.a + params.b,
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn dispatches_single_request() {
+        let response = module()
+            .handle_raw(br#"{"jsonrpc":"2.0","method":"sum","params":{"a":1,"b":2},"id":1}"#)
+            .await
+            .unwrap();
+
+        assert_eq!(response["result"]["total"], 3);
+        assert_eq!(response["id"], 1);
+    }
+
+    #[tokio::test]
+    async fn notification_produces_no_response() {
+        let response = module()
+            .handle_raw(br#"{"jsonrpc":"2.0","method":"sum","params":{"a":1,"b":2}}"#)
+            .await;
+
+        assert!(response.is_none());
+    }
+
+    #[tokio::test]
+    async fn unknown_method_returns_method_not_found() {
+        let response = module()
+            .handle_raw(br#"{"jsonrpc":"2.0","method":"missing","id":1}"#)
+            .await
+            .unwrap();
+
+        assert_eq!(response["error"]["code"], -32601);
+    }
+
+    #[tokio::test]
+    async fn invalid_params_returns_invalid_params_error() {
+        let response = module()
+            .handle_raw(br#"{"jsonrpc":"2.0","method":"sum","params":{"a":"nope"},"id":1}"#)
+            .await
+            .unwrap();
+
+        assert_eq!(response["error"]["code"], -32602);
+    }
+
+    #[tokio::test]
+    async fn malformed_json_returns_parse_error() {
+        let response = module().handle_raw(b"not json").await.unwrap();
+        assert_eq!(response["error"]["code"], -32700);
+    }
+
+    #[tokio::test]
+    async fn batch_returns_responses_for_requests_only() {
+        let response = module()
+            .handle_raw(
+                br#"[
+                    {"jsonrpc":"2.0","method":"sum","params":{"a":1,"b":2},"id":1},
+                    {"jsonrpc":"2.0","method":"sum","params":{"a":3,"b":4}}
+                ]"#,
+            )
+            .await
+            .unwrap();
+
+        let responses = response.as_array().unwrap();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0]["result"]["total"], 3);
+    }
+
+    #[tokio::test]
+    async fn all_notification_batch_produces_no_response() {
+        let response = module()
+            .handle_raw(br#"[{"jsonrpc":"2.0","method":"sum","params":{"a":1,"b":2}}]"#)
+            .await;
+
+        assert!(response.is_none());
+    }
+
+    #[tokio::test]
+    async fn empty_batch_is_invalid_request() {
+        let response = module().handle_raw(b"[]").await.unwrap();
+        assert_eq!(response["error"]["code"], -32600);
+    }
+}
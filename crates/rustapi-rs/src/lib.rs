@@ -49,6 +49,10 @@
 //! - `config` - Configuration management with `.env` file support
 //! - `cookies` - Cookie parsing extractor
 //! - `sqlx` - SQLx database error conversion to ApiError
+//! - `grpc` - gRPC integration (tonic-based, runs alongside the HTTP server)
+//! - `rpc` - JSON-RPC 2.0 integration with OpenRPC document generation
+//! - `toon-tiktoken` - Real BPE token counting for TOON's `LlmResponse` headers (requires `toon`)
+//! - `llm-guard` - LLM guardrail middleware (prompt-injection / secret / PII scanning)
 //! - `extras` - Meta feature enabling jwt, cors, and rate-limit
 //! - `full` - All optional features enabled
 //!
@@ -119,6 +123,9 @@ pub use rustapi_extras::cache;
 #[cfg(feature = "dedup")]
 pub use rustapi_extras::dedup;
 
+#[cfg(feature = "llm-guard")]
+pub use rustapi_extras::llm_guard;
+
 #[cfg(feature = "sanitization")]
 pub use rustapi_extras::sanitization;
 
@@ -128,6 +135,14 @@ pub use rustapi_extras::otel;
 #[cfg(feature = "structured-logging")]
 pub use rustapi_extras::structured_logging;
 
+// Health check providers for common dependencies (feature-gated)
+#[cfg(any(
+    feature = "health-check-redis",
+    feature = "health-check-http",
+    feature = "health-check-system"
+))]
+pub use rustapi_extras::health_checks;
+
 // Re-export TOON (feature-gated)
 #[cfg(feature = "toon")]
 pub mod toon {
@@ -228,6 +243,7 @@ pub mod prelude {
         Body,
         ClientIp,
         Created,
+        DevReloadLayer,
         Extension,
         HeaderValue,
         Headers,
@@ -237,6 +253,7 @@ pub mod prelude {
         // Extractors
         Json,
         KeepAlive,
+        Local,
         // Multipart
         Multipart,
         MultipartConfig,
@@ -248,6 +265,8 @@ pub mod prelude {
         // Request context
         Request,
         // Middleware
+        RequestContext,
+        RequestContextLayer,
         RequestId,
         RequestIdLayer,
         Response,
@@ -286,8 +305,8 @@ pub mod prelude {
     // Re-export the route! macro
     pub use rustapi_core::route;
 
-    // Re-export validation - use validator derive macro directly
-    pub use validator::Validate;
+    // Re-export the framework-native validation derive and trait
+    pub use rustapi_validate::Validate;
 
     // Re-export OpenAPI schema derive
     pub use rustapi_openapi::{IntoParams, Schema};
@@ -299,7 +318,8 @@ pub mod prelude {
     // JWT types (feature-gated)
     #[cfg(feature = "jwt")]
     pub use rustapi_extras::{
-        create_token, AuthUser, JwtError, JwtLayer, JwtValidation, ValidatedClaims,
+        create_token, AuthUser, JwtError, JwtLayer, JwtValidation, OptionalAuthUser, RequireScope,
+        ScopeClaims, ScopeMarker, Scopes, ValidatedClaims,
     };
 
     // CORS types (feature-gated)
@@ -310,6 +330,10 @@ pub mod prelude {
     #[cfg(feature = "rate-limit")]
     pub use rustapi_extras::RateLimitLayer;
 
+    // Request timeout / deadline types (feature-gated)
+    #[cfg(feature = "timeout")]
+    pub use rustapi_extras::{Deadline, TimeoutLayer};
+
     // Configuration types (feature-gated)
     #[cfg(feature = "config")]
     pub use rustapi_extras::{
@@ -323,7 +347,12 @@ pub mod prelude {
 
     // TOON types (feature-gated)
     #[cfg(feature = "toon")]
-    pub use rustapi_toon::{AcceptHeader, LlmResponse, Negotiate, OutputFormat, Toon};
+    pub use rustapi_toon::{
+        AcceptHeader, Format, FormatRegistry, HeuristicTokenCounter, LlmResponse, Negotiate,
+        Negotiated, OutputFormat, StreamEncoder, TokenCounter, Toon,
+    };
+    #[cfg(feature = "toon-tiktoken")]
+    pub use rustapi_toon::TiktokenCounter;
 
     // WebSocket types (feature-gated)
     #[cfg(feature = "ws")]
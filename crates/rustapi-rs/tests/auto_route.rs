@@ -65,6 +65,29 @@ struct Pagination {
     page_size: Option<u32>,
 }
 
+#[rustapi_rs::query_params]
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+struct SortedPagination {
+    #[query(default = 1)]
+    page: u32,
+    #[query(default = 20, alias = "per_page")]
+    page_size: u32,
+}
+
+#[test]
+fn test_query_params_applies_default_when_absent() {
+    let parsed: SortedPagination = serde_urlencoded::from_str("").unwrap();
+    assert_eq!(parsed.page, 1);
+    assert_eq!(parsed.page_size, 20);
+}
+
+#[test]
+fn test_query_params_alias_and_explicit_value_override_default() {
+    let parsed: SortedPagination = serde_urlencoded::from_str("page=3&per_page=50").unwrap();
+    assert_eq!(parsed.page, 3);
+    assert_eq!(parsed.page_size, 50);
+}
+
 #[get("/query")]
 async fn query_handler(Query(p): Query<Pagination>) -> &'static str {
     let _ = (&p.page, &p.page_size);
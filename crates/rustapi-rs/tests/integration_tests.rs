@@ -260,6 +260,119 @@ mod openapi_tests {
         // Should have schemas section
         assert!(!spec.schemas.is_empty(), "OpenAPI spec should have schemas");
     }
+
+    #[rustapi_rs::schema]
+    #[derive(Debug, Clone, Serialize, Schema, Validate)]
+    struct ConstrainedUser {
+        #[validate(length(min = 3, max = 50))]
+        username: String,
+        #[validate(email)]
+        email: String,
+        #[validate(range(min = 18, max = 120))]
+        age: u8,
+    }
+
+    #[test]
+    fn test_validate_attrs_propagate_to_schema() {
+        let (_, schema) = <ConstrainedUser as ToSchema>::schema();
+        let json = serde_json::to_value(schema).unwrap();
+        let props = &json["properties"];
+
+        assert_eq!(props["username"]["maxLength"], 50);
+        assert_eq!(props["username"]["minLength"], 3);
+        assert_eq!(props["email"]["format"], "email");
+        assert_eq!(props["age"]["maximum"], 120);
+        assert_eq!(props["age"]["minimum"], 18);
+    }
+
+    #[rustapi_rs::get("/integ-healthz")]
+    #[rustapi_rs::doc(hidden)]
+    async fn integ_healthz() -> &'static str {
+        "ok"
+    }
+
+    #[test]
+    fn test_hidden_route_excluded_from_spec() {
+        let app = RustApi::new().mount_route(integ_healthz_route());
+        let spec = app.openapi_spec();
+
+        assert!(
+            !spec.paths.contains_key("/integ-healthz"),
+            "hidden route should not appear in the OpenAPI spec"
+        );
+    }
+
+    #[test]
+    fn test_filter_by_doc_group() {
+        use rustapi_openapi::{Operation, OpenApiSpec};
+
+        let spec = OpenApiSpec::new("Test API", "1.0.0")
+            .path("/public/ping", "GET", Operation::new().doc_group("public"))
+            .path(
+                "/admin/users",
+                "GET",
+                Operation::new().doc_group("internal"),
+            )
+            .path("/health", "GET", Operation::new());
+
+        let public_spec = spec.filter_by_group("public");
+
+        assert!(public_spec.paths.contains_key("/public/ping"));
+        assert!(public_spec.paths.contains_key("/health"));
+        assert!(!public_spec.paths.contains_key("/admin/users"));
+    }
+
+    #[derive(Debug, Clone, Serialize, Schema)]
+    struct IntegPagedUser {
+        id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Schema)]
+    struct IntegPagedOrder {
+        id: i64,
+    }
+
+    #[derive(Debug, Clone, Serialize, Schema)]
+    struct IntegPaginated<T: for<'a> ToSchema<'a>> {
+        items: Vec<T>,
+        total: usize,
+    }
+
+    #[test]
+    fn test_generic_schema_names_dont_collide() {
+        let spec = rustapi_openapi::OpenApiSpec::new("Test API", "1.0.0")
+            .register::<IntegPaginated<IntegPagedUser>>()
+            .register::<IntegPaginated<IntegPagedOrder>>();
+
+        assert!(spec.schemas.contains_key("IntegPaginated_IntegPagedUser"));
+        assert!(spec.schemas.contains_key("IntegPaginated_IntegPagedOrder"));
+    }
+
+    #[test]
+    fn test_register_as_overrides_generic_name() {
+        let spec = rustapi_openapi::OpenApiSpec::new("Test API", "1.0.0")
+            .register_as::<IntegPaginated<IntegPagedUser>>("PaginatedUsers");
+
+        assert!(spec.schemas.contains_key("PaginatedUsers"));
+        assert!(!spec.schemas.contains_key("IntegPaginated_IntegPagedUser"));
+    }
+
+    #[test]
+    fn test_tag_metadata_and_ordering() {
+        use rustapi_openapi::OpenApiConfig;
+
+        let config = OpenApiConfig::default()
+            .tag("Orders", Some("Order lifecycle".to_string()), None)
+            .tag("Users", Some("User account management".to_string()), None);
+
+        let app = RustApi::new().security(config);
+        let json = app.openapi_spec().to_json();
+        let tags = json["tags"].as_array().unwrap();
+
+        assert_eq!(tags[0]["name"], "Orders");
+        assert_eq!(tags[0]["description"], "Order lifecycle");
+        assert_eq!(tags[1]["name"], "Users");
+    }
 }
 
 // ============================================================================
@@ -0,0 +1,202 @@
+//! On-disk request/response recordings for `MockServer`'s proxy mode - see
+//! [`crate::server::MockServer::start_with_cassette`]. A cassette is a plain
+//! JSON file of interactions; requests that match one already on the
+//! cassette are replayed locally, and requests that don't are forwarded to
+//! the real upstream and appended to the cassette for next time.
+//!
+//! Cassettes are meant to be committed and replayed, so recording against a
+//! real auth-bearing upstream must not bake credentials into the fixture -
+//! see [`CassetteRedaction`], which every recorded interaction is passed
+//! through before it's written to disk.
+
+use bytes::Bytes;
+use http::{HeaderMap, Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::expectation::MockResponse;
+
+/// How [`RecordedInteraction`]s are scrubbed before being written to a
+/// cassette file. Applied by [`MockServer::start_with_cassette`] and
+/// [`MockServer::start_with_cassette_redacting`] to every interaction, not
+/// just the ones that happen to look sensitive.
+///
+/// [`MockServer::start_with_cassette`]: crate::server::MockServer::start_with_cassette
+/// [`MockServer::start_with_cassette_redacting`]: crate::server::MockServer::start_with_cassette_redacting
+type BodyRedactor = Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>;
+
+#[derive(Clone)]
+pub struct CassetteRedaction {
+    header_names: Vec<String>,
+    body: Option<BodyRedactor>,
+}
+
+impl CassetteRedaction {
+    /// Strip `authorization`, `cookie`, and `set-cookie` response headers -
+    /// the default used by [`MockServer::start_with_cassette`].
+    ///
+    /// [`MockServer::start_with_cassette`]: crate::server::MockServer::start_with_cassette
+    pub fn new() -> Self {
+        Self {
+            header_names: vec![
+                "authorization".to_string(),
+                "cookie".to_string(),
+                "set-cookie".to_string(),
+            ],
+            body: None,
+        }
+    }
+
+    /// Record nothing to disk unscrubbed except what's explicitly kept - use
+    /// this to start from an empty header block-list instead of the default
+    /// one.
+    pub fn none() -> Self {
+        Self {
+            header_names: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Also strip `name` (case-insensitive) from recorded response headers.
+    pub fn redact_header(mut self, name: impl Into<String>) -> Self {
+        self.header_names.push(name.into());
+        self
+    }
+
+    /// Run every recorded request and response body through `f` before it's
+    /// written to disk. `f` sees the original bytes; there's no default body
+    /// redaction since request/response shapes vary too much to guess at.
+    ///
+    /// Matching a live request against a replayed cassette compares against
+    /// the *redacted* request body, so a lossy `f` (anything that isn't a
+    /// stable function of the credential-bearing parts alone) will make
+    /// replay matching miss on a re-run.
+    pub fn redact_body(mut self, f: impl Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static) -> Self {
+        self.body = Some(Arc::new(f));
+        self
+    }
+
+    fn is_redacted_header(&self, name: &str) -> bool {
+        self.header_names.iter().any(|h| h.eq_ignore_ascii_case(name))
+    }
+
+    fn apply_body_redaction(&self, body: &[u8]) -> Vec<u8> {
+        match &self.body {
+            Some(f) => f(body),
+            None => body.to_vec(),
+        }
+    }
+}
+
+impl Default for CassetteRedaction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RecordedInteraction {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) query: String,
+    pub(crate) request_body: Vec<u8>,
+    pub(crate) response_status: u16,
+    pub(crate) response_headers: Vec<(String, String)>,
+    pub(crate) response_body: Vec<u8>,
+}
+
+impl RecordedInteraction {
+    /// Build the interaction that gets written to disk, scrubbing
+    /// `redaction`'s header block-list and running bodies through its body
+    /// redactor first.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn redacted(
+        method: &str,
+        path: &str,
+        query: &str,
+        request_body: &[u8],
+        response_status: u16,
+        response_headers: &[(String, String)],
+        response_body: &[u8],
+        redaction: &CassetteRedaction,
+    ) -> Self {
+        const REDACTED_PLACEHOLDER: &str = "REDACTED";
+
+        let response_headers = response_headers
+            .iter()
+            .map(|(k, v)| {
+                if redaction.is_redacted_header(k) {
+                    (k.clone(), REDACTED_PLACEHOLDER.to_string())
+                } else {
+                    (k.clone(), v.clone())
+                }
+            })
+            .collect();
+
+        Self {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: query.to_string(),
+            request_body: redaction.apply_body_redaction(request_body),
+            response_status,
+            response_headers,
+            response_body: redaction.apply_body_redaction(response_body),
+        }
+    }
+
+    pub(crate) fn to_mock_response(&self) -> MockResponse {
+        let mut headers = HeaderMap::new();
+        for (k, v) in &self.response_headers {
+            if let (Ok(name), Ok(value)) = (
+                http::header::HeaderName::from_bytes(k.as_bytes()),
+                http::header::HeaderValue::from_str(v),
+            ) {
+                headers.insert(name, value);
+            }
+        }
+
+        MockResponse::from_parts(
+            StatusCode::from_u16(self.response_status).unwrap_or(StatusCode::OK),
+            headers,
+            Bytes::from(self.response_body.clone()),
+        )
+    }
+}
+
+/// A sequence of recorded HTTP interactions, replayed in order of matching
+/// criteria rather than recording order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Cassette {
+    pub(crate) interactions: Vec<RecordedInteraction>,
+}
+
+impl Cassette {
+    /// Load a cassette from `path`, or start empty if it doesn't exist yet or
+    /// can't be parsed.
+    pub(crate) fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the cassette to `path`, creating parent directories as needed.
+    pub(crate) fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(self).expect("Cassette is always serializable");
+        std::fs::write(path, contents)
+    }
+
+    pub(crate) fn find(&self, method: &Method, path: &str, query: &str, body: &[u8]) -> Option<&RecordedInteraction> {
+        self.interactions
+            .iter()
+            .find(|i| i.method == method.as_str() && i.path == path && i.query == query && i.request_body == body)
+    }
+
+    pub(crate) fn record(&mut self, interaction: RecordedInteraction) {
+        self.interactions.push(interaction);
+    }
+}
@@ -1,30 +1,147 @@
-use super::matcher::RequestMatcher;
+use super::matcher::Matcher;
 use bytes::Bytes;
 use http::{HeaderMap, StatusCode};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
-/// An expectation for a request
+/// The scenario state an expectation is started in if it's the first
+/// expectation registered for that scenario, mirroring WireMock's default.
+pub const SCENARIO_STARTED: &str = "Started";
+
+/// The incoming request an [`Expectation::respond_with_fn`] closure is
+/// generating a response for.
 #[derive(Debug, Clone)]
+pub struct RequestContext {
+    pub method: http::Method,
+    pub path: String,
+    pub query: String,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl RequestContext {
+    /// Look up a single query-string parameter by name.
+    pub fn query_param(&self, name: &str) -> Option<String> {
+        url::form_urlencoded::parse(self.query.as_bytes())
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.into_owned())
+    }
+
+    /// The last path segment, e.g. `"42"` for a request to `/users/42` -
+    /// handy for echoing a resource id back without a full path-capturing
+    /// scheme.
+    pub fn last_path_segment(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or("")
+    }
+
+    /// Look up a request header by name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// Parse the request body as JSON, if it's well-formed.
+    pub fn json_body(&self) -> Option<serde_json::Value> {
+        serde_json::from_slice(&self.body).ok()
+    }
+}
+
+/// A closure that computes a [`MockResponse`] from the matched request - see
+/// [`Expectation::respond_with_fn`].
+type ResponseFn = Arc<dyn Fn(&RequestContext) -> MockResponse + Send + Sync>;
+
+/// An expectation for a request
+#[derive(Clone)]
 pub struct Expectation {
-    pub(crate) matcher: RequestMatcher,
-    pub(crate) response: MockResponse,
+    pub(crate) matcher: Matcher,
+    pub(crate) responses: Vec<MockResponse>,
+    pub(crate) responder: Option<ResponseFn>,
     pub(crate) times: Times,
     pub(crate) call_count: usize,
+    pub(crate) scenario: Option<String>,
+    pub(crate) required_state: Option<String>,
+    pub(crate) new_state: Option<String>,
+}
+
+impl fmt::Debug for Expectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Expectation")
+            .field("matcher", &self.matcher)
+            .field("responses", &self.responses)
+            .field("responder", &self.responder.as_ref().map(|_| ".."))
+            .field("times", &self.times)
+            .field("call_count", &self.call_count)
+            .field("scenario", &self.scenario)
+            .field("required_state", &self.required_state)
+            .field("new_state", &self.new_state)
+            .finish()
+    }
 }
 
 impl Expectation {
     /// Create a new expectation
-    pub fn new(matcher: RequestMatcher) -> Self {
+    pub fn new(matcher: Matcher) -> Self {
         Self {
             matcher,
-            response: MockResponse::default(),
+            responses: vec![MockResponse::default()],
+            responder: None,
             times: Times::Once,
             call_count: 0,
+            scenario: None,
+            required_state: None,
+            new_state: None,
         }
     }
 
     /// Set the response to match
     pub fn respond_with(mut self, response: MockResponse) -> Self {
-        self.response = response;
+        self.responses = vec![response];
+        self.responder = None;
+        self
+    }
+
+    /// Respond with a different response on each successive call, e.g. a
+    /// `500` followed by a `200` to exercise retry logic. The last response
+    /// in the sequence repeats for all calls once the sequence is exhausted.
+    pub fn respond_with_sequence(mut self, responses: impl IntoIterator<Item = MockResponse>) -> Self {
+        let responses: Vec<_> = responses.into_iter().collect();
+        assert!(
+            !responses.is_empty(),
+            "respond_with_sequence requires at least one response"
+        );
+        self.responses = responses;
+        self.responder = None;
+        self
+    }
+
+    /// Compute the response from the matched request instead of returning a
+    /// fixed [`MockResponse`] - e.g. echo an id from the path or a field from
+    /// the JSON body back, so a CRUD upstream doesn't need an expectation per
+    /// id. Takes precedence over [`Expectation::respond_with`] and
+    /// [`Expectation::respond_with_sequence`] if both are set.
+    pub fn respond_with_fn(mut self, f: impl Fn(&RequestContext) -> MockResponse + Send + Sync + 'static) -> Self {
+        self.responder = Some(Arc::new(f));
+        self
+    }
+
+    /// Join a named, WireMock-style scenario. Expectations in the same
+    /// scenario share a state (starting at [`SCENARIO_STARTED`]) that can
+    /// gate which expectation matches via [`Expectation::when_state`] and
+    /// advance via [`Expectation::set_state`].
+    pub fn in_scenario(mut self, scenario: impl Into<String>) -> Self {
+        self.scenario = Some(scenario.into());
+        self
+    }
+
+    /// Only match while the scenario is in `state`.
+    pub fn when_state(mut self, state: impl Into<String>) -> Self {
+        self.required_state = Some(state.into());
+        self
+    }
+
+    /// Transition the scenario to `state` after this expectation matches.
+    pub fn set_state(mut self, state: impl Into<String>) -> Self {
+        self.new_state = Some(state.into());
         self
     }
 
@@ -51,6 +168,43 @@ impl Expectation {
         self.times = Times::Exactly(0);
         self
     }
+
+    /// Don't require this expectation to be called any particular number of
+    /// times - useful for expectations generated in bulk (e.g.
+    /// [`MockServer::from_openapi`](crate::server::MockServer::from_openapi))
+    /// where a given test may only exercise a handful of operations.
+    pub fn any_number_of_times(mut self) -> Self {
+        self.times = Times::Any;
+        self
+    }
+
+    /// The response to return for the `call_count`'th match (0-indexed): the
+    /// dynamic responder if one is set via [`Expectation::respond_with_fn`],
+    /// else the next entry in the sequence, repeating the last once
+    /// exhausted.
+    pub(crate) fn resolve_response(&self, call_count: usize, ctx: &RequestContext) -> MockResponse {
+        if let Some(responder) = &self.responder {
+            return responder(ctx);
+        }
+        let idx = call_count.min(self.responses.len() - 1);
+        self.responses[idx].clone()
+    }
+
+    /// Whether this expectation's scenario is currently in a state it's
+    /// willing to match in. Expectations with no scenario always match.
+    pub(crate) fn scenario_satisfied(&self, states: &std::collections::HashMap<String, String>) -> bool {
+        let Some(required) = &self.required_state else {
+            return true;
+        };
+        let Some(scenario) = &self.scenario else {
+            return true;
+        };
+        states
+            .get(scenario)
+            .map(String::as_str)
+            .unwrap_or(SCENARIO_STARTED)
+            == required
+    }
 }
 
 /// Define how many times an expectation should be matched
@@ -63,12 +217,25 @@ pub enum Times {
     Any,
 }
 
+/// A fault to inject instead of, or alongside, a normal response - see
+/// [`MockResponse::dropped_connection`] and [`MockResponse::malformed_body`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Fault {
+    /// Close the connection without writing a response.
+    DroppedConnection,
+    /// Send a response whose declared length doesn't match its body.
+    MalformedBody,
+}
+
 /// A mocked response
 #[derive(Debug, Clone)]
 pub struct MockResponse {
     pub(crate) status: StatusCode,
     pub(crate) headers: HeaderMap,
     pub(crate) body: Bytes,
+    pub(crate) delay: Option<Duration>,
+    pub(crate) fault: Option<Fault>,
+    pub(crate) throttle_bytes_per_sec: Option<u64>,
 }
 
 impl Default for MockResponse {
@@ -77,6 +244,9 @@ impl Default for MockResponse {
             status: StatusCode::OK,
             headers: HeaderMap::new(),
             body: Bytes::new(),
+            delay: None,
+            fault: None,
+            throttle_bytes_per_sec: None,
         }
     }
 }
@@ -86,6 +256,17 @@ impl MockResponse {
         Self::default()
     }
 
+    /// Build a response from already-assembled parts, e.g. when replaying a
+    /// [`crate::cassette::Cassette`] interaction.
+    pub(crate) fn from_parts(status: StatusCode, headers: HeaderMap, body: Bytes) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+            ..Self::default()
+        }
+    }
+
     pub fn status(mut self, status: StatusCode) -> Self {
         self.status = status;
         self
@@ -112,4 +293,32 @@ impl MockResponse {
         self.body = serde_json::to_vec(&body).unwrap().into();
         self
     }
+
+    /// Wait `duration` before sending the response, to exercise client-side
+    /// timeout handling.
+    pub fn delay(mut self, duration: Duration) -> Self {
+        self.delay = Some(duration);
+        self
+    }
+
+    /// Close the connection instead of sending a response, to exercise how
+    /// clients handle a server disappearing mid-request.
+    pub fn dropped_connection(mut self) -> Self {
+        self.fault = Some(Fault::DroppedConnection);
+        self
+    }
+
+    /// Send a response whose `Content-Length` doesn't match its actual body,
+    /// to exercise handling of a truncated or corrupt response.
+    pub fn malformed_body(mut self) -> Self {
+        self.fault = Some(Fault::MalformedBody);
+        self
+    }
+
+    /// Trickle the body out at roughly `bytes_per_sec`, to exercise
+    /// read-timeout and slow-consumer handling.
+    pub fn throttle(mut self, bytes_per_sec: u64) -> Self {
+        self.throttle_bytes_per_sec = Some(bytes_per_sec);
+        self
+    }
 }
@@ -4,10 +4,16 @@
 //!
 //! The `MockServer` allows you to mock HTTP services for integration testing.
 
+pub(crate) mod cassette;
 pub mod expectation;
 pub mod matcher;
+pub(crate) mod openapi;
 pub mod server;
+pub mod snapshot;
+pub(crate) mod tls;
 
-pub use expectation::{Expectation, MockResponse, Times};
-pub use matcher::RequestMatcher;
+pub use cassette::CassetteRedaction;
+pub use expectation::{Expectation, MockResponse, RequestContext, Times, SCENARIO_STARTED};
+pub use matcher::{Matcher, RequestMatcher};
 pub use server::{MockServer, RecordedRequest};
+pub use snapshot::{assert_snapshot, assert_snapshot_json};
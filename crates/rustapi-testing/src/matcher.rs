@@ -1,13 +1,32 @@
 use http::{HeaderMap, Method};
+use regex::Regex;
 use serde_json::Value;
+use std::sync::Arc;
+
+/// How a matcher's path criterion is compared against a request's path.
+#[derive(Debug, Clone)]
+pub(crate) enum PathPattern {
+    Exact(String),
+    Regex(Arc<Regex>),
+}
+
+/// How a matcher's header/query-param criterion is compared against a
+/// request's headers/query string.
+#[derive(Debug, Clone)]
+pub(crate) enum ValueMatch {
+    Exact(String),
+    Present,
+}
 
 /// Matcher for HTTP requests
 #[derive(Debug, Clone, Default)]
 pub struct RequestMatcher {
     pub(crate) method: Option<Method>,
-    pub(crate) path: Option<String>,
-    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) path: Option<PathPattern>,
+    pub(crate) query: Vec<(String, ValueMatch)>,
+    pub(crate) headers: Vec<(String, ValueMatch)>,
     pub(crate) body_json: Option<Value>,
+    pub(crate) body_json_contains: Option<Value>,
     pub(crate) body_string: Option<String>,
 }
 
@@ -23,15 +42,44 @@ impl RequestMatcher {
         self
     }
 
-    /// Match a specific path
+    /// Match a specific, exact path
     pub fn path(mut self, path: impl Into<String>) -> Self {
-        self.path = Some(path.into());
+        self.path = Some(PathPattern::Exact(path.into()));
+        self
+    }
+
+    /// Match any path satisfying `pattern`, e.g. `"^/users/\\d+$"`.
+    ///
+    /// # Panics
+    /// Panics if `pattern` isn't a valid regex.
+    pub fn path_regex(mut self, pattern: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Invalid path_regex pattern");
+        self.path = Some(PathPattern::Regex(Arc::new(regex)));
         self
     }
 
-    /// Match a specific header
+    /// Match a specific header value
     pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
-        self.headers.push((key.into(), value.into()));
+        self.headers.push((key.into(), ValueMatch::Exact(value.into())));
+        self
+    }
+
+    /// Match any request that has header `key` set, regardless of its value
+    pub fn header_present(mut self, key: impl Into<String>) -> Self {
+        self.headers.push((key.into(), ValueMatch::Present));
+        self
+    }
+
+    /// Match a specific query-parameter value
+    pub fn query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((key.into(), ValueMatch::Exact(value.into())));
+        self
+    }
+
+    /// Match any request that has query parameter `key` set, regardless of
+    /// its value
+    pub fn query_param_present(mut self, key: impl Into<String>) -> Self {
+        self.query.push((key.into(), ValueMatch::Present));
         self
     }
 
@@ -42,37 +90,88 @@ impl RequestMatcher {
         self
     }
 
+    /// Match a JSON body that contains `partial` as a subset: every field in
+    /// `partial` must be present in the request body with an equal (and, for
+    /// nested objects, also-containing) value. Fields in the request body
+    /// that aren't in `partial` are ignored.
+    pub fn body_json_contains(mut self, partial: impl serde::Serialize) -> Self {
+        self.body_json_contains =
+            Some(serde_json::to_value(partial).expect("Failed to serialize body matcher"));
+        self
+    }
+
     /// Match exact string body
     pub fn body_string(mut self, body: impl Into<String>) -> Self {
         self.body_string = Some(body.into());
         self
     }
 
+    /// Combine this matcher with `other`: both must match.
+    pub fn and(self, other: impl Into<Matcher>) -> Matcher {
+        Matcher::Single(self).and(other)
+    }
+
+    /// Combine this matcher with `other`: either may match.
+    pub fn or(self, other: impl Into<Matcher>) -> Matcher {
+        Matcher::Single(self).or(other)
+    }
+
+    /// Negate this matcher.
+    pub fn negate(self) -> Matcher {
+        Matcher::Single(self).negate()
+    }
+
     /// Check if the matcher matches a request
-    pub fn matches(&self, method: &Method, path: &str, headers: &HeaderMap, body: &[u8]) -> bool {
+    pub fn matches(
+        &self,
+        method: &Method,
+        path: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+        query: &str,
+    ) -> bool {
         if let Some(m) = &self.method {
             if m != method {
                 return false;
             }
         }
 
-        if let Some(p) = &self.path {
-            if p != path {
-                return false;
-            }
+        match &self.path {
+            Some(PathPattern::Exact(p)) if p != path => return false,
+            Some(PathPattern::Regex(re)) if !re.is_match(path) => return false,
+            _ => {}
         }
 
-        for (k, v) in &self.headers {
+        for (k, expected) in &self.headers {
             match headers.get(k) {
                 Some(val) => {
-                    if val != v.as_str() {
-                        return false;
+                    if let ValueMatch::Exact(v) = expected {
+                        if val != v.as_str() {
+                            return false;
+                        }
                     }
                 }
                 None => return false,
             }
         }
 
+        if !self.query.is_empty() {
+            let actual_query: std::collections::HashMap<_, _> =
+                url::form_urlencoded::parse(query.as_bytes()).into_owned().collect();
+            for (k, expected) in &self.query {
+                match actual_query.get(k) {
+                    Some(val) => {
+                        if let ValueMatch::Exact(v) = expected {
+                            if val != v {
+                                return false;
+                            }
+                        }
+                    }
+                    None => return false,
+                }
+            }
+        }
+
         if let Some(expected_json) = &self.body_json {
             if let Ok(actual_json) = serde_json::from_slice::<Value>(body) {
                 if &actual_json != expected_json {
@@ -83,6 +182,16 @@ impl RequestMatcher {
             }
         }
 
+        if let Some(partial) = &self.body_json_contains {
+            if let Ok(actual_json) = serde_json::from_slice::<Value>(body) {
+                if !json_contains(&actual_json, partial) {
+                    return false;
+                }
+            } else {
+                return false;
+            }
+        }
+
         if let Some(expected_str) = &self.body_string {
             if let Ok(actual_str) = std::str::from_utf8(body) {
                 if actual_str != expected_str {
@@ -95,6 +204,193 @@ impl RequestMatcher {
 
         true
     }
+
+    /// Describe every criterion of this matcher that `matches` would reject
+    /// the request for, e.g. `"path: expected \"/a\", got \"/b\""` - used by
+    /// [`crate::server::MockServer::verify`] to explain near misses. Empty if
+    /// the request matches.
+    pub(crate) fn mismatches(
+        &self,
+        method: &Method,
+        path: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+        query: &str,
+    ) -> Vec<String> {
+        let mut reasons = Vec::new();
+
+        if let Some(m) = &self.method {
+            if m != method {
+                reasons.push(format!("method: expected {m}, got {method}"));
+            }
+        }
+
+        match &self.path {
+            Some(PathPattern::Exact(p)) if p != path => {
+                reasons.push(format!("path: expected {p:?}, got {path:?}"));
+            }
+            Some(PathPattern::Regex(re)) if !re.is_match(path) => {
+                reasons.push(format!("path: {path:?} doesn't match regex {:?}", re.as_str()));
+            }
+            _ => {}
+        }
+
+        for (k, expected) in &self.headers {
+            match headers.get(k) {
+                Some(val) => {
+                    if let ValueMatch::Exact(v) = expected {
+                        if val != v.as_str() {
+                            reasons.push(format!("header {k:?}: expected {v:?}, got {val:?}"));
+                        }
+                    }
+                }
+                None => reasons.push(format!("header {k:?}: not present")),
+            }
+        }
+
+        if !self.query.is_empty() {
+            let actual_query: std::collections::HashMap<_, _> =
+                url::form_urlencoded::parse(query.as_bytes()).into_owned().collect();
+            for (k, expected) in &self.query {
+                match actual_query.get(k) {
+                    Some(val) => {
+                        if let ValueMatch::Exact(v) = expected {
+                            if val != v {
+                                reasons.push(format!("query param {k:?}: expected {v:?}, got {val:?}"));
+                            }
+                        }
+                    }
+                    None => reasons.push(format!("query param {k:?}: not present")),
+                }
+            }
+        }
+
+        if let Some(expected_json) = &self.body_json {
+            match serde_json::from_slice::<Value>(body) {
+                Ok(actual_json) if &actual_json != expected_json => {
+                    reasons.push(format!("body_json: expected {expected_json}, got {actual_json}"));
+                }
+                Err(_) => reasons.push("body_json: request body isn't valid JSON".to_string()),
+                _ => {}
+            }
+        }
+
+        if let Some(partial) = &self.body_json_contains {
+            match serde_json::from_slice::<Value>(body) {
+                Ok(actual_json) if !json_contains(&actual_json, partial) => {
+                    reasons.push(format!("body_json_contains: {partial} not found in {actual_json}"));
+                }
+                Err(_) => reasons.push("body_json_contains: request body isn't valid JSON".to_string()),
+                _ => {}
+            }
+        }
+
+        if let Some(expected_str) = &self.body_string {
+            match std::str::from_utf8(body) {
+                Ok(actual_str) if actual_str != expected_str => {
+                    reasons.push(format!("body_string: expected {expected_str:?}, got {actual_str:?}"));
+                }
+                Err(_) => reasons.push("body_string: request body isn't valid UTF-8".to_string()),
+                _ => {}
+            }
+        }
+
+        reasons
+    }
+}
+
+/// Whether `actual` contains `expected` as a subset - see
+/// [`RequestMatcher::body_json_contains`].
+fn json_contains(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => expected
+            .iter()
+            .all(|(k, v)| actual.get(k).is_some_and(|av| json_contains(av, v))),
+        (actual, expected) => actual == expected,
+    }
+}
+
+/// A matcher composed of [`RequestMatcher`]s and boolean combinators, for
+/// expectations that plain AND-of-criteria matching can't express.
+#[derive(Debug, Clone)]
+pub enum Matcher {
+    Single(RequestMatcher),
+    And(Vec<Matcher>),
+    Or(Vec<Matcher>),
+    Not(Box<Matcher>),
+}
+
+impl Matcher {
+    /// Combine this matcher with `other`: both must match.
+    pub fn and(self, other: impl Into<Matcher>) -> Matcher {
+        Matcher::And(vec![self, other.into()])
+    }
+
+    /// Combine this matcher with `other`: either may match.
+    pub fn or(self, other: impl Into<Matcher>) -> Matcher {
+        Matcher::Or(vec![self, other.into()])
+    }
+
+    /// Negate this matcher.
+    pub fn negate(self) -> Matcher {
+        Matcher::Not(Box::new(self))
+    }
+
+    /// Check if the matcher matches a request - see [`RequestMatcher::matches`].
+    pub fn matches(
+        &self,
+        method: &Method,
+        path: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+        query: &str,
+    ) -> bool {
+        match self {
+            Matcher::Single(m) => m.matches(method, path, headers, body, query),
+            Matcher::And(matchers) => matchers.iter().all(|m| m.matches(method, path, headers, body, query)),
+            Matcher::Or(matchers) => matchers.iter().any(|m| m.matches(method, path, headers, body, query)),
+            Matcher::Not(m) => !m.matches(method, path, headers, body, query),
+        }
+    }
+
+    /// Describe why this matcher rejects the request - see
+    /// [`RequestMatcher::mismatches`]. Empty if it matches.
+    pub(crate) fn mismatches(
+        &self,
+        method: &Method,
+        path: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+        query: &str,
+    ) -> Vec<String> {
+        match self {
+            Matcher::Single(m) => m.mismatches(method, path, headers, body, query),
+            Matcher::And(matchers) => matchers
+                .iter()
+                .flat_map(|m| m.mismatches(method, path, headers, body, query))
+                .collect(),
+            Matcher::Or(matchers) => {
+                if matchers.iter().any(|m| m.matches(method, path, headers, body, query)) {
+                    vec![]
+                } else {
+                    vec!["or: none of the alternatives matched".to_string()]
+                }
+            }
+            Matcher::Not(_) => {
+                if self.matches(method, path, headers, body, query) {
+                    vec![]
+                } else {
+                    vec!["not: the negated matcher matched".to_string()]
+                }
+            }
+        }
+    }
+}
+
+impl From<RequestMatcher> for Matcher {
+    fn from(matcher: RequestMatcher) -> Self {
+        Matcher::Single(matcher)
+    }
 }
 
 #[cfg(test)]
@@ -175,7 +471,7 @@ mod property_tests {
             let headers = HeaderMap::new();
 
             // Empty matcher MUST match any request
-            prop_assert!(matcher.matches(&method, &path, &headers, body.as_bytes()));
+            prop_assert!(matcher.matches(&method, &path, &headers, body.as_bytes(), ""));
         }
 
         /// Property 20: Method matcher correctly identifies method
@@ -190,11 +486,11 @@ mod property_tests {
             let body = b"";
 
             // MUST match requests with same method
-            prop_assert!(matcher.matches(&target_method, &path, &headers, body));
+            prop_assert!(matcher.matches(&target_method, &path, &headers, body, ""));
 
             // MUST reject requests with different method
             if target_method != other_method {
-                prop_assert!(!matcher.matches(&other_method, &path, &headers, body));
+                prop_assert!(!matcher.matches(&other_method, &path, &headers, body, ""));
             }
         }
 
@@ -210,11 +506,11 @@ mod property_tests {
             let body = b"";
 
             // MUST match exact path
-            prop_assert!(matcher.matches(&method, &target_path, &headers, body));
+            prop_assert!(matcher.matches(&method, &target_path, &headers, body, ""));
 
             // MUST reject different path
             if target_path != other_path {
-                prop_assert!(!matcher.matches(&method, &other_path, &headers, body));
+                prop_assert!(!matcher.matches(&method, &other_path, &headers, body, ""));
             }
         }
 
@@ -239,7 +535,7 @@ mod property_tests {
             let body = b"";
 
             // MUST match when header value is exact
-            prop_assert!(matcher.matches(&method, &path, &headers_match, body));
+            prop_assert!(matcher.matches(&method, &path, &headers_match, body, ""));
 
             // MUST reject when header value differs
             if header_value != other_value {
@@ -248,12 +544,12 @@ mod property_tests {
                     http::header::HeaderName::from_bytes(header_name.as_bytes()).unwrap(),
                     http::header::HeaderValue::from_str(&other_value).unwrap(),
                 );
-                prop_assert!(!matcher.matches(&method, &path, &headers_differ, body));
+                prop_assert!(!matcher.matches(&method, &path, &headers_differ, body, ""));
             }
 
             // MUST reject when header is missing
             let headers_empty = HeaderMap::new();
-            prop_assert!(!matcher.matches(&method, &path, &headers_empty, body));
+            prop_assert!(!matcher.matches(&method, &path, &headers_empty, body, ""));
         }
 
         /// Property 20: JSON body matcher requires exact match
@@ -269,16 +565,16 @@ mod property_tests {
             let matching_body = serde_json::to_vec(&json_body).unwrap();
 
             // MUST match exact JSON body
-            prop_assert!(matcher.matches(&method, &path, &headers, &matching_body));
+            prop_assert!(matcher.matches(&method, &path, &headers, &matching_body, ""));
 
             // MUST reject different JSON body
             let different_json = json!({"different": "value"});
             let different_body = serde_json::to_vec(&different_json).unwrap();
-            prop_assert!(!matcher.matches(&method, &path, &headers, &different_body));
+            prop_assert!(!matcher.matches(&method, &path, &headers, &different_body, ""));
 
             // MUST reject invalid JSON
             let invalid_json = b"not json at all";
-            prop_assert!(!matcher.matches(&method, &path, &headers, invalid_json));
+            prop_assert!(!matcher.matches(&method, &path, &headers, invalid_json, ""));
         }
 
         /// Property 20: String body matcher requires exact match
@@ -293,11 +589,11 @@ mod property_tests {
             let headers = HeaderMap::new();
 
             // MUST match exact string
-            prop_assert!(matcher.matches(&method, &path, &headers, body_string.as_bytes()));
+            prop_assert!(matcher.matches(&method, &path, &headers, body_string.as_bytes(), ""));
 
             // MUST reject different string
             if body_string != other_string {
-                prop_assert!(!matcher.matches(&method, &path, &headers, other_string.as_bytes()));
+                prop_assert!(!matcher.matches(&method, &path, &headers, other_string.as_bytes(), ""));
             }
         }
 
@@ -325,22 +621,22 @@ mod property_tests {
             let body = b"";
 
             // MUST match when ALL criteria match
-            prop_assert!(matcher.matches(&target_method, &target_path, &headers_correct, body));
+            prop_assert!(matcher.matches(&target_method, &target_path, &headers_correct, body, ""));
 
             // MUST reject when ANY criterion fails
             if target_method != other_method {
                 // Wrong method
-                prop_assert!(!matcher.matches(&other_method, &target_path, &headers_correct, body));
+                prop_assert!(!matcher.matches(&other_method, &target_path, &headers_correct, body, ""));
             }
 
             if target_path != other_path {
                 // Wrong path
-                prop_assert!(!matcher.matches(&target_method, &other_path, &headers_correct, body));
+                prop_assert!(!matcher.matches(&target_method, &other_path, &headers_correct, body, ""));
             }
 
             // Wrong/missing header
             let headers_empty = HeaderMap::new();
-            prop_assert!(!matcher.matches(&target_method, &target_path, &headers_empty, body));
+            prop_assert!(!matcher.matches(&target_method, &target_path, &headers_empty, body, ""));
         }
 
         /// Property 20: Matcher is case-sensitive for paths
@@ -357,11 +653,11 @@ mod property_tests {
             let body = b"";
 
             // MUST match exact case
-            prop_assert!(matcher.matches(&method, &lowercase_path, &headers, body));
+            prop_assert!(matcher.matches(&method, &lowercase_path, &headers, body, ""));
 
             // MUST reject different case (if different)
             if lowercase_path != uppercase_path {
-                prop_assert!(!matcher.matches(&method, &uppercase_path, &headers, body));
+                prop_assert!(!matcher.matches(&method, &uppercase_path, &headers, body, ""));
             }
         }
 
@@ -389,10 +685,10 @@ mod property_tests {
             let body = b"";
 
             // MUST match when all headers present
-            prop_assert!(matcher.matches(&method, &path, &headers_all, body));
+            prop_assert!(matcher.matches(&method, &path, &headers_all, body, ""));
 
             // MUST reject when any header missing
-            prop_assert!(!matcher.matches(&method, &path, &headers_missing_one, body));
+            prop_assert!(!matcher.matches(&method, &path, &headers_missing_one, body, ""));
         }
 
         /// Property 20: JSON body whitespace doesn't affect matching
@@ -407,11 +703,11 @@ mod property_tests {
 
             // Compact JSON
             let compact = serde_json::to_vec(&json_value).unwrap();
-            prop_assert!(matcher.matches(&method, &path, &headers, &compact));
+            prop_assert!(matcher.matches(&method, &path, &headers, &compact, ""));
 
             // Pretty-printed JSON (different whitespace)
             let pretty = serde_json::to_vec_pretty(&json_value).unwrap();
-            prop_assert!(matcher.matches(&method, &path, &headers, &pretty));
+            prop_assert!(matcher.matches(&method, &path, &headers, &pretty, ""));
         }
 
         /// Property 20: JSON field order doesn't affect matching
@@ -429,7 +725,7 @@ mod property_tests {
             let body = serde_json::to_vec(&json_reordered).unwrap();
 
             // MUST match regardless of field order (JSON semantics)
-            prop_assert!(matcher.matches(&method, &path, &headers, &body));
+            prop_assert!(matcher.matches(&method, &path, &headers, &body, ""));
         }
 
         /// Property 20: Matcher with no criteria matches everything
@@ -443,7 +739,92 @@ mod property_tests {
             let headers = HeaderMap::new();
 
             // Default matcher MUST be permissive
-            prop_assert!(matcher.matches(&method, &path, &headers, &body));
+            prop_assert!(matcher.matches(&method, &path, &headers, &body, ""));
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn path_regex_matches_pattern() {
+        let matcher = RequestMatcher::new().path_regex(r"^/users/\d+$");
+        let (method, headers, body) = (Method::GET, HeaderMap::new(), b"".as_slice());
+
+        assert!(matcher.matches(&method, "/users/42", &headers, body, ""));
+        assert!(!matcher.matches(&method, "/users/abc", &headers, body, ""));
+        assert!(!matcher.matches(&method, "/users", &headers, body, ""));
+    }
+
+    #[test]
+    fn query_param_matches_exact_value() {
+        let matcher = RequestMatcher::new().query_param("page", "2");
+        let (method, headers, body) = (Method::GET, HeaderMap::new(), b"".as_slice());
+
+        assert!(matcher.matches(&method, "/items", &headers, body, "page=2"));
+        assert!(!matcher.matches(&method, "/items", &headers, body, "page=3"));
+        assert!(!matcher.matches(&method, "/items", &headers, body, ""));
+    }
+
+    #[test]
+    fn query_param_present_ignores_value() {
+        let matcher = RequestMatcher::new().query_param_present("token");
+        let (method, headers, body) = (Method::GET, HeaderMap::new(), b"".as_slice());
+
+        assert!(matcher.matches(&method, "/items", &headers, body, "token=anything"));
+        assert!(!matcher.matches(&method, "/items", &headers, body, "other=1"));
+    }
+
+    #[test]
+    fn header_present_ignores_value() {
+        let matcher = RequestMatcher::new().header_present("x-request-id");
+        let (method, body) = (Method::GET, b"".as_slice());
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-request-id", "anything".parse().unwrap());
+        assert!(matcher.matches(&method, "/items", &headers, body, ""));
+
+        assert!(!matcher.matches(&method, "/items", &HeaderMap::new(), body, ""));
+    }
+
+    #[test]
+    fn body_json_contains_matches_subset() {
+        let matcher = RequestMatcher::new().body_json_contains(json!({"user": {"name": "Alice"}}));
+        let (method, headers) = (Method::POST, HeaderMap::new());
+
+        let full_body = serde_json::to_vec(&json!({
+            "user": {"name": "Alice", "age": 30},
+            "extra": "ignored",
+        }))
+        .unwrap();
+        assert!(matcher.matches(&method, "/users", &headers, &full_body, ""));
+
+        let wrong_body = serde_json::to_vec(&json!({"user": {"name": "Bob"}})).unwrap();
+        assert!(!matcher.matches(&method, "/users", &headers, &wrong_body, ""));
+    }
+
+    #[test]
+    fn combinators_and_or_not() {
+        let (method, headers, body) = (Method::GET, HeaderMap::new(), b"".as_slice());
+
+        let and = RequestMatcher::new()
+            .method(Method::GET)
+            .and(RequestMatcher::new().path("/a"));
+        assert!(and.matches(&method, "/a", &headers, body, ""));
+        assert!(!and.matches(&method, "/b", &headers, body, ""));
+
+        let or = RequestMatcher::new()
+            .path("/a")
+            .or(RequestMatcher::new().path("/b"));
+        assert!(or.matches(&method, "/a", &headers, body, ""));
+        assert!(or.matches(&method, "/b", &headers, body, ""));
+        assert!(!or.matches(&method, "/c", &headers, body, ""));
+
+        let not = RequestMatcher::new().path("/a").negate();
+        assert!(!not.matches(&method, "/a", &headers, body, ""));
+        assert!(not.matches(&method, "/b", &headers, body, ""));
+    }
+}
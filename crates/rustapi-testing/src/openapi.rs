@@ -0,0 +1,213 @@
+//! Auto-generate `MockServer` expectations from an OpenAPI spec - see
+//! [`MockServer::from_openapi`]. Turns a `rustapi_openapi::OpenApiSpec` into
+//! a contract-testing double: one expectation per operation, returning a
+//! schema-conformant example response and rejecting requests that don't
+//! satisfy the operation's parameter/body schemas.
+
+use crate::expectation::{MockResponse, RequestContext};
+use crate::matcher::RequestMatcher;
+use crate::server::MockServer;
+use http::{Method, StatusCode};
+use rustapi_openapi::{OpenApiSpec, Operation, SchemaRef};
+use serde_json::Value;
+use std::collections::HashMap;
+
+impl MockServer {
+    /// Start a mock server with one expectation per operation in `spec`,
+    /// each returning a schema-conformant example response for the
+    /// operation's first `2xx` response, and a `400` for requests missing a
+    /// required parameter or request-body field.
+    ///
+    /// Expectations don't require a particular number of calls, since a spec
+    /// typically covers far more operations than a single test exercises.
+    pub async fn from_openapi(spec: &OpenApiSpec) -> Self {
+        let server = Self::start().await;
+
+        for (path, item) in &spec.paths {
+            for (method, operation) in [
+                (Method::GET, &item.get),
+                (Method::POST, &item.post),
+                (Method::PUT, &item.put),
+                (Method::PATCH, &item.patch),
+                (Method::DELETE, &item.delete),
+            ] {
+                if let Some(operation) = operation {
+                    register_operation(&server, &spec.schemas, method, path, operation);
+                }
+            }
+        }
+
+        server
+    }
+}
+
+fn register_operation(
+    server: &MockServer,
+    components: &HashMap<String, Value>,
+    method: Method,
+    path: &str,
+    operation: &Operation,
+) {
+    let matcher = RequestMatcher::new().method(method).path_regex(&path_pattern(path));
+    let operation = operation.clone();
+    let components = components.clone();
+
+    server
+        .expect(matcher)
+        .respond_with_fn(move |ctx| match validate_request(&operation, &components, ctx) {
+            Err(reason) => {
+                MockResponse::new().status(StatusCode::BAD_REQUEST).json(serde_json::json!({ "error": reason }))
+            }
+            Ok(()) => example_response(&operation, &components),
+        })
+        .any_number_of_times();
+}
+
+/// Turn an OpenAPI path template like `/users/{id}` into a regex, escaping
+/// the literal segments and matching each `{param}` against one path
+/// segment.
+fn path_pattern(path: &str) -> String {
+    let mut pattern = String::from("^");
+    let mut rest = path;
+
+    while let Some(start) = rest.find('{') {
+        pattern.push_str(&regex::escape(&rest[..start]));
+        let Some(end) = rest[start..].find('}') else {
+            pattern.push_str(&regex::escape(&rest[start..]));
+            rest = "";
+            break;
+        };
+        pattern.push_str("[^/]+");
+        rest = &rest[start + end + 1..];
+    }
+    pattern.push_str(&regex::escape(rest));
+    pattern.push('$');
+    pattern
+}
+
+/// Check the request against the operation's required query/header
+/// parameters and required request-body fields, returning why it failed the
+/// first criterion that doesn't hold.
+fn validate_request(operation: &Operation, components: &HashMap<String, Value>, ctx: &RequestContext) -> Result<(), String> {
+    for param in operation.parameters.iter().flatten() {
+        if !param.required {
+            continue;
+        }
+        let present = match param.location.as_str() {
+            "query" => ctx.query_param(&param.name).is_some(),
+            "header" => ctx.header(&param.name).is_some(),
+            // Path params are guaranteed present by the route matching at all.
+            _ => true,
+        };
+        if !present {
+            return Err(format!("missing required {} parameter '{}'", param.location, param.name));
+        }
+    }
+
+    let Some(request_body) = &operation.request_body else {
+        return Ok(());
+    };
+    let Some(media_type) = request_body.content.get("application/json") else {
+        return Ok(());
+    };
+    let schema = resolve_schema(&media_type.schema, components);
+
+    if request_body.required && ctx.json_body().is_none() {
+        return Err("missing or malformed JSON request body".to_string());
+    }
+
+    let (Some(body), Some(schema)) = (ctx.json_body(), schema) else {
+        return Ok(());
+    };
+    let Some(required_fields) = schema.get("required").and_then(Value::as_array) else {
+        return Ok(());
+    };
+
+    for field in required_fields {
+        let Some(field) = field.as_str() else { continue };
+        if body.get(field).is_none() {
+            return Err(format!("missing required body field '{field}'"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Build the mocked response for the operation's first `2xx` entry, or a
+/// bare `200` if none is declared.
+fn example_response(operation: &Operation, components: &HashMap<String, Value>) -> MockResponse {
+    let mut codes: Vec<&String> = operation.responses.keys().collect();
+    codes.sort();
+
+    let Some(code) = codes.into_iter().find(|code| code.starts_with('2')) else {
+        return MockResponse::new().status(StatusCode::OK);
+    };
+    let status = code.parse::<u16>().ok().and_then(|n| StatusCode::from_u16(n).ok()).unwrap_or(StatusCode::OK);
+    let response_spec = &operation.responses[code];
+
+    let Some(content) = &response_spec.content else {
+        return MockResponse::new().status(status);
+    };
+    let Some(media_type) = content.get("application/json") else {
+        return MockResponse::new().status(status);
+    };
+    let Some(schema) = resolve_schema(&media_type.schema, components) else {
+        return MockResponse::new().status(status);
+    };
+
+    MockResponse::new().status(status).json(example_value(schema, components))
+}
+
+fn resolve_schema<'a>(schema_ref: &'a SchemaRef, components: &'a HashMap<String, Value>) -> Option<&'a Value> {
+    match schema_ref {
+        SchemaRef::Inline(value) => Some(value),
+        SchemaRef::Ref { reference } => {
+            let name = reference.rsplit('/').next()?;
+            components.get(name)
+        }
+    }
+}
+
+/// Generate an example value satisfying `schema`, preferring an explicit
+/// `example`/`default` and otherwise synthesizing one from `type`.
+fn example_value(schema: &Value, components: &HashMap<String, Value>) -> Value {
+    if let Some(example) = schema.get("example") {
+        return example.clone();
+    }
+    if let Some(default) = schema.get("default") {
+        return default.clone();
+    }
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        if let Some(name) = reference.rsplit('/').next() {
+            if let Some(resolved) = components.get(name) {
+                return example_value(resolved, components);
+            }
+        }
+    }
+    if let Some(variants) = schema.get("enum").and_then(Value::as_array) {
+        if let Some(first) = variants.first() {
+            return first.clone();
+        }
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some("object") | None if schema.get("properties").is_some() => {
+            let mut object = serde_json::Map::new();
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (name, property_schema) in properties {
+                    object.insert(name.clone(), example_value(property_schema, components));
+                }
+            }
+            Value::Object(object)
+        }
+        Some("array") => {
+            let item = schema.get("items").map(|items| example_value(items, components)).unwrap_or(Value::Null);
+            Value::Array(vec![item])
+        }
+        Some("string") => Value::String("string".to_string()),
+        Some("integer") => serde_json::json!(0),
+        Some("number") => serde_json::json!(0.0),
+        Some("boolean") => Value::Bool(false),
+        _ => Value::Null,
+    }
+}
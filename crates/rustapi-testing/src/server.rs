@@ -1,34 +1,88 @@
-use super::expectation::{Expectation, MockResponse, Times};
-use super::matcher::RequestMatcher;
+use super::cassette::{Cassette, CassetteRedaction, RecordedInteraction};
+use super::expectation::{Expectation, Fault, MockResponse, RequestContext, Times};
+use super::matcher::Matcher;
 use bytes::Bytes;
-use http_body_util::{BodyExt, Full};
+use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Full, StreamBody};
+use hyper::body::Frame;
 use hyper::service::service_fn;
 use hyper::{Request, Response, StatusCode};
-use hyper_util::rt::TokioIo;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use std::collections::HashMap;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::net::TcpListener;
 use tokio::sync::oneshot;
+use tokio_rustls::TlsAcceptor;
 
 type GenericError = Box<dyn std::error::Error + Send + Sync>;
 type Result<T> = std::result::Result<T, GenericError>;
 
+/// The body type every mock response is sent as, so a plain buffered body
+/// and a throttled streaming body can share one return type.
+type MockBody = BoxBody<Bytes, GenericError>;
+
+fn full_body(body: Bytes) -> MockBody {
+    Full::new(body)
+        .map_err(|never| -> GenericError { match never {} })
+        .boxed()
+}
+
+/// Trickle `body` out in chunks sized for roughly one-tenth of a second at
+/// `bytes_per_sec`, sleeping between chunks - see [`MockResponse::throttle`].
+fn throttled_body(body: Bytes, bytes_per_sec: u64) -> MockBody {
+    let bytes_per_sec = bytes_per_sec.max(1);
+    let chunk_size = ((bytes_per_sec as f64 * 0.1) as usize).max(1);
+
+    let stream = futures_util::stream::unfold(body, move |mut remaining| async move {
+        if remaining.is_empty() {
+            return None;
+        }
+        let take = chunk_size.min(remaining.len());
+        let chunk = remaining.split_to(take);
+        tokio::time::sleep(Duration::from_secs_f64(take as f64 / bytes_per_sec as f64)).await;
+        Some((Ok::<_, GenericError>(Frame::data(chunk)), remaining))
+    });
+
+    StreamBody::new(stream).boxed()
+}
+
 /// A mock HTTP server
 pub struct MockServer {
     addr: SocketAddr,
     state: Arc<Mutex<ServerState>>,
     shutdown_tx: Option<oneshot::Sender<()>>,
+    /// Set for [`MockServer::start_https`] servers - see
+    /// [`MockServer::ca_cert_pem`].
+    ca_cert_pem: Option<String>,
 }
 
 struct ServerState {
     expectations: Vec<Expectation>,
     unmatched_requests: Vec<RecordedRequest>,
+    /// Current state of each named scenario - see [`Expectation::in_scenario`].
+    scenario_states: HashMap<String, String>,
+    /// Set in proxy mode - see [`MockServer::start_with_cassette`].
+    proxy: Option<ProxyConfig>,
+    cassette: Cassette,
+}
+
+struct ProxyConfig {
+    upstream: String,
+    cassette_path: PathBuf,
+    client: Client<HttpConnector, Full<Bytes>>,
+    redaction: CassetteRedaction,
 }
 
 #[derive(Debug, Clone)]
 pub struct RecordedRequest {
     pub method: http::Method,
     pub path: String,
+    pub query: String,
     pub headers: http::HeaderMap,
     pub body: Bytes,
 }
@@ -36,16 +90,122 @@ pub struct RecordedRequest {
 impl MockServer {
     /// Start a new mock server on a random port
     pub async fn start() -> Self {
+        let (addr, state, shutdown_tx) = Self::spawn(
+            ServerState {
+                expectations: Vec::new(),
+                unmatched_requests: Vec::new(),
+                scenario_states: HashMap::new(),
+                proxy: None,
+                cassette: Cassette::default(),
+            },
+            None,
+        )
+        .await;
+
+        Self {
+            addr,
+            state,
+            shutdown_tx: Some(shutdown_tx),
+            ca_cert_pem: None,
+        }
+    }
+
+    /// Start a mock server in record-and-replay proxy mode: requests that
+    /// match an expectation (or an interaction already on the cassette) are
+    /// served locally; any other request is forwarded to `upstream`, and the
+    /// exchange is appended to the cassette at `cassette_path` for next run.
+    ///
+    /// Point tests at an unreachable/fake `upstream` after recording once to
+    /// keep them hermetic - only requests that miss the cassette need it.
+    ///
+    /// Cassettes are meant to be committed, so recorded interactions are
+    /// scrubbed with [`CassetteRedaction::default`] before being written to
+    /// disk (stripping `authorization`/`cookie`/`set-cookie` response
+    /// headers). Use [`MockServer::start_with_cassette_redacting`] to
+    /// customize or extend that.
+    pub async fn start_with_cassette(upstream: impl Into<String>, cassette_path: impl Into<PathBuf>) -> Self {
+        Self::start_with_cassette_redacting(upstream, cassette_path, CassetteRedaction::default()).await
+    }
+
+    /// Like [`MockServer::start_with_cassette`], but with a custom
+    /// [`CassetteRedaction`] instead of the default header block-list -
+    /// e.g. to also redact an API key embedded in request/response bodies,
+    /// or to add another sensitive header.
+    pub async fn start_with_cassette_redacting(
+        upstream: impl Into<String>,
+        cassette_path: impl Into<PathBuf>,
+        redaction: CassetteRedaction,
+    ) -> Self {
+        let cassette_path = cassette_path.into();
+        let cassette = Cassette::load(&cassette_path);
+        let client = Client::builder(TokioExecutor::new()).build_http();
+
+        let (addr, state, shutdown_tx) = Self::spawn(
+            ServerState {
+                expectations: Vec::new(),
+                unmatched_requests: Vec::new(),
+                scenario_states: HashMap::new(),
+                proxy: Some(ProxyConfig {
+                    upstream: upstream.into(),
+                    cassette_path,
+                    client,
+                    redaction,
+                }),
+                cassette,
+            },
+            None,
+        )
+        .await;
+
+        Self {
+            addr,
+            state,
+            shutdown_tx: Some(shutdown_tx),
+            ca_cert_pem: None,
+        }
+    }
+
+    /// Start a mock server that terminates TLS with a freshly generated,
+    /// self-signed certificate valid for `localhost` and `127.0.0.1`.
+    ///
+    /// Configure the client under test to trust [`MockServer::ca_cert_pem`]
+    /// rather than disabling certificate verification, so the test still
+    /// exercises real TLS validation.
+    pub async fn start_https() -> Self {
+        let cert = super::tls::generate();
+
+        let (addr, state, shutdown_tx) = Self::spawn(
+            ServerState {
+                expectations: Vec::new(),
+                unmatched_requests: Vec::new(),
+                scenario_states: HashMap::new(),
+                proxy: None,
+                cassette: Cassette::default(),
+            },
+            Some(cert.server_config),
+        )
+        .await;
+
+        Self {
+            addr,
+            state,
+            shutdown_tx: Some(shutdown_tx),
+            ca_cert_pem: Some(cert.ca_cert_pem),
+        }
+    }
+
+    async fn spawn(
+        initial_state: ServerState,
+        tls_config: Option<Arc<rustls::ServerConfig>>,
+    ) -> (SocketAddr, Arc<Mutex<ServerState>>, oneshot::Sender<()>) {
         let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
         let addr = listener.local_addr().unwrap();
 
-        let state = Arc::new(Mutex::new(ServerState {
-            expectations: Vec::new(),
-            unmatched_requests: Vec::new(),
-        }));
+        let state = Arc::new(Mutex::new(initial_state));
 
         let state_clone = state.clone();
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let tls_acceptor = tls_config.map(TlsAcceptor::from);
 
         tokio::spawn(async move {
             let mut stop_future = shutdown_rx;
@@ -55,15 +215,32 @@ impl MockServer {
                     res = listener.accept() => {
                         match res {
                             Ok((stream, _)) => {
-                                let io = TokioIo::new(stream);
                                 let state = state_clone.clone();
+                                let tls_acceptor = tls_acceptor.clone();
 
                                 tokio::spawn(async move {
-                                    if let Err(err) = hyper_util::server::conn::auto::Builder::new(hyper_util::rt::TokioExecutor::new())
-                                        .serve_connection(io, service_fn(move |req| handle_request(req, state.clone())))
-                                        .await
-                                    {
-                                        eprintln!("Error serving connection: {:?}", err);
+                                    match tls_acceptor {
+                                        Some(acceptor) => match acceptor.accept(stream).await {
+                                            Ok(tls_stream) => {
+                                                let io = TokioIo::new(tls_stream);
+                                                if let Err(err) = hyper_util::server::conn::auto::Builder::new(hyper_util::rt::TokioExecutor::new())
+                                                    .serve_connection(io, service_fn(move |req| handle_request(req, state.clone())))
+                                                    .await
+                                                {
+                                                    eprintln!("Error serving connection: {:?}", err);
+                                                }
+                                            }
+                                            Err(err) => eprintln!("TLS handshake error: {:?}", err),
+                                        },
+                                        None => {
+                                            let io = TokioIo::new(stream);
+                                            if let Err(err) = hyper_util::server::conn::auto::Builder::new(hyper_util::rt::TokioExecutor::new())
+                                                .serve_connection(io, service_fn(move |req| handle_request(req, state.clone())))
+                                                .await
+                                            {
+                                                eprintln!("Error serving connection: {:?}", err);
+                                            }
+                                        }
                                     }
                                 });
                             }
@@ -77,16 +254,13 @@ impl MockServer {
             }
         });
 
-        Self {
-            addr,
-            state,
-            shutdown_tx: Some(shutdown_tx),
-        }
+        (addr, state, shutdown_tx)
     }
 
     /// Get the base URL of the server
     pub fn kind_url(&self) -> String {
-        format!("http://{}", self.addr)
+        let scheme = if self.ca_cert_pem.is_some() { "https" } else { "http" };
+        format!("{scheme}://{}", self.addr)
     }
 
     /// Alias for kind_url but more standard name
@@ -94,6 +268,13 @@ impl MockServer {
         self.kind_url()
     }
 
+    /// The PEM-encoded self-signed CA certificate for a [`MockServer::start_https`]
+    /// server, so a client under test can be configured to trust it. `None`
+    /// for servers started without TLS.
+    pub fn ca_cert_pem(&self) -> Option<&str> {
+        self.ca_cert_pem.as_deref()
+    }
+
     /// Get requests that didn't match any expectation
     pub fn unmatched_requests(&self) -> Vec<RecordedRequest> {
         let state = self.state.lock().unwrap();
@@ -101,44 +282,71 @@ impl MockServer {
     }
 
     /// Add an expectation
-    pub fn expect(&self, matcher: RequestMatcher) -> ExpectationBuilder {
+    pub fn expect(&self, matcher: impl Into<Matcher>) -> ExpectationBuilder {
         ExpectationBuilder {
             server: self.state.clone(),
-            expectation: Some(Expectation::new(matcher)),
+            expectation: Some(Expectation::new(matcher.into())),
         }
     }
 
-    /// Verify that all expectations were met
+    /// Verify that all expectations were met.
+    ///
+    /// On failure, the panic message names the unmet expectation and, for
+    /// every request that arrived but matched nothing, lists which of that
+    /// expectation's criteria it failed - a near-miss diff that's usually
+    /// enough to spot a typo'd path or missing header without a debugger.
     pub fn verify(&self) {
         let state = self.state.lock().unwrap();
         for exp in &state.expectations {
-            match exp.times {
-                Times::Once => assert_eq!(
-                    exp.call_count, 1,
-                    "Expectation {:?} expected 1 call, got {}",
-                    exp.matcher, exp.call_count
-                ),
-                Times::Exactly(n) => assert_eq!(
-                    exp.call_count, n,
-                    "Expectation {:?} expected {} calls, got {}",
-                    exp.matcher, n, exp.call_count
-                ),
-                Times::AtLeast(n) => assert!(
-                    exp.call_count >= n,
-                    "Expectation {:?} expected at least {} calls, got {}",
-                    exp.matcher,
-                    n,
-                    exp.call_count
-                ),
-                Times::AtMost(n) => assert!(
-                    exp.call_count <= n,
-                    "Expectation {:?} expected at most {} calls, got {}",
-                    exp.matcher,
-                    n,
-                    exp.call_count
-                ),
-                Times::Any => {}
+            let satisfied = match exp.times {
+                Times::Once => exp.call_count == 1,
+                Times::Exactly(n) => exp.call_count == n,
+                Times::AtLeast(n) => exp.call_count >= n,
+                Times::AtMost(n) => exp.call_count <= n,
+                Times::Any => true,
+            };
+            if satisfied {
+                continue;
             }
+
+            let expected = match exp.times {
+                Times::Once => "1 call".to_string(),
+                Times::Exactly(n) => format!("{n} call(s)"),
+                Times::AtLeast(n) => format!("at least {n} call(s)"),
+                Times::AtMost(n) => format!("at most {n} call(s)"),
+                Times::Any => "any number of calls".to_string(),
+            };
+
+            let mut message = format!(
+                "Expectation {:?} expected {expected}, got {}",
+                exp.matcher, exp.call_count
+            );
+
+            if state.unmatched_requests.is_empty() {
+                message.push_str("\n  no unmatched requests were recorded");
+            } else {
+                message.push_str("\n  unmatched requests recorded:");
+                for req in &state.unmatched_requests {
+                    let reasons = exp
+                        .matcher
+                        .mismatches(&req.method, &req.path, &req.headers, &req.body, &req.query);
+                    if reasons.is_empty() {
+                        message.push_str(&format!(
+                            "\n    {} {} - matches this expectation; it must have been consumed by another one",
+                            req.method, req.path
+                        ));
+                    } else {
+                        message.push_str(&format!(
+                            "\n    {} {}: {}",
+                            req.method,
+                            req.path,
+                            reasons.join("; ")
+                        ));
+                    }
+                }
+            }
+
+            panic!("{message}");
         }
     }
 }
@@ -158,8 +366,52 @@ pub struct ExpectationBuilder {
 
 impl ExpectationBuilder {
     pub fn respond_with(mut self, response: MockResponse) -> Self {
-        if let Some(exp) = self.expectation.as_mut() {
-            exp.response = response;
+        if let Some(exp) = self.expectation.take() {
+            self.expectation = Some(exp.respond_with(response));
+        }
+        self
+    }
+
+    /// Respond with a different response on each successive call - see
+    /// [`Expectation::respond_with_sequence`].
+    pub fn respond_with_sequence(mut self, responses: impl IntoIterator<Item = MockResponse>) -> Self {
+        if let Some(exp) = self.expectation.take() {
+            self.expectation = Some(exp.respond_with_sequence(responses));
+        }
+        self
+    }
+
+    /// Compute the response from the matched request - see
+    /// [`Expectation::respond_with_fn`].
+    pub fn respond_with_fn(mut self, f: impl Fn(&RequestContext) -> MockResponse + Send + Sync + 'static) -> Self {
+        if let Some(exp) = self.expectation.take() {
+            self.expectation = Some(exp.respond_with_fn(f));
+        }
+        self
+    }
+
+    /// Join a named scenario - see [`Expectation::in_scenario`].
+    pub fn in_scenario(mut self, scenario: impl Into<String>) -> Self {
+        if let Some(exp) = self.expectation.take() {
+            self.expectation = Some(exp.in_scenario(scenario));
+        }
+        self
+    }
+
+    /// Only match while the scenario is in `state` - see
+    /// [`Expectation::when_state`].
+    pub fn when_state(mut self, state: impl Into<String>) -> Self {
+        if let Some(exp) = self.expectation.take() {
+            self.expectation = Some(exp.when_state(state));
+        }
+        self
+    }
+
+    /// Transition the scenario to `state` after this expectation matches -
+    /// see [`Expectation::set_state`].
+    pub fn set_state(mut self, state: impl Into<String>) -> Self {
+        if let Some(exp) = self.expectation.take() {
+            self.expectation = Some(exp.set_state(state));
         }
         self
     }
@@ -191,6 +443,15 @@ impl ExpectationBuilder {
         }
         self
     }
+
+    /// Don't require this expectation to be called any particular number of
+    /// times - see [`Expectation::any_number_of_times`].
+    pub fn any_number_of_times(mut self) -> Self {
+        if let Some(exp) = self.expectation.as_mut() {
+            exp.times = Times::Any;
+        }
+        self
+    }
 }
 
 impl Drop for ExpectationBuilder {
@@ -202,52 +463,204 @@ impl Drop for ExpectationBuilder {
     }
 }
 
+/// What matching a request against expectations, the cassette, and the
+/// proxy config (in that order) resolved to.
+enum Resolved {
+    Local(MockResponse),
+    Proxy,
+}
+
 async fn handle_request(
     req: Request<hyper::body::Incoming>,
     state: Arc<Mutex<ServerState>>,
-) -> Result<Response<Full<Bytes>>> {
+) -> Result<Response<MockBody>> {
     // Read the full body
     let (parts, body) = req.into_parts();
     let body_bytes = body.collect().await?.to_bytes();
+    let path = parts.uri.path().to_string();
+    let query = parts.uri.query().unwrap_or("").to_string();
+
+    // Resolve the match (and record it) while holding the lock, but don't
+    // hold it across the `.await`s below for delay/throttle injection or
+    // proxying.
+    let resolved = {
+        let mut state_guard = state.lock().unwrap();
+
+        // Find matching expectation
+        // We iterate in reverse to prioritize later expectations (override)
+        let matching_idx = state_guard
+            .expectations
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, exp)| {
+                exp.matcher.matches(&parts.method, &path, &parts.headers, &body_bytes, &query)
+                    && exp.scenario_satisfied(&state_guard.scenario_states)
+            })
+            .map(|(i, _)| i);
+
+        match matching_idx {
+            Some(idx) => {
+                let ctx = RequestContext {
+                    method: parts.method.clone(),
+                    path: path.clone(),
+                    query: query.clone(),
+                    headers: parts.headers.clone(),
+                    body: body_bytes.clone(),
+                };
+                let exp = &mut state_guard.expectations[idx];
+                let resp_def = exp.resolve_response(exp.call_count, &ctx);
+                exp.call_count += 1;
+
+                if let (Some(scenario), Some(new_state)) =
+                    (exp.scenario.clone(), exp.new_state.clone())
+                {
+                    state_guard.scenario_states.insert(scenario, new_state);
+                }
 
-    let mut state_guard = state.lock().unwrap();
+                Resolved::Local(resp_def)
+            }
+            None => match state_guard.cassette.find(&parts.method, &path, &query, &body_bytes) {
+                Some(interaction) => Resolved::Local(interaction.to_mock_response()),
+                None if state_guard.proxy.is_some() => Resolved::Proxy,
+                None => {
+                    state_guard.unmatched_requests.push(RecordedRequest {
+                        method: parts.method.clone(),
+                        path: path.clone(),
+                        query: query.clone(),
+                        headers: parts.headers.clone(),
+                        body: body_bytes.clone(),
+                    });
+                    return Ok(Response::builder()
+                        .status(StatusCode::NOT_FOUND)
+                        .body(full_body(Bytes::from("No expectation matched")))?);
+                }
+            },
+        }
+    };
 
-    // Find matching expectation
-    // We iterate in reverse to prioritize later expectations (override)
-    let matching_idx = state_guard
-        .expectations
-        .iter()
-        .enumerate()
-        .rev()
-        .find(|(_, exp)| {
-            exp.matcher
-                .matches(&parts.method, parts.uri.path(), &parts.headers, &body_bytes)
-        })
-        .map(|(i, _)| i);
+    let resp_def = match resolved {
+        Resolved::Local(resp_def) => resp_def,
+        Resolved::Proxy => forward_and_record(&state, &parts.method, &path, &query, &parts.headers, &body_bytes).await?,
+    };
 
-    if let Some(idx) = matching_idx {
-        let exp = &mut state_guard.expectations[idx];
-        exp.call_count += 1;
+    if let Some(delay) = resp_def.delay {
+        tokio::time::sleep(delay).await;
+    }
 
-        let resp_def = &exp.response;
-        let mut response = Response::builder().status(resp_def.status);
+    if resp_def.fault == Some(Fault::DroppedConnection) {
+        return Err("mock: dropped_connection fault injected".into());
+    }
 
-        for (k, v) in &resp_def.headers {
-            response = response.header(k, v);
-        }
+    let mut response = Response::builder().status(resp_def.status);
+    for (k, v) in &resp_def.headers {
+        response = response.header(k, v);
+    }
+
+    if resp_def.fault == Some(Fault::MalformedBody) {
+        // Lie about the body's length so clients see a truncated/corrupt
+        // response rather than a clean one.
+        response = response.header(
+            http::header::CONTENT_LENGTH,
+            (resp_def.body.len() + 4096).to_string(),
+        );
+    }
+
+    let body = match resp_def.throttle_bytes_per_sec {
+        Some(bytes_per_sec) => throttled_body(resp_def.body, bytes_per_sec),
+        None => full_body(resp_def.body),
+    };
+
+    Ok(response.body(body)?)
+}
 
-        Ok(response.body(Full::new(resp_def.body.clone()))?)
+/// Forward a request that missed both expectations and the cassette to the
+/// configured upstream, append the exchange to the cassette, and persist it
+/// to disk - see [`MockServer::start_with_cassette`].
+async fn forward_and_record(
+    state: &Arc<Mutex<ServerState>>,
+    method: &hyper::Method,
+    path: &str,
+    query: &str,
+    headers: &hyper::HeaderMap,
+    body: &Bytes,
+) -> Result<MockResponse> {
+    let (client, upstream, redaction) = {
+        let state_guard = state.lock().unwrap();
+        let proxy = state_guard
+            .proxy
+            .as_ref()
+            .expect("forward_and_record is only called when proxy mode is enabled");
+        (proxy.client.clone(), proxy.upstream.clone(), proxy.redaction.clone())
+    };
+
+    let uri = if query.is_empty() {
+        format!("{upstream}{path}")
     } else {
-        // Record unmatched
-        state_guard.unmatched_requests.push(RecordedRequest {
-            method: parts.method,
-            path: parts.uri.path().to_string(),
-            headers: parts.headers,
-            body: body_bytes,
-        });
+        format!("{upstream}{path}?{query}")
+    };
+
+    let mut upstream_req = Request::builder().method(method.clone()).uri(uri);
+    for (k, v) in headers {
+        upstream_req = upstream_req.header(k, v);
+    }
+    let upstream_req = upstream_req.body(Full::new(body.clone()))?;
 
-        Ok(Response::builder()
-            .status(StatusCode::NOT_FOUND)
-            .body(Full::new(Bytes::from("No expectation matched")))?)
+    let upstream_resp = client.request(upstream_req).await?;
+    let status = upstream_resp.status();
+
+    let mut response_headers = Vec::new();
+    for (k, v) in upstream_resp.headers() {
+        // Framing headers don't survive re-buffering the body; let hyper set
+        // its own when we rebuild the response.
+        if matches!(k.as_str(), "transfer-encoding" | "connection" | "content-length") {
+            continue;
+        }
+        if let Ok(value) = v.to_str() {
+            response_headers.push((k.to_string(), value.to_string()));
+        }
     }
+
+    let response_body = upstream_resp.into_body().collect().await?.to_bytes();
+
+    let interaction = RecordedInteraction::redacted(
+        method.as_str(),
+        path,
+        query,
+        body,
+        status.as_u16(),
+        &response_headers,
+        &response_body,
+        &redaction,
+    );
+
+    let (cassette, cassette_path) = {
+        let mut state_guard = state.lock().unwrap();
+        state_guard.cassette.record(interaction);
+        let cassette_path = state_guard
+            .proxy
+            .as_ref()
+            .expect("forward_and_record is only called when proxy mode is enabled")
+            .cassette_path
+            .clone();
+        (state_guard.cassette.clone(), cassette_path)
+    };
+
+    // Persist outside the lock; a slow or failing disk write shouldn't hold
+    // up other requests, and losing this write just means re-recording.
+    if let Err(e) = cassette.save(&cassette_path) {
+        eprintln!("Failed to save cassette to {}: {}", cassette_path.display(), e);
+    }
+
+    let mut response_header_map = http::HeaderMap::new();
+    for (k, v) in &response_headers {
+        if let (Ok(name), Ok(value)) = (
+            http::header::HeaderName::from_bytes(k.as_bytes()),
+            http::header::HeaderValue::from_str(v),
+        ) {
+            response_header_map.insert(name, value);
+        }
+    }
+
+    Ok(MockResponse::from_parts(status, response_header_map, response_body))
 }
@@ -0,0 +1,127 @@
+//! Review-on-change snapshot testing - see [`assert_snapshot`] and
+//! [`assert_snapshot_json`]. Snapshots live in a `snapshots/` directory next
+//! to the crate's `Cargo.toml`, one plain-text file per snapshot name. A
+//! missing or changed snapshot fails the test; re-run with
+//! `UPDATE_SNAPSHOTS=1` to accept the new content, then review it with
+//! `git diff` before committing. This is a homegrown, dependency-free take
+//! on `insta`'s workflow, scoped to what this crate needs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn snapshot_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots").join(format!("{name}.snap"))
+}
+
+fn write_snapshot(path: &Path, content: &str) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("failed to create snapshots directory");
+    }
+    fs::write(path, content).expect("failed to write snapshot");
+}
+
+/// Assert that `content` matches the stored snapshot named `name`.
+///
+/// # Panics
+///
+/// Panics if the snapshot doesn't exist yet, or exists and doesn't match
+/// `content`, unless the `UPDATE_SNAPSHOTS` environment variable is set - in
+/// which case the snapshot is (re)written instead.
+pub fn assert_snapshot(name: &str, content: &str) {
+    let path = snapshot_path(name);
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+
+    if let Ok(existing) = fs::read_to_string(&path) {
+        if existing == content {
+            return;
+        }
+        if update {
+            write_snapshot(&path, content);
+            return;
+        }
+        panic!(
+            "snapshot '{name}' changed - re-run with UPDATE_SNAPSHOTS=1 to accept, then review with `git diff`\n--- stored ---\n{existing}\n--- actual ---\n{content}"
+        );
+    }
+
+    write_snapshot(&path, content);
+    if !update {
+        panic!("new snapshot '{name}' written to {} - review it, then re-run to accept", path.display());
+    }
+}
+
+/// Assert that `value`, serialized as pretty JSON, matches the stored
+/// snapshot named `name` - handy for [`rustapi_openapi::OpenApiSpec`] or any
+/// other response/spec type that implements `Serialize`.
+pub fn assert_snapshot_json<T: serde::Serialize>(name: &str, value: &T) {
+    let json = serde_json::to_string_pretty(value).expect("snapshot value must be serializable");
+    assert_snapshot(name, &json);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // assert_snapshot reads/writes real files and reacts to a process-wide
+    // env var, so these tests can't run concurrently with each other.
+    static SNAPSHOT_TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    fn with_temp_snapshot(name: &str, body: impl FnOnce() + std::panic::UnwindSafe) {
+        let _guard = SNAPSHOT_TEST_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let path = snapshot_path(name);
+        let _ = fs::remove_file(&path);
+        std::env::remove_var("UPDATE_SNAPSHOTS");
+
+        let result = std::panic::catch_unwind(body);
+
+        let _ = fs::remove_file(&path);
+        if let Err(panic) = result {
+            std::panic::resume_unwind(panic);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "new snapshot")]
+    fn test_assert_snapshot_panics_and_writes_when_missing() {
+        with_temp_snapshot("test_assert_snapshot_panics_and_writes_when_missing", || {
+            assert_snapshot("test_assert_snapshot_panics_and_writes_when_missing", "hello");
+        });
+    }
+
+    #[test]
+    fn test_assert_snapshot_passes_once_accepted() {
+        with_temp_snapshot("test_assert_snapshot_passes_once_accepted", || {
+            std::env::set_var("UPDATE_SNAPSHOTS", "1");
+            assert_snapshot("test_assert_snapshot_passes_once_accepted", "hello");
+            std::env::remove_var("UPDATE_SNAPSHOTS");
+
+            assert_snapshot("test_assert_snapshot_passes_once_accepted", "hello");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "changed")]
+    fn test_assert_snapshot_panics_on_mismatch() {
+        with_temp_snapshot("test_assert_snapshot_panics_on_mismatch", || {
+            std::env::set_var("UPDATE_SNAPSHOTS", "1");
+            assert_snapshot("test_assert_snapshot_panics_on_mismatch", "hello");
+            std::env::remove_var("UPDATE_SNAPSHOTS");
+
+            assert_snapshot("test_assert_snapshot_panics_on_mismatch", "goodbye");
+        });
+    }
+
+    #[test]
+    fn test_assert_snapshot_json_serializes_value() {
+        with_temp_snapshot("test_assert_snapshot_json_serializes_value", || {
+            std::env::set_var("UPDATE_SNAPSHOTS", "1");
+            assert_snapshot_json("test_assert_snapshot_json_serializes_value", &serde_json::json!({ "a": 1 }));
+            std::env::remove_var("UPDATE_SNAPSHOTS");
+
+            let path = snapshot_path("test_assert_snapshot_json_serializes_value");
+            let contents = fs::read_to_string(path).unwrap();
+            assert_eq!(contents, "{\n  \"a\": 1\n}");
+        });
+    }
+}
@@ -0,0 +1,33 @@
+//! Self-signed TLS material for `MockServer::start_https` - see
+//! [`crate::server::MockServer::start_https`].
+
+use rustls::pki_types::{CertificateDer, PrivatePkcs8KeyDer};
+use std::sync::Arc;
+
+/// A freshly generated self-signed certificate, ready to terminate TLS, plus
+/// its PEM so clients can add it as a trusted root.
+pub(crate) struct SelfSignedCert {
+    pub(crate) server_config: Arc<rustls::ServerConfig>,
+    pub(crate) ca_cert_pem: String,
+}
+
+/// Generate a self-signed certificate valid for `localhost` and `127.0.0.1`.
+pub(crate) fn generate() -> SelfSignedCert {
+    let rcgen::CertifiedKey { cert, key_pair } =
+        rcgen::generate_simple_self_signed(vec!["localhost".to_string(), "127.0.0.1".to_string()])
+            .expect("self-signed cert generation shouldn't fail for a fixed SAN list");
+
+    let ca_cert_pem = cert.pem();
+    let cert_der = CertificateDer::from(cert.der().to_vec());
+    let key_der = PrivatePkcs8KeyDer::from(key_pair.serialize_der());
+
+    let server_config = rustls::ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(vec![cert_der], key_der.into())
+        .expect("a freshly generated self-signed cert and key are always valid together");
+
+    SelfSignedCert {
+        server_config: Arc::new(server_config),
+        ca_cert_pem,
+    }
+}
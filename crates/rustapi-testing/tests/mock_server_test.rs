@@ -1,6 +1,8 @@
 use http::{Method, StatusCode};
+use rustapi_openapi::{MediaType, OpenApiSpec, Operation, Parameter, RequestBody, ResponseSpec, SchemaRef};
 use rustapi_testing::{MockResponse, MockServer, RequestMatcher};
 use serde_json::json;
+use std::collections::HashMap;
 
 #[tokio::test]
 async fn test_mock_server_basics() {
@@ -88,3 +90,407 @@ async fn test_verification_failure() {
     // No call made
     server.verify(); // Should panic
 }
+
+#[tokio::test]
+async fn test_response_sequence() {
+    let server = MockServer::start().await;
+
+    server.expect(RequestMatcher::new().path("/flaky")).respond_with_sequence([
+        MockResponse::new().status(StatusCode::INTERNAL_SERVER_ERROR),
+        MockResponse::new().status(StatusCode::OK),
+    ]);
+
+    let client = reqwest::Client::new();
+    let url = format!("{}/flaky", server.base_url());
+
+    let first = client.get(&url).send().await.unwrap();
+    assert_eq!(first.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let second = client.get(&url).send().await.unwrap();
+    assert_eq!(second.status(), StatusCode::OK);
+
+    // Sequence exhausted - the last response repeats.
+    let third = client.get(&url).send().await.unwrap();
+    assert_eq!(third.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_scenario_state_transitions() {
+    let server = MockServer::start().await;
+
+    server
+        .expect(RequestMatcher::new().method(Method::POST).path("/login"))
+        .in_scenario("auth")
+        .when_state(rustapi_testing::SCENARIO_STARTED)
+        .set_state("logged-in")
+        .respond_with(MockResponse::new().status(StatusCode::OK));
+
+    server
+        .expect(RequestMatcher::new().method(Method::GET).path("/profile"))
+        .in_scenario("auth")
+        .when_state("logged-in")
+        .respond_with(MockResponse::new().status(StatusCode::OK).body("secret profile"));
+
+    let client = reqwest::Client::new();
+
+    // Before logging in, the profile expectation isn't active yet.
+    let resp = client
+        .get(format!("{}/profile", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    client
+        .post(format!("{}/login", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+
+    let resp = client
+        .get(format!("{}/profile", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.text().await.unwrap(), "secret profile");
+}
+
+#[tokio::test]
+async fn test_delay_injection() {
+    let server = MockServer::start().await;
+
+    server
+        .expect(RequestMatcher::new().path("/slow"))
+        .respond_with(MockResponse::new().delay(std::time::Duration::from_millis(200)));
+
+    let client = reqwest::Client::new();
+    let start = std::time::Instant::now();
+    client
+        .get(format!("{}/slow", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+
+    assert!(start.elapsed() >= std::time::Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn test_dropped_connection_injection() {
+    let server = MockServer::start().await;
+
+    server
+        .expect(RequestMatcher::new().path("/gone"))
+        .respond_with(MockResponse::new().dropped_connection());
+
+    let client = reqwest::Client::new();
+    let result = client
+        .get(format!("{}/gone", server.base_url()))
+        .send()
+        .await;
+
+    assert!(result.is_err(), "expected the connection to be dropped");
+}
+
+#[tokio::test]
+async fn test_regex_path_and_query_param_matching() {
+    let server = MockServer::start().await;
+
+    server
+        .expect(
+            RequestMatcher::new()
+                .method(Method::GET)
+                .path_regex(r"^/users/\d+$")
+                .query_param("verbose", "true"),
+        )
+        .respond_with(MockResponse::new().body("matched"));
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .get(format!("{}/users/42?verbose=true", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.text().await.unwrap(), "matched");
+
+    let resp = client
+        .get(format!("{}/users/42", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let resp = client
+        .get(format!("{}/users/abc?verbose=true", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+#[should_panic(expected = "path: expected \"/must-call\", got \"/wrong-path\"")]
+async fn test_verification_failure_includes_near_miss_diff() {
+    let server = MockServer::start().await;
+
+    server
+        .expect(RequestMatcher::new().path("/must-call"))
+        .once();
+
+    let client = reqwest::Client::new();
+    client
+        .get(format!("{}/wrong-path", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+
+    server.verify(); // Should panic, naming the near miss
+}
+
+#[tokio::test]
+async fn test_proxy_records_then_replays_from_cassette() {
+    let upstream = MockServer::start().await;
+    upstream
+        .expect(RequestMatcher::new().method(Method::GET).path("/quote"))
+        .respond_with(MockResponse::new().status(StatusCode::OK).body("42"));
+
+    let cassette_path = std::env::temp_dir().join(format!(
+        "rustapi-testing-cassette-{}.json",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&cassette_path);
+
+    // First run: nothing on the cassette yet, so the request is forwarded to
+    // the real upstream and recorded.
+    {
+        let proxy = MockServer::start_with_cassette(upstream.base_url(), &cassette_path).await;
+        let client = reqwest::Client::new();
+        let resp = client
+            .get(format!("{}/quote", proxy.base_url()))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.text().await.unwrap(), "42");
+    }
+
+    assert!(cassette_path.exists());
+
+    // Second run: point the proxy at an upstream that would fail any real
+    // request, proving the response came from the cassette instead.
+    {
+        let proxy = MockServer::start_with_cassette("http://127.0.0.1:1", &cassette_path).await;
+        let client = reqwest::Client::new();
+        let resp = client
+            .get(format!("{}/quote", proxy.base_url()))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.text().await.unwrap(), "42");
+    }
+
+    let _ = std::fs::remove_file(&cassette_path);
+}
+
+#[tokio::test]
+async fn test_cassette_redacts_set_cookie_header_by_default() {
+    let upstream = MockServer::start().await;
+    upstream
+        .expect(RequestMatcher::new().method(Method::GET).path("/login"))
+        .respond_with(
+            MockResponse::new()
+                .status(StatusCode::OK)
+                .header("set-cookie", "session=super-secret-token"),
+        );
+
+    let cassette_path = std::env::temp_dir().join(format!(
+        "rustapi-testing-cassette-redact-{}.json",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&cassette_path);
+
+    {
+        let proxy = MockServer::start_with_cassette(upstream.base_url(), &cassette_path).await;
+        let client = reqwest::Client::new();
+        let resp = client
+            .get(format!("{}/login", proxy.base_url()))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    let cassette_contents = std::fs::read_to_string(&cassette_path).unwrap();
+    assert!(!cassette_contents.contains("super-secret-token"));
+    assert!(cassette_contents.contains("REDACTED"));
+
+    let _ = std::fs::remove_file(&cassette_path);
+}
+
+#[tokio::test]
+async fn test_https_server_uses_trusted_self_signed_cert() {
+    let server = MockServer::start_https().await;
+    assert!(server.base_url().starts_with("https://"));
+
+    server
+        .expect(RequestMatcher::new().method(Method::GET).path("/secure"))
+        .respond_with(MockResponse::new().body("locked down"));
+
+    let ca_cert = reqwest::Certificate::from_pem(server.ca_cert_pem().unwrap().as_bytes()).unwrap();
+    let client = reqwest::Client::builder()
+        .add_root_certificate(ca_cert)
+        .build()
+        .unwrap();
+
+    let resp = client
+        .get(format!("{}/secure", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.text().await.unwrap(), "locked down");
+
+    server.verify();
+}
+
+#[tokio::test]
+async fn test_dynamic_response_echoes_request() {
+    let server = MockServer::start().await;
+
+    server
+        .expect(RequestMatcher::new().method(Method::GET).path_regex(r"^/users/\d+$"))
+        .respond_with_fn(|ctx| {
+            MockResponse::new().json(json!({
+                "id": ctx.last_path_segment(),
+                "verbose": ctx.query_param("verbose"),
+            }))
+        });
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .get(format!("{}/users/42?verbose=true", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body, json!({"id": "42", "verbose": "true"}));
+
+    let resp = client
+        .get(format!("{}/users/7", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body, json!({"id": "7", "verbose": null}));
+}
+
+#[tokio::test]
+async fn test_from_openapi_generates_and_validates_operations() {
+    let mut list_users = Operation::new();
+    list_users.parameters = Some(vec![Parameter {
+        name: "limit".to_string(),
+        location: "query".to_string(),
+        required: true,
+        description: None,
+        schema: SchemaRef::Inline(json!({"type": "integer"})),
+    }]);
+    list_users.responses = HashMap::from([(
+        "200".to_string(),
+        ResponseSpec {
+            description: "A page of users".to_string(),
+            content: Some(HashMap::from([(
+                "application/json".to_string(),
+                MediaType {
+                    schema: SchemaRef::Inline(json!({
+                        "type": "array",
+                        "items": {"$ref": "#/components/schemas/User"},
+                    })),
+                },
+            )])),
+        },
+    )]);
+
+    let mut create_user = Operation::new();
+    create_user.request_body = Some(RequestBody {
+        required: true,
+        content: HashMap::from([(
+            "application/json".to_string(),
+            MediaType {
+                schema: SchemaRef::Ref {
+                    reference: "#/components/schemas/User".to_string(),
+                },
+            },
+        )]),
+    });
+    create_user.responses = HashMap::from([(
+        "201".to_string(),
+        ResponseSpec {
+            description: "Created".to_string(),
+            content: Some(HashMap::from([(
+                "application/json".to_string(),
+                MediaType {
+                    schema: SchemaRef::Ref {
+                        reference: "#/components/schemas/User".to_string(),
+                    },
+                },
+            )])),
+        },
+    )]);
+
+    let spec = OpenApiSpec::new("Users API", "1.0")
+        .schema(
+            "User",
+            json!({
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "id": {"type": "integer"},
+                    "name": {"type": "string", "example": "Alice"},
+                },
+            }),
+        )
+        .path("/users", "GET", list_users)
+        .path("/users", "POST", create_user);
+
+    let server = MockServer::from_openapi(&spec).await;
+    let client = reqwest::Client::new();
+
+    // Missing the required `limit` query param -> 400.
+    let resp = client.get(format!("{}/users", server.base_url())).send().await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // Present -> a schema-conformant example array.
+    let resp = client
+        .get(format!("{}/users?limit=10", server.base_url()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body, json!([{"id": 0, "name": "Alice"}]));
+
+    // Missing the required `name` field in the body -> 400.
+    let resp = client
+        .post(format!("{}/users", server.base_url()))
+        .json(&json!({}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // A valid body -> the example response for the declared 201.
+    let resp = client
+        .post(format!("{}/users", server.base_url()))
+        .json(&json!({"name": "Bob"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body, json!({"id": 0, "name": "Alice"}));
+}
@@ -78,12 +78,39 @@
 //!
 //! - Request: `application/toon` or `text/toon`
 //! - Response: `application/toon`
+//!
+//! ## Token Counting
+//!
+//! [`LlmResponse`]'s `X-Token-Count-*`/`X-Token-Savings` headers use a
+//! ~4-chars-per-token heuristic by default. Enable the `tiktoken` feature and
+//! call `LlmResponse::with_tokenizer` with [`TiktokenCounter`] for real BPE
+//! token counts.
+//!
+//! ## Streaming Large Arrays
+//!
+//! [`encode`]/[`encode_default`] build the whole array in memory before
+//! rendering it to a `String`. For multi-MB LLM payloads, [`StreamEncoder`]
+//! writes tabular or primitive arrays row-by-row directly to a
+//! [`std::io::Write`] sink instead — see its docs for the row-count and
+//! shape-detection tradeoffs that come with streaming a format whose header
+//! carries the row count up front.
+//!
+//! ## Custom Format Negotiation
+//!
+//! [`Negotiate<T>`] only chooses between JSON and TOON. [`FormatRegistry`]
+//! generalizes that to any number of formats (MsgPack, CBOR, XML, CSV, ...)
+//! registered with a media type and an encoder, selected via full q-value
+//! `Accept` header negotiation with an explicit default; [`Negotiated<T>`]
+//! is the response type that renders through a registry.
 
 mod error;
 mod extractor;
 mod llm_response;
 mod negotiate;
 mod openapi;
+mod registry;
+mod stream;
+mod token_counter;
 
 pub use error::ToonError;
 pub use extractor::Toon;
@@ -95,6 +122,11 @@ pub use openapi::{
     api_description_with_toon, format_comparison_example, token_headers_schema, toon_extension,
     toon_schema, TOON_FORMAT_DESCRIPTION,
 };
+pub use registry::{Format, FormatRegistry, Negotiated};
+pub use stream::StreamEncoder;
+pub use token_counter::{HeuristicTokenCounter, TokenCounter};
+#[cfg(feature = "tiktoken")]
+pub use token_counter::TiktokenCounter;
 
 // Re-export toon-format types for advanced usage
 pub use toon_format::{
@@ -34,7 +34,8 @@
 //! }
 //! ```
 
-use crate::{OutputFormat, JSON_CONTENT_TYPE, TOON_CONTENT_TYPE};
+use crate::token_counter::HeuristicTokenCounter;
+use crate::{OutputFormat, TokenCounter, JSON_CONTENT_TYPE, TOON_CONTENT_TYPE};
 use bytes::Bytes;
 use http::{header, StatusCode};
 use http_body_util::Full;
@@ -44,6 +45,7 @@ use rustapi_openapi::{
 };
 use serde::Serialize;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Header name for JSON token count
 pub const X_TOKEN_COUNT_JSON: &str = "x-token-count-json";
@@ -61,12 +63,13 @@ pub const X_FORMAT_USED: &str = "x-format-used";
 /// 2. Calculates estimated token counts for both formats
 /// 3. Adds informative headers about token usage
 ///
-/// ## Token Estimation
+/// ## Token Counting
 ///
-/// Token counts are estimated using a simple heuristic:
-/// - ~4 characters per token (GPT-3/4 average)
-///
-/// For more accurate counts, use a proper tokenizer.
+/// By default, token counts are estimated with a ~4-characters-per-token
+/// heuristic ([`HeuristicTokenCounter`](crate::HeuristicTokenCounter)). For
+/// numbers a cost dashboard can actually bill against, plug in a real
+/// tokenizer with [`LlmResponse::with_tokenizer`] -- e.g.
+/// [`TiktokenCounter`](crate::TiktokenCounter) behind the `tiktoken` feature.
 ///
 /// ## Example
 ///
@@ -96,6 +99,7 @@ pub struct LlmResponse<T> {
     data: T,
     format: OutputFormat,
     include_token_headers: bool,
+    tokenizer: Arc<dyn TokenCounter>,
 }
 
 impl<T> LlmResponse<T> {
@@ -105,6 +109,7 @@ impl<T> LlmResponse<T> {
             data,
             format,
             include_token_headers: true,
+            tokenizer: Arc::new(HeuristicTokenCounter),
         }
     }
 
@@ -129,15 +134,13 @@ impl<T> LlmResponse<T> {
         self.include_token_headers = true;
         self
     }
-}
 
-/// Estimate token count using simple character-based heuristic.
-/// ~4 characters per token (GPT-3/4 average)
-fn estimate_tokens(text: &str) -> usize {
-    // Simple heuristic: ~4 chars per token
-    // Accounts for whitespace and punctuation overhead
-    let char_count = text.len();
-    char_count.div_ceil(4) // Round up
+    /// Use `tokenizer` instead of the default character heuristic to compute
+    /// the `X-Token-Count-*`/`X-Token-Savings` headers.
+    pub fn with_tokenizer(mut self, tokenizer: impl TokenCounter + 'static) -> Self {
+        self.tokenizer = Arc::new(tokenizer);
+        self
+    }
 }
 
 /// Calculate token savings percentage.
@@ -159,11 +162,11 @@ impl<T: Serialize> IntoResponse for LlmResponse<T> {
         let (json_tokens, toon_tokens, savings) = if self.include_token_headers {
             let json_tokens = json_result
                 .as_ref()
-                .map(|s| estimate_tokens(s))
+                .map(|s| self.tokenizer.count_tokens(s))
                 .unwrap_or(0);
             let toon_tokens = toon_result
                 .as_ref()
-                .map(|s| estimate_tokens(s))
+                .map(|s| self.tokenizer.count_tokens(s))
                 .unwrap_or(0);
             let savings = calculate_savings(json_tokens, toon_tokens);
             (Some(json_tokens), Some(toon_tokens), Some(savings))
@@ -272,12 +275,13 @@ mod tests {
     }
 
     #[test]
-    fn test_estimate_tokens() {
+    fn test_heuristic_token_counter() {
         // ~4 chars per token
-        assert_eq!(estimate_tokens(""), 0);
-        assert_eq!(estimate_tokens("test"), 1); // 4 chars = 1 token
-        assert_eq!(estimate_tokens("hello world"), 3); // 11 chars = ~3 tokens
-        assert_eq!(estimate_tokens("a"), 1); // rounds up
+        let counter = HeuristicTokenCounter;
+        assert_eq!(counter.count_tokens(""), 0);
+        assert_eq!(counter.count_tokens("test"), 1); // 4 chars = 1 token
+        assert_eq!(counter.count_tokens("hello world"), 3); // 11 chars = ~3 tokens
+        assert_eq!(counter.count_tokens("a"), 1); // rounds up
     }
 
     #[test]
@@ -333,4 +337,23 @@ mod tests {
             .with_token_headers();
         assert!(response.include_token_headers);
     }
+
+    #[derive(Debug)]
+    struct FixedTokenCounter;
+    impl TokenCounter for FixedTokenCounter {
+        fn count_tokens(&self, _text: &str) -> usize {
+            42
+        }
+    }
+
+    #[test]
+    fn test_llm_response_with_custom_tokenizer() {
+        let data = TestData {
+            id: 1,
+            name: "Test".to_string(),
+            active: true,
+        };
+        let response = LlmResponse::json(data).with_tokenizer(FixedTokenCounter);
+        assert_eq!(response.tokenizer.count_tokens("anything"), 42);
+    }
 }
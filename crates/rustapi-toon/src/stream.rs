@@ -0,0 +1,296 @@
+//! Streaming TOON encoder for large, homogeneous arrays.
+//!
+//! [`encode`](crate::encode)/[`encode_default`](crate::encode_default) build
+//! the entire array as one `serde_json::Value` tree first and then render
+//! the whole output as one `String` — for a multi-MB array of rows that's
+//! two full copies of the payload alive at once. [`StreamEncoder`] writes
+//! the TOON tabular/primitive-array form directly to any [`std::io::Write`],
+//! one row at a time, so only a single row is ever materialized.
+//!
+//! TOON's array header (`users[2]{id,name}:`) carries the row count up
+//! front, so streaming still needs to know the length before the first row
+//! is written — callers pass it explicitly (a `Vec::len()`, a `COUNT(*)`,
+//! a page total, etc). Tabular shape is auto-detected the same way
+//! `toon_format::encode` decides it — from the row's keys and whether its
+//! values are all primitives — but only the *first* row is inspected: a
+//! `T: Serialize` struct source is a safe fit for this since its fields
+//! never vary between instances, but a stream of loosely-typed
+//! `serde_json::Value` rows with drifting shape will produce output whose
+//! header no longer matches later rows. [`StreamEncoder::write_array`]
+//! guards against this by counting the rows it actually wrote against the
+//! declared length and erroring on mismatch, but it cannot detect a later
+//! row silently gaining or losing a field of the same count.
+
+use crate::error::ToonError;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::Write;
+use toon_format::utils::{is_valid_unquoted_key, needs_quoting, quote_string};
+use toon_format::{Delimiter, EncodeOptions};
+
+/// Writes TOON-encoded arrays directly to a [`std::io::Write`] sink, one row
+/// at a time.
+///
+/// See the [module docs](self) for why callers must know the row count up
+/// front and for the tabular-shape auto-detection caveat.
+pub struct StreamEncoder<W: Write> {
+    sink: W,
+    options: EncodeOptions,
+}
+
+impl<W: Write> StreamEncoder<W> {
+    /// Create an encoder with the default TOON options (2-space indent, comma delimiter).
+    pub fn new(sink: W) -> Self {
+        Self::with_options(sink, EncodeOptions::default())
+    }
+
+    /// Create an encoder with custom [`EncodeOptions`] (e.g. a different delimiter).
+    pub fn with_options(sink: W, options: EncodeOptions) -> Self {
+        Self { sink, options }
+    }
+
+    /// Stream an array of exactly `len` rows to the sink, auto-detecting
+    /// whether they form a tabular array of uniform primitive-valued
+    /// objects (`key[len]{f1,f2}:`) or a plain primitive array
+    /// (`key[len]: a,b,c`) from the shape of the first row.
+    ///
+    /// `key` is `None` for a top-level array with no wrapping field name.
+    /// Returns [`ToonError::Encode`] if `rows` yields more or fewer than
+    /// `len` items, or if a row isn't representable in TOON (e.g. a nested
+    /// array/object inside what the first row established as a primitive
+    /// array).
+    pub fn write_array<T, I>(&mut self, key: Option<&str>, len: usize, rows: I) -> Result<(), ToonError>
+    where
+        T: Serialize,
+        I: IntoIterator<Item = T>,
+    {
+        let mut rows = rows.into_iter();
+
+        if len == 0 {
+            self.write_array_header(key, 0, None)?;
+            return Ok(());
+        }
+
+        let first = rows
+            .next()
+            .ok_or_else(|| ToonError::Encode(format!("expected {len} rows, got 0")))?;
+        let first = to_value(&first)?;
+
+        let mut written = 1usize;
+        match tabular_fields(&first) {
+            Some(fields) => {
+                self.write_array_header(key, len, Some(&fields))?;
+                self.newline()?;
+                self.write_row_indent()?;
+                self.write_tabular_row(&fields, &first)?;
+                for row in rows {
+                    let value = to_value(&row)?;
+                    self.newline()?;
+                    self.write_row_indent()?;
+                    self.write_tabular_row(&fields, &value)?;
+                    written += 1;
+                }
+            }
+            None => {
+                self.write_array_header(key, len, None)?;
+                self.write_char(' ')?;
+                self.write_primitive(&first)?;
+                for row in rows {
+                    let value = to_value(&row)?;
+                    self.write_char(self.options.delimiter.as_char())?;
+                    self.write_primitive(&value)?;
+                    written += 1;
+                }
+            }
+        }
+
+        if written != len {
+            return Err(ToonError::Encode(format!(
+                "expected {len} rows, got {written}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Flush the sink and return it.
+    pub fn finish(mut self) -> Result<W, ToonError> {
+        self.sink
+            .flush()
+            .map_err(|err| ToonError::Encode(err.to_string()))?;
+        Ok(self.sink)
+    }
+
+    fn write_row_indent(&mut self) -> Result<(), ToonError> {
+        let indent = self.options.indent.get_string(1);
+        self.write_str(&indent)
+    }
+
+    fn write_tabular_row(&mut self, fields: &[String], row: &Value) -> Result<(), ToonError> {
+        let obj = row
+            .as_object()
+            .ok_or_else(|| ToonError::Encode("tabular row is no longer an object".to_string()))?;
+
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                self.write_char(self.options.delimiter.as_char())?;
+            }
+            match obj.get(field) {
+                Some(value) => self.write_primitive(value)?,
+                None => self.write_str("null")?,
+            }
+        }
+        Ok(())
+    }
+
+    fn write_array_header(
+        &mut self,
+        key: Option<&str>,
+        len: usize,
+        fields: Option<&[String]>,
+    ) -> Result<(), ToonError> {
+        if let Some(key) = key {
+            self.write_key(key)?;
+        }
+        self.write_str("[")?;
+        self.write_str(&len.to_string())?;
+        if self.options.delimiter != Delimiter::Comma {
+            self.write_char(self.options.delimiter.as_char())?;
+        }
+        self.write_str("]")?;
+        if let Some(fields) = fields {
+            self.write_char('{')?;
+            for (i, field) in fields.iter().enumerate() {
+                if i > 0 {
+                    self.write_char(self.options.delimiter.as_char())?;
+                }
+                self.write_key(field)?;
+            }
+            self.write_char('}')?;
+        }
+        self.write_char(':')
+    }
+
+    fn write_key(&mut self, key: &str) -> Result<(), ToonError> {
+        if is_valid_unquoted_key(key) {
+            self.write_str(key)
+        } else {
+            self.write_str(&quote_string(key))
+        }
+    }
+
+    fn write_primitive(&mut self, value: &Value) -> Result<(), ToonError> {
+        match value {
+            Value::Null => self.write_str("null"),
+            Value::Bool(b) => self.write_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => self.write_str(&n.to_string()),
+            Value::String(s) => {
+                let delim = self.options.delimiter.as_char();
+                if needs_quoting(s, delim) {
+                    self.write_str(&quote_string(s))
+                } else {
+                    self.write_str(s)
+                }
+            }
+            Value::Array(_) | Value::Object(_) => Err(ToonError::Encode(
+                "streaming array rows must be primitive or flat objects".to_string(),
+            )),
+        }
+    }
+
+    fn write_str(&mut self, s: &str) -> Result<(), ToonError> {
+        self.sink
+            .write_all(s.as_bytes())
+            .map_err(|err| ToonError::Encode(err.to_string()))
+    }
+
+    fn write_char(&mut self, c: char) -> Result<(), ToonError> {
+        let mut buf = [0u8; 4];
+        self.write_str(c.encode_utf8(&mut buf))
+    }
+
+    fn newline(&mut self) -> Result<(), ToonError> {
+        self.write_char('\n')
+    }
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value, ToonError> {
+    serde_json::to_value(value).map_err(|err| ToonError::Encode(err.to_string()))
+}
+
+/// Mirrors `toon_format::encode`'s tabular-array detection: an object whose
+/// values are all primitives qualifies, and its key order becomes the row's
+/// column order.
+fn tabular_fields(first_row: &Value) -> Option<Vec<String>> {
+    let obj = first_row.as_object()?;
+    if obj.values().any(|v| v.is_array() || v.is_object()) {
+        return None;
+    }
+    Some(obj.keys().cloned().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct User {
+        id: u64,
+        name: String,
+    }
+
+    #[test]
+    fn streams_tabular_array_matching_non_streaming_encoder() {
+        let users = vec![
+            User { id: 1, name: "Alice".to_string() },
+            User { id: 2, name: "Bob".to_string() },
+        ];
+
+        let mut encoder = StreamEncoder::new(Vec::new());
+        encoder.write_array(Some("users"), users.len(), users).unwrap();
+        let out = String::from_utf8(encoder.finish().unwrap()).unwrap();
+
+        assert_eq!(out, "users[2]{id,name}:\n  1,Alice\n  2,Bob");
+    }
+
+    #[test]
+    fn streams_primitive_array() {
+        let tags = vec!["reading", "gaming", "coding"];
+
+        let mut encoder = StreamEncoder::new(Vec::new());
+        encoder.write_array(Some("tags"), tags.len(), tags).unwrap();
+        let out = String::from_utf8(encoder.finish().unwrap()).unwrap();
+
+        assert_eq!(out, "tags[3]: reading,gaming,coding");
+    }
+
+    #[test]
+    fn streams_empty_array() {
+        let rows: Vec<User> = Vec::new();
+
+        let mut encoder = StreamEncoder::new(Vec::new());
+        encoder.write_array::<User, _>(Some("items"), 0, rows).unwrap();
+        let out = String::from_utf8(encoder.finish().unwrap()).unwrap();
+
+        assert_eq!(out, "items[0]:");
+    }
+
+    #[test]
+    fn errors_on_declared_length_mismatch() {
+        let users = vec![User { id: 1, name: "Alice".to_string() }];
+
+        let mut encoder = StreamEncoder::new(Vec::new());
+        let err = encoder.write_array(Some("users"), 2, users).unwrap_err();
+        assert!(matches!(err, ToonError::Encode(_)));
+    }
+
+    #[test]
+    fn quotes_values_needing_it_like_the_non_streaming_encoder() {
+        let rows = vec!["needs,quoting", "plain"];
+        let mut encoder = StreamEncoder::new(Vec::new());
+        encoder.write_array(None, rows.len(), rows).unwrap();
+        let out = String::from_utf8(encoder.finish().unwrap()).unwrap();
+
+        assert_eq!(out, r#"[2]: "needs,quoting",plain"#);
+    }
+}
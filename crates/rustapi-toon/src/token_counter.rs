@@ -0,0 +1,104 @@
+//! Pluggable token counting for [`LlmResponse`](crate::LlmResponse)'s
+//! `X-Token-Count-*`/`X-Token-Savings` headers.
+//!
+//! The default [`HeuristicTokenCounter`] is a zero-dependency ~4-chars-per-token
+//! estimate. Enable the `tiktoken` feature for [`TiktokenCounter`], which
+//! counts real BPE tokens via `tiktoken-rs` -- the numbers cost dashboards can
+//! actually bill against.
+
+use std::fmt;
+
+/// Counts tokens in a rendered response body for the token-count headers.
+///
+/// Implementations should be cheap to call per-request; wrap anything
+/// expensive to construct (like a loaded BPE vocabulary) once and share it
+/// via `Arc`, as [`LlmResponse::with_tokenizer`](crate::LlmResponse::with_tokenizer) does.
+pub trait TokenCounter: Send + Sync + fmt::Debug {
+    /// Count the tokens `text` would occupy.
+    fn count_tokens(&self, text: &str) -> usize;
+}
+
+/// Estimates tokens as `ceil(chars / 4)`, the rule of thumb for GPT-3/4-style
+/// tokenizers. Used when no real tokenizer is configured; free, offline, and
+/// close enough for a rough estimate, but not accurate enough to bill against.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicTokenCounter;
+
+impl TokenCounter for HeuristicTokenCounter {
+    fn count_tokens(&self, text: &str) -> usize {
+        text.chars().count().div_ceil(4)
+    }
+}
+
+/// Counts real BPE tokens via [`tiktoken-rs`](https://docs.rs/tiktoken-rs).
+///
+/// Requires the `tiktoken` feature. The underlying vocabulary is loaded (and
+/// cached by `tiktoken-rs`) on first use, which may require a one-time
+/// network fetch -- see [`TiktokenCounter::for_model`] and
+/// [`TiktokenCounter::generic_bpe`] for the two ways to select one.
+#[cfg(feature = "tiktoken")]
+#[derive(Clone, Copy)]
+pub struct TiktokenCounter {
+    bpe: &'static tiktoken_rs::CoreBPE,
+}
+
+#[cfg(feature = "tiktoken")]
+impl TiktokenCounter {
+    /// Select the tokenizer tiktoken-rs associates with `model` (e.g.
+    /// `"gpt-4"`, `"gpt-4o"`, `"gpt-3.5-turbo"`).
+    ///
+    /// Returns [`crate::ToonError::Encode`] if `model` isn't recognized --
+    /// callers targeting an unknown or non-OpenAI model should fall back to
+    /// [`TiktokenCounter::generic_bpe`] or [`HeuristicTokenCounter`].
+    pub fn for_model(model: &str) -> Result<Self, crate::ToonError> {
+        let bpe = tiktoken_rs::bpe_for_model(model)
+            .map_err(|err| crate::ToonError::Encode(err.to_string()))?;
+        Ok(Self { bpe })
+    }
+
+    /// A model-agnostic BPE tokenizer (GPT-2's `r50k_base` vocabulary).
+    ///
+    /// Not an exact token count for any specific GPT-4/4o model, but a real
+    /// byte-pair-encoding count rather than a character heuristic -- a
+    /// reasonable default when the target model isn't known ahead of time.
+    pub fn generic_bpe() -> Self {
+        Self {
+            bpe: tiktoken_rs::r50k_base_singleton(),
+        }
+    }
+}
+
+#[cfg(feature = "tiktoken")]
+impl fmt::Debug for TiktokenCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TiktokenCounter").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "tiktoken")]
+impl TokenCounter for TiktokenCounter {
+    fn count_tokens(&self, text: &str) -> usize {
+        self.bpe.encode_ordinary(text).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heuristic_rounds_up() {
+        let counter = HeuristicTokenCounter;
+        assert_eq!(counter.count_tokens(""), 0);
+        assert_eq!(counter.count_tokens("test"), 1);
+        assert_eq!(counter.count_tokens("hello world"), 3);
+    }
+
+    #[cfg(feature = "tiktoken")]
+    #[test]
+    fn generic_bpe_counts_tokens() {
+        let counter = TiktokenCounter::generic_bpe();
+        let tokens = counter.count_tokens("hello world, this is a test of real BPE tokenization");
+        assert!(tokens > 0);
+    }
+}
@@ -1,47 +1,35 @@
 //! # RustAPI Validation
 //!
 //! Validation system for RustAPI framework. Provides declarative validation
-//! on structs using the `#[derive(Validate)]` macro.
+//! on structs using the framework-native `#[derive(Validate)]` macro - no
+//! external `validator` crate involved, so field errors come out with the
+//! codes/messages/params RustAPI's error responses expect, and every rule
+//! takes an optional custom `message`.
 //!
 //! ## Example
 //!
 //! ```rust,ignore
 //! use rustapi_validate::prelude::*;
-//! use validator::Validate;
 //!
 //! #[derive(Validate)]
 //! struct CreateUser {
-//!     #[validate(email)]
+//!     #[validate(email, message = "Invalid email format")]
 //!     email: String,
-//!     
+//!
 //!     #[validate(length(min = 3, max = 50))]
 //!     username: String,
-//!     
+//!
 //!     #[validate(range(min = 18, max = 120))]
 //!     age: u8,
-//! }
-//! ```
-//!
-//! ## V2 Validation Engine
-//!
-//! The v2 module provides a custom validation engine with async support:
-//!
-//! ```rust,ignore
-//! use rustapi_validate::v2::prelude::*;
 //!
-//! #[derive(Validate)]
-//! struct CreateUser {
-//!     #[validate(email, message = "Invalid email format")]
-//!     email: String,
-//!     
-//!     #[validate(length(min = 3, max = 50))]
-//!     username: String,
-//!     
 //!     #[validate(async_unique(table = "users", column = "email"))]
 //!     unique_email: String,
 //! }
 //! ```
 //!
+//! For validation rules that need to be composed or invoked by hand (rather
+//! than derived), see the [`v2`] module directly.
+//!
 //! ## Validation Rules
 //!
 //! - `email` - Validates email format
@@ -53,6 +41,20 @@
 //! - `async_unique(table, column)` - Database uniqueness check
 //! - `async_exists(table, column)` - Database existence check
 //! - `async_api(endpoint)` - External API validation
+//! - `custom_async = "name"` - Named validator from the `ValidationContext`, for checks
+//!   backed by app state (a DB pool, an HTTP client, ...)
+//! - `required_if = "other_field"` - Non-empty only if `other_field` is present
+//! - `must_match = "other_field"` - Must equal another field (e.g. password confirmation)
+//! - `nested` - Validate a nested struct or `Vec<Item>`, prefixing errors with
+//!   `field.subfield` (or `field[i].subfield` for collections)
+//! - `length(min = X, max = Y)` also accepts `Vec<T>` fields, checking element count
+//! - Struct-level `schema(function = "...")` - Whole-struct check, reported under `"__all__"`
+//!
+//! ## Input Transforms
+//!
+//! `#[transform(trim, lowercase, truncate = 255)]` normalizes a `String` (or
+//! `Option<String>`) field in place, in the order listed, before validation
+//! runs. `ValidatedJson` applies these automatically; see [`v2::Transform`].
 //!
 //! ## Error Format
 //!
@@ -73,36 +75,36 @@
 
 pub mod custom;
 mod error;
-mod validate;
 
 /// V2 validation engine with async support.
 ///
-/// This module provides a custom validation engine that replaces the external
-/// `validator` dependency and adds support for async validation operations.
+/// This module provides the validation engine backing `#[derive(Validate)]` -
+/// custom rule types, validation groups, and async (database/HTTP-backed)
+/// rules - with no external `validator` dependency.
 pub mod v2;
 
 pub use error::{FieldError, ValidationError};
-pub use validate::Validate;
+pub use v2::Validate;
 
-// Re-export the derive macro from validator (wrapped)
-// In a full implementation, we'd create our own proc-macro
-// For now, we use validator's derive with our own trait
-pub use validator::Validate as ValidatorValidate;
+// Re-export the derive macro that implements `v2::Validate` (and
+// `v2::AsyncValidate`, if the struct has async rules) for the annotated type
+pub use rustapi_macros::Validate;
 
-// Re-export the v2 Validate derive macro
-pub use rustapi_macros::Validate as DeriveValidate;
+// Re-exported so the `Validate` derive can reference `::rustapi_validate::async_trait`
+// in its generated `AsyncValidate` impl without requiring every crate that derives
+// `Validate` to depend on `async-trait` directly.
+#[doc(hidden)]
+pub use async_trait;
 
 /// Prelude module for validation
 pub mod prelude {
     pub use crate::error::{FieldError, ValidationError};
-    pub use crate::validate::Validate;
-    pub use validator::Validate as ValidatorValidate;
 
-    // Re-export v2 prelude
+    // Re-export v2's traits (including `Validate`) and rule types
     pub use crate::v2::prelude::*;
 
-    // Re-export derive macro
-    pub use rustapi_macros::Validate as DeriveValidate;
+    // Re-export the derive macro
+    pub use rustapi_macros::Validate;
 }
 
 #[cfg(test)]
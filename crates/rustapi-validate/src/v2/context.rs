@@ -53,7 +53,7 @@ pub trait CustomValidator: Send + Sync {
 ///
 /// user.validate_async(&ctx).await?;
 /// ```
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct ValidationContext {
     database: Option<Arc<dyn DatabaseValidator>>,
     http: Option<Arc<dyn HttpValidator>>,
@@ -0,0 +1,256 @@
+//! Message catalog for localizing validation errors.
+//!
+//! The `#[derive(Validate)]` macro always generates English [`RuleError`]s -
+//! teaching it every locale's wording would mean forking it per project.
+//! Instead, a [`MessageCatalog`] holds per-locale templates keyed by rule
+//! code and swaps them into a [`ValidationErrors`] after the fact, once the
+//! caller's preferred locale has been negotiated from an `Accept-Language`
+//! header.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use rustapi_validate::v2::prelude::*;
+//!
+//! let catalog = MessageCatalog::builder()
+//!     .template("de", "email", "Ungültige E-Mail-Adresse")
+//!     .template("de", "required", "Dieses Feld ist erforderlich")
+//!     .build();
+//!
+//! let locale = catalog.negotiate(Some("de-DE,de;q=0.9,en;q=0.8"));
+//! let localized = catalog.localize(&errors, &locale);
+//! ```
+
+use crate::v2::error::{RuleError, ValidationErrors};
+use std::collections::HashMap;
+
+/// Per-locale message templates for validation rule codes.
+///
+/// Templates keep the same `{param}` placeholders as the built-in English
+/// messages, so [`RuleError::interpolate_message`] fills them in regardless
+/// of which locale's template was selected.
+#[derive(Debug, Clone)]
+pub struct MessageCatalog {
+    templates: HashMap<(String, String), String>,
+    default_locale: String,
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self {
+            templates: HashMap::new(),
+            default_locale: "en".to_string(),
+        }
+    }
+}
+
+impl MessageCatalog {
+    /// Create an empty catalog with the "en" default locale.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a builder for constructing a message catalog.
+    pub fn builder() -> MessageCatalogBuilder {
+        MessageCatalogBuilder::new()
+    }
+
+    /// Look up the template for a rule code in a specific locale.
+    pub fn template(&self, locale: &str, code: &str) -> Option<&str> {
+        self.templates
+            .get(&(locale.to_string(), code.to_string()))
+            .map(|s| s.as_str())
+    }
+
+    /// The locale used when the `Accept-Language` header is absent or
+    /// doesn't match any configured locale.
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    fn supports(&self, locale: &str) -> bool {
+        self.templates.keys().any(|(l, _)| l == locale)
+    }
+
+    /// Pick the best supported locale for an `Accept-Language` header value.
+    ///
+    /// Parses the header's comma-separated, `q`-weighted language list (RFC
+    /// 9110 §12.5.4), tries each candidate's exact tag and then its primary
+    /// subtag (e.g. `en-US` falls back to `en`), and returns
+    /// [`default_locale`](Self::default_locale) if nothing matches.
+    pub fn negotiate(&self, accept_language: Option<&str>) -> String {
+        let Some(header) = accept_language else {
+            return self.default_locale.clone();
+        };
+
+        let mut candidates: Vec<(&str, f32)> = header
+            .split(',')
+            .filter_map(|part| {
+                let mut segments = part.trim().split(';');
+                let lang = segments.next()?.trim();
+                if lang.is_empty() {
+                    return None;
+                }
+                let q = segments
+                    .find_map(|s| s.trim().strip_prefix("q="))
+                    .and_then(|v| v.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                Some((lang, q))
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        for (lang, _) in candidates {
+            if lang == "*" {
+                continue;
+            }
+            if self.supports(lang) {
+                return lang.to_string();
+            }
+            if let Some((primary, _)) = lang.split_once('-') {
+                if self.supports(primary) {
+                    return primary.to_string();
+                }
+            }
+        }
+
+        self.default_locale.clone()
+    }
+
+    /// Rewrite a [`ValidationErrors`]'s messages using this catalog's
+    /// templates for `locale`, leaving rule codes and interpolation params
+    /// untouched. Codes with no template for `locale` (or its primary
+    /// subtag) keep their original message.
+    pub fn localize(&self, errors: &ValidationErrors, locale: &str) -> ValidationErrors {
+        let primary = locale.split_once('-').map(|(p, _)| p);
+        let mut out = ValidationErrors::new();
+
+        for (field, field_errors) in &errors.fields {
+            for e in field_errors {
+                let message = self
+                    .template(locale, &e.code)
+                    .or_else(|| primary.and_then(|p| self.template(p, &e.code)))
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| e.message.clone());
+
+                out.add(
+                    field.clone(),
+                    RuleError::with_params(e.code.clone(), message, e.params.clone()),
+                );
+            }
+        }
+
+        out
+    }
+}
+
+/// Builder for constructing a [`MessageCatalog`].
+#[derive(Debug, Clone, Default)]
+pub struct MessageCatalogBuilder {
+    catalog: MessageCatalog,
+}
+
+impl MessageCatalogBuilder {
+    /// Create a new builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the locale used when negotiation finds no match.
+    pub fn default_locale(mut self, locale: impl Into<String>) -> Self {
+        self.catalog.default_locale = locale.into();
+        self
+    }
+
+    /// Add a message template for a rule code in a locale.
+    pub fn template(
+        mut self,
+        locale: impl Into<String>,
+        code: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.catalog
+            .templates
+            .insert((locale.into(), code.into()), message.into());
+        self
+    }
+
+    /// Build the message catalog.
+    pub fn build(self) -> MessageCatalog {
+        self.catalog
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn german_catalog() -> MessageCatalog {
+        MessageCatalog::builder()
+            .template("de", "email", "Ungültige E-Mail-Adresse")
+            .template("de", "length", "Muss zwischen {min} und {max} Zeichen lang sein")
+            .build()
+    }
+
+    #[test]
+    fn negotiate_exact_match() {
+        let catalog = german_catalog();
+        assert_eq!(catalog.negotiate(Some("de")), "de");
+    }
+
+    #[test]
+    fn negotiate_falls_back_to_primary_subtag() {
+        let catalog = german_catalog();
+        assert_eq!(catalog.negotiate(Some("de-DE,de;q=0.9")), "de");
+    }
+
+    #[test]
+    fn negotiate_picks_highest_quality_supported_locale() {
+        let catalog = german_catalog();
+        assert_eq!(catalog.negotiate(Some("fr;q=0.9,de;q=0.8")), "de");
+    }
+
+    #[test]
+    fn negotiate_falls_back_to_default_when_unsupported() {
+        let catalog = german_catalog();
+        assert_eq!(catalog.negotiate(Some("fr,ja;q=0.5")), "en");
+    }
+
+    #[test]
+    fn negotiate_falls_back_to_default_when_absent() {
+        let catalog = german_catalog();
+        assert_eq!(catalog.negotiate(None), "en");
+    }
+
+    #[test]
+    fn localize_replaces_message_and_keeps_params() {
+        let catalog = german_catalog();
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            "username",
+            RuleError::new("length", "Must be between {min} and {max} characters")
+                .param("min", 3)
+                .param("max", 50),
+        );
+
+        let localized = catalog.localize(&errors, "de");
+        let error = &localized.get("username").unwrap()[0];
+        assert_eq!(
+            error.interpolate_message(),
+            "Muss zwischen 3 und 50 Zeichen lang sein"
+        );
+    }
+
+    #[test]
+    fn localize_keeps_original_message_when_no_template() {
+        let catalog = german_catalog();
+        let mut errors = ValidationErrors::new();
+        errors.add("age", RuleError::new("range", "Value must be between 18 and 120"));
+
+        let localized = catalog.localize(&errors, "de");
+        assert_eq!(
+            localized.get("age").unwrap()[0].message,
+            "Value must be between 18 and 120"
+        );
+    }
+}
@@ -41,27 +41,35 @@
 mod context;
 mod error;
 mod group;
+mod locale;
 mod rules;
 mod traits;
 
 #[cfg(test)]
 mod tests;
 
-pub use context::{DatabaseValidator, HttpValidator, ValidationContext, ValidationContextBuilder};
+pub use context::{
+    CustomValidator, DatabaseValidator, HttpValidator, ValidationContext, ValidationContextBuilder,
+};
 pub use error::{RuleError, ValidationErrors};
 pub use group::{GroupedRule, GroupedRules, ValidationGroup};
+pub use locale::{MessageCatalog, MessageCatalogBuilder};
 pub use rules::*;
-pub use traits::{AsyncValidate, AsyncValidationRule, SerializableRule, Validate, ValidationRule};
+pub use traits::{
+    AsyncValidate, AsyncValidationRule, SerializableRule, Transform, Validate, ValidationRule,
+};
 
 /// Prelude module for v2 validation
 pub mod prelude {
     pub use super::context::{
-        DatabaseValidator, HttpValidator, ValidationContext, ValidationContextBuilder,
+        CustomValidator, DatabaseValidator, HttpValidator, ValidationContext,
+        ValidationContextBuilder,
     };
     pub use super::error::{RuleError, ValidationErrors};
     pub use super::group::{GroupedRule, GroupedRules, ValidationGroup};
+    pub use super::locale::{MessageCatalog, MessageCatalogBuilder};
     pub use super::rules::*;
     pub use super::traits::{
-        AsyncValidate, AsyncValidationRule, SerializableRule, Validate, ValidationRule,
+        AsyncValidate, AsyncValidationRule, SerializableRule, Transform, Validate, ValidationRule,
     };
 }
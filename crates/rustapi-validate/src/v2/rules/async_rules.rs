@@ -169,6 +169,81 @@ impl AsyncValidationRule<String> for AsyncExistsRule {
     }
 }
 
+/// Named custom async validation rule.
+///
+/// Runs a [`CustomValidator`](crate::v2::CustomValidator) registered under `name`
+/// in the [`ValidationContext`], so validation logic backed by app state
+/// (a DB pool, an HTTP client, ...) can live outside handler bodies.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AsyncCustomRule {
+    /// Name the validator was registered under (`ValidationContextBuilder::custom`)
+    pub name: String,
+    /// Custom error message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl AsyncCustomRule {
+    /// Create a new custom async rule for the validator registered under `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            message: None,
+        }
+    }
+
+    /// Set a custom error message.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+#[async_trait]
+impl AsyncValidationRule<str> for AsyncCustomRule {
+    async fn validate_async(&self, value: &str, ctx: &ValidationContext) -> Result<(), RuleError> {
+        let validator = ctx.custom(&self.name).ok_or_else(|| {
+            RuleError::new(
+                "custom_async",
+                format!("Custom validator `{}` not configured in context", self.name),
+            )
+        })?;
+
+        let is_valid = validator.validate(value).await.map_err(|e| {
+            RuleError::new("custom_async", format!("Custom validator error: {}", e))
+        })?;
+
+        if is_valid {
+            Ok(())
+        } else {
+            let message = self
+                .message
+                .clone()
+                .unwrap_or_else(|| format!("Validation failed for `{}`", self.name));
+            Err(RuleError::new("custom_async", message).param("name", self.name.clone()))
+        }
+    }
+
+    fn rule_name(&self) -> &'static str {
+        "custom_async"
+    }
+}
+
+#[async_trait]
+impl AsyncValidationRule<String> for AsyncCustomRule {
+    async fn validate_async(
+        &self,
+        value: &String,
+        ctx: &ValidationContext,
+    ) -> Result<(), RuleError> {
+        <Self as AsyncValidationRule<str>>::validate_async(self, value.as_str(), ctx).await
+    }
+
+    fn rule_name(&self) -> &'static str {
+        "custom_async"
+    }
+}
+
 /// External API validation rule.
 ///
 /// Validates a value against an external API endpoint.
@@ -243,7 +318,7 @@ impl AsyncValidationRule<String> for AsyncApiRule {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::v2::context::{DatabaseValidator, ValidationContextBuilder};
+    use crate::v2::context::{CustomValidator, DatabaseValidator, ValidationContextBuilder};
 
     struct MockDbValidator {
         unique_values: Vec<String>,
@@ -344,6 +419,64 @@ mod tests {
         assert!(err.message.contains("not configured"));
     }
 
+    struct MockCustomValidator {
+        taken: Vec<String>,
+    }
+
+    #[async_trait]
+    impl CustomValidator for MockCustomValidator {
+        async fn validate(&self, value: &str) -> Result<bool, String> {
+            Ok(!self.taken.contains(&value.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn async_custom_rule_valid() {
+        let ctx = ValidationContextBuilder::new()
+            .custom(
+                "is_unique_email",
+                MockCustomValidator {
+                    taken: vec!["taken@example.com".to_string()],
+                },
+            )
+            .build();
+
+        let rule = AsyncCustomRule::new("is_unique_email");
+        assert!(rule.validate_async("new@example.com", &ctx).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn async_custom_rule_invalid() {
+        let ctx = ValidationContextBuilder::new()
+            .custom(
+                "is_unique_email",
+                MockCustomValidator {
+                    taken: vec!["taken@example.com".to_string()],
+                },
+            )
+            .build();
+
+        let rule = AsyncCustomRule::new("is_unique_email").with_message("Email already taken");
+        let err = rule
+            .validate_async("taken@example.com", &ctx)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, "custom_async");
+        assert_eq!(err.message, "Email already taken");
+    }
+
+    #[tokio::test]
+    async fn async_custom_rule_not_configured() {
+        let ctx = ValidationContext::new();
+
+        let rule = AsyncCustomRule::new("is_unique_email");
+        let err = rule
+            .validate_async("new@example.com", &ctx)
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("not configured"));
+    }
+
     #[test]
     fn async_rule_serialization() {
         let rule = AsyncUniqueRule::new("users", "email").with_message("Email already taken");
@@ -0,0 +1,69 @@
+//! Support types for cross-field validation rules.
+//!
+//! Rules like `required_if` and `must_match` need to read a sibling field's
+//! value, which doesn't fit the single-value [`ValidationRule`](crate::v2::ValidationRule)
+//! trait. The `#[derive(Validate)]` macro generates the field-comparison code
+//! directly (see `generate_rule_validation` in `rustapi-macros`), and only
+//! needs a shared notion of "is this value present" - provided here - to
+//! reuse [`RequiredRule`](super::RequiredRule) for the conditional case.
+
+/// Whether a field's value counts as "present" for conditional-requirement
+/// checks such as `#[validate(required_if = "other_field")]`.
+pub trait FieldPresence {
+    /// Returns `true` if the value should be treated as present/truthy.
+    fn is_present(&self) -> bool;
+}
+
+impl FieldPresence for str {
+    fn is_present(&self) -> bool {
+        !self.trim().is_empty()
+    }
+}
+
+impl FieldPresence for String {
+    fn is_present(&self) -> bool {
+        FieldPresence::is_present(self.as_str())
+    }
+}
+
+impl<T> FieldPresence for Option<T> {
+    fn is_present(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl FieldPresence for bool {
+    fn is_present(&self) -> bool {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_presence() {
+        assert!("hello".to_string().is_present());
+        assert!(!"   ".to_string().is_present());
+        assert!(!"".to_string().is_present());
+    }
+
+    #[test]
+    fn str_presence() {
+        assert!("hello".is_present());
+        assert!(!"".is_present());
+    }
+
+    #[test]
+    fn option_presence() {
+        assert!(Some(5).is_present());
+        assert!(!None::<i32>.is_present());
+    }
+
+    #[test]
+    fn bool_presence() {
+        assert!(true.is_present());
+        assert!(!false.is_present());
+    }
+}
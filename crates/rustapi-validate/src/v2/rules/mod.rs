@@ -3,7 +3,11 @@
 //! This module contains both synchronous and asynchronous validation rules.
 
 mod async_rules;
+mod cross_field;
 mod sync_rules;
+mod transform;
 
 pub use async_rules::*;
+pub use cross_field::*;
 pub use sync_rules::*;
+pub use transform::*;
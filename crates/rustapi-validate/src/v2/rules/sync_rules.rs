@@ -128,10 +128,11 @@ impl LengthRule {
     }
 }
 
-impl ValidationRule<str> for LengthRule {
-    fn validate(&self, value: &str) -> Result<(), RuleError> {
-        let len = value.chars().count();
-
+impl LengthRule {
+    /// Shared bounds check used by every `ValidationRule<T>` impl below - only
+    /// how `len` is derived from `T` differs (characters for strings, element
+    /// count for collections).
+    fn check_len(&self, len: usize) -> Result<(), RuleError> {
         if let Some(min) = self.min {
             if len < min {
                 let message = self
@@ -160,6 +161,12 @@ impl ValidationRule<str> for LengthRule {
 
         Ok(())
     }
+}
+
+impl ValidationRule<str> for LengthRule {
+    fn validate(&self, value: &str) -> Result<(), RuleError> {
+        self.check_len(value.chars().count())
+    }
 
     fn rule_name(&self) -> &'static str {
         "length"
@@ -176,6 +183,16 @@ impl ValidationRule<String> for LengthRule {
     }
 }
 
+impl<T> ValidationRule<Vec<T>> for LengthRule {
+    fn validate(&self, value: &Vec<T>) -> Result<(), RuleError> {
+        self.check_len(value.len())
+    }
+
+    fn rule_name(&self) -> &'static str {
+        "length"
+    }
+}
+
 /// Numeric range validation rule.
 ///
 /// Validates that a number is within specified bounds.
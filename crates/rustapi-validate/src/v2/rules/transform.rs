@@ -0,0 +1,105 @@
+//! Support types for input transformation, applied before validation runs.
+//!
+//! `#[transform(trim, lowercase, truncate = 255)]` on a `#[derive(Validate)]`
+//! field doesn't fit the [`ValidationRule`](crate::v2::ValidationRule) trait
+//! either - it mutates the field instead of checking it. The derive macro
+//! generates calls into [`Transformable`] directly (see `derive_validate` in
+//! `rustapi-macros`), in the order the ops were listed.
+
+/// String-shaped transforms applied to a field before validation.
+pub trait Transformable {
+    /// Trim leading and trailing whitespace.
+    fn trim_transform(&mut self);
+    /// Lowercase the value.
+    fn lowercase_transform(&mut self);
+    /// Truncate to at most `max_len` characters.
+    fn truncate_transform(&mut self, max_len: usize);
+}
+
+impl Transformable for String {
+    fn trim_transform(&mut self) {
+        if self.trim().len() != self.len() {
+            *self = self.trim().to_string();
+        }
+    }
+
+    fn lowercase_transform(&mut self) {
+        *self = self.to_lowercase();
+    }
+
+    fn truncate_transform(&mut self, max_len: usize) {
+        if self.chars().count() > max_len {
+            *self = self.chars().take(max_len).collect();
+        }
+    }
+}
+
+impl Transformable for Option<String> {
+    fn trim_transform(&mut self) {
+        if let Some(s) = self {
+            s.trim_transform();
+        }
+    }
+
+    fn lowercase_transform(&mut self) {
+        if let Some(s) = self {
+            s.lowercase_transform();
+        }
+    }
+
+    fn truncate_transform(&mut self, max_len: usize) {
+        if let Some(s) = self {
+            s.truncate_transform(max_len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trims_string() {
+        let mut s = "  hi  ".to_string();
+        s.trim_transform();
+        assert_eq!(s, "hi");
+    }
+
+    #[test]
+    fn lowercases_string() {
+        let mut s = "HELLO@Example.com".to_string();
+        s.lowercase_transform();
+        assert_eq!(s, "hello@example.com");
+    }
+
+    #[test]
+    fn truncates_string() {
+        let mut s = "hello world".to_string();
+        s.truncate_transform(5);
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    fn truncate_is_noop_when_within_limit() {
+        let mut s = "hi".to_string();
+        s.truncate_transform(5);
+        assert_eq!(s, "hi");
+    }
+
+    #[test]
+    fn option_string_transforms_apply_when_present() {
+        let mut s = Some("  HI  ".to_string());
+        s.trim_transform();
+        s.lowercase_transform();
+        assert_eq!(s, Some("hi".to_string()));
+    }
+
+    #[test]
+    fn option_string_transforms_are_noop_when_absent() {
+        let mut s: Option<String> = None;
+        s.trim_transform();
+        s.lowercase_transform();
+        s.truncate_transform(3);
+        assert_eq!(s, None);
+    }
+}
@@ -52,6 +52,33 @@ pub trait Validate {
     }
 }
 
+/// Trait for normalizing a struct's fields before validation runs.
+///
+/// `#[derive(Validate)]` always implements this trait, generating a no-op
+/// body for structs with no `#[transform(...)]` attributes so extractors
+/// like `ValidatedJson` can call it unconditionally.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use rustapi_validate::v2::prelude::*;
+///
+/// struct SignupForm {
+///     email: String,
+/// }
+///
+/// impl Transform for SignupForm {
+///     fn transform(&mut self) {
+///         self.email.trim_transform();
+///         self.email.lowercase_transform();
+///     }
+/// }
+/// ```
+pub trait Transform {
+    /// Normalize the struct's fields in place.
+    fn transform(&mut self);
+}
+
 /// Trait for asynchronous validation of a struct.
 ///
 /// Use this trait when validation requires async operations like database checks or API calls.
@@ -230,6 +257,27 @@ pub enum SerializableRule {
         #[serde(skip_serializing_if = "Option::is_none")]
         message: Option<String>,
     },
+    /// Named custom async validator check
+    AsyncCustom {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// Field is required only when another field is present
+    RequiredIf {
+        field: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// Field must equal another field's value (e.g. password confirmation)
+    MustMatch {
+        field: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// Validate a nested struct or `Vec<Item>`, prefixing sub-errors with the
+    /// field's path. Carries no message - errors come from the nested value.
+    Nested,
 }
 
 impl SerializableRule {
@@ -325,6 +373,28 @@ impl SerializableRule {
                     .unwrap_or_default();
                 format!("#[validate(async_api(endpoint = \"{}\"{}))]", endpoint, msg)
             }
+            SerializableRule::AsyncCustom { name, message } => {
+                let msg = message
+                    .as_ref()
+                    .map(|m| format!(", message = \"{}\"", m))
+                    .unwrap_or_default();
+                format!("#[validate(custom_async = \"{}\"{})]", name, msg)
+            }
+            SerializableRule::RequiredIf { field, message } => {
+                let msg = message
+                    .as_ref()
+                    .map(|m| format!(", message = \"{}\"", m))
+                    .unwrap_or_default();
+                format!("#[validate(required_if = \"{}\"{})]", field, msg)
+            }
+            SerializableRule::MustMatch { field, message } => {
+                let msg = message
+                    .as_ref()
+                    .map(|m| format!(", message = \"{}\"", m))
+                    .unwrap_or_default();
+                format!("#[validate(must_match = \"{}\"{})]", field, msg)
+            }
+            SerializableRule::Nested => "#[validate(nested)]".to_string(),
         }
     }
 
@@ -359,6 +429,10 @@ impl SerializableRule {
             return Some(SerializableRule::Required { message });
         }
 
+        if inner == "nested" {
+            return Some(SerializableRule::Nested);
+        }
+
         if inner.starts_with("length(") {
             return Self::parse_length(inner);
         }
@@ -383,6 +457,18 @@ impl SerializableRule {
             return Self::parse_async_api(inner);
         }
 
+        if inner.starts_with("custom_async = \"") {
+            return Self::parse_async_custom(inner);
+        }
+
+        if inner.starts_with("required_if = \"") {
+            return Self::parse_required_if(inner);
+        }
+
+        if inner.starts_with("must_match = \"") {
+            return Self::parse_must_match(inner);
+        }
+
         None
     }
 
@@ -464,12 +550,30 @@ impl SerializableRule {
         let message = Self::extract_message(s);
         Some(SerializableRule::AsyncApi { endpoint, message })
     }
+
+    fn parse_async_custom(s: &str) -> Option<Self> {
+        let name = Self::extract_param(s, "custom_async")?;
+        let message = Self::extract_message(s);
+        Some(SerializableRule::AsyncCustom { name, message })
+    }
+
+    fn parse_required_if(s: &str) -> Option<Self> {
+        let field = Self::extract_param(s, "required_if")?;
+        let message = Self::extract_message(s);
+        Some(SerializableRule::RequiredIf { field, message })
+    }
+
+    fn parse_must_match(s: &str) -> Option<Self> {
+        let field = Self::extract_param(s, "must_match")?;
+        let message = Self::extract_message(s);
+        Some(SerializableRule::MustMatch { field, message })
+    }
 }
 
 // Conversion implementations from concrete rules to SerializableRule
 use crate::v2::rules::{
-    AsyncApiRule, AsyncExistsRule, AsyncUniqueRule, EmailRule, LengthRule, RegexRule, RequiredRule,
-    UrlRule,
+    AsyncApiRule, AsyncCustomRule, AsyncExistsRule, AsyncUniqueRule, EmailRule, LengthRule,
+    RegexRule, RequiredRule, UrlRule,
 };
 
 impl From<EmailRule> for SerializableRule {
@@ -544,6 +648,15 @@ impl From<AsyncApiRule> for SerializableRule {
     }
 }
 
+impl From<AsyncCustomRule> for SerializableRule {
+    fn from(rule: AsyncCustomRule) -> Self {
+        SerializableRule::AsyncCustom {
+            name: rule.name,
+            message: rule.message,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -678,6 +791,48 @@ mod tests {
         assert_eq!(rule, parsed);
     }
 
+    #[test]
+    fn serializable_rule_pretty_print_roundtrip_async_custom() {
+        let rule = SerializableRule::AsyncCustom {
+            name: "is_unique_email".to_string(),
+            message: Some("Email already taken".to_string()),
+        };
+        let pretty = rule.pretty_print();
+        let parsed = SerializableRule::parse(&pretty).unwrap();
+        assert_eq!(rule, parsed);
+    }
+
+    #[test]
+    fn serializable_rule_pretty_print_roundtrip_required_if() {
+        let rule = SerializableRule::RequiredIf {
+            field: "shipping_address".to_string(),
+            message: None,
+        };
+        let pretty = rule.pretty_print();
+        let parsed = SerializableRule::parse(&pretty).unwrap();
+        assert_eq!(rule, parsed);
+    }
+
+    #[test]
+    fn serializable_rule_pretty_print_roundtrip_must_match() {
+        let rule = SerializableRule::MustMatch {
+            field: "password_confirm".to_string(),
+            message: Some("Passwords do not match".to_string()),
+        };
+        let pretty = rule.pretty_print();
+        let parsed = SerializableRule::parse(&pretty).unwrap();
+        assert_eq!(rule, parsed);
+    }
+
+    #[test]
+    fn serializable_rule_pretty_print_roundtrip_nested() {
+        let rule = SerializableRule::Nested;
+        let pretty = rule.pretty_print();
+        assert_eq!(pretty, "#[validate(nested)]");
+        let parsed = SerializableRule::parse(&pretty).unwrap();
+        assert_eq!(rule, parsed);
+    }
+
     #[test]
     fn from_email_rule() {
         let rule = EmailRule::with_message("Invalid email");
@@ -717,4 +872,17 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn from_async_custom_rule() {
+        let rule = AsyncCustomRule::new("is_unique_email").with_message("Email already taken");
+        let serializable: SerializableRule = rule.into();
+        assert_eq!(
+            serializable,
+            SerializableRule::AsyncCustom {
+                name: "is_unique_email".to_string(),
+                message: Some("Email already taken".to_string())
+            }
+        );
+    }
 }
@@ -4,11 +4,11 @@
 //! Validate and AsyncValidate implementations.
 
 use async_trait::async_trait;
-use rustapi_validate::v2::{AsyncValidate, DatabaseValidator, Validate, ValidationContextBuilder};
-use rustapi_validate::DeriveValidate;
+use rustapi_validate::v2::{AsyncValidate, DatabaseValidator, ValidationContextBuilder};
+use rustapi_validate::Validate;
 
 // Test struct using the derive macro with sync validation rules
-#[derive(DeriveValidate)]
+#[derive(Validate)]
 struct CreateUser {
     #[validate(email, message = "Invalid email format")]
     email: String,
@@ -95,7 +95,7 @@ fn derive_validate_sync_multiple_errors() {
 }
 
 // Test struct with URL and required validation
-#[derive(DeriveValidate)]
+#[derive(Validate)]
 struct Website {
     #[validate(required)]
     name: String,
@@ -141,7 +141,7 @@ fn derive_validate_required_empty() {
 }
 
 // Test struct with regex validation
-#[derive(DeriveValidate)]
+#[derive(Validate)]
 struct PhoneNumber {
     #[validate(regex(pattern = r"^\d{3}-\d{4}$", message = "Invalid phone format"))]
     number: String,
@@ -172,8 +172,271 @@ fn derive_validate_regex_invalid() {
     );
 }
 
+// Test struct with cross-field and struct-level validation
+fn passwords_match(form: &SignupForm) -> Result<(), String> {
+    if form.password == form.password_confirm {
+        Ok(())
+    } else {
+        Err("Passwords must match".to_string())
+    }
+}
+
+#[derive(Validate)]
+#[validate(schema(function = "passwords_match"))]
+struct SignupForm {
+    #[validate(length(min = 8, max = 100))]
+    password: String,
+
+    password_confirm: String,
+
+    #[validate(required_if = "wants_newsletter")]
+    email: String,
+
+    wants_newsletter: bool,
+
+    #[validate(must_match = "password")]
+    password_repeat: String,
+}
+
+#[test]
+fn derive_validate_required_if_triggers_when_present() {
+    let form = SignupForm {
+        password: "hunter22222".to_string(),
+        password_confirm: "hunter22222".to_string(),
+        email: "".to_string(),
+        wants_newsletter: true,
+        password_repeat: "hunter22222".to_string(),
+    };
+
+    let result = form.validate();
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(errors.get("email").is_some());
+}
+
+#[test]
+fn derive_validate_required_if_skipped_when_absent() {
+    let form = SignupForm {
+        password: "hunter22222".to_string(),
+        password_confirm: "hunter22222".to_string(),
+        email: "".to_string(),
+        wants_newsletter: false,
+        password_repeat: "hunter22222".to_string(),
+    };
+
+    let result = form.validate();
+    assert!(result.is_ok());
+}
+
+#[test]
+fn derive_validate_must_match_valid() {
+    let form = SignupForm {
+        password: "hunter22222".to_string(),
+        password_confirm: "hunter22222".to_string(),
+        email: "".to_string(),
+        wants_newsletter: false,
+        password_repeat: "hunter22222".to_string(),
+    };
+
+    assert!(form.validate().is_ok());
+}
+
+#[test]
+fn derive_validate_must_match_invalid() {
+    let form = SignupForm {
+        password: "hunter22222".to_string(),
+        password_confirm: "hunter22222".to_string(),
+        email: "".to_string(),
+        wants_newsletter: false,
+        password_repeat: "different".to_string(),
+    };
+
+    let result = form.validate();
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(errors.get("password_repeat").is_some());
+    assert_eq!(errors.get("password_repeat").unwrap()[0].code, "must_match");
+}
+
+#[test]
+fn derive_validate_schema_function_invalid() {
+    let form = SignupForm {
+        password: "hunter22222".to_string(),
+        password_confirm: "different".to_string(),
+        email: "".to_string(),
+        wants_newsletter: false,
+        password_repeat: "hunter22222".to_string(),
+    };
+
+    let result = form.validate();
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(errors.get("__all__").is_some());
+    assert_eq!(
+        errors.get("__all__").unwrap()[0].message,
+        "Passwords must match"
+    );
+}
+
+// Test #[transform(...)] normalization with derive macro
+use rustapi_validate::v2::Transform;
+
+#[derive(Validate)]
+struct ContactForm {
+    #[transform(trim, lowercase)]
+    #[validate(email)]
+    email: String,
+
+    #[transform(trim, truncate = 5)]
+    nickname: String,
+}
+
+#[test]
+fn derive_transform_trims_and_lowercases() {
+    let mut form = ContactForm {
+        email: "  User@Example.COM  ".to_string(),
+        nickname: "buddy".to_string(),
+    };
+
+    form.transform();
+    assert_eq!(form.email, "user@example.com");
+}
+
+#[test]
+fn derive_transform_truncates_after_trimming() {
+    let mut form = ContactForm {
+        email: "user@example.com".to_string(),
+        nickname: "  buddyboy  ".to_string(),
+    };
+
+    form.transform();
+    assert_eq!(form.nickname, "buddy");
+}
+
+#[test]
+fn derive_transform_runs_before_validation_normalizes_email() {
+    let mut form = ContactForm {
+        email: "  USER@EXAMPLE.COM  ".to_string(),
+        nickname: "ok".to_string(),
+    };
+
+    form.transform();
+    assert!(form.validate().is_ok());
+}
+
+#[test]
+fn derive_transform_is_noop_for_fields_without_attribute() {
+    let user = CreateUser {
+        email: "test@example.com".to_string(),
+        username: "johndoe".to_string(),
+        age: 25,
+    };
+    let mut user = user;
+    user.transform();
+    assert_eq!(user.email, "test@example.com");
+}
+
+// Test nested struct/collection validation with indexed error paths
+#[derive(Validate)]
+struct LineItem {
+    #[validate(length(min = 1, max = 50))]
+    name: String,
+
+    #[validate(range(min = 1, max = 100))]
+    quantity: u32,
+}
+
+#[derive(Validate)]
+struct ShippingAddress {
+    #[validate(required)]
+    street: String,
+}
+
+#[derive(Validate)]
+struct Order {
+    #[validate(nested)]
+    address: ShippingAddress,
+
+    #[validate(length(min = 1))]
+    #[validate(nested)]
+    items: Vec<LineItem>,
+}
+
+#[test]
+fn derive_validate_nested_struct_valid() {
+    let order = Order {
+        address: ShippingAddress {
+            street: "1 Main St".to_string(),
+        },
+        items: vec![LineItem {
+            name: "Widget".to_string(),
+            quantity: 1,
+        }],
+    };
+
+    assert!(order.validate().is_ok());
+}
+
+#[test]
+fn derive_validate_nested_struct_errors_prefixed_with_field() {
+    let order = Order {
+        address: ShippingAddress {
+            street: "".to_string(),
+        },
+        items: vec![LineItem {
+            name: "Widget".to_string(),
+            quantity: 1,
+        }],
+    };
+
+    let result = order.validate();
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(errors.get("address.street").is_some());
+}
+
+#[test]
+fn derive_validate_nested_vec_errors_use_indexed_path() {
+    let order = Order {
+        address: ShippingAddress {
+            street: "1 Main St".to_string(),
+        },
+        items: vec![
+            LineItem {
+                name: "Widget".to_string(),
+                quantity: 1,
+            },
+            LineItem {
+                name: "".to_string(),
+                quantity: 1,
+            },
+        ],
+    };
+
+    let result = order.validate();
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(errors.get("items[1].name").is_some());
+    assert!(errors.get("items[0].name").is_none());
+}
+
+#[test]
+fn derive_validate_nested_vec_length_rejects_empty_collection() {
+    let order = Order {
+        address: ShippingAddress {
+            street: "1 Main St".to_string(),
+        },
+        items: vec![],
+    };
+
+    let result = order.validate();
+    assert!(result.is_err());
+    let errors = result.unwrap_err();
+    assert!(errors.get("items").is_some());
+}
+
 // Test async validation with derive macro
-#[derive(DeriveValidate)]
+#[derive(Validate)]
 struct AsyncUser {
     #[validate(email)]
     email: String,
@@ -0,0 +1,56 @@
+//! Cache-busted asset resolution backed by a build manifest
+
+use crate::ViewError;
+use rustapi_core::static_files::StaticFileConfig;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Maps logical asset names (`app.css`) to the cache-busted, hashed
+/// filenames (`app.a1b2c3.css`) produced by a frontend build step.
+///
+/// Paired with [`Templates::with_manifest`](crate::Templates::with_manifest),
+/// this backs the `{{ asset(name="app.css") }}` Tera helper.
+#[derive(Debug, Clone, Default)]
+pub struct AssetManifest {
+    entries: HashMap<String, String>,
+}
+
+impl AssetManifest {
+    /// An empty manifest - [`resolve`](Self::resolve) always falls back to
+    /// the requested name unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a manifest from a JSON file mapping logical names to hashed filenames.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ViewError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_json(&content)
+    }
+
+    /// Parse a manifest from a JSON object string.
+    pub fn from_json(json: &str) -> Result<Self, ViewError> {
+        let entries: HashMap<String, String> =
+            serde_json::from_str(json).map_err(|e| ViewError::parse_error(e.to_string()))?;
+        Ok(Self { entries })
+    }
+
+    /// Resolve a logical asset name to its hashed filename, falling back to
+    /// the name unchanged if it isn't in the manifest.
+    pub fn resolve<'a>(&'a self, name: &'a str) -> &'a str {
+        self.entries.get(name).map(String::as_str).unwrap_or(name)
+    }
+
+    /// Build a [`StaticFileConfig`] for serving the hashed files this
+    /// manifest points at, with a one-year immutable `Cache-Control` -
+    /// content-hashed filenames never change contents under the same name.
+    pub fn static_config(
+        &self,
+        prefix: impl Into<String>,
+        root: impl Into<std::path::PathBuf>,
+    ) -> StaticFileConfig {
+        StaticFileConfig::new(root, prefix)
+            .max_age(31_536_000)
+            .immutable(true)
+    }
+}
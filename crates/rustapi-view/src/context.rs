@@ -1,4 +1,9 @@
 //! Context builder for templates
+//!
+//! This builder produces a `tera::Context` and is only available with the
+//! `tera` feature; Handlebars and MiniJinja backends render directly from
+//! serializable values via `Templates::render_with`.
+#![cfg(feature = "tera")]
 
 use serde::Serialize;
 use tera::Context;
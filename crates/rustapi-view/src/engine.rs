@@ -0,0 +1,56 @@
+use crate::ViewError;
+use async_trait::async_trait;
+
+#[cfg(feature = "tera")]
+pub mod tera_engine;
+
+#[cfg(feature = "handlebars")]
+pub mod handlebars_engine;
+
+#[cfg(feature = "minijinja")]
+pub mod minijinja_engine;
+
+/// Rendering backend for [`Templates`](crate::Templates).
+///
+/// `Templates` talks to whichever engine is selected (Tera, Handlebars,
+/// MiniJinja - each behind its own feature flag) through this trait, so
+/// `View<T>` and the rest of the `Templates` API don't change with the
+/// backend.
+#[async_trait]
+pub trait TemplateEngine: Send + Sync {
+    /// Register a template from a string under `name`.
+    async fn add_template(&self, name: &str, content: &str) -> Result<(), ViewError>;
+
+    /// Render a template with a JSON-serializable context.
+    async fn render(&self, name: &str, context: &serde_json::Value) -> Result<String, ViewError>;
+
+    /// Whether a template with this name has been registered.
+    async fn has_template(&self, name: &str) -> bool;
+
+    /// Names of all registered templates.
+    async fn template_names(&self) -> Vec<String>;
+
+    /// Reload all templates from disk.
+    async fn reload(&self) -> Result<(), ViewError>;
+
+    /// Register a `{{ asset(name="...") }}`-style helper backed by an asset
+    /// manifest, for engines that support custom functions. A no-op on
+    /// engines that don't (the default).
+    async fn register_assets(&self, _prefix: &str, _manifest: &crate::assets::AssetManifest) {}
+
+    /// Register a `{{ t(key="...") }}`-style translation helper backed by a
+    /// [`Translator`](crate::Translator), for engines that support custom
+    /// functions. A no-op on engines that don't (the default).
+    async fn register_translations(&self, _translator: std::sync::Arc<dyn crate::Translator>) {}
+}
+
+/// The portion of a glob pattern before its first wildcard, up to the
+/// preceding path separator - e.g. `templates/**/*.html` -> `templates/`.
+#[cfg(any(feature = "handlebars", feature = "minijinja", feature = "watch"))]
+pub(crate) fn glob_base_dir(pattern: &str) -> String {
+    let meta_pos = pattern.find(['*', '?', '[']).unwrap_or(pattern.len());
+    match pattern[..meta_pos].rfind('/') {
+        Some(idx) => pattern[..=idx].to_string(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,114 @@
+//! Handlebars-backed [`TemplateEngine`].
+
+use crate::engine::{glob_base_dir, TemplateEngine};
+use crate::{TemplatesConfig, ViewError};
+use async_trait::async_trait;
+use handlebars::Handlebars;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub(crate) struct HandlebarsEngine {
+    inner: Arc<RwLock<Handlebars<'static>>>,
+    glob: Option<String>,
+}
+
+impl HandlebarsEngine {
+    pub(crate) fn with_config(config: &TemplatesConfig) -> Result<Self, ViewError> {
+        let mut hb = Handlebars::new();
+        hb.set_strict_mode(config.strict_mode);
+        register_templates(&mut hb, &config.glob)?;
+
+        Ok(Self {
+            inner: Arc::new(RwLock::new(hb)),
+            glob: Some(config.glob.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl TemplateEngine for HandlebarsEngine {
+    async fn add_template(&self, name: &str, content: &str) -> Result<(), ViewError> {
+        let mut hb = self.inner.write().await;
+        hb.register_template_string(name, content)
+            .map_err(|e| ViewError::parse_error(e.to_string()))
+    }
+
+    async fn render(&self, name: &str, context: &serde_json::Value) -> Result<String, ViewError> {
+        let hb = self.inner.read().await;
+        hb.render(name, context)
+            .map_err(|e| ViewError::render_error(e.to_string()))
+    }
+
+    async fn has_template(&self, name: &str) -> bool {
+        let hb = self.inner.read().await;
+        hb.has_template(name)
+    }
+
+    async fn template_names(&self) -> Vec<String> {
+        let hb = self.inner.read().await;
+        hb.get_templates().keys().cloned().collect()
+    }
+
+    async fn reload(&self) -> Result<(), ViewError> {
+        let Some(glob) = &self.glob else {
+            return Ok(());
+        };
+        let mut hb = self.inner.write().await;
+        hb.clear_templates();
+        register_templates(&mut hb, glob)
+    }
+}
+
+/// Register every file matching `pattern`, naming each template by its path
+/// relative to the glob's fixed prefix (mirrors Tera's own glob naming).
+fn register_templates(hb: &mut Handlebars<'static>, pattern: &str) -> Result<(), ViewError> {
+    let base_dir = glob_base_dir(pattern);
+    for entry in glob::glob(pattern).map_err(|e| ViewError::parse_error(e.to_string()))? {
+        let path = entry.map_err(|e| ViewError::parse_error(e.to_string()))?;
+        let path_str = path.to_string_lossy().replace('\\', "/");
+        let name = path_str.strip_prefix(&base_dir).unwrap_or(&path_str);
+        hb.register_template_file(name, &path)
+            .map_err(|e| ViewError::parse_error(e.to_string()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Templates, TemplatesConfig};
+
+    fn empty_templates() -> Templates {
+        let config = TemplatesConfig::new("nonexistent/**/*.hbs");
+        let engine = HandlebarsEngine::with_config(&config).unwrap();
+        Templates::with_engine(Arc::new(engine), config)
+    }
+
+    #[tokio::test]
+    async fn test_render_with_struct() {
+        #[derive(serde::Serialize)]
+        struct Data {
+            name: String,
+        }
+
+        let templates = empty_templates();
+        templates
+            .add_template("test", "Hello, {{ name }}!")
+            .await
+            .unwrap();
+
+        let data = Data {
+            name: "Alice".to_string(),
+        };
+        let result = templates.render_with("test", &data).await.unwrap();
+        assert_eq!(result, "Hello, Alice!");
+    }
+
+    #[tokio::test]
+    async fn test_has_template() {
+        let templates = empty_templates();
+        assert!(!templates.has_template("test").await);
+        templates.add_template("test", "Hi!").await.unwrap();
+        assert!(templates.has_template("test").await);
+    }
+}
@@ -0,0 +1,120 @@
+//! MiniJinja-backed [`TemplateEngine`].
+
+use crate::engine::{glob_base_dir, TemplateEngine};
+use crate::{TemplatesConfig, ViewError};
+use async_trait::async_trait;
+use minijinja::Environment;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub(crate) struct MiniJinjaEngine {
+    inner: Arc<RwLock<Environment<'static>>>,
+    glob: Option<String>,
+}
+
+impl MiniJinjaEngine {
+    pub(crate) fn with_config(config: &TemplatesConfig) -> Result<Self, ViewError> {
+        let mut env = Environment::new();
+        register_templates(&mut env, &config.glob)?;
+
+        Ok(Self {
+            inner: Arc::new(RwLock::new(env)),
+            glob: Some(config.glob.clone()),
+        })
+    }
+}
+
+#[async_trait]
+impl TemplateEngine for MiniJinjaEngine {
+    async fn add_template(&self, name: &str, content: &str) -> Result<(), ViewError> {
+        let mut env = self.inner.write().await;
+        env.add_template_owned(name.to_string(), content.to_string())
+            .map_err(|e| ViewError::parse_error(e.to_string()))
+    }
+
+    async fn render(&self, name: &str, context: &serde_json::Value) -> Result<String, ViewError> {
+        let env = self.inner.read().await;
+        let template = env
+            .get_template(name)
+            .map_err(|_| ViewError::not_found(name))?;
+        template
+            .render(context)
+            .map_err(|e| ViewError::render_error(e.to_string()))
+    }
+
+    async fn has_template(&self, name: &str) -> bool {
+        let env = self.inner.read().await;
+        env.get_template(name).is_ok()
+    }
+
+    async fn template_names(&self) -> Vec<String> {
+        let env = self.inner.read().await;
+        env.templates().map(|(name, _)| name.to_string()).collect()
+    }
+
+    async fn reload(&self) -> Result<(), ViewError> {
+        let Some(glob) = &self.glob else {
+            return Ok(());
+        };
+        let mut fresh = Environment::new();
+        register_templates(&mut fresh, glob)?;
+        let mut env = self.inner.write().await;
+        *env = fresh;
+        Ok(())
+    }
+}
+
+/// Register every file matching `pattern`, naming each template by its path
+/// relative to the glob's fixed prefix (mirrors Tera's own glob naming).
+fn register_templates(env: &mut Environment<'static>, pattern: &str) -> Result<(), ViewError> {
+    let base_dir = glob_base_dir(pattern);
+    for entry in glob::glob(pattern).map_err(|e| ViewError::parse_error(e.to_string()))? {
+        let path = entry.map_err(|e| ViewError::parse_error(e.to_string()))?;
+        let path_str = path.to_string_lossy().replace('\\', "/");
+        let name = path_str.strip_prefix(&base_dir).unwrap_or(&path_str).to_string();
+        let source = std::fs::read_to_string(&path)?;
+        env.add_template_owned(name, source)
+            .map_err(|e| ViewError::parse_error(e.to_string()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Templates, TemplatesConfig};
+
+    fn empty_templates() -> Templates {
+        let config = TemplatesConfig::new("nonexistent/**/*.jinja");
+        let engine = MiniJinjaEngine::with_config(&config).unwrap();
+        Templates::with_engine(Arc::new(engine), config)
+    }
+
+    #[tokio::test]
+    async fn test_render_with_struct() {
+        #[derive(serde::Serialize)]
+        struct Data {
+            name: String,
+        }
+
+        let templates = empty_templates();
+        templates
+            .add_template("test", "Hello, {{ name }}!")
+            .await
+            .unwrap();
+
+        let data = Data {
+            name: "Alice".to_string(),
+        };
+        let result = templates.render_with("test", &data).await.unwrap();
+        assert_eq!(result, "Hello, Alice!");
+    }
+
+    #[tokio::test]
+    async fn test_has_template() {
+        let templates = empty_templates();
+        assert!(!templates.has_template("test").await);
+        templates.add_template("test", "Hi!").await.unwrap();
+        assert!(templates.has_template("test").await);
+    }
+}
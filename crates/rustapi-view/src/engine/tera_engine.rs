@@ -0,0 +1,167 @@
+//! Tera-backed [`TemplateEngine`] - the original, still-default rustapi-view backend.
+
+use crate::engine::TemplateEngine;
+use crate::{TemplatesConfig, ViewError};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tera::Tera;
+use tokio::sync::RwLock;
+
+pub(crate) struct TeraEngine {
+    inner: Arc<RwLock<Tera>>,
+    auto_reload: bool,
+}
+
+impl TeraEngine {
+    pub(crate) fn with_config(config: &TemplatesConfig) -> Result<Self, ViewError> {
+        let mut tera = Tera::new(&config.glob)?;
+        register_builtin_filters(&mut tera);
+
+        Ok(Self {
+            inner: Arc::new(RwLock::new(tera)),
+            auto_reload: config.auto_reload,
+        })
+    }
+
+    pub(crate) fn empty() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Tera::default())),
+            auto_reload: false,
+        }
+    }
+}
+
+#[async_trait]
+impl TemplateEngine for TeraEngine {
+    async fn add_template(&self, name: &str, content: &str) -> Result<(), ViewError> {
+        let mut tera = self.inner.write().await;
+        tera.add_raw_template(name, content)?;
+        Ok(())
+    }
+
+    async fn render(&self, name: &str, context: &serde_json::Value) -> Result<String, ViewError> {
+        // If auto-reload is enabled and in debug mode, try to reload
+        #[cfg(debug_assertions)]
+        if self.auto_reload {
+            let mut tera = self.inner.write().await;
+            if let Err(e) = tera.full_reload() {
+                tracing::warn!("Template reload failed: {}", e);
+            }
+        }
+
+        let ctx = tera::Context::from_value(context.clone())
+            .map_err(|e| ViewError::serialization_error(e.to_string()))?;
+        let tera = self.inner.read().await;
+        tera.render(name, &ctx).map_err(ViewError::from)
+    }
+
+    async fn has_template(&self, name: &str) -> bool {
+        let tera = self.inner.read().await;
+        let result = tera.get_template_names().any(|n| n == name);
+        result
+    }
+
+    async fn template_names(&self) -> Vec<String> {
+        let tera = self.inner.read().await;
+        tera.get_template_names().map(String::from).collect()
+    }
+
+    async fn reload(&self) -> Result<(), ViewError> {
+        let mut tera = self.inner.write().await;
+        tera.full_reload()?;
+        Ok(())
+    }
+
+    async fn register_assets(&self, prefix: &str, manifest: &crate::assets::AssetManifest) {
+        let prefix = prefix.to_string();
+        let manifest = manifest.clone();
+        let mut tera = self.inner.write().await;
+        tera.register_function(
+            "asset",
+            move |args: &std::collections::HashMap<String, tera::Value>| {
+                let name = args
+                    .get("name")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| tera::Error::msg("asset() requires a `name` argument"))?;
+                Ok(tera::Value::String(format!(
+                    "{prefix}{}",
+                    manifest.resolve(name)
+                )))
+            },
+        );
+    }
+
+    async fn register_translations(&self, translator: Arc<dyn crate::Translator>) {
+        let mut tera = self.inner.write().await;
+        tera.register_function(
+            "t",
+            move |args: &std::collections::HashMap<String, tera::Value>| {
+                let key = args
+                    .get("key")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| tera::Error::msg("t() requires a `key` argument"))?;
+                let locale = args.get("locale").and_then(|v| v.as_str());
+
+                let vars: std::collections::HashMap<String, String> = args
+                    .iter()
+                    .filter(|(name, _)| name.as_str() != "key" && name.as_str() != "locale")
+                    .map(|(name, value)| {
+                        let rendered = value.as_str().map(String::from).unwrap_or_else(|| value.to_string());
+                        (name.clone(), rendered)
+                    })
+                    .collect();
+
+                Ok(tera::Value::String(translator.translate(locale, key, &vars)))
+            },
+        );
+    }
+}
+
+/// Register built-in template filters
+fn register_builtin_filters(tera: &mut Tera) {
+    // Markdown to sanitized HTML - use with `| safe` to embed the result
+    #[cfg(feature = "markdown")]
+    tera.register_filter(
+        "markdown",
+        |value: &tera::Value, _: &std::collections::HashMap<String, tera::Value>| {
+            let source = tera::try_get_value!("markdown", "value", String, value);
+            Ok(tera::Value::String(crate::markdown::render_markdown(
+                &source,
+            )))
+        },
+    );
+
+    // JSON filter for debugging
+    tera.register_filter(
+        "json_pretty",
+        |value: &tera::Value, _: &std::collections::HashMap<String, tera::Value>| {
+            serde_json::to_string_pretty(value)
+                .map(tera::Value::String)
+                .map_err(|e| tera::Error::msg(e.to_string()))
+        },
+    );
+
+    // Truncate string
+    tera.register_filter(
+        "truncate_words",
+        |value: &tera::Value, args: &std::collections::HashMap<String, tera::Value>| {
+            let s = tera::try_get_value!("truncate_words", "value", String, value);
+            let length = match args.get("length") {
+                Some(val) => tera::try_get_value!("truncate_words", "length", usize, val),
+                None => 50,
+            };
+            let end = match args.get("end") {
+                Some(val) => tera::try_get_value!("truncate_words", "end", String, val),
+                None => "...".to_string(),
+            };
+
+            let words: Vec<&str> = s.split_whitespace().collect();
+            if words.len() <= length {
+                Ok(tera::Value::String(s))
+            } else {
+                let truncated: String = words[..length].join(" ");
+                Ok(tera::Value::String(format!("{}{}", truncated, end)))
+            }
+        },
+    );
+}
@@ -30,8 +30,14 @@ pub enum ViewError {
     IoError(#[from] std::io::Error),
 
     /// Tera error
+    #[cfg(feature = "tera")]
     #[error("Tera error: {0}")]
     Tera(#[from] tera::Error),
+
+    /// Filesystem watcher error (dev-mode auto-reload)
+    #[cfg(feature = "watch")]
+    #[error("Template watcher error: {0}")]
+    Watch(#[from] notify::Error),
 }
 
 impl ViewError {
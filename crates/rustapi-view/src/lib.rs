@@ -1,18 +1,27 @@
 //! # rustapi-view
 //!
-//! Template rendering support for the RustAPI framework using Tera templates.
+//! Template rendering support for the RustAPI framework, with the rendering
+//! backend selected by feature flag behind a single `Templates`/`View` API.
 //!
 //! This crate provides server-side HTML rendering with type-safe template contexts,
 //! layout inheritance, and development-friendly features like auto-reload.
 //!
 //! ## Features
 //!
-//! - **Tera Templates**: Full Tera template engine support with filters, macros, and inheritance
+//! - **Pluggable Engines**: Tera (default), Handlebars, or MiniJinja via the
+//!   [`TemplateEngine`] trait - pick one or more with feature flags
 //! - **Type-Safe Context**: Build template context from Rust structs via serde
-//! - **Auto-Reload**: Development mode can auto-reload templates on change
+//! - **Auto-Reload**: with the `watch` feature, `Templates::watch` reloads
+//!   templates automatically when the glob's files change in development
 //! - **Response Types**: `View<T>` response type for rendering templates
 //! - **Layout Support**: Template inheritance with blocks
 //!
+//! Askama isn't offered as a backend: it compiles each template into its own
+//! Rust type with a generated `render()` method, so there's no way to look up
+//! a template by name at runtime the way [`TemplateEngine`] requires. Askama
+//! users should render into a `String` themselves and hand it to
+//! `View::from_html`.
+//!
 //! ## Quick Start
 //!
 //! ```rust,ignore
@@ -48,20 +57,46 @@
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
+mod assets;
+#[cfg(feature = "tera")]
 mod context;
+mod engine;
 mod error;
+#[cfg(feature = "markdown")]
+mod markdown;
 mod templates;
+mod translate;
 mod view;
 
+pub use assets::AssetManifest;
+#[cfg(feature = "tera")]
 pub use context::ContextBuilder;
+pub use engine::TemplateEngine;
 pub use error::ViewError;
+#[cfg(feature = "markdown")]
+pub use markdown::render_markdown;
 pub use templates::{Templates, TemplatesConfig};
+pub use translate::Translator;
+#[cfg(feature = "markdown")]
+pub use view::Markdown;
 pub use view::View;
 
 // Re-export tera types that users might need
+#[cfg(feature = "tera")]
 pub use tera::Context;
 
+// Re-export the preload hint type so `View::preload`/`Markdown::preload`
+// callers don't need a direct `rustapi-core` dependency.
+pub use rustapi_core::PreloadHint;
+
 /// Prelude module for convenient imports
 pub mod prelude {
-    pub use crate::{Context, ContextBuilder, Templates, TemplatesConfig, View, ViewError};
+    #[cfg(feature = "tera")]
+    pub use crate::{Context, ContextBuilder};
+    #[cfg(feature = "markdown")]
+    pub use crate::{render_markdown, Markdown};
+    pub use crate::{
+        AssetManifest, PreloadHint, TemplateEngine, Templates, TemplatesConfig, Translator, View,
+        ViewError,
+    };
 }
@@ -0,0 +1,34 @@
+//! CommonMark rendering, backing the `markdown` Tera filter and [`crate::Markdown`].
+
+use pulldown_cmark::{html, Options, Parser};
+
+/// Render `source` (CommonMark) to sanitized HTML.
+///
+/// Runs the parsed HTML through [`ammonia`]'s default sanitizer, which
+/// strips `<script>`/`<style>` tags, inline event handlers, and anything
+/// else unsafe to embed straight from user- or file-provided markdown.
+pub fn render_markdown(source: &str) -> String {
+    let parser = Parser::new_ext(source, Options::all());
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, parser);
+    ammonia::clean(&unsafe_html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renders_commonmark() {
+        let html = render_markdown("# Title\n\nSome **bold** text.");
+        assert!(html.contains("<h1>Title</h1>"));
+        assert!(html.contains("<strong>bold</strong>"));
+    }
+
+    #[test]
+    fn test_strips_unsafe_markup() {
+        let html = render_markdown("<script>alert('xss')</script>\n\nHello");
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("Hello"));
+    }
+}
@@ -1,9 +1,9 @@
 //! Template engine wrapper
 
+use crate::engine::TemplateEngine;
 use crate::ViewError;
+use rustapi_core::Request;
 use std::sync::Arc;
-use tera::Tera;
-use tokio::sync::RwLock;
 
 /// Configuration for the template engine
 #[derive(Debug, Clone)]
@@ -50,8 +50,9 @@ impl TemplatesConfig {
 
 /// Template engine wrapper providing thread-safe template rendering
 ///
-/// This type wraps the Tera template engine and can be shared across
-/// handlers via `State<Templates>`.
+/// `Templates` renders through a pluggable [`TemplateEngine`] backend - Tera
+/// by default, or Handlebars/MiniJinja when their feature flags are enabled -
+/// and can be shared across handlers via `State<Templates>`.
 ///
 /// # Example
 ///
@@ -62,12 +63,16 @@ impl TemplatesConfig {
 /// ```
 #[derive(Clone)]
 pub struct Templates {
-    inner: Arc<RwLock<Tera>>,
+    engine: Arc<dyn TemplateEngine>,
     config: TemplatesConfig,
+    globals: Option<Arc<GlobalsFn>>,
 }
 
+/// A function deriving global template context fields from the current request.
+type GlobalsFn = dyn Fn(&Request) -> serde_json::Value + Send + Sync;
+
 impl Templates {
-    /// Create a new template engine from a glob pattern
+    /// Create a new Tera-backed template engine from a glob pattern
     ///
     /// The glob pattern specifies which files to load as templates.
     /// Common patterns:
@@ -77,60 +82,112 @@ impl Templates {
     /// # Errors
     ///
     /// Returns an error if the glob pattern is invalid or templates fail to parse.
+    #[cfg(feature = "tera")]
     pub fn new(glob: impl Into<String>) -> Result<Self, ViewError> {
         let config = TemplatesConfig::new(glob);
         Self::with_config(config)
     }
 
-    /// Create a new template engine with configuration
+    /// Create a new Tera-backed template engine with configuration
+    #[cfg(feature = "tera")]
     pub fn with_config(config: TemplatesConfig) -> Result<Self, ViewError> {
-        let mut tera = Tera::new(&config.glob)?;
+        let engine = crate::engine::tera_engine::TeraEngine::with_config(&config)?;
+        Ok(Self::with_engine(Arc::new(engine), config))
+    }
 
-        // Register custom filters/functions
-        register_builtin_filters(&mut tera);
+    /// Create an empty Tera-backed template engine (for adding templates programmatically)
+    #[cfg(feature = "tera")]
+    pub fn empty() -> Self {
+        Self::with_engine(
+            Arc::new(crate::engine::tera_engine::TeraEngine::empty()),
+            TemplatesConfig::default(),
+        )
+    }
 
-        Ok(Self {
-            inner: Arc::new(RwLock::new(tera)),
-            config,
-        })
+    /// Create a new Handlebars-backed template engine from a glob pattern
+    #[cfg(feature = "handlebars")]
+    pub fn handlebars(glob: impl Into<String>) -> Result<Self, ViewError> {
+        let config = TemplatesConfig::new(glob);
+        let engine = crate::engine::handlebars_engine::HandlebarsEngine::with_config(&config)?;
+        Ok(Self::with_engine(Arc::new(engine), config))
     }
 
-    /// Create an empty template engine (for adding templates programmatically)
-    pub fn empty() -> Self {
+    /// Create a new MiniJinja-backed template engine from a glob pattern
+    #[cfg(feature = "minijinja")]
+    pub fn minijinja(glob: impl Into<String>) -> Result<Self, ViewError> {
+        let config = TemplatesConfig::new(glob);
+        let engine = crate::engine::minijinja_engine::MiniJinjaEngine::with_config(&config)?;
+        Ok(Self::with_engine(Arc::new(engine), config))
+    }
+
+    /// Create a `Templates` wrapper around any [`TemplateEngine`] backend
+    pub fn with_engine(engine: Arc<dyn TemplateEngine>, config: TemplatesConfig) -> Self {
         Self {
-            inner: Arc::new(RwLock::new(Tera::default())),
-            config: TemplatesConfig::default(),
+            engine,
+            config,
+            globals: None,
         }
     }
 
+    /// Register a function that derives global context fields from the
+    /// current request, merged into every [`render_with_request`](Self::render_with_request)
+    /// call - the current user, a CSRF token, flash messages, and similar
+    /// per-request data that every template needs but no handler should have
+    /// to repeat.
+    ///
+    /// `f` must return a JSON object; fields set explicitly by a render call
+    /// take precedence over same-named global fields.
+    pub fn with_globals(
+        mut self,
+        f: impl Fn(&Request) -> serde_json::Value + Send + Sync + 'static,
+    ) -> Self {
+        self.globals = Some(Arc::new(f));
+        self
+    }
+
+    /// Register a cache-busted asset manifest, enabling the
+    /// `{{ asset(name="app.css") }}` template helper. `prefix` is prepended
+    /// to every resolved filename and should include a trailing slash (e.g.
+    /// `"/assets/"`). A no-op on backends that don't support custom
+    /// functions - currently only Tera does.
+    pub async fn with_manifest(
+        self,
+        prefix: impl Into<String>,
+        manifest: crate::assets::AssetManifest,
+    ) -> Self {
+        self.engine.register_assets(&prefix.into(), &manifest).await;
+        self
+    }
+
+    /// Register a [`Translator`](crate::Translator) (e.g. `rustapi-i18n`'s
+    /// `Catalogs`), enabling the `{{ t(key="...") }}` template helper. A
+    /// no-op on backends that don't support custom functions - currently
+    /// only Tera does.
+    pub async fn with_translations(self, translator: std::sync::Arc<dyn crate::Translator>) -> Self {
+        self.engine.register_translations(translator).await;
+        self
+    }
+
     /// Add a template from a string
     pub async fn add_template(
         &self,
         name: impl Into<String>,
         content: impl Into<String>,
     ) -> Result<(), ViewError> {
-        let mut tera = self.inner.write().await;
-        tera.add_raw_template(&name.into(), &content.into())?;
-        Ok(())
+        self.engine.add_template(&name.into(), &content.into()).await
     }
 
-    /// Render a template with the given context
+    /// Render a template with a Tera context
+    #[cfg(feature = "tera")]
     pub async fn render(
         &self,
         template: &str,
         context: &tera::Context,
     ) -> Result<String, ViewError> {
-        // If auto-reload is enabled and in debug mode, try to reload
-        #[cfg(debug_assertions)]
-        if self.config.auto_reload {
-            let mut tera = self.inner.write().await;
-            if let Err(e) = tera.full_reload() {
-                tracing::warn!("Template reload failed: {}", e);
-            }
-        }
-
-        let tera = self.inner.read().await;
-        tera.render(template, context).map_err(ViewError::from)
+        let value = context
+            .clone()
+            .into_json();
+        self.engine.render(template, &value).await
     }
 
     /// Render a template with a serializable context
@@ -139,75 +196,118 @@ impl Templates {
         template: &str,
         data: &T,
     ) -> Result<String, ViewError> {
-        let context = tera::Context::from_serialize(data)
+        let context = serde_json::to_value(data)
+            .map_err(|e| ViewError::serialization_error(e.to_string()))?;
+        self.engine.render(template, &context).await
+    }
+
+    /// Render a template from a raw JSON context, for response types (like
+    /// [`Markdown<T>`](crate::Markdown)) that build up context beyond a
+    /// single serializable struct.
+    #[cfg(feature = "markdown")]
+    pub(crate) async fn render_value(
+        &self,
+        template: &str,
+        context: serde_json::Value,
+    ) -> Result<String, ViewError> {
+        self.engine.render(template, &context).await
+    }
+
+    /// Render a template, merging in the request-derived globals registered
+    /// via [`with_globals`](Self::with_globals) (if any) under the fields of
+    /// `data`.
+    pub async fn render_with_request<T: serde::Serialize>(
+        &self,
+        req: &Request,
+        template: &str,
+        data: &T,
+    ) -> Result<String, ViewError> {
+        let mut context = match &self.globals {
+            Some(globals) => globals(req),
+            None => serde_json::Value::Object(Default::default()),
+        };
+        let data = serde_json::to_value(data)
             .map_err(|e| ViewError::serialization_error(e.to_string()))?;
-        self.render(template, &context).await
+        merge_json(&mut context, data);
+        self.engine.render(template, &context).await
     }
 
     /// Check if a template exists
     pub async fn has_template(&self, name: &str) -> bool {
-        let tera = self.inner.read().await;
-        let result = tera.get_template_names().any(|n| n == name);
-        result
+        self.engine.has_template(name).await
     }
 
     /// Get all template names
     pub async fn template_names(&self) -> Vec<String> {
-        let tera = self.inner.read().await;
-        tera.get_template_names().map(String::from).collect()
+        self.engine.template_names().await
     }
 
     /// Reload all templates from disk
     pub async fn reload(&self) -> Result<(), ViewError> {
-        let mut tera = self.inner.write().await;
-        tera.full_reload()?;
-        Ok(())
+        self.engine.reload().await
     }
 
     /// Get the configuration
     pub fn config(&self) -> &TemplatesConfig {
         &self.config
     }
-}
 
-/// Register built-in template filters
-fn register_builtin_filters(tera: &mut Tera) {
-    // JSON filter for debugging
-    tera.register_filter(
-        "json_pretty",
-        |value: &tera::Value, _: &std::collections::HashMap<String, tera::Value>| {
-            serde_json::to_string_pretty(value)
-                .map(tera::Value::String)
-                .map_err(|e| tera::Error::msg(e.to_string()))
-        },
-    );
-
-    // Truncate string
-    tera.register_filter(
-        "truncate_words",
-        |value: &tera::Value, args: &std::collections::HashMap<String, tera::Value>| {
-            let s = tera::try_get_value!("truncate_words", "value", String, value);
-            let length = match args.get("length") {
-                Some(val) => tera::try_get_value!("truncate_words", "length", usize, val),
-                None => 50,
-            };
-            let end = match args.get("end") {
-                Some(val) => tera::try_get_value!("truncate_words", "end", String, val),
-                None => "...".to_string(),
-            };
-
-            let words: Vec<&str> = s.split_whitespace().collect();
-            if words.len() <= length {
-                Ok(tera::Value::String(s))
-            } else {
-                let truncated: String = words[..length].join(" ");
-                Ok(tera::Value::String(format!("{}{}", truncated, end)))
+    /// Watch the template glob for changes and reload automatically
+    ///
+    /// Only does anything when both `config().auto_reload` is set and `env`
+    /// is [`rustapi_core::Environment::Development`] - in production this is
+    /// a no-op, so it's safe to call unconditionally on startup.
+    #[cfg(feature = "watch")]
+    pub async fn watch(&self, env: rustapi_core::Environment) -> Result<(), ViewError> {
+        use notify::{RecursiveMode, Watcher};
+
+        if !(self.config.auto_reload && env.is_development()) {
+            return Ok(());
+        }
+
+        let base_dir = crate::engine::glob_base_dir(&self.config.glob);
+        let watch_dir = if base_dir.is_empty() {
+            ".".to_string()
+        } else {
+            base_dir
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            if event.is_ok() {
+                let _ = tx.send(());
+            }
+        })?;
+        watcher.watch(std::path::Path::new(&watch_dir), RecursiveMode::Recursive)?;
+
+        let templates = self.clone();
+        tokio::spawn(async move {
+            let _watcher = watcher;
+            while rx.recv().await.is_some() {
+                if let Err(e) = templates.reload().await {
+                    tracing::warn!("Template reload failed: {}", e);
+                }
             }
+        });
+
+        Ok(())
+    }
+}
+
+/// Merge `overlay`'s object fields into `base`, overwriting same-named
+/// fields. If either side isn't an object, `overlay` replaces `base`
+/// wholesale.
+fn merge_json(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match overlay {
+        serde_json::Value::Object(overlay_map) => match base {
+            serde_json::Value::Object(base_map) => base_map.extend(overlay_map),
+            _ => *base = serde_json::Value::Object(overlay_map),
         },
-    );
+        other => *base = other,
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "tera"))]
 mod tests {
     use super::*;
 
@@ -245,4 +345,62 @@ mod tests {
         let result = templates.render_with("test", &data).await.unwrap();
         assert_eq!(result, "Hello, Alice!");
     }
+
+    #[tokio::test]
+    async fn test_with_manifest_resolves_asset() {
+        let manifest =
+            crate::assets::AssetManifest::from_json(r#"{"app.css": "app.a1b2c3.css"}"#).unwrap();
+        let templates = Templates::empty().with_manifest("/assets/", manifest).await;
+        templates
+            .add_template("test", r#"<link href="{{ asset(name="app.css") }}">"#)
+            .await
+            .unwrap();
+
+        let result = templates
+            .render_with("test", &serde_json::json!({}))
+            .await
+            .unwrap();
+        assert_eq!(result, r#"<link href="/assets/app.a1b2c3.css">"#);
+    }
+
+    #[cfg(feature = "markdown")]
+    #[tokio::test]
+    async fn test_markdown_filter() {
+        let config = TemplatesConfig::new("nonexistent/**/*.html").auto_reload(false);
+        let templates = Templates::with_config(config).unwrap();
+        templates
+            .add_template("test", "{{ source | markdown | safe }}")
+            .await
+            .unwrap();
+
+        let result = templates
+            .render_with("test", &serde_json::json!({ "source": "# Hi\n\n**there**" }))
+            .await
+            .unwrap();
+        assert_eq!(result, "<h1>Hi</h1>\n<p><strong>there</strong></p>\n");
+    }
+}
+
+#[cfg(all(test, feature = "tera", feature = "watch"))]
+mod watch_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn watch_is_noop_in_production() {
+        let templates = Templates::empty();
+        templates
+            .watch(rustapi_core::Environment::Production)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn watch_is_noop_without_auto_reload() {
+        let config = TemplatesConfig::new("templates/**/*.html").auto_reload(false);
+        let templates = Templates::with_config(config).unwrap();
+        templates
+            .watch(rustapi_core::Environment::Development)
+            .await
+            .unwrap();
+    }
 }
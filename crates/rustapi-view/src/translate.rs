@@ -0,0 +1,15 @@
+//! Translation hook backing the `t(key = "...")` template helper
+
+use std::collections::HashMap;
+
+/// Resolves a translation key to localized text, for engines that support
+/// registering the `t()` helper (currently Tera).
+///
+/// Implemented by `rustapi-i18n`'s `Catalogs`, or any other message-catalog
+/// type an application wants to render translations from.
+pub trait Translator: Send + Sync {
+    /// Resolve `key`, interpolating `args` into the message. `locale` is an
+    /// engine-agnostic locale tag (e.g. `"en-US"`) taken from the template
+    /// call's `locale` argument - `None` requests the translator's default.
+    fn translate(&self, locale: Option<&str>, key: &str, args: &HashMap<String, String>) -> String;
+}
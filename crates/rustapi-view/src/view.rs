@@ -4,7 +4,7 @@ use crate::{Templates, ViewError};
 use bytes::Bytes;
 use http::{header, Response, StatusCode};
 use http_body_util::Full;
-use rustapi_core::IntoResponse;
+use rustapi_core::{IntoResponse, PreloadHint, Request};
 use rustapi_openapi::{MediaType, Operation, ResponseModifier, ResponseSpec, SchemaRef};
 use serde::Serialize;
 use std::collections::HashMap;
@@ -36,6 +36,8 @@ pub struct View<T> {
     content: Result<String, ViewError>,
     /// Status code (default 200)
     status: StatusCode,
+    /// `Link` preload/preconnect hints to attach to the response
+    preload: Vec<PreloadHint>,
     /// Phantom data for the context type
     _phantom: PhantomData<T>,
 }
@@ -50,6 +52,7 @@ impl<T: Serialize> View<T> {
         Self {
             content,
             status: StatusCode::OK,
+            preload: Vec::new(),
             _phantom: PhantomData,
         }
     }
@@ -65,6 +68,24 @@ impl<T: Serialize> View<T> {
         Self {
             content,
             status,
+            preload: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a view by rendering a template, merging in the request's
+    /// registered [`Templates::with_globals`] context under `context`
+    pub async fn render_req(
+        req: &Request,
+        templates: &Templates,
+        template: &str,
+        context: T,
+    ) -> Self {
+        let content = templates.render_with_request(req, template, &context).await;
+        Self {
+            content,
+            status: StatusCode::OK,
+            preload: Vec::new(),
             _phantom: PhantomData,
         }
     }
@@ -74,6 +95,7 @@ impl<T: Serialize> View<T> {
         Self {
             content: Ok(html.into()),
             status: StatusCode::OK,
+            preload: Vec::new(),
             _phantom: PhantomData,
         }
     }
@@ -83,6 +105,7 @@ impl<T: Serialize> View<T> {
         Self {
             content: Err(err),
             status: StatusCode::INTERNAL_SERVER_ERROR,
+            preload: Vec::new(),
             _phantom: PhantomData,
         }
     }
@@ -92,8 +115,21 @@ impl<T: Serialize> View<T> {
         self.status = status;
         self
     }
+
+    /// Attach a `Link` preload/preconnect hint for a critical asset
+    /// referenced by the rendered page (e.g. a stylesheet or font).
+    ///
+    /// The server hands each request a single response, so this can't emit
+    /// a true `103 Early Hints` interim response; the hint is instead added
+    /// to the final response's `Link` headers, which browsers honor the
+    /// same way, just without the earlier round trip.
+    pub fn preload(mut self, hint: PreloadHint) -> Self {
+        self.preload.push(hint);
+        self
+    }
 }
 
+#[cfg(feature = "tera")]
 impl View<()> {
     /// Create a view by rendering a template with a tera Context
     pub async fn render_context(
@@ -105,14 +141,115 @@ impl View<()> {
         Self {
             content,
             status: StatusCode::OK,
+            preload: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A response that renders a markdown string to an HTML page.
+///
+/// Useful for docs/changelog endpoints backed by a `.md` file or database
+/// column - `Markdown::from_source` renders it standalone, or
+/// `Markdown::render` wraps it in a Tera `layout` alongside the rest of
+/// `context` (available to the layout as its `content` field).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_view::{Markdown, Templates};
+///
+/// async fn changelog(templates: State<Templates>) -> Markdown<()> {
+///     let source = std::fs::read_to_string("CHANGELOG.md").unwrap();
+///     Markdown::from_source(source)
+/// }
+/// ```
+#[cfg(feature = "markdown")]
+pub struct Markdown<T> {
+    content: Result<String, ViewError>,
+    status: StatusCode,
+    preload: Vec<PreloadHint>,
+    _phantom: PhantomData<T>,
+}
+
+#[cfg(feature = "markdown")]
+impl Markdown<()> {
+    /// Render `markdown` straight to sanitized HTML, with no layout.
+    pub fn from_source(markdown: impl AsRef<str>) -> Self {
+        Self {
+            content: Ok(crate::markdown::render_markdown(markdown.as_ref())),
+            status: StatusCode::OK,
+            preload: Vec::new(),
             _phantom: PhantomData,
         }
     }
 }
 
+#[cfg(feature = "markdown")]
+impl<T: Serialize> Markdown<T> {
+    /// Render `markdown` to sanitized HTML and pass it as `content` into
+    /// `layout`, alongside `context`'s other fields.
+    pub async fn render(
+        templates: &Templates,
+        layout: &str,
+        markdown: impl AsRef<str>,
+        context: T,
+    ) -> Self {
+        let mut value = match serde_json::to_value(&context) {
+            Ok(value) => value,
+            Err(e) => {
+                return Self {
+                    content: Err(ViewError::serialization_error(e.to_string())),
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    preload: Vec::new(),
+                    _phantom: PhantomData,
+                }
+            }
+        };
+
+        let html = crate::markdown::render_markdown(markdown.as_ref());
+        match &mut value {
+            serde_json::Value::Object(map) => {
+                map.insert("content".to_string(), serde_json::Value::String(html));
+            }
+            _ => value = serde_json::json!({ "content": html }),
+        }
+
+        let content = templates.render_value(layout, value).await;
+        Self {
+            content,
+            status: StatusCode::OK,
+            preload: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "markdown")]
+impl<T> Markdown<T> {
+    /// Attach a `Link` preload/preconnect hint; see [`View::preload`].
+    pub fn preload(mut self, hint: PreloadHint) -> Self {
+        self.preload.push(hint);
+        self
+    }
+}
+
+#[cfg(feature = "markdown")]
+impl<T> IntoResponse for Markdown<T> {
+    fn into_response(self) -> Response<Full<Bytes>> {
+        View::<T> {
+            content: self.content,
+            status: self.status,
+            preload: self.preload,
+            _phantom: PhantomData,
+        }
+        .into_response()
+    }
+}
+
 impl<T> IntoResponse for View<T> {
     fn into_response(self) -> Response<Full<Bytes>> {
-        match self.content {
+        let mut response = match self.content {
             Ok(html) => Response::builder()
                 .status(self.status)
                 .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
@@ -130,7 +267,21 @@ impl<T> IntoResponse for View<T> {
                     )))
                     .unwrap()
             }
+        };
+
+        for hint in &self.preload {
+            response
+                .headers_mut()
+                .append(header::LINK, hint.header_value());
         }
+        response
+    }
+}
+
+#[cfg(feature = "markdown")]
+impl<T> ResponseModifier for Markdown<T> {
+    fn update_response(op: &mut Operation) {
+        View::<T>::update_response(op);
     }
 }
 
@@ -172,3 +323,37 @@ impl<T: Serialize> View<T> {
         Self::render_with_status(templates, template, context, StatusCode::UNAUTHORIZED).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preload_hints_become_link_headers() {
+        let response = View::<()>::from_html("<h1>Hi</h1>")
+            .preload(PreloadHint::preload("/app.css", "style"))
+            .preload(PreloadHint::preload("/app.js", "script"))
+            .into_response();
+
+        let links: Vec<_> = response
+            .headers()
+            .get_all(header::LINK)
+            .iter()
+            .map(|v| v.to_str().unwrap())
+            .collect();
+
+        assert_eq!(
+            links,
+            vec![
+                "</app.css>; rel=preload; as=style",
+                "</app.js>; rel=preload; as=script",
+            ]
+        );
+    }
+
+    #[test]
+    fn no_preload_hints_means_no_link_header() {
+        let response = View::<()>::from_html("<h1>Hi</h1>").into_response();
+        assert!(response.headers().get(header::LINK).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+//! Multi-node broadcast via a pluggable pub/sub transport
+//!
+//! [`Broadcast`] only fans out to subscribers within the same process. To share
+//! a broadcast channel across server instances behind a load balancer, wrap a
+//! [`BroadcastTransport`] (e.g. [`RedisTransport`], enabled by the `redis` feature)
+//! in a [`DistributedBroadcast`], which relays every sent message through the
+//! transport and re-delivers it locally, on every node, when it comes back in.
+
+use crate::{Broadcast, BroadcastReceiver, CloseCode, CloseFrame, Message, WebSocketError};
+use async_trait::async_trait;
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+use std::sync::Arc;
+
+#[cfg(feature = "redis")]
+mod redis_transport;
+#[cfg(feature = "redis")]
+pub use redis_transport::RedisTransport;
+
+/// A pub/sub transport that can relay [`Message`]s between server instances
+///
+/// Implementations only need to move opaque bytes; [`DistributedBroadcast`] takes
+/// care of encoding and decoding [`Message`]s.
+#[async_trait]
+pub trait BroadcastTransport: Send + Sync {
+    /// Publish a payload to every subscriber of `channel`, including this
+    /// node's own subscription
+    async fn publish(&self, channel: &str, payload: Vec<u8>) -> Result<(), WebSocketError>;
+
+    /// Subscribe to `channel`, returning a stream of payloads published to it
+    async fn subscribe(&self, channel: &str) -> Result<BoxStream<'static, Vec<u8>>, WebSocketError>;
+}
+
+/// A [`Broadcast`] whose messages are relayed across server instances through a
+/// [`BroadcastTransport`]
+///
+/// `send` publishes through the transport rather than fanning out locally; a
+/// background task subscribes to the same channel and delivers every message it
+/// receives (including our own) to local subscribers. This keeps a single
+/// delivery path and every node, including the sender's, sees the same ordering.
+#[derive(Clone)]
+pub struct DistributedBroadcast {
+    local: Broadcast,
+    transport: Arc<dyn BroadcastTransport>,
+    channel: String,
+}
+
+impl DistributedBroadcast {
+    /// Create a distributed broadcast on `channel` with the default local capacity
+    /// (100 messages, matching [`Broadcast::new`])
+    pub fn new(transport: impl BroadcastTransport + 'static, channel: impl Into<String>) -> Self {
+        Self::with_capacity(transport, channel, 100)
+    }
+
+    /// Create a distributed broadcast on `channel` with a specified local capacity
+    pub fn with_capacity(
+        transport: impl BroadcastTransport + 'static,
+        channel: impl Into<String>,
+        capacity: usize,
+    ) -> Self {
+        let local = Broadcast::with_capacity(capacity);
+        let transport: Arc<dyn BroadcastTransport> = Arc::new(transport);
+        let channel = channel.into();
+
+        let relay_local = local.clone();
+        let relay_transport = transport.clone();
+        let relay_channel = channel.clone();
+        tokio::spawn(async move {
+            match relay_transport.subscribe(&relay_channel).await {
+                Ok(mut incoming) => {
+                    while let Some(payload) = incoming.next().await {
+                        if let Some(msg) = decode_message(&payload) {
+                            relay_local.send(msg);
+                        }
+                    }
+                }
+                Err(err) => {
+                    tracing::error!("distributed broadcast subscribe failed: {err}");
+                }
+            }
+        });
+
+        Self {
+            local,
+            transport,
+            channel,
+        }
+    }
+
+    /// Subscribe to receive broadcast messages relayed to this node
+    pub fn subscribe(&self) -> BroadcastReceiver {
+        self.local.subscribe()
+    }
+
+    /// Publish a message to every subscriber on every node
+    pub async fn send(&self, msg: Message) -> Result<(), WebSocketError> {
+        self.transport
+            .publish(&self.channel, encode_message(&msg))
+            .await
+    }
+
+    /// Publish a text message to every subscriber on every node
+    pub async fn send_text(&self, text: impl Into<String>) -> Result<(), WebSocketError> {
+        self.send(Message::text(text)).await
+    }
+
+    /// Publish a JSON message to every subscriber on every node
+    pub async fn send_json<T: serde::Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<(), WebSocketError> {
+        self.send(Message::json(value)?).await
+    }
+
+    /// Get the number of subscribers on this node
+    pub fn subscriber_count(&self) -> usize {
+        self.local.subscriber_count()
+    }
+
+    /// Check if there are any subscribers on this node
+    pub fn has_subscribers(&self) -> bool {
+        self.local.has_subscribers()
+    }
+}
+
+/// Tag byte identifying the encoded `Message` variant on the wire
+const TAG_TEXT: u8 = 0;
+const TAG_BINARY: u8 = 1;
+const TAG_PING: u8 = 2;
+const TAG_PONG: u8 = 3;
+const TAG_CLOSE: u8 = 4;
+
+fn encode_message(msg: &Message) -> Vec<u8> {
+    match msg {
+        Message::Text(text) => {
+            let mut buf = Vec::with_capacity(1 + text.len());
+            buf.push(TAG_TEXT);
+            buf.extend_from_slice(text.as_bytes());
+            buf
+        }
+        Message::Binary(data) => {
+            let mut buf = Vec::with_capacity(1 + data.len());
+            buf.push(TAG_BINARY);
+            buf.extend_from_slice(data);
+            buf
+        }
+        Message::Ping(data) => {
+            let mut buf = Vec::with_capacity(1 + data.len());
+            buf.push(TAG_PING);
+            buf.extend_from_slice(data);
+            buf
+        }
+        Message::Pong(data) => {
+            let mut buf = Vec::with_capacity(1 + data.len());
+            buf.push(TAG_PONG);
+            buf.extend_from_slice(data);
+            buf
+        }
+        Message::Close(frame) => {
+            let mut buf = vec![TAG_CLOSE];
+            if let Some(frame) = frame {
+                buf.extend_from_slice(&frame.code.as_u16().to_be_bytes());
+                buf.extend_from_slice(frame.reason.as_bytes());
+            }
+            buf
+        }
+    }
+}
+
+fn decode_message(payload: &[u8]) -> Option<Message> {
+    let (&tag, rest) = payload.split_first()?;
+    match tag {
+        TAG_TEXT => String::from_utf8(rest.to_vec()).ok().map(Message::Text),
+        TAG_BINARY => Some(Message::Binary(rest.to_vec())),
+        TAG_PING => Some(Message::Ping(rest.to_vec())),
+        TAG_PONG => Some(Message::Pong(rest.to_vec())),
+        TAG_CLOSE if rest.is_empty() => Some(Message::Close(None)),
+        TAG_CLOSE if rest.len() >= 2 => {
+            let code = CloseCode::from(u16::from_be_bytes([rest[0], rest[1]]));
+            let reason = String::from_utf8(rest[2..].to_vec()).ok()?;
+            Some(Message::Close(Some(CloseFrame::new(code, reason))))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_and_binary_messages_roundtrip() {
+        for msg in [Message::text("hello"), Message::binary(vec![1, 2, 3])] {
+            let encoded = encode_message(&msg);
+            assert_eq!(decode_message(&encoded), Some(msg));
+        }
+    }
+
+    #[test]
+    fn ping_pong_and_close_messages_roundtrip() {
+        let messages = [
+            Message::ping(vec![9]),
+            Message::pong(vec![9]),
+            Message::close(),
+            Message::close_with(CloseCode::Normal, "bye"),
+        ];
+        for msg in messages {
+            let encoded = encode_message(&msg);
+            assert_eq!(decode_message(&encoded), Some(msg));
+        }
+    }
+
+    #[test]
+    fn decode_rejects_empty_or_unknown_payloads() {
+        assert_eq!(decode_message(&[]), None);
+        assert_eq!(decode_message(&[255]), None);
+    }
+}
@@ -0,0 +1,57 @@
+//! Redis pub/sub implementation of [`BroadcastTransport`]
+
+use super::BroadcastTransport;
+use crate::WebSocketError;
+use async_trait::async_trait;
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+use redis::AsyncCommands;
+
+/// A [`BroadcastTransport`] backed by Redis `PUBLISH`/`SUBSCRIBE`
+#[derive(Debug, Clone)]
+pub struct RedisTransport {
+    client: redis::Client,
+}
+
+impl RedisTransport {
+    /// Connect to a Redis server at `url` (e.g. `redis://127.0.0.1/`)
+    pub fn new(url: &str) -> Result<Self, WebSocketError> {
+        let client =
+            redis::Client::open(url).map_err(|e| WebSocketError::transport_error(e.to_string()))?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl BroadcastTransport for RedisTransport {
+    async fn publish(&self, channel: &str, payload: Vec<u8>) -> Result<(), WebSocketError> {
+        let mut conn = self
+            .client
+            .get_async_connection()
+            .await
+            .map_err(|e| WebSocketError::transport_error(e.to_string()))?;
+
+        conn.publish::<_, _, ()>(channel, payload)
+            .await
+            .map_err(|e| WebSocketError::transport_error(e.to_string()))
+    }
+
+    async fn subscribe(&self, channel: &str) -> Result<BoxStream<'static, Vec<u8>>, WebSocketError> {
+        let conn = self
+            .client
+            .get_async_connection()
+            .await
+            .map_err(|e| WebSocketError::transport_error(e.to_string()))?;
+
+        let mut pubsub = conn.into_pubsub();
+        pubsub
+            .subscribe(channel)
+            .await
+            .map_err(|e| WebSocketError::transport_error(e.to_string()))?;
+
+        let stream = pubsub
+            .into_on_message()
+            .map(|msg| msg.get_payload_bytes().to_vec());
+        Ok(Box::pin(stream))
+    }
+}
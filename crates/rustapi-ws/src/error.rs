@@ -17,6 +17,23 @@ pub enum WebSocketError {
     #[error("Connection closed unexpectedly")]
     ConnectionClosed,
 
+    /// No pong was received within the configured heartbeat timeout, so the
+    /// connection was closed as dead
+    #[error("WebSocket idle timeout: no pong received within {0:?}")]
+    IdleTimeout(std::time::Duration),
+
+    /// The outbound send queue was full and its `QueueFullPolicy` was `Close`
+    #[error("WebSocket send queue full, closing connection")]
+    SendQueueFull,
+
+    /// The connection exceeded its configured `WsRateLimitConfig` and was closed
+    #[error("WebSocket rate limit exceeded")]
+    RateLimitExceeded,
+
+    /// A `BroadcastTransport` (e.g. Redis pub/sub) failed to publish or subscribe
+    #[error("Broadcast transport error: {0}")]
+    TransportError(String),
+
     /// Failed to send message
     #[error("Failed to send message: {0}")]
     SendFailed(String),
@@ -81,6 +98,11 @@ impl WebSocketError {
     pub fn protocol_error(msg: impl Into<String>) -> Self {
         Self::ProtocolError(msg.into())
     }
+
+    /// Create a broadcast transport error
+    pub fn transport_error(msg: impl Into<String>) -> Self {
+        Self::TransportError(msg.into())
+    }
 }
 
 impl From<WebSocketError> for rustapi_core::ApiError {
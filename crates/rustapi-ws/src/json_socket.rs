@@ -0,0 +1,110 @@
+//! Typed JSON WebSocket protocol wrapper
+
+use crate::{CloseCode, Message, WebSocketError, WebSocketStream};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+
+/// A [`WebSocketStream`] wrapper that speaks JSON.
+///
+/// [`JsonSocket::recv_msg`] and [`JsonSocket::send_msg`] work with typed
+/// values instead of raw [`Message`]s, so handlers stop manually matching
+/// `Message::Text` and parsing it themselves.
+///
+/// A frame that isn't valid JSON for `In` is treated as a protocol error:
+/// the connection is closed with an "invalid payload" close frame and the
+/// error is returned, so one malformed frame can't leave the handler stuck
+/// talking to a client it can no longer understand. Ping/pong frames are
+/// transparently skipped.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_ws::JsonSocket;
+///
+/// let mut socket: JsonSocket<ClientMsg, ServerMsg> = JsonSocket::new(stream);
+/// while let Some(msg) = socket.recv_msg().await {
+///     let reply = handle(msg?);
+///     socket.send_msg(&reply).await?;
+/// }
+/// ```
+pub struct JsonSocket<In, Out> {
+    inner: WebSocketStream,
+    _marker: PhantomData<fn() -> (In, Out)>,
+}
+
+impl<In, Out> JsonSocket<In, Out>
+where
+    In: DeserializeOwned,
+    Out: Serialize,
+{
+    /// Wrap an existing [`WebSocketStream`] as a typed JSON socket.
+    pub fn new(inner: WebSocketStream) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Receive and deserialize the next message as `In`.
+    ///
+    /// Returns `None` once the connection is closed. On a protocol error -
+    /// a frame that doesn't deserialize as `In` - the connection is closed
+    /// with a close frame carrying the error and `Some(Err(_))` is returned.
+    pub async fn recv_msg(&mut self) -> Option<Result<In, WebSocketError>> {
+        loop {
+            match self.inner.recv().await? {
+                Ok(msg) if msg.is_close() => return None,
+                Ok(msg) if msg.is_ping() || msg.is_pong() => continue,
+                Ok(msg) => match msg.as_json::<In>() {
+                    Ok(value) => return Some(Ok(value)),
+                    Err(err) => {
+                        self.close_on_protocol_error(&err).await;
+                        return Some(Err(err));
+                    }
+                },
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+
+    /// Serialize `value` and send it as a text frame.
+    pub async fn send_msg(&mut self, value: &Out) -> Result<(), WebSocketError> {
+        let msg = Message::json(value)?;
+        self.inner.send(msg).await
+    }
+
+    /// Consume the wrapper, returning the underlying untyped stream.
+    pub fn into_inner(self) -> WebSocketStream {
+        self.inner
+    }
+
+    async fn close_on_protocol_error(&mut self, err: &WebSocketError) {
+        let close = Message::close_with(CloseCode::Invalid, err.to_string());
+        let _ = self.inner.send(close).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct Ping {
+        n: u32,
+    }
+
+    #[test]
+    fn as_json_roundtrips_through_message() {
+        let msg = Message::json(&Ping { n: 3 }).unwrap();
+        let decoded: Ping = msg.as_json().unwrap();
+        assert_eq!(decoded, Ping { n: 3 });
+    }
+
+    #[test]
+    fn non_json_text_is_a_deserialization_error() {
+        let msg = Message::text("not json");
+        let result = msg.as_json::<Ping>();
+        assert!(result.is_err());
+    }
+}
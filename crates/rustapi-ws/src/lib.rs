@@ -12,6 +12,18 @@
 //! - **Type-Safe JSON**: Serialize/deserialize JSON messages with serde
 //! - **Connection Management**: Clean connection lifecycle with proper close handling
 //! - **Broadcast Support**: Send messages to multiple connected clients
+//! - **Rooms**: Named channels with join/leave, per-room broadcast, and automatic cleanup
+//! - **Typed JSON Sockets**: [`JsonSocket`] wraps a stream in typed `recv_msg`/`send_msg`
+//! - **Limits & Backpressure**: Configurable frame/message size caps and outbound
+//!   send-queue overflow behavior via [`WebSocketUpgrade::config`]
+//! - **Distributed Broadcast**: [`DistributedBroadcast`] relays [`Broadcast`] messages
+//!   across server instances through a pluggable [`BroadcastTransport`] (e.g. Redis
+//!   pub/sub via the `redis` feature)
+//! - **Rate Limiting**: Per-connection inbound message rate limiting via
+//!   [`WebSocketUpgrade::rate_limit`], since HTTP's `RateLimitLayer` stops applying
+//!   once a connection is upgraded
+//! - **Metrics**: Prometheus gauges/counters for active connections, messages, and
+//!   close reasons, tagged by route, via [`WebSocketUpgrade::metrics`] (`metrics` feature)
 //!
 //! ## Quick Start
 //!
@@ -52,30 +64,51 @@
 
 mod broadcast;
 mod compression;
+mod distributed;
 mod error;
 mod extractor;
 mod heartbeat;
+mod json_socket;
+mod limits;
 mod message;
+mod metrics;
+mod queue;
+mod rate_limit;
+mod rooms;
 mod socket;
 mod upgrade;
 
 /// Authentication support for WebSocket connections
 pub mod auth;
 
-pub use broadcast::Broadcast;
-pub use compression::WsCompressionConfig;
+pub use broadcast::{Broadcast, BroadcastReceiver, BroadcastRecvError};
+pub use compression::{PermessageDeflate, WsCompressionConfig};
+pub use distributed::{BroadcastTransport, DistributedBroadcast};
+#[cfg(feature = "redis")]
+pub use distributed::RedisTransport;
 pub use error::WebSocketError;
 pub use extractor::WebSocket;
 pub use heartbeat::WsHeartbeatConfig;
+pub use json_socket::JsonSocket;
+pub use limits::WsConnectionConfig;
 pub use message::{CloseCode, CloseFrame, Message};
+#[cfg(feature = "metrics")]
+pub use metrics::WsMetrics;
+pub use queue::QueueFullPolicy;
+pub use rate_limit::WsRateLimitConfig;
+pub use rooms::{RoomReceiver, Rooms};
 pub use socket::{WebSocketReceiver, WebSocketSender, WebSocketStream};
 pub use upgrade::WebSocketUpgrade;
 
 /// Prelude module for convenient imports
 pub mod prelude {
     pub use crate::auth::{AuthError, Claims, TokenExtractor, TokenValidator, WsAuthConfig};
+    #[cfg(feature = "metrics")]
+    pub use crate::WsMetrics;
     pub use crate::{
-        Broadcast, CloseCode, CloseFrame, Message, WebSocket, WebSocketError, WebSocketReceiver,
-        WebSocketSender, WebSocketStream, WebSocketUpgrade, WsCompressionConfig,
+        Broadcast, BroadcastReceiver, BroadcastTransport, CloseCode, CloseFrame,
+        DistributedBroadcast, JsonSocket, Message, QueueFullPolicy, RoomReceiver, Rooms,
+        WebSocket, WebSocketError, WebSocketReceiver, WebSocketSender, WebSocketStream,
+        WebSocketUpgrade, WsCompressionConfig, WsConnectionConfig, WsRateLimitConfig,
     };
 }
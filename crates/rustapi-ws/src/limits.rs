@@ -0,0 +1,93 @@
+//! WebSocket connection size limits and outbound backpressure configuration
+
+use crate::queue::QueueFullPolicy;
+
+/// Configuration for per-connection message size limits and outbound
+/// backpressure, set via [`WebSocketUpgrade::config`](crate::WebSocketUpgrade::config).
+///
+/// Setting this (like [`heartbeat`](crate::WebSocketUpgrade::heartbeat)) upgrades the
+/// connection to a managed stream, since the outbound send queue only exists there.
+#[derive(Debug, Clone, Copy)]
+pub struct WsConnectionConfig {
+    /// Maximum size of a single incoming frame's payload, in bytes. `None` keeps
+    /// tungstenite's own default (16 MiB).
+    pub max_frame_size: Option<usize>,
+    /// Maximum size of a fully reassembled incoming message, in bytes. `None` keeps
+    /// tungstenite's own default (64 MiB).
+    pub max_message_size: Option<usize>,
+    /// How many outbound messages may be queued before `send` applies `on_full_queue`.
+    pub send_queue_capacity: usize,
+    /// What to do when the outbound queue is full.
+    pub on_full_queue: QueueFullPolicy,
+}
+
+impl Default for WsConnectionConfig {
+    fn default() -> Self {
+        Self {
+            max_frame_size: None,
+            max_message_size: None,
+            send_queue_capacity: 32,
+            on_full_queue: QueueFullPolicy::Await,
+        }
+    }
+}
+
+impl WsConnectionConfig {
+    /// Create a connection config with default limits (tungstenite's own frame/message
+    /// caps, a 32-message send queue, and blocking backpressure).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum size of a single incoming frame's payload, in bytes.
+    pub fn max_frame_size(mut self, size: usize) -> Self {
+        self.max_frame_size = Some(size);
+        self
+    }
+
+    /// Set the maximum size of a fully reassembled incoming message, in bytes.
+    pub fn max_message_size(mut self, size: usize) -> Self {
+        self.max_message_size = Some(size);
+        self
+    }
+
+    /// Set how many outbound messages may be queued before backpressure kicks in.
+    pub fn send_queue_capacity(mut self, capacity: usize) -> Self {
+        self.send_queue_capacity = capacity;
+        self
+    }
+
+    /// Set what happens once the outbound queue is full.
+    pub fn on_full_queue(mut self, policy: QueueFullPolicy) -> Self {
+        self.on_full_queue = policy;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_match_tungstenites_own_limits_being_unset() {
+        let config = WsConnectionConfig::new();
+        assert_eq!(config.max_frame_size, None);
+        assert_eq!(config.max_message_size, None);
+        assert_eq!(config.send_queue_capacity, 32);
+        assert_eq!(config.on_full_queue, QueueFullPolicy::Await);
+    }
+
+    #[test]
+    fn builder_overrides_each_field() {
+        let config = WsConnectionConfig::new()
+            .max_frame_size(1024)
+            .max_message_size(4096)
+            .send_queue_capacity(8)
+            .on_full_queue(QueueFullPolicy::DropOldest);
+
+        assert_eq!(config.max_frame_size, Some(1024));
+        assert_eq!(config.max_message_size, Some(4096));
+        assert_eq!(config.send_queue_capacity, 8);
+        assert_eq!(config.on_full_queue, QueueFullPolicy::DropOldest);
+    }
+}
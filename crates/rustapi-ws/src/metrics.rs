@@ -0,0 +1,218 @@
+//! WebSocket connection metrics
+//!
+//! Requires the `metrics` feature. Mirrors [`rustapi_core`]'s Prometheus
+//! `MetricsLayer` so WebSocket connection health shows up on the same
+//! dashboards as HTTP: a `ws_active_connections` gauge, `ws_messages_total`
+//! counter (tagged `direction`), and `ws_close_total` counter (tagged
+//! `reason`) - all tagged by `route`. Only application-visible messages are
+//! counted; protocol-level pings/pongs handled internally by the managed
+//! connection are not. Set via [`WebSocketUpgrade::metrics`](crate::WebSocketUpgrade::metrics),
+//! which (like [`heartbeat`](crate::WebSocketUpgrade::heartbeat)) upgrades the
+//! connection to a managed stream.
+
+#[cfg(feature = "metrics")]
+use prometheus::{GaugeVec, IntCounterVec, Opts, Registry};
+#[cfg(feature = "metrics")]
+use std::sync::Arc;
+
+/// Prometheus metrics for WebSocket connections
+#[cfg(feature = "metrics")]
+#[derive(Clone)]
+pub struct WsMetrics {
+    inner: Arc<WsMetricsInner>,
+}
+
+#[cfg(feature = "metrics")]
+struct WsMetricsInner {
+    registry: Registry,
+    active_connections: GaugeVec,
+    messages_total: IntCounterVec,
+    close_total: IntCounterVec,
+}
+
+#[cfg(feature = "metrics")]
+impl WsMetrics {
+    /// Create WS metrics with their own Prometheus registry
+    pub fn new() -> Self {
+        Self::with_registry(Registry::new())
+    }
+
+    /// Create WS metrics registered against an existing registry (e.g. to
+    /// share one `/metrics` endpoint with [`rustapi_core`]'s `MetricsLayer`)
+    pub fn with_registry(registry: Registry) -> Self {
+        let active_connections = GaugeVec::new(
+            Opts::new(
+                "ws_active_connections",
+                "Currently open WebSocket connections",
+            ),
+            &["route"],
+        )
+        .expect("Failed to create ws_active_connections metric");
+
+        let messages_total = IntCounterVec::new(
+            Opts::new("ws_messages_total", "Total WebSocket messages exchanged"),
+            &["route", "direction"],
+        )
+        .expect("Failed to create ws_messages_total metric");
+
+        let close_total = IntCounterVec::new(
+            Opts::new("ws_close_total", "Total WebSocket connections closed"),
+            &["route", "reason"],
+        )
+        .expect("Failed to create ws_close_total metric");
+
+        registry
+            .register(Box::new(active_connections.clone()))
+            .expect("Failed to register ws_active_connections");
+        registry
+            .register(Box::new(messages_total.clone()))
+            .expect("Failed to register ws_messages_total");
+        registry
+            .register(Box::new(close_total.clone()))
+            .expect("Failed to register ws_close_total");
+
+        Self {
+            inner: Arc::new(WsMetricsInner {
+                registry,
+                active_connections,
+                messages_total,
+                close_total,
+            }),
+        }
+    }
+
+    /// Get the Prometheus registry
+    pub fn registry(&self) -> &Registry {
+        &self.inner.registry
+    }
+
+    fn connection_opened(&self, route: &str) {
+        self.inner
+            .active_connections
+            .with_label_values(&[route])
+            .inc();
+    }
+
+    fn connection_closed(&self, route: &str, reason: &str) {
+        self.inner
+            .active_connections
+            .with_label_values(&[route])
+            .dec();
+        self.inner
+            .close_total
+            .with_label_values(&[route, reason])
+            .inc();
+    }
+
+    fn message_sent(&self, route: &str) {
+        self.inner
+            .messages_total
+            .with_label_values(&[route, "sent"])
+            .inc();
+    }
+
+    fn message_received(&self, route: &str) {
+        self.inner
+            .messages_total
+            .with_label_values(&[route, "received"])
+            .inc();
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl Default for WsMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`WsMetrics`] handle plus the route it's tagging - `()` when the
+/// `metrics` feature is disabled, so the connection lifecycle can record
+/// against it unconditionally.
+#[cfg(feature = "metrics")]
+pub(crate) type MetricsHandle = Option<(WsMetrics, String)>;
+#[cfg(not(feature = "metrics"))]
+pub(crate) type MetricsHandle = ();
+
+pub(crate) fn note_open(#[allow(unused_variables)] handle: &MetricsHandle) {
+    #[cfg(feature = "metrics")]
+    if let Some((metrics, route)) = handle {
+        metrics.connection_opened(route);
+    }
+}
+
+pub(crate) fn note_close(
+    #[allow(unused_variables)] handle: &MetricsHandle,
+    #[allow(unused_variables)] reason: &str,
+) {
+    #[cfg(feature = "metrics")]
+    if let Some((metrics, route)) = handle {
+        metrics.connection_closed(route, reason);
+    }
+}
+
+pub(crate) fn note_sent(#[allow(unused_variables)] handle: &MetricsHandle) {
+    #[cfg(feature = "metrics")]
+    if let Some((metrics, route)) = handle {
+        metrics.message_sent(route);
+    }
+}
+
+pub(crate) fn note_received(#[allow(unused_variables)] handle: &MetricsHandle) {
+    #[cfg(feature = "metrics")]
+    if let Some((metrics, route)) = handle {
+        metrics.message_received(route);
+    }
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_active_connections_and_close_reasons() {
+        let metrics = WsMetrics::new();
+        metrics.connection_opened("/ws");
+        metrics.connection_opened("/ws");
+        metrics.connection_closed("/ws", "idle_timeout");
+
+        let families = metrics.registry().gather();
+        let active = families
+            .iter()
+            .find(|mf| mf.get_name() == "ws_active_connections")
+            .unwrap();
+        assert_eq!(active.get_metric()[0].get_gauge().get_value(), 1.0);
+
+        let closes = families
+            .iter()
+            .find(|mf| mf.get_name() == "ws_close_total")
+            .unwrap();
+        let reason_label = closes.get_metric()[0]
+            .get_label()
+            .iter()
+            .find(|l| l.get_name() == "reason")
+            .unwrap();
+        assert_eq!(reason_label.get_value(), "idle_timeout");
+    }
+
+    #[test]
+    fn tracks_messages_by_direction() {
+        let metrics = WsMetrics::new();
+        metrics.message_received("/ws");
+        metrics.message_received("/ws");
+        metrics.message_sent("/ws");
+
+        let families = metrics.registry().gather();
+        let messages = families
+            .iter()
+            .find(|mf| mf.get_name() == "ws_messages_total")
+            .unwrap();
+
+        let total: u64 = messages
+            .get_metric()
+            .iter()
+            .map(|m| m.get_counter().get_value() as u64)
+            .sum();
+        assert_eq!(total, 3);
+    }
+}
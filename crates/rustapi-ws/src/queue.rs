@@ -0,0 +1,192 @@
+//! Bounded outbound message queue with configurable overflow behavior
+//!
+//! Backs the outbound side of a managed [`WebSocketStream`](crate::WebSocketStream),
+//! so `send` can apply a [`QueueFullPolicy`] instead of buffering without limit.
+
+use crate::{Message, WebSocketError};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+/// What an outbound queue does when [`OutboundSender::send`] finds it at capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueFullPolicy {
+    /// Wait for room to free up. The default.
+    Await,
+    /// Drop the oldest queued message to make room for the new one.
+    DropOldest,
+    /// Close the connection instead of accepting more messages.
+    Close,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    room_freed: Notify,
+    item_queued: Notify,
+}
+
+struct State {
+    items: VecDeque<Message>,
+    capacity: usize,
+    policy: QueueFullPolicy,
+    closed: bool,
+}
+
+/// Send half of a bounded outbound queue, created by [`channel`].
+pub(crate) struct OutboundSender {
+    shared: Arc<Shared>,
+}
+
+/// Receive half of a bounded outbound queue, created by [`channel`].
+pub(crate) struct OutboundReceiver {
+    shared: Arc<Shared>,
+}
+
+/// Create a bounded outbound queue enforcing `policy` once `capacity` is reached.
+pub(crate) fn channel(capacity: usize, policy: QueueFullPolicy) -> (OutboundSender, OutboundReceiver) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            items: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            policy,
+            closed: false,
+        }),
+        room_freed: Notify::new(),
+        item_queued: Notify::new(),
+    });
+    (
+        OutboundSender {
+            shared: shared.clone(),
+        },
+        OutboundReceiver { shared },
+    )
+}
+
+impl OutboundSender {
+    /// Enqueue `msg`, applying the configured [`QueueFullPolicy`] if the queue is full.
+    pub async fn send(&self, msg: Message) -> Result<(), WebSocketError> {
+        let mut msg = Some(msg);
+        loop {
+            let room_freed = self.shared.room_freed.notified();
+            {
+                let mut state = self.shared.state.lock().unwrap();
+                if state.closed {
+                    return Err(WebSocketError::ConnectionClosed);
+                }
+                if state.items.len() < state.capacity {
+                    state.items.push_back(msg.take().unwrap());
+                    self.shared.item_queued.notify_one();
+                    return Ok(());
+                }
+                match state.policy {
+                    QueueFullPolicy::DropOldest => {
+                        state.items.pop_front();
+                        state.items.push_back(msg.take().unwrap());
+                        self.shared.item_queued.notify_one();
+                        return Ok(());
+                    }
+                    QueueFullPolicy::Close => {
+                        state.closed = true;
+                        return Err(WebSocketError::SendQueueFull);
+                    }
+                    QueueFullPolicy::Await => {
+                        // Fall through and wait for the receiver to free up room.
+                    }
+                }
+            }
+            room_freed.await;
+        }
+    }
+}
+
+impl Drop for OutboundSender {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.closed = true;
+        drop(state);
+        self.shared.item_queued.notify_waiters();
+    }
+}
+
+impl OutboundReceiver {
+    /// Receive the next queued message, or `None` once the sender is dropped
+    /// (or the queue was closed by [`QueueFullPolicy::Close`]) and the queue is empty.
+    pub async fn recv(&mut self) -> Option<Message> {
+        loop {
+            let item_queued = self.shared.item_queued.notified();
+            {
+                let mut state = self.shared.state.lock().unwrap();
+                if let Some(msg) = state.items.pop_front() {
+                    self.shared.room_freed.notify_one();
+                    return Some(msg);
+                }
+                if state.closed {
+                    return None;
+                }
+            }
+            item_queued.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn delivers_messages_in_order() {
+        let (tx, mut rx) = channel(4, QueueFullPolicy::Await);
+        tx.send(Message::text("a")).await.unwrap();
+        tx.send(Message::text("b")).await.unwrap();
+        assert_eq!(rx.recv().await.unwrap(), Message::text("a"));
+        assert_eq!(rx.recv().await.unwrap(), Message::text("b"));
+    }
+
+    #[tokio::test]
+    async fn recv_returns_none_once_sender_dropped_and_drained() {
+        let (tx, mut rx) = channel(4, QueueFullPolicy::Await);
+        tx.send(Message::text("a")).await.unwrap();
+        drop(tx);
+        assert_eq!(rx.recv().await.unwrap(), Message::text("a"));
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_evicts_the_front_of_the_queue() {
+        let (tx, mut rx) = channel(2, QueueFullPolicy::DropOldest);
+        tx.send(Message::text("a")).await.unwrap();
+        tx.send(Message::text("b")).await.unwrap();
+        tx.send(Message::text("c")).await.unwrap();
+
+        assert_eq!(rx.recv().await.unwrap(), Message::text("b"));
+        assert_eq!(rx.recv().await.unwrap(), Message::text("c"));
+    }
+
+    #[tokio::test]
+    async fn close_policy_fails_the_send_once_full() {
+        let (tx, mut rx) = channel(1, QueueFullPolicy::Close);
+        tx.send(Message::text("a")).await.unwrap();
+        let err = tx.send(Message::text("b")).await.unwrap_err();
+        assert!(matches!(err, WebSocketError::SendQueueFull));
+
+        // The queue is now closed, so the already-queued message is the last one.
+        assert_eq!(rx.recv().await.unwrap(), Message::text("a"));
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn await_policy_unblocks_once_the_receiver_makes_room() {
+        let (tx, mut rx) = channel(1, QueueFullPolicy::Await);
+        tx.send(Message::text("a")).await.unwrap();
+
+        let sender = tokio::spawn(async move {
+            tx.send(Message::text("b")).await.unwrap();
+        });
+
+        // Give the blocked send a moment to register before making room.
+        tokio::task::yield_now().await;
+        assert_eq!(rx.recv().await.unwrap(), Message::text("a"));
+        assert_eq!(rx.recv().await.unwrap(), Message::text("b"));
+        sender.await.unwrap();
+    }
+}
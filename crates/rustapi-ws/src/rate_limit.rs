@@ -0,0 +1,103 @@
+//! Per-connection inbound message rate limiting
+
+use std::time::{Duration, Instant};
+
+/// Configuration for per-connection inbound message rate limiting, set via
+/// [`WebSocketUpgrade::rate_limit`](crate::WebSocketUpgrade::rate_limit).
+///
+/// Enforced with a token bucket: `burst` tokens are available immediately, and
+/// refill at a rate of `messages` per `window` thereafter. A connection that
+/// sends faster than the bucket refills is closed with [`CloseCode::Policy`](crate::CloseCode::Policy).
+///
+/// Unlike an HTTP `RateLimitLayer`, this only applies to messages sent *after*
+/// the upgrade - it doesn't share state across connections or nodes. Setting
+/// this (like [`heartbeat`](crate::WebSocketUpgrade::heartbeat)) upgrades the
+/// connection to a managed stream.
+#[derive(Debug, Clone, Copy)]
+pub struct WsRateLimitConfig {
+    /// Maximum number of messages allowed per `window` once the bucket has
+    /// drained down from `burst`.
+    pub messages: u32,
+    /// The window over which `messages` is measured.
+    pub window: Duration,
+    /// Tokens available immediately, before the refill rate takes over.
+    pub burst: u32,
+}
+
+impl WsRateLimitConfig {
+    /// Create a rate limit config allowing `messages` messages per `window`,
+    /// with burst capacity equal to `messages`.
+    pub fn new(messages: u32, window: Duration) -> Self {
+        Self {
+            messages,
+            window,
+            burst: messages,
+        }
+    }
+
+    /// Set the burst capacity (tokens available up front, before the refill
+    /// rate applies).
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst;
+        self
+    }
+}
+
+/// Token-bucket enforcement of a [`WsRateLimitConfig`] for a single connection.
+pub(crate) struct RateLimiter {
+    config: WsRateLimitConfig,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: WsRateLimitConfig) -> Self {
+        Self {
+            tokens: config.burst as f64,
+            last_refill: Instant::now(),
+            config,
+        }
+    }
+
+    /// Try to consume one token for an inbound message. Returns `false` if the
+    /// bucket is empty, meaning the connection has exceeded its rate limit.
+    pub(crate) fn try_acquire(&mut self) -> bool {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.last_refill = now;
+
+        let refill_rate = self.config.messages as f64 / self.config.window.as_secs_f64();
+        self.tokens = (self.tokens + elapsed * refill_rate).min(self.config.burst as f64);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_up_to_burst_immediately() {
+        let mut limiter = RateLimiter::new(WsRateLimitConfig::new(10, Duration::from_secs(1)).burst(3));
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+    }
+
+    #[test]
+    fn refills_over_time() {
+        let mut limiter = RateLimiter::new(WsRateLimitConfig::new(100, Duration::from_millis(100)).burst(1));
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(limiter.try_acquire());
+    }
+}
@@ -0,0 +1,237 @@
+//! Named rooms/channels built on top of broadcast channels
+
+use crate::broadcast::BroadcastRecvError;
+use crate::Message;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use tokio::sync::broadcast;
+
+/// A registry of named broadcast channels ("rooms" or "channels"), so
+/// chat/collab handlers don't need to build their own map-of-broadcasts
+/// around a single global [`Broadcast`](crate::Broadcast).
+///
+/// Rooms are created lazily on the first [`join`](Rooms::join) and removed
+/// automatically once their last subscriber leaves.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use rustapi_ws::Rooms;
+///
+/// let rooms = Rooms::new();
+///
+/// let mut alice = rooms.join("lobby");
+/// rooms.broadcast_text("lobby", "hello");
+/// let msg = alice.recv().await.unwrap();
+///
+/// assert_eq!(rooms.room_size("lobby"), 1);
+/// ```
+#[derive(Clone)]
+pub struct Rooms {
+    inner: Arc<RwLock<HashMap<String, RoomState>>>,
+    capacity: usize,
+}
+
+struct RoomState {
+    sender: broadcast::Sender<Message>,
+    subscriber_count: Arc<AtomicUsize>,
+}
+
+impl Rooms {
+    /// Create an empty room registry with the default per-room capacity (100 messages).
+    pub fn new() -> Self {
+        Self::with_capacity(100)
+    }
+
+    /// Create an empty room registry, using `capacity` for each room's message buffer.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(HashMap::new())),
+            capacity,
+        }
+    }
+
+    /// Join `room`, creating it if it doesn't exist yet, and return a receiver
+    /// subscribed to it.
+    pub fn join(&self, room: impl Into<String>) -> RoomReceiver {
+        let room = room.into();
+        let mut rooms = self.inner.write().unwrap();
+        let state = rooms.entry(room.clone()).or_insert_with(|| {
+            let (sender, _) = broadcast::channel(self.capacity);
+            RoomState {
+                sender,
+                subscriber_count: Arc::new(AtomicUsize::new(0)),
+            }
+        });
+        state.subscriber_count.fetch_add(1, Ordering::SeqCst);
+
+        RoomReceiver {
+            room,
+            rooms: self.inner.clone(),
+            subscriber_count: state.subscriber_count.clone(),
+            inner: state.sender.subscribe(),
+        }
+    }
+
+    /// Broadcast a message to every subscriber of `room`.
+    ///
+    /// Returns the number of subscribers that received it, or `0` if the
+    /// room doesn't exist or has no subscribers.
+    pub fn broadcast(&self, room: &str, msg: Message) -> usize {
+        self.inner
+            .read()
+            .unwrap()
+            .get(room)
+            .map(|state| state.sender.send(msg).unwrap_or(0))
+            .unwrap_or(0)
+    }
+
+    /// Broadcast a text message to every subscriber of `room`.
+    pub fn broadcast_text(&self, room: &str, text: impl Into<String>) -> usize {
+        self.broadcast(room, Message::text(text))
+    }
+
+    /// Broadcast a JSON message to every subscriber of `room`.
+    pub fn broadcast_json<T: serde::Serialize>(
+        &self,
+        room: &str,
+        value: &T,
+    ) -> Result<usize, crate::WebSocketError> {
+        let msg = Message::json(value)?;
+        Ok(self.broadcast(room, msg))
+    }
+
+    /// Number of subscribers currently in `room` (`0` if it doesn't exist).
+    pub fn room_size(&self, room: &str) -> usize {
+        self.inner
+            .read()
+            .unwrap()
+            .get(room)
+            .map(|state| state.subscriber_count.load(Ordering::SeqCst))
+            .unwrap_or(0)
+    }
+
+    /// Whether `room` currently exists (i.e. has at least one subscriber).
+    pub fn has_room(&self, room: &str) -> bool {
+        self.inner.read().unwrap().contains_key(room)
+    }
+
+    /// Names of every room that currently has at least one subscriber.
+    pub fn room_names(&self) -> Vec<String> {
+        self.inner.read().unwrap().keys().cloned().collect()
+    }
+}
+
+impl Default for Rooms {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A subscription to a room, returned by [`Rooms::join`].
+///
+/// Dropping it (or calling [`RoomReceiver::leave`] explicitly) removes the
+/// subscription; once a room's last subscriber leaves, the room itself is
+/// removed from the registry.
+pub struct RoomReceiver {
+    room: String,
+    rooms: Arc<RwLock<HashMap<String, RoomState>>>,
+    subscriber_count: Arc<AtomicUsize>,
+    inner: broadcast::Receiver<Message>,
+}
+
+impl RoomReceiver {
+    /// The room this receiver is subscribed to.
+    pub fn room(&self) -> &str {
+        &self.room
+    }
+
+    /// Receive the next message broadcast to this room.
+    ///
+    /// Returns `None` if the room's channel is closed.
+    /// Returns `Err` if messages were missed due to slow consumption.
+    pub async fn recv(&mut self) -> Option<Result<Message, BroadcastRecvError>> {
+        match self.inner.recv().await {
+            Ok(msg) => Some(Ok(msg)),
+            Err(broadcast::error::RecvError::Closed) => None,
+            Err(broadcast::error::RecvError::Lagged(count)) => {
+                Some(Err(BroadcastRecvError::Lagged(count)))
+            }
+        }
+    }
+
+    /// Leave the room. Equivalent to dropping the receiver.
+    pub fn leave(self) {}
+}
+
+impl Drop for RoomReceiver {
+    fn drop(&mut self) {
+        if self.subscriber_count.fetch_sub(1, Ordering::SeqCst) != 1 {
+            return;
+        }
+
+        // We were (probably) the last subscriber. Recheck under the lock in
+        // case another `join` raced in between the decrement above and here.
+        let mut rooms = self.rooms.write().unwrap();
+        let still_empty = rooms
+            .get(&self.room)
+            .map(|state| state.subscriber_count.load(Ordering::SeqCst) == 0)
+            .unwrap_or(false);
+        if still_empty {
+            rooms.remove(&self.room);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn join_and_broadcast_delivers_to_subscribers() {
+        let rooms = Rooms::new();
+        let mut alice = rooms.join("lobby");
+        let mut bob = rooms.join("lobby");
+
+        assert_eq!(rooms.room_size("lobby"), 2);
+
+        rooms.broadcast_text("lobby", "hi");
+
+        assert_eq!(alice.recv().await.unwrap().unwrap(), Message::text("hi"));
+        assert_eq!(bob.recv().await.unwrap().unwrap(), Message::text("hi"));
+    }
+
+    #[tokio::test]
+    async fn broadcast_to_unknown_room_is_a_noop() {
+        let rooms = Rooms::new();
+        assert_eq!(rooms.broadcast_text("nowhere", "hi"), 0);
+        assert_eq!(rooms.room_size("nowhere"), 0);
+        assert!(!rooms.has_room("nowhere"));
+    }
+
+    #[tokio::test]
+    async fn room_is_cleaned_up_once_empty() {
+        let rooms = Rooms::new();
+        let alice = rooms.join("lobby");
+        let bob = rooms.join("lobby");
+
+        assert!(rooms.has_room("lobby"));
+
+        alice.leave();
+        assert!(rooms.has_room("lobby"), "room should survive one leave");
+
+        bob.leave();
+        assert!(!rooms.has_room("lobby"), "room should be removed once empty");
+    }
+
+    #[tokio::test]
+    async fn rejoining_after_cleanup_creates_a_fresh_room() {
+        let rooms = Rooms::new();
+        rooms.join("lobby").leave();
+        assert!(!rooms.has_room("lobby"));
+
+        let _alice = rooms.join("lobby");
+        assert_eq!(rooms.room_size("lobby"), 1);
+    }
+}
@@ -1,6 +1,12 @@
 //! WebSocket stream implementation
 
-use crate::{Message, WebSocketError, WsHeartbeatConfig};
+use crate::metrics::{self, MetricsHandle};
+use crate::queue::OutboundSender;
+use crate::rate_limit::RateLimiter;
+use crate::{
+    CloseCode, CloseFrame, Message, WebSocketError, WsConnectionConfig, WsHeartbeatConfig,
+    WsRateLimitConfig,
+};
 use futures_util::{
     stream::{SplitSink, SplitStream},
     SinkExt, Stream, StreamExt,
@@ -22,7 +28,7 @@ enum StreamImpl {
     Direct(UpgradedConnection),
     /// Managed connection (heartbeat/cleanup running in background task)
     Managed {
-        tx: mpsc::Sender<Message>,
+        tx: OutboundSender,
         rx: mpsc::Receiver<Result<Message, WebSocketError>>,
     },
 }
@@ -40,31 +46,33 @@ impl WebSocketStream {
         }
     }
 
-    /// Create a new managed WebSocket stream with heartbeat
-    pub(crate) fn new_managed(inner: UpgradedConnection, config: WsHeartbeatConfig) -> Self {
+    /// Create a new managed WebSocket stream with heartbeat, connection limits,
+    /// an optional rate limit, and optional metrics
+    pub(crate) fn new_managed(
+        inner: UpgradedConnection,
+        config: WsHeartbeatConfig,
+        limits: WsConnectionConfig,
+        rate_limit: Option<WsRateLimitConfig>,
+        conn_metrics: MetricsHandle,
+    ) -> Self {
         let (mut sender, mut receiver) = inner.split();
-        let (user_tx, mut internal_rx) = mpsc::channel::<Message>(32);
+        let (user_tx, mut internal_rx) =
+            crate::queue::channel(limits.send_queue_capacity, limits.on_full_queue);
         let (internal_tx, user_rx) = mpsc::channel::<Result<Message, WebSocketError>>(32);
+        let mut rate_limiter = rate_limit.map(RateLimiter::new);
 
-        // Spawn management task
+        // Spawn management task: sends periodic pings, replies to the peer's
+        // pings, and reaps the connection if no activity (a pong or any other
+        // message) is seen within `interval + timeout`.
         tokio::spawn(async move {
+            metrics::note_open(&conn_metrics);
             let mut heartbeat_interval = tokio::time::interval(config.interval);
             // First tick finishes immediately
             heartbeat_interval.tick().await;
 
-            // For pong tracking, we can just track last activity or strictly check pongs.
-            // Simplified: we rely on TCP checks and ping writing success.
-            // If we want to enforce timeout, we need to track "last pong".
-
-            // Tungstenite handles Pong responses to our Pings automatically IF we poll the stream.
-            // But we are polling the stream in the select loop below.
-
-            // Note: Tungstenite returns Pongs as messages. We should filter them out mostly,
-            // or pass them if the user wants them?
-            // Usually heartbeat Pongs are an implementation detail.
-
             let mut last_heartbeat = tokio::time::Instant::now();
             let mut timeout_check = tokio::time::interval(config.timeout);
+            let close_reason;
 
             loop {
                 tokio::select! {
@@ -74,37 +82,48 @@ impl WebSocketStream {
                             Some(Ok(msg)) => {
                                 last_heartbeat = tokio::time::Instant::now();
                                 if msg.is_pong() {
-                                    // Received a pong (response to our ping)
+                                    // Response to one of our pings; already
+                                    // counted as activity above.
                                     continue;
                                 }
                                 if msg.is_ping() {
-                                    // Received a ping (from client)
-                                    // Tungstenite might have auto-replied if we used the right callback,
-                                    // but default poll_next does reply to pings by queueing a pong.
-                                    // We need to ensure that queued pong is sent.
-                                    // However, we are in a split stream.
-                                    // `receiver` is Stream. `sender` is Sink.
-                                    // Tungstenite's split separates them.
-                                    // The `receiver` will NOT automatically write to `sender`.
-                                    // WE must handle Ping replies if split?
-                                    // `tokio-tungstenite` docs: "You must handle Pings manually when split?"
-                                    // No, the `tungstenite` protocol handler is shared? No.
-
-                                    // If we receive a Ping, we should send a Pong.
+                                    // `receiver`/`sender` are split, so tungstenite
+                                    // won't auto-reply on our behalf - do it here.
                                     let _ = sender.send(Message::Pong(msg.into_data()).into()).await;
                                     continue;
                                 }
 
+                                if let Some(limiter) = &mut rate_limiter {
+                                    if !limiter.try_acquire() {
+                                        let close = Message::Close(Some(CloseFrame::new(
+                                            CloseCode::Policy,
+                                            "rate limit exceeded",
+                                        )));
+                                        let _ = sender.send(close.into()).await;
+                                        let _ = internal_tx
+                                            .send(Err(WebSocketError::RateLimitExceeded))
+                                            .await;
+                                        close_reason = "rate_limited";
+                                        break;
+                                    }
+                                }
+
                                 // Forward other messages to user
                                 if internal_tx.send(Ok(Message::from(msg))).await.is_err() {
+                                    close_reason = "user_dropped";
                                     break; // User dropped receiver
                                 }
+                                metrics::note_received(&conn_metrics);
                             }
                             Some(Err(e)) => {
                                 let _ = internal_tx.send(Err(WebSocketError::from(e))).await;
+                                close_reason = "receive_error";
                                 break;
                             }
-                            None => break, // Connection closed
+                            None => {
+                                close_reason = "closed_by_peer";
+                                break; // Connection closed
+                            }
                         }
                     }
 
@@ -113,31 +132,45 @@ impl WebSocketStream {
                         match msg {
                             Some(msg) => {
                                 if sender.send(msg.into()).await.is_err() {
+                                    close_reason = "send_error";
                                     break; // Connection closed
                                 }
+                                metrics::note_sent(&conn_metrics);
+                            }
+                            None => {
+                                close_reason = "user_dropped";
+                                break; // User dropped sender
                             }
-                            None => break, // User dropped sender
                         }
                     }
 
                     // 3. Send Ping
                     _ = heartbeat_interval.tick() => {
                          if sender.send(Message::Ping(vec![]).into()).await.is_err() {
+                             close_reason = "send_error";
                              break;
                          }
                     }
 
-                    // 4. Check timeout
+                    // 4. Check for a dead connection
                     _ = timeout_check.tick() => {
                         if last_heartbeat.elapsed() > config.interval + config.timeout {
-                            // Timeout
+                            let close = Message::Close(Some(CloseFrame::new(
+                                CloseCode::Policy,
+                                "idle timeout: no pong received",
+                            )));
+                            let _ = sender.send(close.into()).await;
+                            let _ = internal_tx
+                                .send(Err(WebSocketError::IdleTimeout(config.timeout)))
+                                .await;
+                            close_reason = "idle_timeout";
                             break;
-                            // This drops 'sender', closing the connection
                         }
                     }
                 }
             }
             // Loop break drops sender/receiver, closing connection
+            metrics::note_close(&conn_metrics, close_reason);
         });
 
         Self {
@@ -180,10 +213,7 @@ impl WebSocketStream {
     pub async fn send(&mut self, msg: Message) -> Result<(), WebSocketError> {
         match &mut self.inner {
             StreamImpl::Direct(s) => s.send(msg.into()).await.map_err(WebSocketError::from),
-            StreamImpl::Managed { tx, .. } => tx
-                .send(msg)
-                .await
-                .map_err(|_| WebSocketError::ConnectionClosed),
+            StreamImpl::Managed { tx, .. } => tx.send(msg).await,
         }
     }
 
@@ -221,7 +251,7 @@ impl WebSocketStream {
 
 enum SenderImpl {
     Direct(SplitSink<UpgradedConnection, tungstenite::Message>),
-    Managed(mpsc::Sender<Message>),
+    Managed(OutboundSender),
 }
 
 /// Sender half of a WebSocket stream
@@ -234,10 +264,7 @@ impl WebSocketSender {
     pub async fn send(&mut self, msg: Message) -> Result<(), WebSocketError> {
         match &mut self.inner {
             SenderImpl::Direct(s) => s.send(msg.into()).await.map_err(WebSocketError::from),
-            SenderImpl::Managed(s) => s
-                .send(msg)
-                .await
-                .map_err(|_| WebSocketError::ConnectionClosed),
+            SenderImpl::Managed(s) => s.send(msg).await,
         }
     }
 
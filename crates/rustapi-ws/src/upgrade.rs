@@ -1,14 +1,15 @@
 //! WebSocket upgrade response
 
-use crate::{WebSocketError, WebSocketStream, WsHeartbeatConfig};
+use crate::{WebSocketError, WebSocketStream, WsConnectionConfig, WsHeartbeatConfig, WsRateLimitConfig};
 use bytes::Bytes;
 use http::{header, Response, StatusCode};
 use http_body_util::Full;
 use hyper::upgrade::OnUpgrade;
 use hyper_util::rt::TokioIo;
 use rustapi_core::IntoResponse;
-use rustapi_openapi::{Operation, ResponseModifier, ResponseSpec};
+use rustapi_openapi::{MessageSchema, Operation, ResponseModifier, ResponseSpec, Undocumented};
 use std::future::Future;
+use std::marker::PhantomData;
 use std::pin::Pin;
 use tokio_tungstenite::tungstenite::protocol::Role;
 
@@ -26,7 +27,12 @@ use crate::compression::WsCompressionConfig;
 ///
 /// This type is returned from WebSocket handlers to initiate the upgrade
 /// handshake and establish a WebSocket connection.
-pub struct WebSocketUpgrade {
+///
+/// `Inbound` and `Outbound` optionally document the schemas of messages the
+/// server receives from and sends to the client, for OpenAPI generation (see
+/// [`WebSocketUpgrade::messages`]). They default to [`Undocumented`] and have
+/// no effect on the connection itself.
+pub struct WebSocketUpgrade<Inbound = Undocumented, Outbound = Undocumented> {
     /// The upgrade response
     response: Response<Full<Bytes>>,
     /// Callback to handle the WebSocket connection
@@ -40,11 +46,19 @@ pub struct WebSocketUpgrade {
     compression: Option<WsCompressionConfig>,
     /// Configured heartbeat
     heartbeat: Option<WsHeartbeatConfig>,
+    /// Configured size limits and outbound backpressure
+    limits: Option<WsConnectionConfig>,
+    /// Configured inbound message rate limit
+    rate_limit: Option<WsRateLimitConfig>,
+    /// Configured connection metrics
+    metrics: crate::metrics::MetricsHandle,
     /// OnUpgrade future from hyper
     on_upgrade_fut: Option<OnUpgrade>,
+    /// Message schema markers, see [`WebSocketUpgrade::messages`]
+    _messages: PhantomData<(Inbound, Outbound)>,
 }
 
-impl WebSocketUpgrade {
+impl WebSocketUpgrade<Undocumented, Undocumented> {
     /// Create a new WebSocket upgrade from request headers
     pub(crate) fn new(
         sec_key: String,
@@ -70,7 +84,38 @@ impl WebSocketUpgrade {
             client_extensions,
             compression: None,
             heartbeat: None,
+            limits: None,
+            rate_limit: None,
+            metrics: Default::default(),
             on_upgrade_fut,
+            _messages: PhantomData,
+        }
+    }
+}
+
+impl<Inbound, Outbound> WebSocketUpgrade<Inbound, Outbound> {
+    /// Document the schemas of the messages this route receives from and
+    /// sends to the client, for OpenAPI generation. Purely a documentation
+    /// annotation - it doesn't validate or parse messages at runtime.
+    ///
+    /// ```rust,ignore
+    /// async fn handler(ws: WebSocket) -> WebSocketUpgrade {
+    ///     ws.on_upgrade(...).messages::<ClientMsg, ServerMsg>()
+    /// }
+    /// ```
+    pub fn messages<NewInbound, NewOutbound>(self) -> WebSocketUpgrade<NewInbound, NewOutbound> {
+        WebSocketUpgrade {
+            response: self.response,
+            on_upgrade: self.on_upgrade,
+            sec_key: self.sec_key,
+            client_extensions: self.client_extensions,
+            compression: self.compression,
+            heartbeat: self.heartbeat,
+            limits: self.limits,
+            rate_limit: self.rate_limit,
+            metrics: self.metrics,
+            on_upgrade_fut: self.on_upgrade_fut,
+            _messages: PhantomData,
         }
     }
 
@@ -80,40 +125,45 @@ impl WebSocketUpgrade {
         self
     }
 
-    /// Enable WebSocket compression
-    pub fn compress(mut self, config: WsCompressionConfig) -> Self {
-        self.compression = Some(config);
+    /// Configure message size limits and outbound send-queue backpressure
+    pub fn config(mut self, config: WsConnectionConfig) -> Self {
+        self.limits = Some(config);
+        self
+    }
 
-        // Simple negotiation: if client supports it, we enable it
-        if let Some(exts) = &self.client_extensions {
-            if exts.contains("permessage-deflate") {
-                // We currently use a simple negotiation strategy
-                // TODO: Parse parameters and negotiate window bits
-                let mut header_val = String::from("permessage-deflate");
-
-                // Add server/client_no_context_takeover to reduce memory usage at cost of compression ratio
-                // This is a common default for many servers
-                header_val.push_str("; server_no_context_takeover");
-                header_val.push_str("; client_no_context_takeover");
-
-                if config.window_bits < 15 {
-                    header_val
-                        .push_str(&format!("; server_max_window_bits={}", config.window_bits));
-                }
-                if config.client_window_bits < 15 {
-                    header_val.push_str(&format!(
-                        "; client_max_window_bits={}",
-                        config.client_window_bits
-                    ));
-                }
+    /// Rate limit inbound messages, closing the connection with a policy
+    /// violation close code once the limit is exceeded
+    ///
+    /// HTTP's `RateLimitLayer` stops applying once a connection is upgraded, so
+    /// this is the WebSocket-layer equivalent for abusive senders.
+    pub fn rate_limit(mut self, config: WsRateLimitConfig) -> Self {
+        self.rate_limit = Some(config);
+        self
+    }
 
-                if let Ok(val) = header::HeaderValue::from_str(&header_val) {
-                    self.response
-                        .headers_mut()
-                        .insert("Sec-WebSocket-Extensions", val);
-                }
-            }
-        }
+    /// Track this connection's lifecycle and message counts in `metrics`,
+    /// tagged by `route`, so it shows up alongside HTTP metrics
+    ///
+    /// Requires the `metrics` feature.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(mut self, metrics: crate::metrics::WsMetrics, route: impl Into<String>) -> Self {
+        self.metrics = Some((metrics, route.into()));
+        self
+    }
+
+    /// Record a per-message deflate ("permessage-deflate", RFC 7692)
+    /// configuration for this connection.
+    ///
+    /// This currently does **not** negotiate the `Sec-WebSocket-Extensions`
+    /// response header, even if the client offered `permessage-deflate` --
+    /// `PermessageDeflate` isn't wired into [`WebSocketStream`]'s
+    /// send/receive path yet (see the module docs on
+    /// [`compression::PermessageDeflate`]), so confirming the extension
+    /// would invite a compliant client to send RSV1-compressed frames this
+    /// server can't decode. `config` is stored for whenever that wiring
+    /// lands, but has no effect until then.
+    pub fn compress(mut self, config: WsCompressionConfig) -> Self {
+        self.compression = Some(config);
         self
     }
 
@@ -162,16 +212,35 @@ impl WebSocketUpgrade {
     }
 }
 
-impl IntoResponse for WebSocketUpgrade {
+impl<Inbound, Outbound> IntoResponse for WebSocketUpgrade<Inbound, Outbound> {
     fn into_response(mut self) -> http::Response<Full<Bytes>> {
         // If we have the upgrade future and a callback, spawn the upgrade task
         if let (Some(on_upgrade), Some(callback)) =
             (self.on_upgrade_fut.take(), self.on_upgrade.take())
         {
             let heartbeat = self.heartbeat;
-
-            // TODO: Apply compression config to WebSocketConfig if/when supported by from_raw_socket
-            // Currently tungstenite negotiation logic in handshake is separate from stream config
+            let limits = self.limits;
+            let rate_limit = self.rate_limit;
+            #[allow(clippy::let_unit_value)]
+            let metrics = self.metrics;
+            #[cfg(feature = "metrics")]
+            let metrics_active = metrics.is_some();
+            #[cfg(not(feature = "metrics"))]
+            let metrics_active = false;
+
+            // Note: the negotiated `self.compression` isn't applied to the stream here.
+            // See the module docs on `compression::PermessageDeflate` for why.
+
+            let tungstenite_config = limits.map(|limits| {
+                let mut config = tokio_tungstenite::tungstenite::protocol::WebSocketConfig::default();
+                if let Some(max_frame_size) = limits.max_frame_size {
+                    config.max_frame_size = Some(max_frame_size);
+                }
+                if let Some(max_message_size) = limits.max_message_size {
+                    config.max_message_size = Some(max_message_size);
+                }
+                config
+            });
 
             tokio::spawn(async move {
                 match on_upgrade.await {
@@ -179,12 +248,22 @@ impl IntoResponse for WebSocketUpgrade {
                         let ws_stream = tokio_tungstenite::WebSocketStream::from_raw_socket(
                             TokioIo::new(upgraded),
                             Role::Server,
-                            None,
+                            tungstenite_config,
                         )
                         .await;
 
-                        let socket = if let Some(hb_config) = heartbeat {
-                            WebSocketStream::new_managed(ws_stream, hb_config)
+                        let socket = if heartbeat.is_some()
+                            || limits.is_some()
+                            || rate_limit.is_some()
+                            || metrics_active
+                        {
+                            WebSocketStream::new_managed(
+                                ws_stream,
+                                heartbeat.unwrap_or_default(),
+                                limits.unwrap_or_default(),
+                                rate_limit,
+                                metrics,
+                            )
                         } else {
                             WebSocketStream::new(ws_stream)
                         };
@@ -206,7 +285,9 @@ impl IntoResponse for WebSocketUpgrade {
     }
 }
 
-impl ResponseModifier for WebSocketUpgrade {
+impl<Inbound: MessageSchema, Outbound: MessageSchema> ResponseModifier
+    for WebSocketUpgrade<Inbound, Outbound>
+{
     fn update_response(op: &mut Operation) {
         op.responses.insert(
             "101".to_string(),
@@ -215,6 +296,24 @@ impl ResponseModifier for WebSocketUpgrade {
                 content: None,
             },
         );
+
+        let mut extension = serde_json::Map::new();
+        if let Some(schema) = Inbound::schema_ref() {
+            extension.insert(
+                "receives".to_string(),
+                serde_json::to_value(&schema).unwrap_or(serde_json::Value::Null),
+            );
+        }
+        if let Some(schema) = Outbound::schema_ref() {
+            extension.insert(
+                "sends".to_string(),
+                serde_json::to_value(&schema).unwrap_or(serde_json::Value::Null),
+            );
+        }
+        if !extension.is_empty() {
+            op.extensions
+                .insert("x-websocket".to_string(), serde_json::Value::Object(extension));
+        }
     }
 }
 